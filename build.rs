@@ -57,7 +57,7 @@ fn check_file_licenses<P: AsRef<Path>>(path: P) {
     println!("cargo:rerun-if-changed=.");
 }
 
-// The build script; it currently only checks the licenses.
+// The build script; it checks the licenses of the workspace.
 fn main() {
     // Check licenses in the current folder.
     check_file_licenses(".");
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{AddressBook, ALIASES_FILE},
+    Network,
+};
+
+use snarkvm::prelude::Address;
+
+use anyhow::{bail, Result};
+use clap::Parser;
+
+/// Manages the address book, whose aliases are accepted as `@alias` wherever a command expects an address.
+#[derive(Clone, Debug, Parser)]
+pub enum Alias {
+    /// Adds an alias for an address, or changes the address of an existing alias.
+    Add {
+        /// The alias, which may only contain letters, digits, '_', and '-'.
+        name: String,
+        /// The address.
+        #[clap(parse(try_from_str))]
+        address: Address<Network>,
+    },
+    /// Removes an alias.
+    Remove {
+        /// The alias.
+        name: String,
+    },
+    /// Lists the aliases.
+    List,
+}
+
+impl Alias {
+    /// Runs the alias command.
+    pub fn parse(self) -> Result<String> {
+        let mut book = AddressBook::load()?;
+        match self {
+            Self::Add { name, address } => {
+                let previous = book.insert(&name, address)?;
+                book.save()?;
+                match previous {
+                    Some(previous) if previous != address => {
+                        Ok(format!("✅ Changed '@{name}' from {previous} to {address}."))
+                    }
+                    _ => Ok(format!("✅ Added '@{name}' for {address}.")),
+                }
+            }
+            Self::Remove { name } => match book.remove(&name) {
+                Some(address) => {
+                    book.save()?;
+                    Ok(format!("✅ Removed '@{name}' ({address})."))
+                }
+                None => bail!("Unknown alias '@{name}'"),
+            },
+            Self::List => {
+                let aliases =
+                    book.aliases().map(|(name, address)| format!(" • @{name}: {address}")).collect::<Vec<_>>();
+                match aliases.is_empty() {
+                    true => Ok(format!(
+                        "No aliases in '{ALIASES_FILE}' (add one with 'slingshot alias add <NAME> <ADDRESS>')."
+                    )),
+                    false => Ok(format!("Aliases in '{ALIASES_FILE}':\n\n{}\n", aliases.join("\n"))),
+                }
+            }
+        }
+    }
+}
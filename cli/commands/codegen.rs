@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{helpers::fetch_program, Network};
+
+use snarkvm::prelude::{EntryType, PlaintextType, Program, ProgramID, ValueType};
+
+use anyhow::Result;
+use clap::Parser;
+use std::{fmt::Write, path::PathBuf};
+
+/// Generates typed client code for a deployed program.
+#[derive(Clone, Debug, Parser)]
+pub enum Codegen {
+    /// Generates TypeScript interfaces and typed request wrappers.
+    Ts {
+        /// The program identifier.
+        #[clap(long, parse(try_from_str))]
+        program: ProgramID<Network>,
+        /// The output directory. Defaults to the current working directory.
+        #[clap(short, long, default_value = ".")]
+        out: String,
+        /// The base endpoint of the node. Defaults to a local development node.
+        #[clap(short, long, default_value = "http://localhost:4180")]
+        endpoint: String,
+    },
+}
+
+impl Codegen {
+    /// Generates the requested client code.
+    pub async fn parse(self) -> Result<String> {
+        match self {
+            Self::Ts { program, out, endpoint } => {
+                // Fetch the program.
+                let program = fetch_program(&endpoint, &program).await?;
+
+                // Write the TypeScript module.
+                let directory = PathBuf::from(out);
+                std::fs::create_dir_all(&directory)?;
+                let path = directory.join(format!("{}.ts", program.id().name()));
+                std::fs::write(&path, typescript(&program)?)?;
+
+                Ok(format!("✅ Generated the TypeScript client for '{}' at '{}'.", program.id(), path.display()))
+            }
+        }
+    }
+}
+
+/// The helpers shared by every generated TypeScript module.
+const TYPESCRIPT_PRELUDE: &str = r#"/** An Aleo literal in its string form, including its visibility (e.g. `5u64.private`). */
+export type Literal = string;
+
+/** An Aleo plaintext object, as parsed by `parsePlaintext`. */
+export interface PlaintextObject {
+  [member: string]: Literal | PlaintextObject;
+}
+
+/** The account signing a request: a registered account token, or a raw private key. */
+export type Signer = { account_token: string } | { private_key: string };
+
+/** Parses an Aleo plaintext (e.g. a decrypted record) into an object. */
+export function parsePlaintext(text: string): Literal | PlaintextObject {
+  const tokens = text.match(/[{}:,]|[^\s{}:,]+/g) ?? [];
+  let index = 0;
+  const parse = (): Literal | PlaintextObject => {
+    const token = tokens[index++];
+    if (token !== "{") {
+      return token;
+    }
+    const object: PlaintextObject = {};
+    while (tokens[index] !== "}") {
+      const member = tokens[index++];
+      index++; // ':'
+      object[member] = parse();
+      if (tokens[index] === ",") {
+        index++;
+      }
+    }
+    index++; // '}'
+    return object;
+  };
+  return parse();
+}
+
+/** Formats an object as an Aleo plaintext. */
+export function toPlaintext(value: Literal | PlaintextObject): string {
+  if (typeof value === "string") {
+    return value;
+  }
+  return `{ ${Object.entries(value).map(([member, entry]) => `${member}: ${toPlaintext(entry)}`).join(", ")} }`;
+}
+
+/** Returns `true` if the object has exactly the given members. */
+function hasMembers(value: PlaintextObject, members: string[]): boolean {
+  const keys = Object.keys(value);
+  return keys.length === members.length && members.every((member) => keys.includes(member));
+}
+
+async function post(endpoint: string, route: string, body: unknown): Promise<any> {
+  const response = await fetch(`${endpoint}${route}`, {
+    method: "POST",
+    headers: { "Content-Type": "application/json" },
+    body: JSON.stringify(body),
+  });
+  if (!response.ok) {
+    throw new Error(`${route} failed with status ${response.status}: ${await response.text()}`);
+  }
+  return response.json();
+}
+"#;
+
+/// Returns the TypeScript module for the given program.
+fn typescript(program: &Program<Network>) -> Result<String> {
+    let mut module = String::new();
+    writeln!(module, "// Generated by `slingshot codegen ts` for '{}'. Do not edit.\n", program.id())?;
+    writeln!(module, "export const PROGRAM_ID = \"{}\";\n", program.id())?;
+    module.push_str(TYPESCRIPT_PRELUDE);
+
+    // Emit an interface for each interface in the program.
+    for (name, interface) in program.interfaces() {
+        writeln!(module, "\nexport interface {} {{", pascal_case(&name.to_string()))?;
+        for (member, member_type) in interface.members() {
+            writeln!(module, "  {member}: {};", plaintext_type(member_type))?;
+        }
+        writeln!(module, "}}")?;
+    }
+
+    // Emit an interface and a type guard for each record in the program.
+    let mut records = Vec::new();
+    for (name, record_type) in program.records() {
+        let record_name = pascal_case(&name.to_string());
+        let mut members = vec!["owner".to_string(), "gates".to_string()];
+        writeln!(module, "\nexport interface {record_name} {{")?;
+        writeln!(module, "  owner: Literal;")?;
+        writeln!(module, "  gates: Literal;")?;
+        for (entry, entry_type) in record_type.entries() {
+            let entry_type = match entry_type {
+                EntryType::Constant(plaintext) | EntryType::Public(plaintext) | EntryType::Private(plaintext) => {
+                    plaintext_type(plaintext)
+                }
+            };
+            writeln!(module, "  {entry}: {entry_type};")?;
+            members.push(entry.to_string());
+        }
+        writeln!(module, "  _nonce: Literal;")?;
+        writeln!(module, "}}")?;
+        members.push("_nonce".to_string());
+
+        let members = members.iter().map(|member| format!("\"{member}\"")).collect::<Vec<_>>().join(", ");
+        writeln!(module, "\nexport function is{record_name}(value: PlaintextObject): value is {record_name} {{")?;
+        writeln!(module, "  return hasMembers(value, [{members}]);")?;
+        writeln!(module, "}}")?;
+        records.push(record_name);
+    }
+
+    // Emit a typed wrapper for `/records/unspent`, returning the records defined by the program.
+    if !records.is_empty() {
+        writeln!(module, "\nexport type ProgramRecord = {};", records.join(" | "))?;
+        writeln!(
+            module,
+            r#"
+/** Returns the unspent records of this program owned by the given view key, keyed by commitment. */
+export async function unspentRecords(endpoint: string, viewKey: string): Promise<Map<string, ProgramRecord>> {{
+  const response = await post(endpoint, "/testnet3/records/unspent", {{ view_key: viewKey }});
+  const records = new Map<string, ProgramRecord>();
+  for (const [commitment, plaintext] of Object.entries<string>(response.records)) {{
+    const record = parsePlaintext(plaintext);
+    if (typeof record !== "string" && ({})) {{
+      records.set(commitment, record as ProgramRecord);
+    }}
+  }}
+  return records;
+}}"#,
+            records.iter().map(|record| format!("is{record}(record)")).collect::<Vec<_>>().join(" || ")
+        )?;
+    }
+
+    // Emit the inputs and a typed wrapper for `/program/execute`, for each function in the program.
+    for (name, function) in program.functions() {
+        let function_name = pascal_case(&name.to_string());
+        writeln!(module, "\nexport interface {function_name}Inputs {{")?;
+        for input in function.inputs() {
+            writeln!(module, "  {}: {};", input.register(), value_type(input.value_type()))?;
+        }
+        writeln!(module, "}}")?;
+
+        let inputs = function
+            .inputs()
+            .iter()
+            .map(|input| format!("toPlaintext(inputs.{})", input.register()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            module,
+            r#"
+/** Executes `{name}`, and returns the transaction ID. */
+export async function execute{function_name}(
+  endpoint: string,
+  signer: Signer,
+  inputs: {function_name}Inputs,
+  additionalFee?: number,
+): Promise<string> {{
+  const response = await post(endpoint, "/testnet3/program/execute", {{
+    ...signer,
+    program_id: PROGRAM_ID,
+    function_name: "{name}",
+    inputs: [{inputs}],
+    additional_fee: additionalFee ?? null,
+  }});
+  return response.transaction_id;
+}}"#
+        )?;
+    }
+
+    Ok(module)
+}
+
+/// Returns the TypeScript type for the given value type.
+fn value_type(value_type: &ValueType<Network>) -> String {
+    match value_type {
+        ValueType::Constant(plaintext) | ValueType::Public(plaintext) | ValueType::Private(plaintext) => {
+            plaintext_type(plaintext)
+        }
+        ValueType::Record(record_name) => pascal_case(&record_name.to_string()),
+        ValueType::ExternalRecord(_) => "PlaintextObject".to_string(),
+    }
+}
+
+/// Returns the TypeScript type for the given plaintext type.
+fn plaintext_type(plaintext_type: &PlaintextType<Network>) -> String {
+    match plaintext_type {
+        PlaintextType::Literal(_) => "Literal".to_string(),
+        PlaintextType::Interface(interface_name) => pascal_case(&interface_name.to_string()),
+    }
+}
+
+/// Converts the given snake case identifier into pascal case.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut characters = word.chars();
+            match characters.next() {
+                Some(first) => first.to_uppercase().chain(characters).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
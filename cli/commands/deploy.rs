@@ -14,18 +14,38 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{messages::DeployRequest, Network};
+use crate::{
+    errors::NodeError,
+    helpers::{base_endpoint, ensure_funded, fee_record_source, project_endpoint, AccountTokens, Context, FeeOption},
+    messages::{
+        DeployRequest,
+        DeploymentCost,
+        FeeEstimate,
+        FeeEstimateRequest,
+        NodeRequest,
+        ProgramMetadata,
+        Signer,
+    },
+    output,
+    Network,
+};
 
+use slingshot_client::ClientError;
 use snarkvm::{
     file::{AleoFile, Manifest},
     package::Package,
-    prelude::ProgramID,
+    prelude::{Field, Program, ProgramID, ViewKey},
 };
 
-use anyhow::{bail, ensure, Result};
+use anyhow::{anyhow, bail, ensure, Result};
 use clap::Parser;
 use colored::Colorize;
-use std::{path::PathBuf, str::FromStr};
+use notify::{Event, RecursiveMode, Watcher};
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
 
 // TODO: Prettify
 
@@ -36,25 +56,45 @@ pub struct Deploy {
     #[clap(parse(try_from_str))]
     pub program: ProgramID<Network>,
     /// The deployment fee in gates.
-    #[clap(short, long, help = "The deployment fee in gates, defaults to 0.")]
-    pub fee: Option<u64>,
-    /// The endpoint to deploy to. Defaults to a local development node.
+    #[clap(short, long, help = "The deployment fee in gates, or 'auto' to estimate it, defaults to 0.")]
+    pub fee: Option<FeeOption>,
+    /// The endpoint to deploy to. Defaults to the endpoint in the project file, or a local development node.
     #[clap(short, long)]
     pub endpoint: Option<String>,
     /// A path to a directory containing a manifest file. Defaults to the current working directory.
     #[clap(short, long)]
     pub path: Option<String>,
+    /// Watches the program directory, and redeploys the program whenever it changes.
+    #[clap(long)]
+    pub watch: bool,
+    /// A path to a JSON file with the metadata of the program (`description`, `repository`, and the
+    /// documentation of each function in `functions`), served by the node alongside the program.
+    #[clap(long)]
+    pub metadata: Option<PathBuf>,
+    /// A path to a JSON file of the records of the account, keyed by commitment, to pay the fee from,
+    /// e.g. as saved from `/testnet3/records/unspent`.
+    #[clap(long, conflicts_with_all = &["fee-record", "auto-fund"])]
+    pub wallet: Option<PathBuf>,
+    /// The commitment of an unspent record to pay the fee from. May be repeated.
+    #[clap(long = "fee-record", parse(try_from_str), number_of_values = 1, conflicts_with = "auto-fund")]
+    pub fee_records: Vec<Field<Network>>,
+    /// Funds the account from the faucet of the node, if it has no record that can pay the fee.
+    #[clap(long)]
+    pub auto_fund: bool,
 }
 
 impl Deploy {
     /// Deploys an Aleo program with the specified name.
-    pub fn parse(self) -> Result<String> {
-        // Setup the endpoint.
-        let endpoint = self.endpoint.unwrap_or_else(|| "http://localhost:4180/testnet3/program/deploy".to_string());
+    pub async fn parse(self) -> Result<String> {
+        // Setup the endpoint, which defaults to the node in the project file of the program directory.
+        let endpoint = match self.endpoint.clone() {
+            Some(endpoint) => endpoint,
+            None => format!("{}/testnet3/program/deploy", project_endpoint(self.path.as_deref())?),
+        };
 
         // Instantiate a path to the directory containing the manifest file.
-        let directory = match self.path {
-            Some(path) => PathBuf::from_str(&path)?,
+        let directory = match &self.path {
+            Some(path) => PathBuf::from_str(path)?,
             None => std::env::current_dir()?,
         };
 
@@ -68,14 +108,132 @@ impl Deploy {
             directory.display()
         );
 
+        // Deploy the program.
+        let program = self.deploy(&directory, &endpoint).await?;
+
+        // If requested, redeploy the program whenever it changes.
+        if self.watch {
+            self.watch(&directory, &endpoint, program).await?;
+        }
+
+        Ok("".to_string())
+    }
+
+    /// Loads the program from the package in the given directory, and deploys it to the given endpoint.
+    async fn deploy(&self, directory: &Path, endpoint: &str) -> Result<Program<Network>> {
         // Open the manifest file.
-        let manifest = Manifest::<Network>::open(&directory)?;
+        let manifest = Manifest::<Network>::open(directory)?;
 
         // Retrieve the private key.
         let private_key = manifest.development_private_key();
 
+        // Load the program.
+        let program = self.load_program(directory)?;
+
+        let program_id = program.id().clone();
+        output!("📦 Deploying '{}' to the local development node...\n", &program_id.to_string().bold());
+
+        // Register the account with the node, to sign the deployment without sending the private key.
+        let registration = AccountTokens::register(endpoint, *private_key).await?;
+
+        // Determine the source of the record that pays the fee, which defaults to the records scanned by the node.
+        let record_source = fee_record_source(self.wallet.as_deref(), &self.fee_records, self.auto_fund)?;
+
+        // Ensure the account can pay the fee, before the deployment is proven.
+        if record_source.is_none() {
+            let required_fee = match self.fee {
+                Some(FeeOption::Gates(fee)) => fee,
+                _ => 0,
+            };
+            ensure_funded(&base_endpoint(endpoint), &ViewKey::try_from(private_key)?, required_fee).await?;
+        }
+
+        // Determine the fee, estimating the minimum fee that covers the storage of the deployment if requested.
+        let signer = Signer::Token(registration.token().to_string());
+        let fee = match self.fee {
+            Some(FeeOption::Auto) => {
+                let estimate = FeeEstimateRequest::new(signer.clone(), FeeEstimate::Deploy(program.clone()))
+                    .send(&format!("{}/testnet3/fee/estimate", base_endpoint(endpoint)))
+                    .await?;
+                output!("💰 Estimated the deployment fee at {} gates.\n", estimate.fee());
+                estimate.fee()
+            }
+            Some(FeeOption::Gates(fee)) => fee,
+            None => 0,
+        };
+
+        // Load the metadata of the program, if provided.
+        let metadata = self.metadata.as_ref().map(|path| Self::load_metadata(path)).transpose()?;
+
+        // Create a deployment request.
+        let request = DeployRequest::new(signer, program.clone(), fee, metadata, record_source);
+
+        // Send the deployment request to the local development node.
+        match request.send(endpoint).await {
+            Ok(response) => {
+                // The deployment changes the program list, so the cached chain context is stale.
+                Context::invalidate(endpoint);
+                output!("✅ Successfully deployed '{}' to the local development node.\n", program_id);
+                output!("{}", Self::describe_cost(response.cost()))
+            }
+            Err(error) => match error.downcast::<ClientError>() {
+                Ok(ClientError::Status { code, body }) => {
+                    return Err(NodeError::Rejected(format!(
+                        "❌ Failed to deploy '{}' to the local development node: {} {:?}",
+                        &program_id, code, body
+                    ))
+                    .into());
+                }
+                Ok(ClientError::Transport(error)) => {
+                    return Err(NodeError::Unreachable(format!(
+                        "❌ Failed to deploy '{}' to the local development node: {}",
+                        &program_id, error
+                    ))
+                    .into());
+                }
+                // Keep the cause, e.g. a typed error of the node.
+                Err(error) => {
+                    return Err(
+                        error.context(format!("❌ Failed to deploy '{program_id}' to the local development node"))
+                    );
+                }
+            },
+        };
+
+        Ok(program)
+    }
+
+    /// Returns a report of the constraints and the key sizes of each function, and the storage fee of the deployment.
+    fn describe_cost(cost: &DeploymentCost<Network>) -> String {
+        let mut message = String::from("➡️  Functions\n\n");
+        for function in &cost.functions {
+            message.push_str(&format!(
+                " • {}: {} constraints, {} bytes of proving key, {} bytes of verifying key\n",
+                function.function_name,
+                function.num_constraints,
+                function.proving_key_size_in_bytes,
+                function.verifying_key_size_in_bytes
+            ));
+        }
+        message.push_str(&format!(
+            "\nThe deployment is {} bytes, and requires a storage fee of at least {} gates.",
+            cost.size_in_bytes, cost.storage_fee
+        ));
+        message
+    }
+
+    /// Loads the metadata of the program from the JSON file at the given path.
+    fn load_metadata(path: &Path) -> Result<ProgramMetadata> {
+        let metadata = std::fs::read_to_string(path)
+            .map_err(|error| anyhow!("Failed to read the metadata '{}': {error}", path.display()))?;
+        serde_json::from_str(&metadata)
+            .map_err(|error| anyhow!("Failed to parse the metadata '{}': {error}", path.display()))
+    }
+
+    /// Loads the program to deploy from the package in the given directory.
+    fn load_program(&self, directory: &Path) -> Result<Program<Network>> {
         // Load the package.
-        let package = Package::open(&directory)?;
+        let package = Package::open(directory)?;
 
         // Load the main program.
         let program = package.program();
@@ -94,35 +252,68 @@ impl Deploy {
                 false => bail!("The program '{}' does not exist in {}", self.program, directory.display()),
             },
         };
+        Ok(program)
+    }
 
-        let program_id = program.id().clone();
-        println!("📦 Deploying '{}' to the local development node...\n", &program_id.to_string().bold());
+    /// Watches the given directory, and redeploys the program whenever an Aleo file changes.
+    async fn watch(&self, directory: &Path, endpoint: &str, mut program: Program<Network>) -> Result<()> {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            let _ = sender.send(event);
+        })?;
+        watcher.watch(directory, RecursiveMode::Recursive)?;
 
-        // Create a deployment request.
-        let request = DeployRequest::new(*private_key, program, self.fee.unwrap_or(0));
+        output!("\n👀 Watching '{}' for changes...\n", directory.display());
+        loop {
+            // Wait for a change to an Aleo file, outside of the build directory.
+            let event = receiver
+                .recv()
+                .await
+                .ok_or_else(|| anyhow!("The watcher of '{}' stopped", directory.display()))??;
+            let is_program_change = event.paths.iter().any(|path| {
+                path.extension().map_or(false, |extension| extension == "aleo")
+                    && !path.strip_prefix(directory).map_or(false, |path| path.starts_with("build"))
+            });
+            if !is_program_change {
+                continue;
+            }
+            // Wait for the remaining events of the change, such as a save from an editor, to settle.
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            while receiver.try_recv().is_ok() {}
 
-        // Send the deployment request to the local development node.
-        match request.send(&endpoint) {
-            Ok(_) => println!("✅ Successfully deployed '{}' to the local development node.", program_id),
-            Err(error) => {
-                match error.downcast::<ureq::Error>() {
-                    Ok(ureq::Error::Status(code, response)) => {
-                        bail!(
-                            "❌ Failed to deploy '{}' to the local development node: {} {:?}",
-                            &program_id,
-                            code,
-                            response.into_string()
-                        );
-                    }
-                    Ok(ureq::Error::Transport(error)) => {
-                        bail!("❌ Failed to deploy '{}' to the local development node: {}", &program_id, error);
-                    }
-                    _ => {}
+            // Reload the program, and print the changes.
+            let updated = match self.load_program(directory) {
+                Ok(program) => program,
+                Err(error) => {
+                    output!("⚠️  Failed to build the package: {error}\n");
+                    continue;
                 }
-                bail!("❌ Failed to deploy '{}' to the local development node", &program_id);
+            };
+            if updated.to_string() == program.to_string() {
+                continue;
             }
-        };
+            output!("✏️  '{}' changed:\n{}", updated.id(), program_diff(&program, &updated));
 
-        Ok("".to_string())
+            // Redeploy the program.
+            match self.deploy(directory, endpoint).await {
+                Ok(deployed) => program = deployed,
+                Err(error) => output!("{error}\n"),
+            }
+        }
+    }
+}
+
+/// Returns a line diff between the given programs, listing removed lines with `-` and added lines with `+`.
+fn program_diff(old: &Program<Network>, new: &Program<Network>) -> String {
+    let (old, new) = (old.to_string(), new.to_string());
+    let (old_lines, new_lines) = (old.lines().collect::<Vec<_>>(), new.lines().collect::<Vec<_>>());
+
+    let mut diff = String::new();
+    for line in old_lines.iter().filter(|line| !new_lines.contains(line)) {
+        diff.push_str(&format!("{}\n", format!("- {line}").red()));
+    }
+    for line in new_lines.iter().filter(|line| !old_lines.contains(line)) {
+        diff.push_str(&format!("{}\n", format!("+ {line}").green()));
     }
+    diff
 }
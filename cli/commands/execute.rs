@@ -14,18 +14,47 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{messages::ExecuteRequest, Network};
+use crate::{
+    errors::NodeError,
+    helpers::{
+        base_endpoint,
+        ensure_funded,
+        fee_record_source,
+        fetch_program,
+        inputs_template,
+        parse_value,
+        project_endpoint,
+        prompt_inputs,
+        read_inputs_file,
+        wait_for_transaction,
+        AccountTokens,
+        FeeOption,
+    },
+    messages::{
+        ExecuteRequest,
+        FeeEstimate,
+        FeeEstimateRequest,
+        NodeRequest,
+        RecordViewRequest,
+        Signer,
+    },
+    records,
+    Network,
+};
 
 use snarkos::account::Account;
 
-use snarkvm::prelude::{Address, Identifier, Locator, Value};
+use snarkvm::{
+    prelude::{Address, Field, Identifier, Locator, Transaction, Value, ViewKey},
+    synthesizer::Output,
+};
 
 use anyhow::{ensure, Result};
 use clap::Parser;
 use colored::Colorize;
 use core::str::FromStr;
 use snarkvm::{file::Manifest, prelude::ProgramID};
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 /// Executes an Aleo program function on a development node.
 #[derive(Debug, Parser)]
@@ -36,27 +65,66 @@ pub struct Execute {
     /// The function name.
     #[clap(parse(try_from_str))]
     function: Identifier<Network>,
-    /// The function inputs.
-    #[clap(parse(try_from_str))]
+    /// The function inputs, in which `@alias` is replaced with the address of the alias.
+    #[clap(parse(try_from_str = parse_value))]
     inputs: Vec<Value<Network>>,
 
-    /// The additional fee.
+    /// The additional fee in gates, or `auto` to estimate the minimum fee that covers the storage of the execution.
     #[clap(short, long)]
-    pub fee: Option<u64>,
-    /// The endpoint to deploy to. Defaults to a local development node.
+    pub fee: Option<FeeOption>,
+    /// The endpoint to deploy to. Defaults to the endpoint in the project file, or a local development node.
     #[clap(short, long)]
     pub endpoint: Option<String>,
     /// A path to a directory containing a manifest file. Defaults to the current working directory.
     #[clap(short, long)]
     pub path: Option<String>,
+    /// A path to a JSON file mapping each input, by register (e.g. `r0`) or position, to its value, or to
+    /// `{ "commitment": "..." }` for an unspent record of the account.
+    #[clap(long, conflicts_with = "inputs")]
+    pub inputs_file: Option<String>,
+    /// Fetches the function from the node, and prompts for each input with its expected type.
+    #[clap(long, conflicts_with_all = &["inputs", "inputs-file", "print-template"])]
+    pub interactive: bool,
+    /// A path to a JSON file of the records of the account, keyed by commitment, to pay the fee from,
+    /// e.g. as saved from `/testnet3/records/unspent`.
+    #[clap(long, conflicts_with_all = &["fee-record", "auto-fund"])]
+    pub wallet: Option<PathBuf>,
+    /// The commitment of an unspent record to pay the fee from. May be repeated.
+    #[clap(long = "fee-record", parse(try_from_str), number_of_values = 1, conflicts_with = "auto-fund")]
+    pub fee_records: Vec<Field<Network>>,
+    /// Funds the account from the faucet of the node, if it has no record that can pay the fee.
+    #[clap(long)]
+    pub auto_fund: bool,
+    /// Prints a JSON template of the function inputs, instead of executing the function.
+    #[clap(long)]
+    pub print_template: bool,
+    /// Waits for the transaction to be confirmed, and prints the decrypted outputs.
+    #[clap(long)]
+    pub confirm: bool,
+    /// The number of seconds to wait for confirmation.
+    #[clap(long, default_value = "120", requires = "confirm")]
+    pub timeout: u64,
+    /// Fails with the timeout exit code (5) if the transaction is still pending after the timeout.
+    /// Otherwise, the command succeeds once the transaction is accepted by the node.
+    #[clap(long, requires = "confirm")]
+    pub fail_on_pending: bool,
 }
 
 impl Execute {
     /// Executes an Aleo program function with the provided inputs.
     #[allow(clippy::format_in_format_args)]
-    pub fn parse(self) -> Result<String> {
-        // Setup the endpoint.
-        let endpoint = self.endpoint.unwrap_or_else(|| "http://localhost:4180/testnet3/program/execute".to_string());
+    pub async fn parse(self) -> Result<String> {
+        // Setup the endpoint, which defaults to the node in the project file of the program directory.
+        let endpoint = match self.endpoint {
+            Some(endpoint) => endpoint,
+            None => format!("{}/testnet3/program/execute", project_endpoint(self.path.as_deref())?),
+        };
+
+        // If requested, print the inputs template for the function.
+        if self.print_template {
+            let program = fetch_program(&base_endpoint(&endpoint), &self.program).await?;
+            return Ok(serde_json::to_string_pretty(&inputs_template(&program, &self.function)?)?);
+        }
 
         // Instantiate a path to the directory containing the manifest file.
         let directory = match self.path {
@@ -80,30 +148,130 @@ impl Execute {
         // Retrieve the private key.
         let private_key = manifest.development_private_key();
 
+        // Load the inputs from the inputs file, if one is provided, or prompt for them, if requested.
+        let inputs = match (self.inputs_file, self.interactive) {
+            (Some(inputs_file), _) => {
+                let program = fetch_program(&base_endpoint(&endpoint), &self.program).await?;
+                let view_key = ViewKey::try_from(private_key)?;
+                read_inputs_file(&PathBuf::from_str(&inputs_file)?, &program, &self.function, || async {
+                    let endpoint = format!("{}/testnet3/records/unspent", base_endpoint(&endpoint));
+                    Ok(RecordViewRequest::new(view_key).send(&endpoint).await?.records().clone())
+                })
+                .await?
+            }
+            (None, true) => {
+                let program = fetch_program(&base_endpoint(&endpoint), &self.program).await?;
+                prompt_inputs(&program, &self.function)?
+            }
+            (None, false) => self.inputs,
+        };
+
+        // Register the account with the node, to sign the execution without sending the private key.
+        let registration = AccountTokens::register(&endpoint, *private_key).await?;
+
+        // Determine the source of the record that pays the fee, which defaults to the records scanned by the node.
+        let record_source = fee_record_source(self.wallet.as_deref(), &self.fee_records, self.auto_fund)?;
+
+        // Ensure the account can pay the fee, if any, before the execution is proven.
+        if let (Some(fee), None) = (self.fee, &record_source) {
+            let required_fee = match fee {
+                FeeOption::Gates(fee) => fee,
+                FeeOption::Auto => 0,
+            };
+            ensure_funded(&base_endpoint(&endpoint), &ViewKey::try_from(private_key)?, required_fee).await?;
+        }
+
+        // Determine the fee, estimating the minimum fee that covers the storage of the execution if requested.
+        let signer = Signer::Token(registration.token().to_string());
+        let fee = match self.fee {
+            Some(FeeOption::Auto) => Some(
+                FeeEstimateRequest::new(
+                    signer.clone(),
+                    FeeEstimate::Execute(self.program, self.function, inputs.clone()),
+                )
+                .send(&format!("{}/testnet3/fee/estimate", base_endpoint(&endpoint)))
+                .await?
+                .fee(),
+            ),
+            Some(FeeOption::Gates(fee)) => Some(fee),
+            None => None,
+        };
+
         // Create the execute request.
-        let request = ExecuteRequest::new(*private_key, self.program, self.function, self.inputs, self.fee);
-
-        // TODO: Log outputs
-        // Log the outputs.
-        //match response.outputs().len() {
-        //    0 => (),
-        //    1 => println!("\n➡️  Output\n"),
-        //    _ => println!("\n➡️  Outputs\n"),
-        //};
-        //for output in response.outputs() {
-        //    println!("{}", format!(" • {output}"));
-        //}
-        //println!();
+        let request = ExecuteRequest::new(signer, self.program, self.function, inputs, fee, record_source);
 
         // Send the request and wait for the response.
-        match request.send(&endpoint) {
-            // TODO: Just send tx id?
-            Ok(_) => {
-                // Prepare the locator.
-                let locator = Locator::<Network>::from_str(&format!("{}/{}", self.program, self.function))?;
-                Ok(format!("✅ Executed '{}'", locator.to_string().bold()))
+        let response = request.send(&endpoint).await?;
+
+        // Prepare the locator.
+        let locator = Locator::<Network>::from_str(&format!("{}/{}", self.program, self.function))?;
+
+        match self.confirm {
+            true => {
+                // Wait for the transaction to be confirmed.
+                let transaction = match wait_for_transaction(
+                    &base_endpoint(&endpoint),
+                    response.transaction_id(),
+                    Duration::from_secs(self.timeout),
+                )
+                .await
+                {
+                    Ok(transaction) => transaction,
+                    Err(error)
+                        if !self.fail_on_pending
+                            && matches!(error.downcast_ref::<NodeError>(), Some(NodeError::Timeout(_))) =>
+                    {
+                        return Ok(format!(
+                            "⏳ Submitted '{}' ({}), which is still pending after {} seconds",
+                            locator.to_string().bold(),
+                            response.transaction_id(),
+                            self.timeout
+                        ));
+                    }
+                    Err(error) => return Err(error),
+                };
+                // Decrypt the outputs with the view key of the account.
+                let view_key = ViewKey::try_from(private_key)?;
+                let outputs = Self::decode_outputs(&transaction, &view_key);
+
+                // Log the outputs.
+                let mut message =
+                    format!("✅ Executed '{}' ({})\n", locator.to_string().bold(), response.transaction_id());
+                match outputs.len() {
+                    0 => (),
+                    1 => message.push_str("\n➡️  Output\n\n"),
+                    _ => message.push_str("\n➡️  Outputs\n\n"),
+                };
+                for output in outputs {
+                    message.push_str(&format!(" • {output}\n"));
+                }
+                Ok(message)
+            }
+            false => Ok(format!("✅ Executed '{}' ({})", locator.to_string().bold(), response.transaction_id())),
+        }
+    }
+
+    /// Returns the outputs of the given transaction that are visible to the given view key.
+    fn decode_outputs(transaction: &Transaction<Network>, view_key: &ViewKey<Network>) -> Vec<String> {
+        let mut outputs = Vec::new();
+        for transition in transaction.transitions() {
+            for output in transition.outputs() {
+                match output {
+                    Output::Constant(_, Some(plaintext)) => outputs.push(format!("constant {plaintext}")),
+                    Output::Public(_, Some(plaintext)) => outputs.push(format!("public {plaintext}")),
+                    Output::Private(id, _) => outputs.push(format!("private (output ID {id})")),
+                    Output::Record(commitment, _, Some(record)) => match records::is_owner(record, view_key) {
+                        true => match records::decrypt(record, view_key) {
+                            Ok(record) => outputs.push(format!("record (commitment {commitment})\n{record}")),
+                            Err(error) => outputs.push(format!("record (commitment {commitment}): {error}")),
+                        },
+                        false => outputs.push(format!("record (commitment {commitment}) owned by another account")),
+                    },
+                    Output::ExternalRecord(id) => outputs.push(format!("external record (output ID {id})")),
+                    _ => (),
+                }
             }
-            Err(error) => Err(error),
         }
+        outputs
     }
 }
@@ -0,0 +1,415 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{base_endpoint, node_client},
+    messages::{NodeRequest, RecordViewRequest},
+    Network,
+};
+
+use snarkvm::{
+    file::Manifest,
+    prelude::{Block, PrivateKey, ToBytes, Transaction, ViewKey},
+};
+
+use anyhow::{bail, ensure, Result};
+use clap::Parser;
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// The maximum number of blocks the node returns per `/blocks` request.
+const MAX_BLOCK_RANGE: u32 = 50;
+
+/// Exports ledger data into a tabular format for offline analysis.
+#[derive(Clone, Debug, Parser)]
+pub enum Export {
+    /// Exports the transactions included in the given range of blocks.
+    Transactions {
+        /// The output format [options: csv, parquet].
+        #[clap(long, default_value = "csv")]
+        format: Format,
+        /// The starting block height (inclusive).
+        #[clap(long, default_value = "0")]
+        from: u32,
+        /// The ending block height (inclusive), or `latest`.
+        #[clap(long, default_value = "latest")]
+        to: String,
+        /// The output file. Defaults to `transactions.<format>` in the current working directory.
+        #[clap(short, long)]
+        out: Option<String>,
+        /// The base endpoint of the node. Defaults to a local development node.
+        #[clap(short, long, default_value = "http://localhost:4180")]
+        endpoint: String,
+    },
+    /// Exports the decrypted records associated with an account.
+    Records {
+        /// A view key.
+        #[clap(long, conflicts_with_all = &["key", "path"])]
+        view_key: Option<String>,
+        /// A private key, from which the view key is derived.
+        #[clap(short, long, conflicts_with_all = &["path", "view_key"])]
+        key: Option<String>,
+        /// A path to a directory containing a manifest file, from whose private key the view key is derived.
+        #[clap(short, long, conflicts_with_all = &["key", "view_key"])]
+        path: Option<String>,
+        /// The output format [options: csv, parquet].
+        #[clap(long, default_value = "csv")]
+        format: Format,
+        /// The output file. Defaults to `records.<format>` in the current working directory.
+        #[clap(short, long)]
+        out: Option<String>,
+        /// The base endpoint of the node. Defaults to a local development node.
+        #[clap(short, long, default_value = "http://localhost:4180")]
+        endpoint: String,
+    },
+}
+
+impl Export {
+    /// Exports the requested data.
+    pub async fn parse(self) -> Result<String> {
+        match self {
+            Self::Transactions { format, from, to, out, endpoint } => {
+                // Resolve the ending block height.
+                let client = node_client(&base_endpoint(&endpoint));
+                let to = match to.as_str() {
+                    "latest" => client.latest_height().await?,
+                    height => height.parse::<u32>()?,
+                };
+                ensure!(from <= to, "Invalid block range: {from} is greater than {to}");
+
+                // Fetch the blocks in chunks, and collect a row per transaction.
+                let mut table = Table::new(&[
+                    ("block_height", Kind::Integer),
+                    ("block_hash", Kind::Text),
+                    ("timestamp", Kind::Integer),
+                    ("transaction_id", Kind::Text),
+                    ("type", Kind::Text),
+                    ("fee", Kind::Integer),
+                    ("num_transitions", Kind::Integer),
+                    ("functions", Kind::Text),
+                    ("size_in_bytes", Kind::Integer),
+                ]);
+                let mut start = from;
+                loop {
+                    // Fetch the blocks from the start to the last height of the chunk (inclusive).
+                    let last = to.min(start.saturating_add(MAX_BLOCK_RANGE - 1));
+                    let blocks = client.blocks(start, last.saturating_add(1)).await?;
+                    for block in &blocks {
+                        for (_, transaction) in block.transactions().iter() {
+                            table.push(Self::transaction_row(block, transaction)?);
+                        }
+                    }
+                    if last == to {
+                        break;
+                    }
+                    start = last + 1;
+                }
+
+                // Write the table.
+                let path = PathBuf::from(out.unwrap_or_else(|| format!("transactions.{format}")));
+                table.write(&path, format)?;
+                Ok(format!("✅ Exported {} transaction(s) to '{}'.", table.len(), path.display()))
+            }
+            Self::Records { view_key, key, path, format, out, endpoint } => {
+                let view_key = match (view_key, key, path) {
+                    (Some(view_key), None, None) => ViewKey::<Network>::from_str(&view_key)?,
+                    (None, Some(key), None) => ViewKey::try_from(PrivateKey::<Network>::from_str(&key)?)?,
+                    (None, None, Some(path)) => {
+                        // Instantiate a path to the directory containing the manifest file.
+                        let directory = PathBuf::from_str(&path)?;
+                        // Ensure the manifest file exists.
+                        ensure!(
+                            Manifest::<Network>::exists_at(&directory),
+                            "Please ensure that the manifest file exists in the Aleo program directory (missing '{}' at '{}')",
+                            Manifest::<Network>::file_name(),
+                            directory.display()
+                        );
+                        ViewKey::try_from(Manifest::<Network>::open(&directory)?.development_private_key())?
+                    }
+                    (None, None, None) => bail!("Please specify a view key, a private key, or a manifest file"),
+                    _ => unreachable!("Clap prevents conflicting options from being enabled"),
+                };
+
+                // Fetch the unspent records, and the spent records.
+                let request = RecordViewRequest::new(view_key);
+                let unspent = request.send(&format!("{endpoint}/testnet3/records/unspent")).await?;
+                let spent = request.send(&format!("{endpoint}/testnet3/records/spent")).await?;
+
+                // Collect a row per record.
+                let mut table = Table::new(&[
+                    ("commitment", Kind::Text),
+                    ("owner", Kind::Text),
+                    ("gates", Kind::Integer),
+                    ("spent", Kind::Text),
+                    ("record", Kind::Text),
+                ]);
+                for (is_spent, records) in [(false, unspent.records()), (true, spent.records())] {
+                    for (commitment, record) in records {
+                        table.push(vec![
+                            Cell::Text(commitment.to_string()),
+                            Cell::Text(record.owner().to_string()),
+                            Cell::Integer(***record.gates() as i64),
+                            Cell::Text(is_spent.to_string()),
+                            Cell::Text(record.to_string()),
+                        ]);
+                    }
+                }
+
+                // Write the table.
+                let path = PathBuf::from(out.unwrap_or_else(|| format!("records.{format}")));
+                table.write(&path, format)?;
+                Ok(format!(
+                    "✅ Exported {} record(s) for {} to '{}'.",
+                    table.len(),
+                    view_key.to_address(),
+                    path.display()
+                ))
+            }
+        }
+    }
+
+    /// Returns the row describing the given transaction.
+    fn transaction_row(block: &Block<Network>, transaction: &Transaction<Network>) -> Result<Vec<Cell>> {
+        let kind = match transaction {
+            Transaction::Deploy(..) => "deploy",
+            Transaction::Execute(..) => "execute",
+        };
+        let functions = transaction
+            .transitions()
+            .map(|transition| format!("{}/{}", transition.program_id(), transition.function_name()))
+            .collect::<Vec<_>>()
+            .join(";");
+
+        Ok(vec![
+            Cell::Integer(block.height() as i64),
+            Cell::Text(block.hash().to_string()),
+            Cell::Integer(block.timestamp()),
+            Cell::Text(transaction.id().to_string()),
+            Cell::Text(kind.to_string()),
+            Cell::Integer(transaction.fee()?),
+            Cell::Integer(transaction.transitions().count() as i64),
+            Cell::Text(functions),
+            Cell::Integer(transaction.to_bytes_le()?.len() as i64),
+        ])
+    }
+}
+
+/// The supported export formats.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Parquet,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(format: &str) -> Result<Self> {
+        match format.to_lowercase().as_str() {
+            "csv" => Ok(Self::Csv),
+            "parquet" => Ok(Self::Parquet),
+            _ => bail!("Unsupported export format '{format}' (expected 'csv' or 'parquet')"),
+        }
+    }
+}
+
+impl core::fmt::Display for Format {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Csv => write!(f, "csv"),
+            Self::Parquet => write!(f, "parquet"),
+        }
+    }
+}
+
+/// The type of a column.
+#[derive(Copy, Clone, Debug)]
+enum Kind {
+    Integer,
+    Text,
+}
+
+/// A single value in a row.
+#[derive(Clone, Debug)]
+enum Cell {
+    Integer(i64),
+    Text(String),
+}
+
+impl core::fmt::Display for Cell {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// An in-memory table of exported rows.
+struct Table {
+    columns: Vec<(&'static str, Kind)>,
+    rows: Vec<Vec<Cell>>,
+}
+
+impl Table {
+    /// Initializes a new table with the given columns.
+    fn new(columns: &[(&'static str, Kind)]) -> Self {
+        Self { columns: columns.to_vec(), rows: vec![] }
+    }
+
+    /// Returns the number of rows in the table.
+    fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Appends a row to the table.
+    fn push(&mut self, row: Vec<Cell>) {
+        debug_assert_eq!(row.len(), self.columns.len());
+        self.rows.push(row);
+    }
+
+    /// Writes the table to the given path, in the given format.
+    fn write(&self, path: &Path, format: Format) -> Result<()> {
+        match format {
+            Format::Csv => self.write_csv(path),
+            Format::Parquet => self.write_parquet(path),
+        }
+    }
+
+    /// Writes the table to the given path as CSV.
+    fn write_csv(&self, path: &Path) -> Result<()> {
+        let mut writer = csv::Writer::from_path(path)?;
+        writer.write_record(self.columns.iter().map(|(name, _)| *name))?;
+        for row in &self.rows {
+            writer.write_record(row.iter().map(|cell| cell.to_string()))?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes the table to the given path as Parquet.
+    #[cfg(feature = "parquet")]
+    fn write_parquet(&self, path: &Path) -> Result<()> {
+        use arrow::{
+            array::{ArrayRef, Int64Array, StringArray},
+            datatypes::{DataType, Field, Schema},
+            record_batch::RecordBatch,
+        };
+        use parquet::arrow::ArrowWriter;
+        use std::sync::Arc;
+
+        // Construct the schema.
+        let schema = Arc::new(Schema::new(
+            self.columns
+                .iter()
+                .map(|(name, kind)| match kind {
+                    Kind::Integer => Field::new(name, DataType::Int64, false),
+                    Kind::Text => Field::new(name, DataType::Utf8, false),
+                })
+                .collect::<Vec<_>>(),
+        ));
+
+        // Construct the columns.
+        let columns = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, (_, kind))| -> ArrayRef {
+                match kind {
+                    Kind::Integer => {
+                        Arc::new(Int64Array::from_iter_values(self.rows.iter().map(|row| match &row[index] {
+                            Cell::Integer(value) => *value,
+                            Cell::Text(_) => unreachable!("Integer columns only contain integer cells"),
+                        })))
+                    }
+                    Kind::Text => {
+                        Arc::new(StringArray::from_iter_values(self.rows.iter().map(|row| row[index].to_string())))
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Write the record batch.
+        let batch = RecordBatch::try_new(schema.clone(), columns)?;
+        let mut writer = ArrowWriter::try_new(std::fs::File::create(path)?, schema, None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+        Ok(())
+    }
+
+    /// Writes the table to the given path as Parquet.
+    #[cfg(not(feature = "parquet"))]
+    fn write_parquet(&self, _path: &Path) -> Result<()> {
+        bail!("Parquet export requires slingshot to be built with the 'parquet' feature")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a table with a row of each kind of cell.
+    fn sample_table() -> Table {
+        let mut table = Table::new(&[("block_height", Kind::Integer), ("transaction_id", Kind::Text)]);
+        table.push(vec![Cell::Integer(1), Cell::Text("at1abc".to_string())]);
+        table.push(vec![Cell::Integer(2), Cell::Text("at1, with a comma".to_string())]);
+        table
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let path = std::env::temp_dir().join(format!("slingshot-export-{}.csv", std::process::id()));
+        sample_table().write(&path, Format::Csv).unwrap();
+
+        let mut reader = csv::Reader::from_path(&path).unwrap();
+        assert_eq!(reader.headers().unwrap(), vec!["block_height", "transaction_id"]);
+        let rows = reader.records().map(|record| record.unwrap()).collect::<Vec<_>>();
+        assert_eq!(rows, vec![vec!["1", "at1abc"], vec!["2", "at1, with a comma"]]);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_write_parquet() {
+        use arrow::{
+            array::{Int64Array, StringArray},
+            datatypes::DataType,
+        };
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let path = std::env::temp_dir().join(format!("slingshot-export-{}.parquet", std::process::id()));
+        sample_table().write(&path, Format::Parquet).unwrap();
+
+        let file = std::fs::File::open(&path).unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file).unwrap().build().unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        let schema = batch.schema();
+        assert_eq!(schema.field(0).name(), "block_height");
+        assert_eq!(schema.field(0).data_type(), &DataType::Int64);
+        assert_eq!(schema.field(1).name(), "transaction_id");
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+
+        let heights = batch.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(heights.values(), &[1, 2]);
+        let transaction_ids = batch.column(1).as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(transaction_ids.iter().collect::<Vec<_>>(), vec![Some("at1abc"), Some("at1, with a comma")]);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{DEFAULT_ENDPOINT, PROJECT_FILE},
+    Network,
+};
+
+use snarkvm::{
+    file::Manifest,
+    prelude::{Program, ProgramID},
+};
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use core::str::FromStr;
+use std::path::PathBuf;
+
+/// The file name of the sample scenario.
+const SCENARIO_FILE: &str = "scenario.yaml";
+
+/// The number of gates the sample scenario pours into the development account, to pay for the deployment.
+const SCENARIO_POUR: u64 = 1_000_000;
+
+/// Scaffolds a program directory, with a manifest, a sample program, a project file, and a sample scenario.
+#[derive(Debug, Parser)]
+pub struct Init {
+    /// The name of the program, which is also the name of the directory.
+    name: String,
+    /// The directory in which the program directory is created. Defaults to the current working directory.
+    #[clap(short, long, default_value = ".")]
+    path: PathBuf,
+    /// The base endpoint of the node, written to the project file.
+    #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+    endpoint: String,
+}
+
+impl Init {
+    /// Creates the program directory.
+    pub fn parse(self) -> Result<String> {
+        // Ensure the name is a valid program name.
+        let program_id = ProgramID::<Network>::from_str(&format!("{}.aleo", self.name))?;
+        let source = sample_program(&program_id);
+        Program::<Network>::from_str(&source)?;
+
+        // Ensure the directory does not exist.
+        let directory = self.path.join(&self.name);
+        ensure!(!directory.exists(), "The directory '{}' already exists", directory.display());
+        std::fs::create_dir_all(&directory)?;
+
+        // Create the manifest, with a new development private key.
+        let manifest = Manifest::<Network>::create(&directory, &program_id)?;
+        std::fs::write(directory.join("main.aleo"), source)?;
+        std::fs::write(directory.join(PROJECT_FILE), project_file(&self.endpoint))?;
+        std::fs::write(directory.join(SCENARIO_FILE), sample_scenario(&program_id, &manifest))?;
+        std::fs::write(directory.join(".gitignore"), ".slingshot/\n")?;
+
+        Ok(format!(
+            "✅ Created '{program_id}' in '{}'. With a development node running, run 'slingshot run {SCENARIO_FILE}' \
+             in '{}' to deploy and execute the program.",
+            directory.display(),
+            directory.display()
+        ))
+    }
+}
+
+/// Returns the source of the sample program.
+fn sample_program(program_id: &ProgramID<Network>) -> String {
+    format!(
+        "program {program_id};
+
+function add:
+    input r0 as u32.public;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;
+"
+    )
+}
+
+/// Returns the project file, which points the commands run in the program directory at the given endpoint.
+fn project_file(endpoint: &str) -> String {
+    format!(
+        "# The configuration of the slingshot commands run in this directory.
+
+# The base endpoint of the node that 'deploy', 'execute', and 'run' send their requests to.
+endpoint = \"{endpoint}\"
+"
+    )
+}
+
+/// Returns the sample scenario, which funds the development account, deploys the program, and executes it.
+fn sample_scenario(program_id: &ProgramID<Network>, manifest: &Manifest<Network>) -> String {
+    format!(
+        "# A scenario for 'slingshot run {SCENARIO_FILE}', against the node in '{PROJECT_FILE}'.
+private_key: {private_key}
+steps:
+  - pour:
+      address: {address}
+      amount: {SCENARIO_POUR}
+  - deploy:
+      path: .
+  - execute:
+      program: {program_id}
+      function: add
+      inputs: [\"1u32\", \"2u32\"]
+      expect_outputs: [\"3u32\"]
+",
+        private_key = manifest.development_private_key(),
+        address = manifest.development_address(),
+    )
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{base_endpoint, node_client, DEFAULT_ENDPOINT},
+    node::LogEntry,
+    output,
+};
+
+use anyhow::Result;
+use clap::Parser;
+use colored::Colorize;
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// The number of milliseconds between polls of the node for new log entries, when following them.
+const FOLLOW_INTERVAL_IN_MS: u64 = 1_000;
+
+/// Prints the recent log entries of a development node, e.g. one running in a container.
+#[derive(Debug, Parser)]
+pub struct Logs {
+    /// Keeps printing the new log entries, until interrupted.
+    #[clap(short, long)]
+    follow: bool,
+    /// Prints only the entries after the given sequence number.
+    #[clap(long)]
+    since: Option<u64>,
+    /// The base endpoint of the node. Defaults to a local development node.
+    #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+    endpoint: String,
+}
+
+impl Logs {
+    /// Prints the log entries of the node.
+    pub async fn parse(self) -> Result<String> {
+        let base = base_endpoint(&self.endpoint);
+        let entries = Self::fetch(&base, self.since).await?;
+        if !self.follow {
+            return Ok(entries.iter().map(Self::format).collect::<Vec<_>>().join("\n"));
+        }
+
+        // Print the entries as they are recorded, starting after the latest entry printed.
+        let mut since = self.since;
+        let mut entries = entries;
+        loop {
+            for entry in &entries {
+                output!("{}\n", Self::format(entry));
+                since = Some(entry.sequence);
+            }
+            tokio::time::sleep(Duration::from_millis(FOLLOW_INTERVAL_IN_MS)).await;
+            entries = Self::fetch(&base, since).await?;
+        }
+    }
+
+    /// Fetches the log entries of the node at the given base endpoint, after the given sequence number, if any.
+    async fn fetch(base: &str, since: Option<u64>) -> Result<Vec<LogEntry>> {
+        node_client(base).logs(since).await
+    }
+
+    /// Formats the given log entry as a single line.
+    fn format(entry: &LogEntry) -> String {
+        let timestamp = OffsetDateTime::from_unix_timestamp_nanos(entry.timestamp_ms as i128 * 1_000_000)
+            .map(|timestamp| timestamp.to_string())
+            .unwrap_or_else(|_| entry.timestamp_ms.to_string());
+        let level = match entry.level.as_str() {
+            "ERROR" => entry.level.red(),
+            "WARN" => entry.level.yellow(),
+            "INFO" => entry.level.green(),
+            _ => entry.level.dimmed(),
+        };
+        format!("{} {level:>5} {}: {}", timestamp.dimmed(), entry.target.dimmed(), entry.message)
+    }
+}
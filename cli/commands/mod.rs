@@ -14,24 +14,78 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
+mod alias;
+pub use alias::*;
+
+mod codegen;
+pub use codegen::*;
+
 mod deploy;
 pub use deploy::*;
 
+mod eval;
+pub use eval::*;
+
+mod export;
+pub use export::*;
+
+mod init;
+pub use init::*;
+
+mod logs;
+pub use logs::*;
+
 mod node;
 pub use node::*;
 
 mod pour;
 pub use pour::*;
 
+mod profile;
+pub use profile::*;
+
+mod repl;
+pub use repl::*;
+
+mod replay;
+pub use replay::*;
+
 mod execute;
 pub use execute::*;
 
+mod run;
+pub use run::*;
+
+mod scaffold;
+pub use scaffold::*;
+
+mod split;
+pub use split::*;
+
+mod sweep;
+pub use sweep::*;
+
+#[cfg(feature = "contract-tests")]
+mod selftest;
+#[cfg(feature = "contract-tests")]
+pub use selftest::*;
+
+mod tx;
+pub use tx::*;
+
 mod update;
 pub use update::*;
 
 mod view;
 pub use view::*;
 
+use crate::{
+    helpers::{DEFAULT_HTTP_RETRIES, DEFAULT_HTTP_TIMEOUT_IN_SECS},
+    Network,
+};
+
+use snarkvm::prelude::Address;
+
 use anyhow::Result;
 use clap::Parser;
 
@@ -46,6 +100,24 @@ pub struct CLI {
     /// Specify the verbosity [options: 0, 1, 2, 3]
     #[clap(default_value = "2", short, long)]
     pub verbosity: u8,
+    /// Print plain ASCII output, without emoji or colors. This is the default if the output is not a terminal.
+    #[clap(long, global = true)]
+    pub plain: bool,
+    /// Fail, rather than warn, if the node is incompatible with the CLI.
+    #[clap(long, global = true)]
+    pub strict: bool,
+    /// Do not contact the node for its chain context; print the last-known context instead.
+    #[clap(long, global = true)]
+    pub offline: bool,
+    /// Verify that the responses of the node are signed by the given node address (see `node start --sign-responses`).
+    #[clap(long, global = true, parse(try_from_str))]
+    pub node_address: Option<Address<Network>>,
+    /// The time, in seconds, after which a request to the node times out.
+    #[clap(long, global = true, default_value_t = DEFAULT_HTTP_TIMEOUT_IN_SECS)]
+    pub http_timeout: u64,
+    /// The number of times a request the node did not process is retried, e.g. while the node is busy.
+    #[clap(long, global = true, default_value_t = DEFAULT_HTTP_RETRIES)]
+    pub http_retries: u32,
     /// Specify a subcommand.
     #[clap(subcommand)]
     pub command: Command,
@@ -53,15 +125,45 @@ pub struct CLI {
 
 #[derive(Debug, Parser)]
 pub enum Command {
+    #[clap(subcommand)]
+    Alias(Alias),
+    #[clap(subcommand)]
+    Codegen(Codegen),
     #[clap(name = "deploy")]
     Deploy(Deploy),
+    #[clap(name = "eval")]
+    Eval(Eval),
+    #[clap(subcommand)]
+    Export(Export),
+    #[clap(name = "init")]
+    Init(Init),
+    #[clap(name = "logs")]
+    Logs(Logs),
     #[clap(subcommand)]
     Node(Node),
     #[clap(name = "pour")]
     Pour(Pour),
+    #[clap(name = "profile")]
+    Profile(Profile),
+    #[clap(name = "repl")]
+    Repl(Repl),
+    #[clap(name = "replay-bundle")]
+    ReplayBundle(ReplayBundle),
     #[clap(name = "execute")]
     Execute(Execute),
+    #[clap(name = "run")]
+    Run(Run),
+    #[clap(subcommand)]
+    Scaffold(Scaffold),
+    #[cfg(feature = "contract-tests")]
+    #[clap(name = "selftest")]
+    Selftest(Selftest),
+    #[clap(name = "split")]
+    Split(Split),
+    #[clap(name = "sweep")]
+    Sweep(Sweep),
     #[clap(subcommand)]
+    Tx(Tx),
     Update(Update),
     #[clap(subcommand)]
     View(View),
@@ -69,14 +171,31 @@ pub enum Command {
 
 impl Command {
     /// Parses the command.
-    pub fn parse(self) -> Result<String> {
+    pub async fn parse(self) -> Result<String> {
         match self {
-            Self::Deploy(command) => command.parse(),
-            Self::Node(command) => command.parse(),
-            Self::Pour(command) => command.parse(),
-            Self::Execute(command) => command.parse(),
-            Self::Update(command) => command.parse(),
-            Self::View(command) => command.parse(),
+            Self::Alias(command) => command.parse(),
+            Self::Codegen(command) => command.parse().await,
+            Self::Deploy(command) => command.parse().await,
+            Self::Eval(command) => command.parse().await,
+            Self::Init(command) => command.parse(),
+            Self::Logs(command) => command.parse().await,
+            Self::Export(command) => command.parse().await,
+            Self::Node(command) => command.parse().await,
+            Self::Pour(command) => command.parse().await,
+            Self::Profile(command) => command.parse().await,
+            Self::Repl(command) => command.parse().await,
+            Self::ReplayBundle(command) => command.parse().await,
+            Self::Execute(command) => command.parse().await,
+            Self::Run(command) => command.parse().await,
+            Self::Scaffold(command) => command.parse(),
+            #[cfg(feature = "contract-tests")]
+            Self::Selftest(command) => command.parse().await,
+            Self::Split(command) => command.parse().await,
+            Self::Sweep(command) => command.parse().await,
+            Self::Tx(command) => command.parse().await,
+            // Note: The updater blocks on its own HTTP client, which must not run on the runtime of the commands.
+            Self::Update(command) => tokio::task::spawn_blocking(move || command.parse()).await?,
+            Self::View(command) => command.parse().await,
         }
     }
 }
@@ -14,19 +14,59 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::Network;
+use crate::{
+    helpers::{base_endpoint, node_client, parse_gates, Compatibility, Context, Daemon, DEFAULT_ENDPOINT},
+    messages::BuildInfo,
+    output,
+    records,
+    Network,
+};
 
 use snarkvm::file::Manifest;
 
-use crate::node::DevelopmentBeacon;
-use anyhow::{bail, ensure, Result};
+use crate::node::{
+    generate_api_token,
+    BlockTemplateScript,
+    ChainFile,
+    DevelopmentBeacon,
+    EvictionPolicy,
+    KeyCache,
+    Ledger,
+    LogBuffer,
+    NodeConfig,
+    Peer,
+    PoolLimits,
+    ReloadableConfig,
+    RestLimits,
+    Snapshot,
+    SnapshotConfig,
+    StandardBlockPolicy,
+    Tenants,
+    ViewOnlyNode,
+    DEFAULT_JOB_RETENTION_IN_SECS,
+    DEFAULT_LOG_CAPACITY,
+    DEFAULT_MAX_BODY_SIZE,
+    DEFAULT_MAX_CONCURRENT_REQUESTS,
+    DEFAULT_PROVING_TIMEOUT_IN_SECS,
+    DEFAULT_REQUEST_TIMEOUT_IN_SECS,
+    DEFAULT_REST_PORT,
+    DEFAULT_SNAPSHOT_DIR,
+};
+use anyhow::{anyhow, bail, ensure, Result};
 use clap::Parser;
 use colored::*;
 use rand::SeedableRng;
 use rand_chacha::ChaChaRng;
-use snarkvm::prelude::{Block, ConsensusMemory, ConsensusStore, PrivateKey, VM};
-use std::{net::SocketAddr, path::PathBuf, str::FromStr, sync::Arc};
-use tokio::{runtime, runtime::Runtime};
+use snarkvm::prelude::{Address, Block, ConsensusMemory, ConsensusStore, FromBytes, PrivateKey, ViewKey, VM};
+use std::{
+    net::{IpAddr, SocketAddr},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{atomic::AtomicU64, Arc},
+    time::Duration,
+};
+use time::OffsetDateTime;
+use tracing::Level;
 
 // TODO: Quiet option
 // TODO: Rethink CLI interface
@@ -42,104 +82,873 @@ pub enum Node {
         /// A path to a directory containing a manifest file.
         #[clap(short, long, conflicts_with = "private_key")]
         path: Option<String>,
+        /// A private key for the faucet. Defaults to the private key of the node.
+        #[clap(long)]
+        faucet_key: Option<String>,
+        /// Allow deploy and execute requests to contain a raw private key (legacy).
+        #[clap(long)]
+        allow_private_key_requests: bool,
+        /// Requires deploy, execute, pour, and node control requests to carry the given API token, in an
+        /// `Authorization: Bearer <TOKEN>` header. Without a value, a token is generated and printed at startup.
+        #[clap(long)]
+        api_token: Option<Option<String>>,
+        /// Signs each response, with the request it answers and the time, with the node account, in an
+        /// `X-Node-Signature` header, so that clients started with `--node-address` can detect responses
+        /// tampered with in transit.
+        #[clap(long)]
+        sign_responses: bool,
+        /// The IP address the REST and gRPC servers listen on. Use `0.0.0.0` to accept connections from
+        /// other hosts, e.g. when the node runs in a container.
+        #[clap(long, default_value = "127.0.0.1")]
+        listen_ip: IpAddr,
+        /// The port of the REST server.
+        #[clap(long, default_value_t = DEFAULT_REST_PORT)]
+        rest_port: u16,
+        /// Starts a gRPC server on the given port, alongside the REST server.
+        #[clap(long)]
+        grpc_port: Option<u16>,
+        /// The maximum size in bytes of the body of a REST request with a fixed shape (e.g. a pour or a view key).
+        /// Deployments, executions, and transactions are limited by '--max-transaction-body-size' instead.
+        /// A larger body is answered with `413 body_too_large`.
+        #[clap(long, default_value_t = DEFAULT_MAX_BODY_SIZE)]
+        max_body_size: u64,
+        /// The maximum size in bytes of the body of a deployment, an execution, or a transaction.
+        /// Defaults to the limit of the network parameters, which is also the highest allowed value.
+        #[clap(long)]
+        max_transaction_body_size: Option<u64>,
+        /// The number of seconds after which a REST request is answered with `504 Gateway Timeout`.
+        #[clap(long, default_value_t = DEFAULT_REQUEST_TIMEOUT_IN_SECS)]
+        request_timeout: u64,
+        /// The number of seconds after which a REST request that constructs a transaction (e.g. a deployment,
+        /// an execution, or a pour) is answered with `504 Gateway Timeout`.
+        #[clap(long, default_value_t = DEFAULT_PROVING_TIMEOUT_IN_SECS)]
+        proving_timeout: u64,
+        /// The maximum number of REST requests handled at once. Further requests are answered with
+        /// `503 Service Unavailable`.
+        #[clap(long, default_value_t = DEFAULT_MAX_CONCURRENT_REQUESTS)]
+        max_concurrent_requests: usize,
+        /// The number of seconds a completed background job (`?async=true`) is retained after its last update,
+        /// before it is removed from `GET /testnet3/job/<JOB_ID>` and `GET /testnet3/jobs`.
+        #[clap(long, default_value_t = DEFAULT_JOB_RETENTION_IN_SECS)]
+        job_retention: u64,
+        /// The number of seconds the host clock may fall behind the latest block (e.g. after a suspend),
+        /// before block production fails.
+        #[clap(long, default_value = "300")]
+        clock_skew_tolerance: u64,
+        /// When the node produces a block: every block interval (`interval`), as soon as a transaction is pending
+        /// (`instant`), only with `POST /testnet3/node/mine` (`manual`), once a number of transactions are pending
+        /// (`threshold:<COUNT>`), or whichever comes first of a threshold and the interval (`hybrid:<COUNT>`).
+        /// This can be changed while the node is running, with `slingshot node policy`.
+        #[clap(long, default_value = "interval")]
+        block_policy: StandardBlockPolicy,
+        /// Assigns a sequence number to each deploy and execute request of an account, echoed in the response.
+        #[clap(long)]
+        account_sequences: bool,
+        /// The number of seconds between blocks. This can be changed while the node is running,
+        /// via `POST /testnet3/node/config`.
+        #[clap(long, default_value = "15")]
+        block_interval: u64,
+        /// Produces a block every round, even if there are no pending transactions,
+        /// so that the block height and timestamp keep advancing.
+        #[clap(long)]
+        produce_empty_blocks: bool,
+        /// Changes each proposed block before it is signed, with the rules of the given JSON file
+        /// (e.g. `[{ "height": 10, "drop": [0], "reverse": true, "timestamp_offset": 5 }]`). For consensus experiments.
+        #[clap(long)]
+        block_template: Option<PathBuf>,
+        /// Splits the faucet records at initialization into the given number of records with the given gates,
+        /// written as `<COUNT>x<GATES>` (e.g. `10000x1000`).
+        #[clap(long, parse(try_from_str = parse_genesis_records))]
+        genesis_records: Option<(usize, u64)>,
+        /// The total supply minted to the faucet at genesis, in gates or with the `credits` suffix
+        /// (e.g. `5000credits`). Defaults to the supply of the network's genesis block.
+        #[clap(long, parse(try_from_str = parse_gates))]
+        supply: Option<u64>,
+        /// The share of the genesis supply kept by the faucet, in gates or with the `credits` suffix.
+        /// The rest is transferred to the node account at initialization. Requires a separate `--faucet-key`.
+        #[clap(long, requires = "faucet_key", parse(try_from_str = parse_gates))]
+        faucet_share: Option<u64>,
+        /// The number of threads used to construct the transactions of requests. Defaults to the number of cores.
+        #[clap(long)]
+        proving_threads: Option<usize>,
+        /// The maximum number of transactions the node constructs at once. Further requests wait for their turn.
+        #[clap(long, default_value = "2")]
+        max_concurrent_proofs: usize,
+        /// The maximum number of requests waiting for their turn to construct a transaction. Further requests
+        /// are answered with `429 prover_busy`, so that a burst of requests cannot exhaust the memory of the node.
+        /// Unlimited by default.
+        #[clap(long)]
+        max_queued_proofs: Option<usize>,
+        /// Persists the proving and verifying keys of program functions to the given directory,
+        /// so that they are not synthesized again after the node restarts.
+        #[clap(long)]
+        cache_dir: Option<PathBuf>,
+        /// Delegates the proofs of executions to the remote prover service at the given endpoint, which is sent the
+        /// authorization of each execution, rather than the private key. If the prover fails, the execution is
+        /// proven locally. Executions that pay an additional fee are always proven locally.
+        #[clap(long)]
+        prover_endpoint: Option<String>,
+        /// The maximum number of unconfirmed transactions in the memory pool. When it is full, and no transaction
+        /// can be evicted, further transactions are answered with `429 mempool_full`.
+        #[clap(long, default_value = "10000")]
+        pool_capacity: usize,
+        /// The maximum number of unconfirmed transactions submitted by a single account. Unlimited by default.
+        #[clap(long)]
+        pool_max_per_sender: Option<usize>,
+        /// The transaction evicted when the memory pool is full [options: lowest-fee, oldest, none].
+        #[clap(long, default_value = "lowest-fee")]
+        pool_eviction: EvictionPolicy,
+        /// Writes a snapshot of the ledger to the snapshot directory every given number of seconds,
+        /// if a block was produced since the previous snapshot.
+        #[clap(long)]
+        auto_snapshot: Option<u64>,
+        /// The directory of the snapshots.
+        #[clap(long, default_value = DEFAULT_SNAPSHOT_DIR)]
+        snapshot_dir: PathBuf,
+        /// The number of most recent snapshots to retain.
+        #[clap(long, default_value = "5")]
+        keep_snapshots: usize,
+        /// Restores the ledger from the given snapshot, by name in the snapshot directory or by path.
+        /// The node must be started with the same private keys as the node that took the snapshot.
+        #[clap(long, conflicts_with_all = &["supply", "faucet_share", "genesis_records"])]
+        restore: Option<String>,
+        /// The number of validators in the devnet, which propose blocks in turn and gossip them to each other.
+        /// The node is the first validator, and serves the REST API.
+        #[clap(long, default_value = "1")]
+        validators: usize,
+        /// The number of milliseconds before a message gossiped between validators is delivered.
+        #[clap(long, default_value = "0")]
+        gossip_latency_ms: u64,
+        /// The maximum number of milliseconds added at random to the latency of each gossiped message,
+        /// so that messages may be delivered out of order.
+        #[clap(long, default_value = "0")]
+        gossip_jitter_ms: u64,
+        /// Replays the blocks of the given chain file, written by `slingshot node export`, after its genesis block.
+        /// The node must be started with the same private keys as the node that produced the chain.
+        #[clap(long, conflicts_with_all = &["supply", "faucet_share", "genesis_records", "restore"])]
+        import: Option<PathBuf>,
+        /// Starts a restored or imported node even if its genesis block does not fund the faucet account,
+        /// in which case the faucet cannot spend anything.
+        #[clap(long)]
+        force: bool,
+        /// Replicates the blocks of the slingshot node at the given endpoint, instead of producing blocks,
+        /// so that the node serves a read replica of a shared chain.
+        #[clap(long, conflicts_with_all = &["supply", "faucet_share", "genesis_records", "restore", "import"])]
+        peer: Option<String>,
+        /// Hosts an isolated ledger for each of the given tenants (comma-separated), with its own account and
+        /// block production, served under `/tenants/{name}/testnet3/...`. Each genesis block funds the faucet key.
+        #[clap(
+            long,
+            use_value_delimiter = true,
+            conflicts_with_all = &[
+                "supply",
+                "faucet_share",
+                "genesis_records",
+                "restore",
+                "import",
+                "peer",
+                "grpc_port"
+            ]
+        )]
+        tenants: Vec<String>,
+        /// Starts a view-only node, which holds no private key and never produces blocks. It serves queries,
+        /// and accepts broadcast transactions, for the genesis block of '--genesis-file' or the chain of '--peer'.
+        #[clap(
+            long,
+            conflicts_with_all = &[
+                "key",
+                "path",
+                "faucet_key",
+                "allow_private_key_requests",
+                "sign_responses",
+                "grpc_port",
+                "produce_empty_blocks",
+                "block_policy",
+                "block_template",
+                "genesis_records",
+                "supply",
+                "faucet_share",
+                "restore",
+                "import",
+                "tenants"
+            ]
+        )]
+        view_only: bool,
+        /// The genesis block of a view-only node, as JSON (e.g. from `GET /testnet3/block/0`) or as bytes.
+        /// With '--peer', it must be the genesis block of the peer.
+        #[clap(long, requires = "view_only")]
+        genesis_file: Option<PathBuf>,
+        /// The most verbose level of the log entries retained for `GET /testnet3/node/logs`
+        /// [options: error, warn, info, debug, trace].
+        #[clap(long, default_value = "info")]
+        log_level: Level,
+        /// A JSON file of the settings that can be reloaded while the node is running, without losing its chain:
+        /// `block_interval`, `log_level`, `faucet` (`enabled`, `max_pour`), and `cors` (`allowed_origins`).
+        /// Its settings override the options above, and it is reloaded on `SIGHUP` or `slingshot node reload`.
+        #[clap(long)]
+        config: Option<PathBuf>,
+        /// Captures each deploy, execute, and pour request the node accepts, without its credentials, with its
+        /// transaction and receipt, to a reproduction bundle in the given directory, for `slingshot replay-bundle`.
+        #[clap(long, conflicts_with = "tenants")]
+        capture: Option<PathBuf>,
+        /// Starts the node in the background, and records its PID and endpoint in `~/.slingshot/node.json`,
+        /// for `slingshot node stop` and `slingshot node restart`. Its output is written to `~/.slingshot/node.log`.
+        #[clap(long)]
+        daemon: bool,
+    },
+    /// Prints the block policy of a running development node, or switches it to the given policy.
+    Policy {
+        /// The new block policy [options: interval, instant, manual, threshold:<COUNT>, hybrid:<COUNT>].
+        policy: Option<StandardBlockPolicy>,
+        /// The base endpoint of the node. Defaults to a local development node.
+        #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+        endpoint: String,
+    },
+    /// Stops the development node started in the background with `node start --daemon`.
+    Stop {
+        /// The number of seconds to wait for the node to shut down, before it is killed.
+        #[clap(long, default_value = "30")]
+        timeout: u64,
+    },
+    /// Restarts the development node started in the background with `node start --daemon`, with the same options.
+    /// The node is in-memory, so its chain starts over, unless it is restored (e.g. with `--restore`).
+    Restart {
+        /// The number of seconds to wait for the node to shut down, before it is killed.
+        #[clap(long, default_value = "30")]
+        timeout: u64,
+    },
+    /// Lists the snapshots of a development node.
+    #[clap(subcommand)]
+    Snapshots(Snapshots),
+    /// Pauses block production on a running development node, so that transactions accumulate in the memory pool.
+    Pause {
+        /// The base endpoint of the node. Defaults to a local development node.
+        #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+        endpoint: String,
+    },
+    /// Resumes block production on a running development node.
+    Resume {
+        /// The base endpoint of the node. Defaults to a local development node.
+        #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+        endpoint: String,
+    },
+    /// Reloads the configuration file of a running development node, given by `node start --config`.
+    Reload {
+        /// The base endpoint of the node. Defaults to a local development node.
+        #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+        endpoint: String,
+    },
+    /// Exports every block of a running development node to a chain file, which `node start --import` replays.
+    Export {
+        /// The path of the chain file.
+        #[clap(long)]
+        out: PathBuf,
+        /// The base endpoint of the node. Defaults to a local development node.
+        #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+        endpoint: String,
+    },
+    /// Prints the chain context of a development node: its network, latest block, and deployed programs.
+    /// With '--offline', prints the last-known context, without contacting the node.
+    Info {
+        /// The base endpoint of the node. Defaults to a local development node.
+        #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+        endpoint: String,
+    },
+    /// Prints the builds of the CLI and a running development node, and their incompatibilities.
+    Version {
+        /// The base endpoint of the node. Defaults to a local development node.
+        #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+        endpoint: String,
+    },
+    /// Upgrades the files written by an older version of slingshot to the current version of their format, in place:
+    /// a chain file, the snapshots of a snapshot directory, or a key cache.
+    Migrate {
+        /// The path of a chain file, written by `slingshot node export`.
+        #[clap(long)]
+        chain: Option<PathBuf>,
+        /// The directory of the snapshots.
+        #[clap(long)]
+        snapshot_dir: Option<PathBuf>,
+        /// The directory of the key cache, passed as '--cache-dir' to `slingshot node start`.
+        #[clap(long)]
+        cache_dir: Option<PathBuf>,
     },
 }
 
 impl Node {
     #[allow(unused_must_use)]
-    pub fn parse(self) -> Result<String> {
+    pub async fn parse(self) -> Result<String> {
+        // Start the node in the background, if requested, by running the same command without `--daemon`.
+        if let Self::Start { daemon: true, listen_ip, rest_port, .. } = &self {
+            let args = std::env::args().skip(1).filter(|arg| arg != "--daemon").collect();
+            let host = match listen_ip.is_unspecified() {
+                true => IpAddr::from([127, 0, 0, 1]),
+                false => *listen_ip,
+            };
+            let endpoint = format!("http://{}", SocketAddr::new(host, *rest_port));
+            let daemon = Daemon::start(args, endpoint, std::env::current_dir()?).await?;
+            return Ok(format!(
+                "✅ Started the node in the background (PID {}) at {}. Its output is written to '{}'.",
+                daemon.pid,
+                daemon.endpoint.bold(),
+                daemon.log_file.display()
+            ));
+        }
+
         // Parse the command and get the private key.
-        let private_key = match self {
-            Self::Start { key, path } => match (key, path) {
-                (Some(_), Some(_)) => unreachable!("Clap prevents conflicting options from being enabled"),
-                (None, None) => panic!("Please specify either a private key or a manifest file"),
-                (Some(key), None) => PrivateKey::<Network>::from_str(&key)?,
-                (None, Some(path)) => {
-                    // Instantiate a path to the directory containing the manifest file.
-                    let directory = PathBuf::from_str(&path)?;
-                    // Ensure the directory path exists.
-                    ensure!(directory.exists(), "The program directory does not exist: {}", directory.display());
-                    // Ensure the manifest file exists.
-                    ensure!(
-                        Manifest::<Network>::exists_at(&directory),
-                        "Please ensure that the manifest file exists in the Aleo program directory (missing '{}' at '{}')",
-                        Manifest::<Network>::file_name(),
-                        directory.display()
-                    );
+        let (private_key, faucet_key, supply, rest_ip, tenants, genesis_file, config) = match self {
+            Self::Start {
+                key,
+                path,
+                faucet_key,
+                allow_private_key_requests,
+                api_token,
+                sign_responses,
+                listen_ip,
+                rest_port,
+                grpc_port,
+                max_body_size,
+                max_transaction_body_size,
+                request_timeout,
+                proving_timeout,
+                max_concurrent_requests,
+                job_retention,
+                clock_skew_tolerance,
+                block_policy,
+                account_sequences,
+                block_interval,
+                produce_empty_blocks,
+                block_template,
+                genesis_records,
+                supply,
+                faucet_share,
+                proving_threads,
+                max_concurrent_proofs,
+                max_queued_proofs,
+                cache_dir,
+                prover_endpoint,
+                pool_capacity,
+                pool_max_per_sender,
+                pool_eviction,
+                auto_snapshot,
+                snapshot_dir,
+                keep_snapshots,
+                restore,
+                validators,
+                gossip_latency_ms,
+                gossip_jitter_ms,
+                import,
+                force,
+                peer,
+                tenants,
+                view_only,
+                genesis_file,
+                log_level,
+                config: config_file,
+                capture,
+                daemon: _,
+            } => {
+                // Parse the private key of the node, unless the node is view-only.
+                let private_key = match (key, path) {
+                    (Some(_), Some(_)) => unreachable!("Clap prevents conflicting options from being enabled"),
+                    (None, None) if view_only => None,
+                    (None, None) => panic!("Please specify either a private key or a manifest file"),
+                    (Some(key), None) => Some(PrivateKey::<Network>::from_str(&key)?),
+                    (None, Some(path)) => {
+                        // Instantiate a path to the directory containing the manifest file.
+                        let directory = PathBuf::from_str(&path)?;
+                        // Ensure the directory path exists.
+                        ensure!(directory.exists(), "The program directory does not exist: {}", directory.display());
+                        // Ensure the manifest file exists.
+                        ensure!(
+                            Manifest::<Network>::exists_at(&directory),
+                            "Please ensure that the manifest file exists in the Aleo program directory (missing '{}' at '{}')",
+                            Manifest::<Network>::file_name(),
+                            directory.display()
+                        );
 
-                    // Open the manifest file.
-                    let manifest = Manifest::open(&directory)?;
+                        // Open the manifest file.
+                        let manifest = Manifest::open(&directory)?;
 
-                    *manifest.development_private_key()
+                        Some(*manifest.development_private_key())
+                    }
+                };
+                // Parse the faucet private key, if one is provided.
+                let faucet_key = faucet_key.map(|key| PrivateKey::<Network>::from_str(&key)).transpose()?;
+                // Ensure the gRPC server is available.
+                if grpc_port.is_some() && !cfg!(feature = "grpc") {
+                    bail!("The gRPC server requires slingshot to be built with the 'grpc' feature");
+                }
+                // Ensure the REST server can handle requests.
+                ensure!(max_body_size > 0, "The maximum body size must be at least 1 byte");
+                ensure!(
+                    max_transaction_body_size != Some(0),
+                    "The maximum transaction body size must be at least 1 byte"
+                );
+                ensure!(request_timeout > 0, "The request timeout must be at least 1 second");
+                ensure!(proving_timeout > 0, "The proving timeout must be at least 1 second");
+                ensure!(max_concurrent_requests > 0, "The maximum number of concurrent requests must be at least 1");
+                // Ensure the block interval is positive.
+                ensure!(block_interval > 0, "The block interval must be at least 1 second");
+                // Ensure the prover can construct transactions.
+                ensure!(proving_threads != Some(0), "The number of proving threads must be at least 1");
+                ensure!(max_concurrent_proofs > 0, "The maximum number of concurrent proofs must be at least 1");
+                // Ensure the memory pool can hold transactions.
+                ensure!(pool_capacity > 0, "The memory pool capacity must be at least 1");
+                ensure!(pool_max_per_sender != Some(0), "The memory pool limit per sender must be at least 1");
+                // Ensure the snapshots are taken, and retained.
+                ensure!(auto_snapshot != Some(0), "The snapshot interval must be at least 1 second");
+                ensure!(keep_snapshots > 0, "At least 1 snapshot must be retained");
+                // Ensure the devnet has a validator.
+                ensure!(validators > 0, "The devnet must have at least 1 validator");
+                // Ensure a replica does not produce blocks.
+                ensure!(peer.is_none() || validators == 1, "A replica of a peer cannot have validators");
+                ensure!(peer.is_none() || block_template.is_none(), "A replica of a peer cannot change its blocks");
+                // Ensure a view-only node has a chain to serve, and does not produce blocks.
+                if view_only {
+                    ensure!(
+                        genesis_file.is_some() || peer.is_some(),
+                        "A view-only node requires a '--genesis-file', or a '--peer' to follow"
+                    );
+                    ensure!(validators == 1, "A view-only node cannot have validators");
+                }
+                // Load the block template script, if one is provided.
+                let block_template = block_template.map(|path| BlockTemplateScript::load(&path)).transpose()?;
+                // Locate the snapshot to restore from, if one is provided.
+                let restore = restore.map(|name| Snapshot::resolve(&snapshot_dir, &name)).transpose()?;
+                // A chain file is imported in the same way as a snapshot is restored.
+                let restore = restore.or(import);
+                // Ensure the faucet account owns the records of the restored genesis block, unless forced.
+                if let (Some(path), Some(private_key)) = (&restore, &private_key) {
+                    Self::check_genesis_owner(path, faucet_key.as_ref().unwrap_or(private_key), force)?;
                 }
-            },
+                // Ensure the faucet share does not exceed the supply.
+                if let (Some(supply), Some(faucet_share)) = (supply, faucet_share) {
+                    ensure!(faucet_share <= supply, "The faucet share ({faucet_share}) exceeds the supply ({supply})");
+                }
+                // Generate the API token, if it is required without a value.
+                let api_token = match api_token {
+                    Some(Some(api_token)) => Some(api_token),
+                    Some(None) => {
+                        let api_token = generate_api_token();
+                        output!(
+                            "🔑 Generated the API token {} (export it as SLINGSHOT_API_TOKEN for the CLI).\n",
+                            api_token.bold()
+                        );
+                        Some(api_token)
+                    }
+                    None => None,
+                };
+                // Record the log entries of the node, so that they can be read remotely.
+                let logs = LogBuffer::new(DEFAULT_LOG_CAPACITY, log_level);
+                logs.install()?;
+                // Initialize the node configuration.
+                let config = NodeConfig {
+                    allow_private_keys: allow_private_key_requests,
+                    grpc_ip: grpc_port.map(|port| SocketAddr::new(listen_ip, port)),
+                    clock_skew_tolerance,
+                    block_policy,
+                    account_sequences,
+                    block_interval: Arc::new(AtomicU64::new(block_interval)),
+                    produce_empty_blocks,
+                    genesis_records,
+                    faucet_share,
+                    proving_threads: proving_threads.unwrap_or_else(num_cpus::get),
+                    max_concurrent_proofs,
+                    max_queued_proofs,
+                    cache_dir,
+                    prover_endpoint,
+                    pool_limits: PoolLimits {
+                        capacity: pool_capacity,
+                        max_per_sender: pool_max_per_sender,
+                        eviction: pool_eviction,
+                    },
+                    auto_snapshot: auto_snapshot.map(|interval| SnapshotConfig {
+                        directory: snapshot_dir,
+                        interval,
+                        retain: keep_snapshots,
+                    }),
+                    restore,
+                    validators,
+                    gossip_latency: Duration::from_millis(gossip_latency_ms),
+                    gossip_jitter: Duration::from_millis(gossip_jitter_ms),
+                    peer,
+                    api_token,
+                    sign_responses,
+                    rest_limits: RestLimits {
+                        max_body_size,
+                        max_transaction_body_size,
+                        request_timeout: Duration::from_secs(request_timeout),
+                        proving_timeout: Duration::from_secs(proving_timeout),
+                        max_concurrent_requests,
+                    },
+                    job_retention: Duration::from_secs(job_retention),
+                    block_template,
+                    logs,
+                    faucet_policy: Default::default(),
+                    cors_policy: Default::default(),
+                    config_file,
+                    capture,
+                };
+                // Apply the configuration file, if one is provided.
+                if let Some(path) = &config.config_file {
+                    ReloadableConfig::open(path)
+                        .and_then(|settings| settings.apply(&config))
+                        .map_err(|error| anyhow!("Invalid configuration file: {error}"))?;
+                }
+                let rest_ip = SocketAddr::new(listen_ip, rest_port);
+                (private_key, faucet_key, supply, rest_ip, tenants, genesis_file, config)
+            }
+            Self::Policy { policy, endpoint } => {
+                let client = node_client(&base_endpoint(&endpoint));
+                return match policy {
+                    Some(policy) => {
+                        let response = client.set_block_policy(&policy.to_string()).await?;
+                        Ok(format!("✅ Switched the block policy to '{}'.", response.policy))
+                    }
+                    None => Ok(format!("The block policy is '{}'.", client.block_policy().await?.policy)),
+                };
+            }
+            Self::Pause { endpoint } => {
+                Self::set_block_production(&endpoint, "pause").await?;
+                return Ok("✅ Paused block production. Pending transactions are included in the next block after \
+                           'slingshot node resume'."
+                    .to_string());
+            }
+            Self::Resume { endpoint } => {
+                Self::set_block_production(&endpoint, "resume").await?;
+                return Ok("✅ Resumed block production.".to_string());
+            }
+            Self::Reload { endpoint } => {
+                let settings = node_client(&base_endpoint(&endpoint)).reload_node_config().await?;
+                return Ok(format!(
+                    "✅ Reloaded the configuration of the node.\n\n{}",
+                    serde_json::to_string_pretty(&settings)?
+                ));
+            }
+            Self::Stop { timeout } => {
+                let daemon =
+                    Daemon::load()?.ok_or_else(|| anyhow!("No node was started with 'node start --daemon'"))?;
+                daemon.stop(Duration::from_secs(timeout))?;
+                return Ok(format!("✅ Stopped the node (PID {}).", daemon.pid));
+            }
+            Self::Restart { timeout } => {
+                let daemon =
+                    Daemon::load()?.ok_or_else(|| anyhow!("No node was started with 'node start --daemon'"))?;
+                daemon.stop(Duration::from_secs(timeout))?;
+                let daemon = Daemon::start(daemon.args, daemon.endpoint, daemon.working_directory).await?;
+                return Ok(format!(
+                    "✅ Restarted the node in the background (PID {}) at {}.",
+                    daemon.pid, daemon.endpoint
+                ));
+            }
+            Self::Snapshots(command) => return command.parse(),
+            Self::Export { out, endpoint } => {
+                let node = Peer::new(&endpoint);
+                let blocks = node.chain::<Network>().await?;
+                let num_blocks = blocks.len() as u32;
+                let size_in_bytes = ChainFile::write(&out, num_blocks, blocks.into_iter().map(Ok))?;
+                return Ok(format!(
+                    "✅ Exported {num_blocks} blocks from {} to '{}' ({size_in_bytes} bytes).",
+                    node.endpoint(),
+                    out.display()
+                ));
+            }
+            Self::Info { endpoint } => return Self::info(&endpoint).await,
+            Self::Version { endpoint } => return Self::version(&endpoint).await,
+            Self::Migrate { chain, snapshot_dir, cache_dir } => return Self::migrate(chain, snapshot_dir, cache_dir),
         };
 
-        // Construct the REST IP address.
-        // TODO: Input via CLI
-        let rest_ip = Some(SocketAddr::from_str("127.0.0.1:4180")?);
-
         // Initialize an (insecure) fixed RNG.
         // TODO: Input via CLI
         let mut rng = ChaChaRng::seed_from_u64(1234567890u64);
 
-        println!("⏳ Starting a local development node (in-memory)...\n",);
-
-        // Initialize the runtime.
-        Self::runtime().block_on(async move {
-            // Initialize the consensus store.
-            let store = ConsensusStore::<Network, ConsensusMemory<Network>>::open(None)
-                .expect("Failed to initialize the consensus store");
+        // Initialize the thread pool that proves the transactions.
+        Self::initialize_thread_pool()?;
 
-            // Initialize a new VM.
-            let vm = VM::from(store).expect("Failed to initialize the VM");
+        // Start a view-only node, if the node has no private key.
+        let private_key = match private_key {
+            Some(private_key) => private_key,
+            None => {
+                output!("⏳ Starting a view-only node (in-memory)...\n");
+                let genesis = Self::view_only_genesis(genesis_file.as_deref(), config.peer.as_deref())
+                    .await
+                    .map_err(|error| anyhow!("Failed to initialize the genesis block: {error}"))?;
+                ViewOnlyNode::new(rest_ip, genesis, config)
+                    .await
+                    .map_err(|error| anyhow!("Failed to start the view-only node: {error}"))?;
+                // Note: Do not move this. The pending await must be here otherwise
+                // other slingshot commands will not exit.
+                std::future::pending::<()>().await;
+                return Ok(String::new());
+            }
+        };
 
-            // Initialize the genesis block.
-            println!("⏳ Initializing the genesis block...");
-            let genesis = Some(
-                Block::<Network>::genesis(&vm, &private_key, &mut rng).expect("Failed to initialize the genesis block"),
-            );
-            println!();
+        output!("⏳ Starting a local development node (in-memory)...\n");
 
-            // Start the development node.
-            DevelopmentBeacon::new(rest_ip, private_key, genesis, None)
+        // Start a node for each tenant, if requested.
+        if !tenants.is_empty() {
+            Tenants::start(rest_ip, tenants, faucet_key.unwrap_or(private_key), config)
                 .await
-                .expect("Failed to start the development node");
+                .map_err(|error| anyhow!("Failed to start the tenants: {error}"))?;
             // Note: Do not move this. The pending await must be here otherwise
             // other slingshot commands will not exit.
             std::future::pending::<()>().await;
-        });
+        }
+
+        // Initialize the consensus store.
+        let store = ConsensusStore::<Network, ConsensusMemory<Network>>::open(None)
+            .map_err(|error| anyhow!("Failed to initialize the consensus store: {error}"))?;
+
+        // Initialize a new VM.
+        let vm = VM::from(store).map_err(|error| anyhow!("Failed to initialize the VM: {error}"))?;
+
+        // Initialize the genesis block, which funds the faucet account.
+        // If the node is restored from a snapshot or a chain file, the genesis block is its first block,
+        // and if the node replicates a peer, the genesis block is the genesis block of the peer.
+        output!("⏳ Initializing the genesis block...");
+        let genesis_key = faucet_key.as_ref().unwrap_or(&private_key);
+        let genesis = Some(
+            match (&config.restore, &config.peer, supply) {
+                (Some(path), _, _) => ChainFile::read_genesis::<Network>(path),
+                (None, Some(peer), _) => Peer::new(peer).genesis::<Network>().await,
+                (None, None, Some(supply)) => {
+                    Ledger::<Network, ConsensusMemory<Network>>::genesis(&vm, genesis_key, supply, &mut rng)
+                }
+                (None, None, None) => Block::<Network>::genesis(&vm, genesis_key, &mut rng),
+            }
+            .map_err(|error| anyhow!("Failed to initialize the genesis block: {error}"))?,
+        );
+        output!();
+
+        // Start the development node.
+        DevelopmentBeacon::new(Some(rest_ip), private_key, faucet_key, genesis, None, config)
+            .await
+            .map_err(|error| anyhow!("Failed to start the development node: {error}"))?;
+        // Note: Do not move this. The pending await must be here otherwise
+        // other slingshot commands will not exit.
+        std::future::pending::<()>().await;
 
         Ok(String::new())
     }
 
-    /// Returns a runtime for the node.
-    fn runtime() -> Runtime {
+    /// Upgrades the given chain file, the snapshots of the given directory, and the given key cache,
+    /// to the current version of their format, and reports each file that was upgraded.
+    fn migrate(chain: Option<PathBuf>, snapshot_dir: Option<PathBuf>, cache_dir: Option<PathBuf>) -> Result<String> {
+        ensure!(
+            chain.is_some() || snapshot_dir.is_some() || cache_dir.is_some(),
+            "Please specify a '--chain', a '--snapshot-dir', or a '--cache-dir' to migrate"
+        );
+        let mut chain_files = chain.into_iter().collect::<Vec<_>>();
+        if let Some(snapshot_dir) = &snapshot_dir {
+            chain_files.extend(Snapshot::list(snapshot_dir)?.into_iter().map(|snapshot| snapshot.path));
+        }
+
+        let mut message = String::new();
+        let mut report = |path: &Path, version: Option<u32>| {
+            message.push_str(&match version {
+                Some(version) => format!(" • Upgraded '{}' from version {version}\n", path.display()),
+                None => format!(" • '{}' is up to date\n", path.display()),
+            })
+        };
+        for path in &chain_files {
+            report(path, ChainFile::migrate::<Network>(path)?);
+        }
+        if let Some(cache_dir) = &cache_dir {
+            report(cache_dir, KeyCache::<Network>::migrate(cache_dir)?);
+        }
+        Ok(format!("✅ Migrated the files to the current version of slingshot.\n\n{message}"))
+    }
+
+    /// Ensures the genesis block of the given snapshot or chain file funds the account of the given key,
+    /// so that the faucet of the restored node can spend its records. If forced, a mismatch is only reported.
+    fn check_genesis_owner(path: &Path, genesis_key: &PrivateKey<Network>, force: bool) -> Result<()> {
+        let genesis = ChainFile::read_genesis::<Network>(path)?;
+        let view_key = ViewKey::try_from(genesis_key)?;
+        let is_funded = genesis.transactions().iter().any(|(_, transaction)| {
+            records::record_outputs(transaction)
+                .any(|(_, record)| record.map_or(false, |record| records::is_owner(record, &view_key)))
+        });
+        if !is_funded {
+            let message = format!(
+                "The genesis block of '{}' does not fund the faucet account '{}', so its faucet cannot spend anything. \
+                 Start the node with the keys of the node that produced it ('--key', or '--faucet-key'), \
+                 or pass '--force' to start it anyway",
+                path.display(),
+                Address::try_from(genesis_key)?
+            );
+            ensure!(force, message);
+            output!("⚠️  {message}\n");
+        }
+        Ok(())
+    }
+
+    /// Returns the genesis block of a view-only node, from the given file, or else from the given peer.
+    async fn view_only_genesis(genesis_file: Option<&Path>, peer: Option<&str>) -> Result<Block<Network>> {
+        let path = match (genesis_file, peer) {
+            (Some(path), _) => path,
+            (None, Some(peer)) => return Peer::new(peer).genesis().await,
+            (None, None) => bail!("A view-only node requires a '--genesis-file', or a '--peer' to follow"),
+        };
+        // Read the block as JSON, or else as bytes.
+        let bytes = std::fs::read(path).map_err(|error| anyhow!("Failed to read '{}': {error}", path.display()))?;
+        let genesis = match serde_json::from_slice::<Block<Network>>(&bytes) {
+            Ok(genesis) => genesis,
+            Err(_) => Block::<Network>::from_bytes_le(&bytes)
+                .map_err(|error| anyhow!("'{}' is not a block, as JSON or bytes: {error}", path.display()))?,
+        };
+        ensure!(genesis.height() == 0, "'{}' is block {}, not a genesis block", path.display(), genesis.height());
+        // Ensure the genesis block is the genesis block of the peer, if any.
+        if let Some(peer) = peer {
+            let peer = Peer::new(peer);
+            ensure!(
+                peer.genesis::<Network>().await?.hash() == genesis.hash(),
+                "'{}' is not the genesis block of the peer at {}",
+                path.display(),
+                peer.endpoint()
+            );
+        }
+        Ok(genesis)
+    }
+
+    /// Sends the given block production action (`pause` or `resume`) to the node at the given base endpoint.
+    async fn set_block_production(endpoint: &str, action: &str) -> Result<()> {
+        let client = node_client(&base_endpoint(endpoint));
+        let response = match action {
+            "pause" => client.pause().await?,
+            _ => client.resume().await?,
+        };
+        ensure!(response.paused == (action == "pause"), "The node did not {action} block production");
+        Ok(())
+    }
+
+    /// Returns the chain context of the node at the given base endpoint, which may be cached.
+    async fn info(endpoint: &str) -> Result<String> {
+        let base = base_endpoint(endpoint);
+        let (info, age) = Context::fetch(&base).await?;
+
+        let mut message = match age.as_secs() {
+            0 => format!("🌐 Chain context of the node at {base}\n\n"),
+            age => format!("🌐 Chain context of the node at {base} (cached {age} seconds ago)\n\n"),
+        };
+        message.push_str(&format!(" • Network: {}\n", info.network_id));
+        message.push_str(&format!(" • Latest block: {} ({})\n", info.latest_height, info.latest_hash));
+        message.push_str(&format!(" • Programs ({}):\n", info.programs.len()));
+        for program_id in &info.programs {
+            message.push_str(&format!("   • {program_id}\n"));
+        }
+        if let Some(limits) = &info.limits {
+            let max_queued_proofs = limits.max_queued_proofs.map_or("unlimited".to_string(), |max| max.to_string());
+            message.push_str(&format!(
+                " • Proofs: {} of {} in progress, {} of {max_queued_proofs} waiting\n",
+                limits.active_proofs, limits.max_concurrent_proofs, limits.queued_proofs
+            ));
+            let (pool_size, pool_capacity) = (limits.pool_size, limits.pool_capacity);
+            message.push_str(&format!(" • Memory pool: {pool_size} of {pool_capacity} transactions\n"));
+            message.push_str(&format!(
+                " • Request bodies: {} bytes, or {} bytes for transactions\n",
+                limits.max_body_size, limits.max_transaction_body_size
+            ));
+        }
+        Ok(message)
+    }
+
+    /// Returns the compatibility report of the CLI and the node at the given base endpoint.
+    async fn version(endpoint: &str) -> Result<String> {
+        let base = base_endpoint(endpoint);
+        let client = BuildInfo::current::<Network>();
+        let node = Compatibility::handshake(&base).await?.node().clone();
+
+        let mut message = format!("🌐 Builds of the CLI and the node at {base}\n\n");
+        message.push_str(&format!(
+            " • slingshot: {} (CLI), {} (node)\n",
+            client.slingshot_version, node.slingshot_version
+        ));
+        message.push_str(&format!(" • snarkVM: {} (CLI), {} (node)\n", client.snarkvm_revision, node.snarkvm_revision));
+        message.push_str(&format!(" • Network: {} (CLI), {} (node)\n", client.network_id, node.network_id));
+        message.push_str(&format!(" • Message version: {} (CLI), {} (node)\n", client.api_version, node.api_version));
+        message.push_str(&format!(
+            " • Features: [{}] (CLI), [{}] (node)\n",
+            client.features.join(", "),
+            node.features.join(", ")
+        ));
+
+        let incompatibilities = BuildInfo::incompatibilities(&client, &node);
+        match incompatibilities.is_empty() {
+            true => message.push_str("\n✅ The CLI is compatible with the node."),
+            false => {
+                message.push_str("\n⚠️  The CLI may be incompatible with the node:\n");
+                for incompatibility in incompatibilities {
+                    message.push_str(&format!(" • {incompatibility}\n"));
+                }
+            }
+        }
+        Ok(message)
+    }
+
+    /// Initializes the global thread pool of the node.
+    fn initialize_thread_pool() -> Result<()> {
         // TODO: This should be supplied by a config file. Think infrastruct as code tool.
-        // let (num_tokio_worker_threads, max_tokio_blocking_threads, num_rayon_cores_global) = if !Self::node_type().is_beacon() {
-        //     ((num_cpus::get() / 8 * 2).max(1), num_cpus::get(), (num_cpus::get() / 8 * 5).max(1))
-        // } else {
-        //     (num_cpus::get(), 512, num_cpus::get()) // 512 is tokio's current default
-        // };
-        let (num_tokio_worker_threads, max_tokio_blocking_threads, num_rayon_cores_global) =
-            // { ((num_cpus::get() / 2).max(1), num_cpus::get(), (num_cpus::get() / 4 * 3).max(1)) };
-            // { (num_cpus::get().min(8), 512, num_cpus::get().saturating_sub(8).max(1)) };
-            { (1, 512, 4) };
+        // let num_rayon_cores_global = (num_cpus::get() / 8 * 5).max(1);
+        let num_rayon_cores_global = 4;
 
         // Initialize the parallelization parameters.
         rayon::ThreadPoolBuilder::new()
             .stack_size(8 * 1024 * 1024)
             .num_threads(num_rayon_cores_global)
             .build_global()
-            .unwrap();
-
-        // Initialize the runtime configuration.
-        runtime::Builder::new_multi_thread()
-            .enable_all()
-            .thread_stack_size(8 * 1024 * 1024)
-            .worker_threads(num_tokio_worker_threads)
-            .max_blocking_threads(max_tokio_blocking_threads)
-            .build()
-            .expect("Failed to initialize a runtime for the router")
+            .map_err(|error| anyhow!("Failed to initialize the thread pool: {error}"))
+    }
+}
+
+/// Commands to manage the snapshots of a development node.
+#[derive(Clone, Debug, Parser)]
+pub enum Snapshots {
+    /// Lists the snapshots in the snapshot directory, from oldest to newest.
+    List {
+        /// The directory of the snapshots.
+        #[clap(long, default_value = DEFAULT_SNAPSHOT_DIR)]
+        snapshot_dir: PathBuf,
+    },
+}
+
+impl Snapshots {
+    /// Parses the snapshot command.
+    pub fn parse(self) -> Result<String> {
+        match self {
+            Self::List { snapshot_dir } => {
+                let snapshots = Snapshot::list(&snapshot_dir)?;
+                if snapshots.is_empty() {
+                    return Ok(format!("⚠️  There are no snapshots in '{}'.", snapshot_dir.display()));
+                }
+                let mut message = format!("📸 Snapshots in '{}'\n\n", snapshot_dir.display());
+                for snapshot in snapshots {
+                    let taken_at = OffsetDateTime::from_unix_timestamp(snapshot.timestamp)?;
+                    message.push_str(&format!(
+                        " • {} (block {}, taken at {taken_at}, {} bytes)\n",
+                        snapshot.name.bold(),
+                        snapshot.height,
+                        snapshot.size_in_bytes
+                    ));
+                }
+                message.push_str(
+                    "\nTo restore a snapshot, run 'slingshot node start --restore <NAME>' with the node's keys.",
+                );
+                Ok(message)
+            }
+        }
+    }
+}
+
+/// Parses the number of genesis records and the gates in each record, written as `<COUNT>x<GATES>`.
+fn parse_genesis_records(genesis_records: &str) -> Result<(usize, u64)> {
+    match genesis_records.split_once('x') {
+        Some((num_records, gates)) => {
+            let num_records = num_records.parse::<usize>()?;
+            let gates = gates.parse::<u64>()?;
+            ensure!(num_records > 0 && gates > 0, "The number of records and the gates must be positive");
+            Ok((num_records, gates))
+        }
+        None => bail!("Invalid genesis records '{genesis_records}' (expected '<COUNT>x<GATES>', e.g. '10000x1000')"),
     }
 }
@@ -14,7 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{messages::PourRequest, Network};
+use crate::{
+    helpers::parse_address,
+    messages::{NodeRequest, PourRequest},
+    Network,
+};
 
 use snarkvm::prelude::Address;
 
@@ -24,8 +28,8 @@ use clap::Parser;
 /// Pours Aleo credits into an account.
 #[derive(Debug, Parser)]
 pub struct Pour {
-    /// The function name.
-    #[clap(parse(try_from_str))]
+    /// The recipient address, or its alias as `@alias`.
+    #[clap(parse(try_from_str = parse_address))]
     address: Address<Network>,
     /// The amount to send.
     #[clap(parse(try_from_str))]
@@ -38,7 +42,7 @@ pub struct Pour {
 impl Pour {
     /// Pours a specified number of Aleo credits into an address.
     #[allow(clippy::format_in_format_args)]
-    pub fn parse(self) -> Result<String> {
+    pub async fn parse(self) -> Result<String> {
         // Use the provided endpoint, or default to a local faucet.
         let endpoint = match self.endpoint {
             Some(endpoint) => endpoint,
@@ -49,7 +53,7 @@ impl Pour {
         let request = PourRequest::new(self.address, self.amount);
 
         // Send the request and wait for the response.
-        match request.send(&endpoint) {
+        match request.send(&endpoint).await {
             // TODO: Just send tx id?
             Ok(_) => Ok(format!("✅ Poured {} Aleo credits into {}.", self.amount, self.address)),
             Err(error) => Err(error),
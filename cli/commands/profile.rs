@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{base_endpoint, fetch_program, parse_value, post_to, read_inputs_file, AccountTokens, Compatibility},
+    messages::{EvaluateRequest, NodeRequest, RecordViewRequest, Signer},
+    node::ExecutionProfile,
+    Network,
+};
+
+use snarkvm::{
+    file::Manifest,
+    prelude::{Identifier, Locator, ProgramID, Value, ViewKey},
+};
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use colored::Colorize;
+use core::str::FromStr;
+use std::path::PathBuf;
+
+/// Executes an Aleo program function on a development node, and reports the time spent synthesizing, proving,
+/// and verifying it, the constraints of each transition, and the estimated fee. No transaction is submitted.
+#[derive(Debug, Parser)]
+pub struct Profile {
+    /// The program identifier.
+    #[clap(parse(try_from_str))]
+    program: ProgramID<Network>,
+    /// The function name.
+    #[clap(parse(try_from_str))]
+    function: Identifier<Network>,
+    /// The function inputs, in which `@alias` is replaced with the address of the alias.
+    #[clap(parse(try_from_str = parse_value))]
+    inputs: Vec<Value<Network>>,
+
+    /// The endpoint to profile on. Defaults to a local development node.
+    #[clap(short, long)]
+    pub endpoint: Option<String>,
+    /// A path to a directory containing a manifest file. Defaults to the current working directory.
+    #[clap(short, long)]
+    pub path: Option<String>,
+    /// A path to a JSON file mapping each input, by register (e.g. `r0`) or position, to its value, or to
+    /// `{ "commitment": "..." }` for an unspent record of the account.
+    #[clap(long, conflicts_with = "inputs")]
+    pub inputs_file: Option<String>,
+}
+
+impl Profile {
+    /// Profiles an Aleo program function with the provided inputs, and prints the profile.
+    pub async fn parse(self) -> Result<String> {
+        // Setup the endpoint.
+        let endpoint = self.endpoint.unwrap_or_else(|| "http://localhost:4180/testnet3/program/profile".to_string());
+
+        // Instantiate a path to the directory containing the manifest file.
+        let directory = match self.path {
+            Some(path) => PathBuf::from_str(&path)?,
+            None => std::env::current_dir()?,
+        };
+
+        // Ensure the directory path exists.
+        ensure!(directory.exists(), "The program directory does not exist: {}", directory.display());
+        // Ensure the manifest file exists.
+        ensure!(
+            Manifest::<Network>::exists_at(&directory),
+            "Please ensure that the manifest file exists in the Aleo program directory (missing '{}' at '{}')",
+            Manifest::<Network>::file_name(),
+            directory.display()
+        );
+
+        // Retrieve the private key.
+        let private_key = *Manifest::<Network>::open(&directory)?.development_private_key();
+
+        // Load the inputs from the inputs file, if one is provided.
+        let inputs = match self.inputs_file {
+            Some(inputs_file) => {
+                let program = fetch_program(&base_endpoint(&endpoint), &self.program).await?;
+                let view_key = ViewKey::try_from(private_key)?;
+                read_inputs_file(&PathBuf::from_str(&inputs_file)?, &program, &self.function, || async {
+                    let endpoint = format!("{}/testnet3/records/unspent", base_endpoint(&endpoint));
+                    Ok(RecordViewRequest::new(view_key).send(&endpoint).await?.records().clone())
+                })
+                .await?
+            }
+            None => self.inputs,
+        };
+
+        // Register the account with the node, to authorize the execution without sending the private key.
+        let registration = AccountTokens::register(&endpoint, private_key).await?;
+
+        // Send the profile request, which has the body of an evaluate request.
+        let request =
+            EvaluateRequest::new(Signer::Token(registration.token().to_string()), self.program, self.function, inputs);
+        Compatibility::check(&endpoint).await?;
+        let profile: ExecutionProfile<Network> = post_to(&endpoint, &request).await?;
+
+        // Log the profile.
+        let locator = Locator::<Network>::from_str(&format!("{}/{}", self.program, self.function))?;
+        let mut message = format!("✅ Profiled '{}' (no transaction was submitted)\n\n", locator.to_string().bold());
+        message.push_str(&format!(" • Synthesis:    {} ms\n", profile.synthesis_ms));
+        message.push_str(&format!(" • Proving:      {} ms\n", profile.proving_ms));
+        message.push_str(&format!(" • Verification: {} ms\n", profile.verification_ms));
+        message.push_str("\n➡️  Transitions\n\n");
+        for transition in &profile.transitions {
+            message.push_str(&format!(
+                " • {}/{}: {} constraints\n",
+                transition.program_id, transition.function_name, transition.num_constraints
+            ));
+        }
+        message.push_str(&format!(
+            "\nThe transaction is {} bytes, and requires a fee of at least {} gates.\n",
+            profile.size_in_bytes, profile.fee
+        ));
+        Ok(message)
+    }
+}
@@ -0,0 +1,408 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    commands::{Deploy, View},
+    helpers::{base_endpoint, parse_value, wait_for_transaction, AccountTokens, FeeOption, DEFAULT_ENDPOINT},
+    messages::{ExecuteRequest, NodeRequest, Signer},
+    records,
+    Network,
+};
+
+use snarkvm::{
+    file::Manifest,
+    prelude::{Identifier, PrivateKey, ProgramID, Transaction, Value, ViewKey},
+    synthesizer::Output,
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use clap::Parser;
+use colored::Colorize;
+use indexmap::IndexMap;
+use rustyline::{error::ReadlineError, Editor};
+use std::{path::PathBuf, time::Duration};
+
+/// The file in which the history of the interactive session is stored.
+pub const REPL_HISTORY_FILE: &str = ".slingshot/history.txt";
+
+/// Opens an interactive session connected to a development node, to deploy and execute programs, and to query the
+/// records of the account. Outputs are bound with `let name = execute ...`, and passed as inputs with `$name`.
+#[derive(Debug, Parser)]
+pub struct Repl {
+    /// The private key that signs the transactions of the session.
+    #[clap(short, long, parse(try_from_str), conflicts_with = "path")]
+    key: Option<PrivateKey<Network>>,
+    /// A path to a directory containing a manifest file, whose program is deployed and whose development private key
+    /// signs the transactions of the session. Defaults to the current working directory.
+    #[clap(short, long)]
+    path: Option<PathBuf>,
+    /// The base endpoint of the node. Defaults to a local development node.
+    #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+    endpoint: String,
+    /// The number of seconds to wait for each transaction to be confirmed.
+    #[clap(long, default_value = "120")]
+    timeout: u64,
+}
+
+/// A command of the interactive session.
+#[derive(Debug, Parser)]
+#[clap(no_binary_name = true, disable_version_flag = true)]
+enum ReplCommand {
+    /// Deploys a program from the program directory of the session.
+    Deploy {
+        /// The name of the program to deploy.
+        #[clap(parse(try_from_str))]
+        program: ProgramID<Network>,
+        /// The deployment fee in gates.
+        #[clap(short, long)]
+        fee: Option<u64>,
+    },
+    /// Executes a program function, waits for the transaction to be confirmed, and prints its outputs.
+    Execute {
+        /// The program identifier.
+        #[clap(parse(try_from_str))]
+        program: ProgramID<Network>,
+        /// The function name.
+        #[clap(parse(try_from_str))]
+        function: Identifier<Network>,
+        /// The function inputs, in which `$name` is replaced with a bound value, and `@alias` with an address.
+        inputs: Vec<String>,
+        /// The additional fee in gates.
+        #[clap(short, long)]
+        fee: Option<u64>,
+    },
+    /// Prints the balance of the account of the session.
+    Balance,
+    /// Prints the records of the account of the session.
+    Records {
+        /// Print only the spent records.
+        #[clap(short, long, conflicts_with = "unspent")]
+        spent: bool,
+        /// Print only the unspent records.
+        #[clap(short, long, conflicts_with = "spent")]
+        unspent: bool,
+    },
+    /// Prints the bound values.
+    Vars,
+    /// Prints the commands entered in this and previous sessions.
+    History,
+    /// Ends the session.
+    #[clap(alias = "quit")]
+    Exit,
+}
+
+/// The state of an interactive session.
+struct Session {
+    /// The base endpoint of the node.
+    base: String,
+    /// The program directory of the session.
+    directory: PathBuf,
+    /// The private key that signs the transactions of the session.
+    private_key: PrivateKey<Network>,
+    /// The account token of the private key, as registered with the node.
+    token: String,
+    /// The number of seconds to wait for each transaction to be confirmed.
+    timeout: u64,
+    /// The values bound with `let`, in the order they were bound. An execution binds all of its outputs.
+    bindings: IndexMap<String, Vec<Value<Network>>>,
+}
+
+impl Repl {
+    /// Runs the interactive session, until it is ended with `exit` or end of input.
+    pub async fn parse(self) -> Result<String> {
+        let base = base_endpoint(&self.endpoint);
+
+        // Determine the program directory and the private key of the session.
+        let directory = match self.path {
+            Some(path) => path,
+            None => std::env::current_dir()?,
+        };
+        let private_key = match self.key {
+            Some(private_key) => private_key,
+            None => {
+                ensure!(
+                    Manifest::<Network>::exists_at(&directory),
+                    "Please pass '--key', or a program directory with a manifest file (missing '{}' at '{}')",
+                    Manifest::<Network>::file_name(),
+                    directory.display()
+                );
+                *Manifest::<Network>::open(&directory)?.development_private_key()
+            }
+        };
+
+        // Register the account with the node, to sign the transactions without sending the private key.
+        let registration = AccountTokens::register(&base, private_key).await?;
+        let mut session = Session {
+            base,
+            directory,
+            private_key,
+            token: registration.token().to_string(),
+            timeout: self.timeout,
+            bindings: IndexMap::new(),
+        };
+
+        // Load the history of previous sessions, if any.
+        let mut editor = Editor::<()>::new()?;
+        let _ = editor.load_history(REPL_HISTORY_FILE);
+
+        output!(
+            "🔗 Connected to {} as {}. Type 'help' for the commands, and 'exit' to quit.\n",
+            session.base.bold(),
+            registration.address()
+        );
+        loop {
+            let line = match editor.readline("slingshot> ") {
+                Ok(line) => line,
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(error) => return Err(error.into()),
+            };
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            editor.add_history_entry(line);
+
+            match session.evaluate(line, &editor).await {
+                Ok(Some(message)) => output!("{message}"),
+                Ok(None) => break,
+                Err(error) => output!("⚠️  {error:#}\n"),
+            }
+        }
+
+        // Save the history for the next session.
+        if let Some(directory) = PathBuf::from(REPL_HISTORY_FILE).parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+        editor.save_history(REPL_HISTORY_FILE)?;
+        Ok("👋 Ended the session".to_string())
+    }
+}
+
+impl Session {
+    /// Evaluates the given line, and returns the message to print, or `None` if the session is ended.
+    async fn evaluate(&mut self, line: &str, editor: &Editor<()>) -> Result<Option<String>> {
+        // Split off the binding, if any.
+        let (binding, line) = match line.strip_prefix("let ") {
+            Some(rest) => match rest.split_once('=') {
+                Some((name, line)) => (Some(Self::binding_name(name.trim())?), line.trim()),
+                None => bail!("Expected a binding of the form 'let <name> = <command or value>'"),
+            },
+            None => (None, line),
+        };
+
+        // Bind a literal value, e.g. `let amount = 5u64`.
+        if let Some(name) = &binding {
+            if let Ok(value) = parse_value(&self.substitute(line)?) {
+                self.bindings.insert(name.clone(), vec![value.clone()]);
+                return Ok(Some(format!("{name} = {value}\n")));
+            }
+        }
+
+        // Parse the command, in which bound values are substituted.
+        let words = shell_words::split(line)?.iter().map(|word| self.substitute(word)).collect::<Result<Vec<_>>>()?;
+        let command = match ReplCommand::try_parse_from(words) {
+            Ok(command) => command,
+            // Print the help and usage errors of clap as messages, rather than exiting the process.
+            Err(error) => return Ok(Some(error.to_string())),
+        };
+        if binding.is_some() && !matches!(command, ReplCommand::Execute { .. }) {
+            bail!("Only the outputs of 'execute' or a value can be bound with 'let'");
+        }
+
+        let message = match command {
+            ReplCommand::Deploy { program, fee } => Deploy {
+                program,
+                fee: fee.map(FeeOption::Gates),
+                endpoint: Some(format!("{}/testnet3/program/deploy", self.base)),
+                path: Some(self.directory.display().to_string()),
+                watch: false,
+                metadata: None,
+                wallet: None,
+                fee_records: Vec::new(),
+                auto_fund: false,
+            }
+            .parse()
+            .await?,
+            ReplCommand::Execute { program, function, inputs, fee } => {
+                self.execute(program, function, &inputs, fee, binding.as_deref()).await?
+            }
+            ReplCommand::Balance => View::Balance {
+                key: Some(self.private_key.to_string()),
+                path: None,
+                endpoint: Some(format!("{}/testnet3/records/stats", self.base)),
+            }
+            .parse()
+            .await?,
+            ReplCommand::Records { spent, unspent } => {
+                let filter = match (spent, unspent) {
+                    (true, _) => "spent",
+                    (_, true) => "unspent",
+                    _ => "all",
+                };
+                View::Record {
+                    key: Some(self.private_key.to_string()),
+                    path: None,
+                    spent,
+                    unspent,
+                    endpoint: Some(format!("{}/testnet3/records/{filter}", self.base)),
+                }
+                .parse()
+                .await?
+            }
+            ReplCommand::Vars => {
+                let mut message = String::new();
+                for (name, values) in &self.bindings {
+                    match values.as_slice() {
+                        [value] => message.push_str(&format!("{name} = {value}\n")),
+                        values => {
+                            for (index, value) in values.iter().enumerate() {
+                                message.push_str(&format!("{name}.{index} = {value}\n"));
+                            }
+                        }
+                    }
+                }
+                message
+            }
+            ReplCommand::History => {
+                editor.history().iter().enumerate().map(|(index, line)| format!("{index:>4}  {line}\n")).collect()
+            }
+            ReplCommand::Exit => return Ok(None),
+        };
+        Ok(Some(message))
+    }
+
+    /// Executes the given function, waits for its transaction to be confirmed, and returns its outputs.
+    /// The outputs visible to the account are bound to the given name, if any.
+    async fn execute(
+        &mut self,
+        program: ProgramID<Network>,
+        function: Identifier<Network>,
+        inputs: &[String],
+        fee: Option<u64>,
+        binding: Option<&str>,
+    ) -> Result<String> {
+        let inputs = inputs.iter().map(|input| parse_value(input)).collect::<Result<Vec<_>>>()?;
+
+        // Send the execute request, and wait for the transaction to be confirmed.
+        let signer = Signer::Token(self.token.clone());
+        let response = ExecuteRequest::new(signer, program, function, inputs, fee, None)
+            .send(&format!("{}/testnet3/program/execute", self.base))
+            .await?;
+        let transaction =
+            wait_for_transaction(&self.base, response.transaction_id(), Duration::from_secs(self.timeout)).await?;
+        let outputs = self.outputs(&transaction)?;
+
+        let mut message = format!("✅ Executed '{program}/{function}' ({})\n\n", response.transaction_id());
+        for (index, output) in outputs.iter().enumerate() {
+            match binding {
+                Some(name) if outputs.len() == 1 => message.push_str(&format!("{name} = {output}\n")),
+                Some(name) => message.push_str(&format!("{name}.{index} = {output}\n")),
+                None => message.push_str(&format!(" • {output}\n")),
+            }
+        }
+        if let Some(name) = binding {
+            self.bindings.insert(name.to_string(), outputs);
+        }
+        Ok(message)
+    }
+
+    /// Returns the outputs of the function of the given transaction, that are visible to the account.
+    fn outputs(&self, transaction: &Transaction<Network>) -> Result<Vec<Value<Network>>> {
+        let view_key = ViewKey::try_from(&self.private_key)?;
+
+        // Note: The last transition is the function called by the execution; the fee has a transition of its own.
+        let transition = match transaction {
+            Transaction::Execute(_, execution, _) => execution.transitions().last(),
+            Transaction::Deploy(..) => None,
+        };
+        let mut outputs = Vec::new();
+        for output in transition.into_iter().flat_map(|transition| transition.outputs()) {
+            match output {
+                Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => {
+                    outputs.push(Value::Plaintext(plaintext.clone()))
+                }
+                Output::Record(_, _, Some(record)) if records::is_owner(record, &view_key) => {
+                    outputs.push(Value::Record(records::decrypt(record, &view_key)?))
+                }
+                _ => (),
+            }
+        }
+        Ok(outputs)
+    }
+
+    /// Returns the given word, in which `$name` is replaced with the value bound to the name, and `$name.N` with
+    /// the N-th output bound to the name.
+    fn substitute(&self, word: &str) -> Result<String> {
+        let name = match word.strip_prefix('$') {
+            Some(name) => name,
+            None => return Ok(word.to_string()),
+        };
+        let (name, index) = match name.rsplit_once('.') {
+            Some((name, index)) => (name, Some(index.parse::<usize>()?)),
+            None => (name, None),
+        };
+        let values = self.bindings.get(name).ok_or_else(|| anyhow!("'{name}' is not bound"))?;
+        match (index, values.as_slice()) {
+            (None, [value]) => Ok(value.to_string()),
+            (None, _) => bail!("'{name}' is bound to {} outputs; select one with '${name}.<index>'", values.len()),
+            (Some(index), values) => values
+                .get(index)
+                .map(|value| value.to_string())
+                .ok_or_else(|| anyhow!("'{name}' is bound to {} outputs, not {}", values.len(), index + 1)),
+        }
+    }
+
+    /// Ensures the given name can be bound, and returns it.
+    fn binding_name(name: &str) -> Result<String> {
+        ensure!(
+            !name.is_empty()
+                && name.chars().all(|character| character.is_ascii_alphanumeric() || character == '_')
+                && !name.starts_with(|character: char| character.is_ascii_digit()),
+            "'{name}' is not a valid name; use letters, digits, and underscores"
+        );
+        Ok(name.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_substitute() {
+        let private_key = PrivateKey::<Network>::new(&mut rand::thread_rng()).unwrap();
+        let mut session = Session {
+            base: DEFAULT_ENDPOINT.to_string(),
+            directory: PathBuf::from("."),
+            private_key,
+            token: String::new(),
+            timeout: 0,
+            bindings: IndexMap::new(),
+        };
+        session.bindings.insert("amount".to_string(), vec![parse_value("5u64").unwrap()]);
+        session.bindings.insert("pair".to_string(), vec![parse_value("1u8").unwrap(), parse_value("2u8").unwrap()]);
+
+        assert_eq!(session.substitute("$amount").unwrap(), "5u64");
+        assert_eq!(session.substitute("$pair.1").unwrap(), "2u8");
+        assert_eq!(session.substitute("10u64").unwrap(), "10u64");
+        assert!(session.substitute("$pair").is_err());
+        assert!(session.substitute("$pair.2").is_err());
+        assert!(session.substitute("$missing").is_err());
+        assert!(Session::binding_name("1st").is_err());
+        assert!(Session::binding_name("first_output").is_ok());
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{base_endpoint, node_client, wait_for_transaction, AccountTokens, DEFAULT_ENDPOINT},
+    node::BUNDLE_MANIFEST,
+    Network,
+};
+
+use snarkvm::prelude::{Address, PrivateKey};
+
+use anyhow::{bail, ensure, Result};
+use clap::Parser;
+use serde_json::Value;
+use std::{collections::HashSet, path::PathBuf, time::Duration};
+
+/// Replays the requests of a reproduction bundle, captured with `node start --capture`, on a development node.
+#[derive(Debug, Parser)]
+pub struct ReplayBundle {
+    /// The directory of the bundle.
+    directory: PathBuf,
+    /// The private key that signs the replayed deploy and execute requests, in place of their captured signers.
+    /// Pours to a captured signer are poured to the account of this key instead.
+    #[clap(long, parse(try_from_str))]
+    key: PrivateKey<Network>,
+    /// The base endpoint of the node. Defaults to a local development node.
+    #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+    endpoint: String,
+    /// The number of seconds to wait for each replayed transaction to be confirmed, before the next request is sent.
+    #[clap(long, default_value = "120")]
+    timeout: u64,
+}
+
+impl ReplayBundle {
+    /// Sends the captured requests to the node in the order they were captured, and reports the outcome of each,
+    /// next to the outcome on the node that captured it.
+    pub async fn parse(self) -> Result<String> {
+        let base = base_endpoint(&self.endpoint);
+
+        // Read the captured requests, in the order they were accepted.
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(&self.directory)? {
+            let path = entry?.path();
+            let is_manifest = path.file_name().map_or(false, |name| name == BUNDLE_MANIFEST);
+            if !is_manifest && path.extension().map_or(false, |extension| extension == "json") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        ensure!(!paths.is_empty(), "The bundle '{}' has no captured requests", self.directory.display());
+        let mut entries = Vec::with_capacity(paths.len());
+        for path in &paths {
+            let entry: Value = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+            entries.push(entry);
+        }
+        let signers = entries.iter().filter_map(|entry| entry["signer"].as_str()).collect::<HashSet<_>>();
+
+        // Register the account with the node, to sign the requests without sending the private key.
+        let address = Address::try_from(&self.key)?;
+        let registration = AccountTokens::register(&base, self.key).await?;
+        let client = node_client(&base);
+
+        let mut message =
+            format!("🔁 Replaying {} requests of '{}' on {base}\n\n", entries.len(), self.directory.display());
+        for (path, entry) in paths.iter().zip(&entries) {
+            let mut request = entry["request"].clone();
+            let route = match entry["kind"].as_str() {
+                Some("deploy") => "program/deploy",
+                Some("execute") => "program/execute",
+                Some("pour") => "faucet/pour",
+                kind => bail!("'{}' has an unknown kind of request ({kind:?})", path.display()),
+            };
+            match route {
+                "faucet/pour" => {
+                    if request["address"].as_str().map_or(false, |recipient| signers.contains(recipient)) {
+                        request["address"] = address.to_string().into();
+                    }
+                }
+                _ => request["account_token"] = registration.token().into(),
+            }
+
+            // Send the request, and wait for its transaction to be confirmed before the next request is sent.
+            let outcome = async {
+                let response = client.post::<_, Value>(&format!("/testnet3/{route}"), &request).await?;
+                let transaction_id = response["transaction_id"].as_str().unwrap_or_default().to_string();
+                wait_for_transaction(&base, &transaction_id.parse()?, Duration::from_secs(self.timeout)).await?;
+                Ok::<_, anyhow::Error>(transaction_id)
+            }
+            .await;
+            let captured = match entry["receipt"]["accepted"].as_bool() {
+                Some(true) => "accepted".to_string(),
+                _ => format!("rejected: {}", entry["receipt"]["error"].as_str().unwrap_or("unknown error")),
+            };
+            let name = path.file_stem().unwrap_or_default().to_string_lossy();
+            match outcome {
+                Ok(transaction_id) => {
+                    message.push_str(&format!(" • {name}: confirmed '{transaction_id}' (captured: {captured})\n"))
+                }
+                Err(error) => message.push_str(&format!(" • {name}: failed: {error:#} (captured: {captured})\n")),
+            }
+        }
+        Ok(message)
+    }
+}
@@ -0,0 +1,528 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{project_endpoint, resolve_aliases, wait_for_transaction, AccountTokens},
+    messages::{
+        DeployRequest,
+        ExecuteRequest,
+        NodeRequest,
+        PourRequest,
+        RecordViewRequest,
+        Signer,
+    },
+    output,
+    records,
+    Network,
+};
+
+use snarkvm::{
+    file::Manifest,
+    package::Package,
+    prelude::{Address, Identifier, PrivateKey, ProgramID, Transaction, Value, ViewKey},
+    synthesizer::Output,
+};
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use clap::Parser;
+use indexmap::IndexMap;
+use serde::Deserialize;
+use std::{collections::HashMap, fmt, iter::Peekable, path::PathBuf, time::Duration};
+
+/// Runs a scenario file, describing a sequence of steps and assertions, against a development node.
+#[derive(Debug, Parser)]
+pub struct Run {
+    /// The path to the scenario file (YAML or JSON).
+    scenario: PathBuf,
+    /// The base endpoint of the node. Overrides the endpoint in the scenario file.
+    #[clap(short, long)]
+    endpoint: Option<String>,
+}
+
+/// A scenario file.
+#[derive(Deserialize)]
+struct Scenario {
+    /// The base endpoint of the node.
+    #[serde(default)]
+    endpoint: Option<String>,
+    /// The private key that signs deploys and executes, unless a step specifies its own.
+    #[serde(default)]
+    private_key: Option<String>,
+    /// The number of seconds to wait for each transaction to be confirmed.
+    #[serde(default = "Scenario::default_timeout")]
+    timeout: u64,
+    /// The initial variables.
+    #[serde(default)]
+    variables: IndexMap<String, String>,
+    /// The steps, which are deserialized once the variables they reference are known.
+    steps: Vec<serde_json::Value>,
+}
+
+impl Scenario {
+    /// Returns the default number of seconds to wait for each transaction to be confirmed.
+    const fn default_timeout() -> u64 {
+        120
+    }
+}
+
+/// A step of a scenario.
+#[derive(Deserialize)]
+struct Step {
+    /// The action performed by the step.
+    #[serde(flatten)]
+    action: Action,
+    /// The variable that captures the transaction ID (and the outputs) of the step.
+    #[serde(default)]
+    capture: Option<String>,
+}
+
+/// An action performed by a step.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Action {
+    /// Pours credits from the faucet into an address.
+    Pour { address: Address<Network>, amount: u64 },
+    /// Deploys the main program of the package at the given path, signed by the package's development private key.
+    Deploy {
+        path: PathBuf,
+        #[serde(default)]
+        fee: Option<u64>,
+    },
+    /// Executes a function, and optionally asserts its outputs.
+    Execute {
+        program: ProgramID<Network>,
+        function: Identifier<Network>,
+        #[serde(default)]
+        inputs: Vec<Value<Network>>,
+        #[serde(default)]
+        private_key: Option<PrivateKey<Network>>,
+        #[serde(default)]
+        fee: Option<u64>,
+        #[serde(default)]
+        expect_outputs: Option<Vec<serde_json::Value>>,
+    },
+    /// Asserts the spendable balance of an account.
+    AssertBalance {
+        #[serde(default)]
+        private_key: Option<PrivateKey<Network>>,
+        gates: u64,
+    },
+    /// Asserts that each of the given patterns matches a distinct unspent record of an account.
+    AssertRecords {
+        #[serde(default)]
+        private_key: Option<PrivateKey<Network>>,
+        records: Vec<serde_json::Value>,
+    },
+}
+
+/// An expected value, which an output or a record is matched against.
+/// A string is parsed as a plaintext, in which `*` matches any value (e.g. `_nonce: *`), and must match every member.
+/// A map only asserts the members it lists, so that the other members of a record are ignored.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Pattern {
+    /// Matches any value.
+    Any,
+    /// A literal, e.g. `5u64.private`.
+    Literal(String),
+    /// An object, e.g. a record. If it is exhaustive, the value must have no other members.
+    Object { members: IndexMap<String, Pattern>, exhaustive: bool },
+}
+
+impl Pattern {
+    /// Parses a pattern from a scenario value.
+    fn from_json(value: &serde_json::Value) -> Result<Self> {
+        match value {
+            serde_json::Value::String(string) => Self::parse(string),
+            serde_json::Value::Object(members) => Ok(Self::Object {
+                members: members
+                    .iter()
+                    .map(|(name, member)| Ok((name.clone(), Self::from_json(member)?)))
+                    .collect::<Result<_>>()?,
+                exhaustive: false,
+            }),
+            serde_json::Value::Null | serde_json::Value::Array(_) => {
+                bail!("Expected a plaintext or a map, found {value}")
+            }
+            value => Ok(Self::Literal(value.to_string())),
+        }
+    }
+
+    /// Parses a pattern from a plaintext, e.g. `{ owner: aleo1....private, gates: 0u64.private, _nonce: * }`.
+    fn parse(text: &str) -> Result<Self> {
+        // Split the plaintext into braces, colons, commas, and the literals between them.
+        let mut tokens = Vec::new();
+        let mut literal = String::new();
+        for character in text.chars() {
+            if character.is_whitespace() || "{}:,".contains(character) {
+                if !literal.is_empty() {
+                    tokens.push(std::mem::take(&mut literal));
+                }
+                if !character.is_whitespace() {
+                    tokens.push(character.to_string());
+                }
+            } else {
+                literal.push(character);
+            }
+        }
+        if !literal.is_empty() {
+            tokens.push(literal);
+        }
+
+        let mut tokens = tokens.into_iter().peekable();
+        let pattern = Self::parse_tokens(&mut tokens, text)?;
+        ensure!(tokens.next().is_none(), "Unexpected trailing characters in '{text}'");
+        Ok(pattern)
+    }
+
+    /// Parses a pattern from the given tokens of the given plaintext.
+    fn parse_tokens(tokens: &mut Peekable<impl Iterator<Item = String>>, text: &str) -> Result<Self> {
+        match tokens.next().as_deref() {
+            Some("*") => Ok(Self::Any),
+            Some("{") => {
+                let mut members = IndexMap::new();
+                while tokens.peek().map(String::as_str) != Some("}") {
+                    let name = tokens.next().ok_or_else(|| anyhow!("Unterminated object in '{text}'"))?;
+                    ensure!(tokens.next().as_deref() == Some(":"), "Expected ':' after '{name}' in '{text}'");
+                    members.insert(name, Self::parse_tokens(tokens, text)?);
+                    if tokens.peek().map(String::as_str) == Some(",") {
+                        tokens.next();
+                    }
+                }
+                tokens.next();
+                Ok(Self::Object { members, exhaustive: true })
+            }
+            Some(token) if !"}:,".contains(token) => Ok(Self::Literal(token.to_string())),
+            _ => bail!("Invalid plaintext '{text}'"),
+        }
+    }
+
+    /// Matches the given value against the pattern, and returns a line for each difference, under the given path.
+    fn diff(&self, value: &Pattern, path: &str) -> Vec<String> {
+        match (self, value) {
+            (Self::Any, _) => vec![],
+            (Self::Literal(expected), Self::Literal(found)) if expected == found => vec![],
+            (Self::Object { members, exhaustive }, Self::Object { members: found, .. }) => {
+                let mut differences = Vec::new();
+                for (name, expected) in members {
+                    match found.get(name) {
+                        Some(found) => differences.extend(expected.diff(found, &format!("{path}.{name}"))),
+                        None => differences.push(format!("{path}.{name}: expected {expected}, found nothing")),
+                    }
+                }
+                if *exhaustive {
+                    for (name, found) in found.iter().filter(|(name, _)| !members.contains_key(*name)) {
+                        differences.push(format!("{path}.{name}: expected nothing, found {found}"));
+                    }
+                }
+                differences
+            }
+            (expected, found) => vec![format!("{path}: expected {expected}, found {found}")],
+        }
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Any => write!(f, "*"),
+            Self::Literal(literal) => write!(f, "{literal}"),
+            Self::Object { members, .. } => {
+                let members = members.iter().map(|(name, member)| format!("{name}: {member}")).collect::<Vec<_>>();
+                write!(f, "{{ {} }}", members.join(", "))
+            }
+        }
+    }
+}
+
+impl Run {
+    /// Runs the scenario.
+    pub async fn parse(self) -> Result<String> {
+        // Load the scenario. Note that YAML is a superset of JSON.
+        let contents = std::fs::read_to_string(&self.scenario)
+            .map_err(|error| anyhow!("Failed to read the scenario '{}': {error}", self.scenario.display()))?;
+        let scenario: Scenario = serde_yaml::from_str(&contents)
+            .map_err(|error| anyhow!("Failed to parse the scenario '{}': {error}", self.scenario.display()))?;
+
+        // Setup the endpoint, which defaults to the node in the project file of the current directory.
+        let endpoint = match self.endpoint.or(scenario.endpoint) {
+            Some(endpoint) => endpoint,
+            None => project_endpoint(None)?,
+        };
+
+        let mut runner = Runner {
+            endpoint,
+            private_key: scenario.private_key.map(|key| key.parse()).transpose()?,
+            timeout: Duration::from_secs(scenario.timeout),
+            variables: scenario.variables.into_iter().collect(),
+            tokens: Default::default(),
+        };
+
+        let num_steps = scenario.steps.len();
+        for (index, step) in scenario.steps.into_iter().enumerate() {
+            // Substitute the variables, and parse the step.
+            let step: Step = serde_json::from_value(runner.substitute(step)?)
+                .map_err(|error| anyhow!("Step {} is invalid: {error}", index + 1))?;
+            // Run the step.
+            let message = runner.run(step).await.with_context(|| format!("Step {} failed", index + 1))?;
+            output!("✅ Step {}/{num_steps}: {message}", index + 1);
+        }
+
+        Ok(format!("✅ Scenario '{}' passed ({num_steps} steps).", self.scenario.display()))
+    }
+}
+
+/// The state of a running scenario.
+struct Runner {
+    /// The base endpoint of the node.
+    endpoint: String,
+    /// The default private key.
+    private_key: Option<PrivateKey<Network>>,
+    /// The time to wait for each transaction to be confirmed.
+    timeout: Duration,
+    /// The variables.
+    variables: HashMap<String, String>,
+    /// The account tokens of the registered private keys.
+    tokens: HashMap<String, String>,
+}
+
+impl Runner {
+    /// Runs the given step, and returns a description of the result.
+    async fn run(&mut self, step: Step) -> Result<String> {
+        let (transaction, message) = match step.action {
+            Action::Pour { address, amount } => {
+                let response =
+                    PourRequest::new(address, amount).send(&format!("{}/testnet3/faucet/pour", self.endpoint)).await?;
+                let transaction = self.wait(response.transaction_id()).await?;
+                (transaction, format!("poured {amount} credits into {address}"))
+            }
+            Action::Deploy { path, fee } => {
+                ensure!(
+                    Manifest::<Network>::exists_at(&path),
+                    "Please ensure that the manifest file exists in the Aleo program directory (missing '{}' at '{}')",
+                    Manifest::<Network>::file_name(),
+                    path.display()
+                );
+                let private_key = *Manifest::<Network>::open(&path)?.development_private_key();
+                let program = Package::open(&path)?.program().clone();
+                let program_id = *program.id();
+
+                let signer = self.signer(&private_key).await?;
+                let request = DeployRequest::new(signer, program, fee.unwrap_or(0), None, None);
+                let response = request.send(&format!("{}/testnet3/program/deploy", self.endpoint)).await?;
+                let transaction = self.wait(response.transaction_id()).await?;
+                (transaction, format!("deployed '{program_id}'"))
+            }
+            Action::Execute { program, function, inputs, private_key, fee, expect_outputs } => {
+                let private_key = self.private_key(private_key)?;
+                let signer = self.signer(&private_key).await?;
+                let request = ExecuteRequest::new(signer, program, function, inputs, fee, None);
+                let response = request.send(&format!("{}/testnet3/program/execute", self.endpoint)).await?;
+                let transaction = self.wait(response.transaction_id()).await?;
+
+                // Decode the outputs, and capture them.
+                let outputs = output_values(&transaction, &ViewKey::try_from(&private_key)?);
+                if let Some(capture) = &step.capture {
+                    for (index, output) in outputs.iter().enumerate() {
+                        self.variables.insert(format!("{capture}.outputs.{index}"), output.clone());
+                    }
+                }
+                // Ensure the outputs match the expected outputs.
+                if let Some(expected) = expect_outputs {
+                    ensure!(
+                        outputs.len() == expected.len(),
+                        "Expected {} outputs, found {}: {outputs:?}",
+                        expected.len(),
+                        outputs.len()
+                    );
+                    let mut differences = Vec::new();
+                    for (index, (expected, output)) in expected.iter().zip(&outputs).enumerate() {
+                        differences.extend(
+                            Pattern::from_json(expected)?.diff(&Pattern::parse(output)?, &format!("outputs[{index}]")),
+                        );
+                    }
+                    ensure!(differences.is_empty(), "The outputs do not match:\n  {}", differences.join("\n  "));
+                }
+                (transaction, format!("executed '{program}/{function}'"))
+            }
+            Action::AssertBalance { private_key, gates } => {
+                let private_key = self.private_key(private_key)?;
+                let view_key = ViewKey::try_from(&private_key)?;
+                let address = Address::try_from(&view_key)?;
+                let response = RecordViewRequest::new(view_key)
+                    .send(&format!("{}/testnet3/records/unspent", self.endpoint))
+                    .await?;
+                let balance = response.records().values().map(|record| ***record.gates()).sum::<u64>();
+                ensure!(balance == gates, "Expected {address} to have {gates} gates, found {balance}");
+                return Ok(format!("{address} has {gates} gates"));
+            }
+            Action::AssertRecords { private_key, records } => {
+                let private_key = self.private_key(private_key)?;
+                let view_key = ViewKey::try_from(&private_key)?;
+                let address = Address::try_from(&view_key)?;
+                let response = RecordViewRequest::new(view_key)
+                    .send(&format!("{}/testnet3/records/unspent", self.endpoint))
+                    .await?;
+                let mut unspent = response
+                    .records()
+                    .values()
+                    .map(|record| Pattern::parse(&record.to_string()))
+                    .collect::<Result<Vec<_>>>()?;
+
+                // Match each pattern against a distinct record, reporting the closest record if none matches.
+                for (index, expected) in records.iter().enumerate() {
+                    let expected = Pattern::from_json(expected)?;
+                    let path = format!("records[{index}]");
+                    let closest = unspent
+                        .iter()
+                        .enumerate()
+                        .map(|(position, record)| (position, expected.diff(record, &path)))
+                        .min_by_key(|(_, differences)| differences.len());
+                    match closest {
+                        Some((position, differences)) if differences.is_empty() => {
+                            unspent.remove(position);
+                        }
+                        Some((_, differences)) => bail!(
+                            "No unspent record of {address} matches {path}. The closest record differs in:\n  {}",
+                            differences.join("\n  ")
+                        ),
+                        None => bail!("No unspent record of {address} is left to match {path}"),
+                    }
+                }
+                return Ok(format!("{address} has the {} expected records", records.len()));
+            }
+        };
+
+        // Capture the transaction ID.
+        if let Some(capture) = step.capture {
+            self.variables.insert(capture, transaction.id().to_string());
+        }
+        Ok(format!("{message} ({})", transaction.id()))
+    }
+
+    /// Returns the given private key, or the default private key of the scenario.
+    fn private_key(&self, private_key: Option<PrivateKey<Network>>) -> Result<PrivateKey<Network>> {
+        private_key.or(self.private_key).ok_or_else(|| anyhow!("The step requires a private key"))
+    }
+
+    /// Returns the signer for the given private key, registering the account with the node if needed.
+    async fn signer(&mut self, private_key: &PrivateKey<Network>) -> Result<Signer<Network>> {
+        let token = match self.tokens.get(&private_key.to_string()) {
+            Some(token) => token.clone(),
+            None => {
+                let registration = AccountTokens::register(&self.endpoint, *private_key).await?;
+                self.tokens.insert(private_key.to_string(), registration.token().to_string());
+                registration.token().to_string()
+            }
+        };
+        Ok(Signer::Token(token))
+    }
+
+    /// Waits for the given transaction to be confirmed.
+    async fn wait(
+        &self,
+        transaction_id: &<Network as snarkvm::prelude::Network>::TransactionID,
+    ) -> Result<Transaction<Network>> {
+        wait_for_transaction(&self.endpoint, transaction_id, self.timeout).await
+    }
+
+    /// Substitutes the variables referenced as `${name}`, and the aliases referenced as `@alias`,
+    /// in the strings of the given value.
+    fn substitute(&self, value: serde_json::Value) -> Result<serde_json::Value> {
+        Ok(match value {
+            serde_json::Value::String(string) => {
+                let mut result = String::new();
+                let mut rest = string.as_str();
+                while let Some(start) = rest.find("${") {
+                    let end = rest[start..].find('}').ok_or_else(|| anyhow!("Unterminated variable in '{string}'"))?;
+                    let name = &rest[start + 2..start + end];
+                    match self.variables.get(name) {
+                        Some(variable) => result.push_str(&format!("{}{variable}", &rest[..start])),
+                        None => bail!("Unknown variable '{name}'"),
+                    }
+                    rest = &rest[start + end + 1..];
+                }
+                result.push_str(rest);
+                serde_json::Value::String(resolve_aliases(&result)?)
+            }
+            serde_json::Value::Array(values) => {
+                serde_json::Value::Array(values.into_iter().map(|value| self.substitute(value)).collect::<Result<_>>()?)
+            }
+            serde_json::Value::Object(entries) => serde_json::Value::Object(
+                entries.into_iter().map(|(key, value)| Ok((key, self.substitute(value)?))).collect::<Result<_>>()?,
+            ),
+            value => value,
+        })
+    }
+}
+
+/// Returns the values of the outputs of the given transaction that are visible to the given view key.
+fn output_values(transaction: &Transaction<Network>, view_key: &ViewKey<Network>) -> Vec<String> {
+    transaction
+        .transitions()
+        .flat_map(|transition| transition.outputs())
+        .filter_map(|output| match output {
+            Output::Constant(_, Some(plaintext)) | Output::Public(_, Some(plaintext)) => Some(plaintext.to_string()),
+            Output::Record(_, _, Some(record)) => {
+                records::decrypt(record, view_key).ok().map(|record| record.to_string())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECORD: &str =
+        "{ owner: aleo1owner.private, gates: 0u64.private, amount: 5u64.private, _nonce: 1group.public }";
+
+    #[test]
+    fn test_pattern_exhaustive() {
+        let record = Pattern::parse(RECORD).unwrap();
+        let expected =
+            Pattern::parse("{ owner: aleo1owner.private, gates: 0u64.private, amount: 5u64.private, _nonce: * }")
+                .unwrap();
+        assert!(expected.diff(&record, "record").is_empty());
+
+        let expected = Pattern::parse("{ owner: aleo1owner.private, amount: 6u64.private }").unwrap();
+        assert_eq!(
+            expected.diff(&record, "record"),
+            vec![
+                "record.amount: expected 6u64.private, found 5u64.private",
+                "record.gates: expected nothing, found 0u64.private",
+                "record._nonce: expected nothing, found 1group.public",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pattern_partial() {
+        let record = Pattern::parse(RECORD).unwrap();
+        let expected = Pattern::from_json(&serde_json::json!({ "amount": "5u64.private" })).unwrap();
+        assert!(expected.diff(&record, "record").is_empty());
+
+        let expected = Pattern::from_json(&serde_json::json!({ "memo": "*" })).unwrap();
+        assert_eq!(expected.diff(&record, "record"), vec!["record.memo: expected *, found nothing"]);
+    }
+
+    #[test]
+    fn test_pattern_invalid() {
+        assert!(Pattern::parse("{ amount 5u64 }").is_err());
+        assert!(Pattern::parse("{ amount: 5u64").is_err());
+        assert!(Pattern::parse("1u64 2u64").is_err());
+    }
+}
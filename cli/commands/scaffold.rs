@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    node::{DEFAULT_REST_PORT, DEFAULT_SNAPSHOT_DIR},
+    Network,
+};
+
+use snarkvm::prelude::PrivateKey;
+
+use anyhow::{ensure, Result};
+use clap::Parser;
+use indexmap::IndexMap;
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// The directory of the node's state in the container, which is backed by a volume.
+const DATA_DIR: &str = "/data";
+
+/// Generates the descriptors to run a devnet.
+#[derive(Clone, Debug, Parser)]
+pub enum Scaffold {
+    /// Generates a docker-compose.yml to run a development node, with a Dockerfile that builds slingshot,
+    /// and a .env file that holds the node's private key.
+    Compose {
+        /// The number of validators in the devnet.
+        #[clap(long, default_value = "1")]
+        validators: usize,
+        /// Adds a read replica of the node, to serve queries (e.g. of an indexer) apart from the node.
+        #[clap(long)]
+        indexer: bool,
+        /// Exposes the gRPC server of the node on the given port. Requires the 'grpc' feature.
+        #[clap(long)]
+        grpc_port: Option<u16>,
+        /// The output directory. Defaults to the current working directory.
+        #[clap(short, long, default_value = ".")]
+        out: PathBuf,
+        /// Overwrites the docker-compose.yml and the Dockerfile, if they exist.
+        #[clap(long)]
+        force: bool,
+    },
+}
+
+impl Scaffold {
+    /// Generates the requested descriptors.
+    pub fn parse(self) -> Result<String> {
+        match self {
+            Self::Compose { validators, indexer, grpc_port, out, force } => {
+                ensure!(validators > 0, "The devnet must have at least 1 validator");
+
+                let compose_path = out.join("docker-compose.yml");
+                let dockerfile_path = out.join("Dockerfile");
+                for path in [&compose_path, &dockerfile_path] {
+                    ensure!(
+                        force || !path.exists(),
+                        "'{}' already exists (pass '--force' to overwrite it)",
+                        path.display()
+                    );
+                }
+
+                std::fs::create_dir_all(&out)?;
+                std::fs::write(&compose_path, serde_yaml::to_string(&compose(validators, indexer, grpc_port))?)?;
+                std::fs::write(&dockerfile_path, dockerfile())?;
+
+                // Generate a private key for the node, unless one is already configured.
+                let env_path = out.join(".env");
+                if !env_path.exists() {
+                    let private_key = PrivateKey::<Network>::new(&mut rand::thread_rng())?;
+                    std::fs::write(&env_path, format!("SLINGSHOT_PRIVATE_KEY={private_key}\n"))?;
+                }
+
+                Ok(format!(
+                    "✅ Generated '{}'. Run 'docker compose up' in '{}' to start the devnet at \
+                     http://localhost:{DEFAULT_REST_PORT}.",
+                    compose_path.display(),
+                    out.display()
+                ))
+            }
+        }
+    }
+}
+
+/// A docker-compose file.
+#[derive(Serialize)]
+struct ComposeFile {
+    services: IndexMap<String, Service>,
+    volumes: IndexMap<String, Volume>,
+}
+
+/// A service of a docker-compose file.
+#[derive(Serialize)]
+struct Service {
+    build: String,
+    image: String,
+    command: Vec<String>,
+    ports: Vec<String>,
+    volumes: Vec<String>,
+    healthcheck: Healthcheck,
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    depends_on: IndexMap<String, Dependency>,
+}
+
+/// The health check of a service.
+#[derive(Serialize)]
+struct Healthcheck {
+    test: Vec<String>,
+    interval: String,
+    timeout: String,
+    retries: u32,
+    start_period: String,
+}
+
+/// A dependency of a service on another service.
+#[derive(Serialize)]
+struct Dependency {
+    condition: String,
+}
+
+/// A named volume, with the default driver.
+#[derive(Serialize)]
+struct Volume {}
+
+/// Returns the docker-compose file of a devnet.
+fn compose(validators: usize, indexer: bool, grpc_port: Option<u16>) -> ComposeFile {
+    let mut services = IndexMap::new();
+    let mut volumes = IndexMap::new();
+
+    // The node, which produces the blocks.
+    let mut command = node_command();
+    command.extend(["--validators".to_string(), validators.to_string()]);
+    let mut ports = vec![format!("{DEFAULT_REST_PORT}:{DEFAULT_REST_PORT}")];
+    if let Some(grpc_port) = grpc_port {
+        command.extend(["--grpc-port".to_string(), grpc_port.to_string()]);
+        ports.push(format!("{grpc_port}:{grpc_port}"));
+    }
+    services.insert("node".to_string(), service(command, ports, "node-data", IndexMap::new()));
+    volumes.insert("node-data".to_string(), Volume {});
+
+    // The read replica, which syncs the blocks of the node once the node is healthy.
+    if indexer {
+        let mut command = node_command();
+        command.extend(["--peer".to_string(), format!("http://node:{DEFAULT_REST_PORT}")]);
+        let ports = vec![format!("{}:{DEFAULT_REST_PORT}", DEFAULT_REST_PORT + 1)];
+        let depends_on =
+            IndexMap::from([("node".to_string(), Dependency { condition: "service_healthy".to_string() })]);
+        services.insert("indexer".to_string(), service(command, ports, "indexer-data", depends_on));
+        volumes.insert("indexer-data".to_string(), Volume {});
+    }
+
+    ComposeFile { services, volumes }
+}
+
+/// Returns the arguments that start a node in a container, listening on all interfaces.
+fn node_command() -> Vec<String> {
+    [
+        "node",
+        "start",
+        "--key",
+        "${SLINGSHOT_PRIVATE_KEY}",
+        "--listen-ip",
+        "0.0.0.0",
+        "--rest-port",
+        &DEFAULT_REST_PORT.to_string(),
+        "--cache-dir",
+        ".slingshot/keys",
+        "--snapshot-dir",
+        DEFAULT_SNAPSHOT_DIR,
+    ]
+    .iter()
+    .map(|argument| argument.to_string())
+    .collect()
+}
+
+/// Returns a service running the given command, with its state in the given volume.
+fn service(
+    command: Vec<String>,
+    ports: Vec<String>,
+    volume: &str,
+    depends_on: IndexMap<String, Dependency>,
+) -> Service {
+    Service {
+        build: ".".to_string(),
+        image: "slingshot:devnet".to_string(),
+        command,
+        ports,
+        volumes: vec![format!("{volume}:{DATA_DIR}")],
+        healthcheck: Healthcheck {
+            test: vec![
+                "CMD".to_string(),
+                "curl".to_string(),
+                "-sf".to_string(),
+                format!("http://localhost:{DEFAULT_REST_PORT}/testnet3/latest/height"),
+            ],
+            interval: "5s".to_string(),
+            timeout: "3s".to_string(),
+            retries: 10,
+            // The node synthesizes the keys of the genesis block before the REST server starts.
+            start_period: "300s".to_string(),
+        },
+        depends_on,
+    }
+}
+
+/// Returns the Dockerfile that builds slingshot from its repository.
+fn dockerfile() -> String {
+    format!(
+        r#"FROM rust:1-bullseye AS builder
+RUN cargo install --locked --git {} slingshot
+
+FROM debian:bullseye-slim
+RUN apt-get update && apt-get install -y --no-install-recommends ca-certificates curl && rm -rf /var/lib/apt/lists/*
+COPY --from=builder /usr/local/cargo/bin/slingshot /usr/local/bin/slingshot
+WORKDIR {DATA_DIR}
+ENTRYPOINT ["slingshot"]
+"#,
+        env!("CARGO_PKG_REPOSITORY")
+    )
+}
@@ -0,0 +1,350 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{node_client, wait_for_transaction},
+    output,
+    Network,
+};
+
+use snarkvm::prelude::{Address, PrivateKey, ViewKey};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use clap::Parser;
+use indexmap::IndexMap;
+use rand::Rng;
+use serde::Deserialize;
+use serde_json::Value;
+use std::{collections::HashMap, time::Duration};
+
+/// The golden files, in the order they are run. Later cases use the values captured by earlier cases.
+const GOLDEN_FILES: &[&str] = &[
+    include_str!("../../contract-tests/01_latest_height.json"),
+    include_str!("../../contract-tests/02_latest_hash.json"),
+    include_str!("../../contract-tests/03_latest_block.json"),
+    include_str!("../../contract-tests/04_latest_state_root.json"),
+    include_str!("../../contract-tests/05_get_block.json"),
+    include_str!("../../contract-tests/06_get_blocks.json"),
+    include_str!("../../contract-tests/07_get_block_by_hash.json"),
+    include_str!("../../contract-tests/08_get_block_height_by_hash.json"),
+    include_str!("../../contract-tests/09_get_block_transactions.json"),
+    include_str!("../../contract-tests/10_get_memory_pool_transactions.json"),
+    include_str!("../../contract-tests/11_get_memory_pool_rejected.json"),
+    include_str!("../../contract-tests/12_get_node_address.json"),
+    include_str!("../../contract-tests/13_node_config.json"),
+    include_str!("../../contract-tests/14_faucet_balance.json"),
+    include_str!("../../contract-tests/15_accounts_register.json"),
+    include_str!("../../contract-tests/16_get_account_sequence.json"),
+    include_str!("../../contract-tests/17_faucet_pour.json"),
+    include_str!("../../contract-tests/18_get_transaction.json"),
+    include_str!("../../contract-tests/19_find_block_hash.json"),
+    include_str!("../../contract-tests/20_records_all.json"),
+    include_str!("../../contract-tests/21_records_spent.json"),
+    include_str!("../../contract-tests/22_records_unspent.json"),
+    include_str!("../../contract-tests/23_program_deploy.json"),
+    include_str!("../../contract-tests/24_get_program.json"),
+    include_str!("../../contract-tests/25_find_deployment_id.json"),
+    include_str!("../../contract-tests/26_program_evaluate.json"),
+    include_str!("../../contract-tests/27_fee_estimate.json"),
+    include_str!("../../contract-tests/28_transaction_simulate.json"),
+    include_str!("../../contract-tests/29_transaction_broadcast.json"),
+    include_str!("../../contract-tests/30_program_execute.json"),
+    include_str!("../../contract-tests/31_events_replay.json"),
+    include_str!("../../contract-tests/32_get_execute_transaction.json"),
+    include_str!("../../contract-tests/33_find_transaction_id.json"),
+    include_str!("../../contract-tests/34_find_transition_id.json"),
+    include_str!("../../contract-tests/35_transfer_and_wait.json"),
+    include_str!("../../contract-tests/36_get_state_path_for_commitment.json"),
+    include_str!("../../contract-tests/37_node_increase_time.json"),
+    include_str!("../../contract-tests/38_node_pause.json"),
+    include_str!("../../contract-tests/39_node_resume.json"),
+    include_str!("../../contract-tests/40_records_stats.json"),
+    include_str!("../../contract-tests/41_node_cache_stats.json"),
+    include_str!("../../contract-tests/42_program_execute_async.json"),
+    include_str!("../../contract-tests/43_get_job.json"),
+    include_str!("../../contract-tests/44_node_handshake.json"),
+    include_str!("../../contract-tests/45_get_program_metadata.json"),
+    include_str!("../../contract-tests/46_history.json"),
+    include_str!("../../contract-tests/47_node_info.json"),
+    include_str!("../../contract-tests/48_get_transaction_events.json"),
+    include_str!("../../contract-tests/49_get_program_events.json"),
+    include_str!("../../contract-tests/50_usage.json"),
+    include_str!("../../contract-tests/51_stats_timeseries.json"),
+    include_str!("../../contract-tests/52_get_record_ciphertext.json"),
+    include_str!("../../contract-tests/53_get_record_ciphertexts.json"),
+    include_str!("../../contract-tests/54_program_profile.json"),
+    include_str!("../../contract-tests/55_decode_transaction.json"),
+    include_str!("../../contract-tests/56_get_block_policy.json"),
+    include_str!("../../contract-tests/57_records_send.json"),
+    include_str!("../../contract-tests/58_records_consolidate.json"),
+    include_str!("../../contract-tests/59_records_split.json"),
+    include_str!("../../contract-tests/60_transaction_verify.json"),
+];
+
+/// Runs the contract tests against a development node, checking that every REST route
+/// accepts the golden requests and returns responses of the golden shape.
+#[derive(Debug, Parser)]
+pub struct Selftest {
+    /// The base endpoint of the node.
+    #[clap(short, long, default_value = "http://localhost:4180")]
+    endpoint: String,
+    /// The number of seconds to wait for each transaction to be confirmed.
+    #[clap(long, default_value = "120")]
+    timeout: u64,
+}
+
+/// A golden request and response.
+#[derive(Deserialize)]
+struct Case {
+    /// The name of the route.
+    name: String,
+    /// The HTTP method.
+    method: String,
+    /// The route, relative to the base endpoint.
+    route: String,
+    /// The JSON body of the request.
+    #[serde(default)]
+    request: Option<Value>,
+    /// The shape of the JSON body of the response.
+    response: Value,
+    /// The variables to capture, mapped to JSON pointers into the response.
+    #[serde(default)]
+    capture: IndexMap<String, String>,
+    /// The ID of a transaction to wait for, once the response is checked.
+    #[serde(default)]
+    wait_for: Option<String>,
+    /// If `true`, a failure is reported as skipped, as the route depends on an optional node feature.
+    #[serde(default)]
+    optional: bool,
+}
+
+impl Selftest {
+    /// Runs the contract tests.
+    pub async fn parse(self) -> Result<String> {
+        let rng = &mut rand::thread_rng();
+
+        // Initialize a fresh account, and the name of a program to deploy with it.
+        let private_key = PrivateKey::<Network>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&view_key)?;
+        let program_id = format!("selftest_{}.aleo", rng.gen::<u32>());
+        let program = format!(
+            "program {program_id};\n\n\
+             function double:\n    input r0 as u32.private;\n    add r0 r0 into r1;\n    output r1 as u32.private;\n"
+        );
+
+        let mut variables = HashMap::new();
+        variables.insert("private_key".to_string(), Value::String(private_key.to_string()));
+        variables.insert("view_key".to_string(), Value::String(view_key.to_string()));
+        variables.insert("address".to_string(), Value::String(address.to_string()));
+        variables.insert("program_id".to_string(), Value::String(program_id));
+        variables.insert("program".to_string(), Value::String(program));
+
+        let mut runner = Runner {
+            endpoint: self.endpoint.trim_end_matches('/').to_string(),
+            timeout: Duration::from_secs(self.timeout),
+            variables,
+        };
+
+        let (mut passed, mut skipped, mut failed) = (0, 0, 0);
+        for golden in GOLDEN_FILES {
+            let case: Case = serde_json::from_str(golden)?;
+            match runner.run(&case).await {
+                Ok(()) => {
+                    passed += 1;
+                    output!("✅ {}", case.name);
+                }
+                Err(error) if case.optional => {
+                    skipped += 1;
+                    output!("⏭️  {} (skipped: {error})", case.name);
+                }
+                Err(error) => {
+                    failed += 1;
+                    output!("❌ {}: {error}", case.name);
+                }
+            }
+        }
+
+        let summary = format!("{passed} passed, {skipped} skipped, {failed} failed");
+        ensure!(failed == 0, "❌ The node does not match the contract tests ({summary}).");
+        Ok(format!("✅ The node matches the contract tests ({summary})."))
+    }
+}
+
+/// The state of a contract test run.
+struct Runner {
+    /// The base endpoint of the node.
+    endpoint: String,
+    /// The time to wait for each transaction to be confirmed.
+    timeout: Duration,
+    /// The variables.
+    variables: HashMap<String, Value>,
+}
+
+impl Runner {
+    /// Sends the golden request of the given case, and checks the response.
+    async fn run(&mut self, case: &Case) -> Result<()> {
+        let route = match self.substitute(Value::String(case.route.clone()))? {
+            Value::String(route) => route,
+            route => route.to_string(),
+        };
+
+        // Send the request.
+        let client = node_client(&self.endpoint);
+        let response: Result<Value> = match case.method.as_str() {
+            "GET" => client.get(&route).await,
+            "POST" => {
+                let request =
+                    self.substitute(case.request.clone().unwrap_or_else(|| Value::Object(Default::default())))?;
+                client.post(&route, &request).await
+            }
+            method => bail!("Unsupported method '{method}'"),
+        };
+        let response = match response {
+            Ok(response) => response,
+            Err(error) => bail!("{} {route} failed: {error}", case.method),
+        };
+
+        // Check the response.
+        check_shape(&case.response, &response, "")?;
+
+        // Capture the variables.
+        for (name, pointer) in &case.capture {
+            let value = response.pointer(pointer).ok_or_else(|| anyhow!("The response has no value at '{pointer}'"))?;
+            self.variables.insert(name.clone(), value.clone());
+        }
+
+        // Wait for the transaction to be confirmed.
+        if let Some(transaction_id) = &case.wait_for {
+            let transaction_id = serde_json::from_value(self.substitute(Value::String(transaction_id.clone()))?)?;
+            wait_for_transaction(&self.endpoint, &transaction_id, self.timeout).await?;
+        }
+        Ok(())
+    }
+
+    /// Substitutes the variables referenced as `${name}` in the strings of the given value.
+    /// A string that consists of a single reference is replaced by the value of the variable.
+    fn substitute(&self, value: Value) -> Result<Value> {
+        Ok(match value {
+            Value::String(string) => {
+                if let Some(name) = string.strip_prefix("${").and_then(|rest| rest.strip_suffix('}')) {
+                    if !name.contains('}') {
+                        return self.variables.get(name).cloned().ok_or_else(|| anyhow!("Unknown variable '{name}'"));
+                    }
+                }
+                let mut result = String::new();
+                let mut rest = string.as_str();
+                while let Some(start) = rest.find("${") {
+                    let end = rest[start..].find('}').ok_or_else(|| anyhow!("Unterminated variable in '{string}'"))?;
+                    let name = &rest[start + 2..start + end];
+                    match self.variables.get(name) {
+                        Some(Value::String(variable)) => result.push_str(&format!("{}{variable}", &rest[..start])),
+                        Some(variable) => result.push_str(&format!("{}{variable}", &rest[..start])),
+                        None => bail!("Unknown variable '{name}'"),
+                    }
+                    rest = &rest[start + end + 1..];
+                }
+                result.push_str(rest);
+                Value::String(result)
+            }
+            Value::Array(values) => {
+                Value::Array(values.into_iter().map(|value| self.substitute(value)).collect::<Result<_>>()?)
+            }
+            Value::Object(entries) => Value::Object(
+                entries.into_iter().map(|(key, value)| Ok((key, self.substitute(value)?))).collect::<Result<_>>()?,
+            ),
+            value => value,
+        })
+    }
+}
+
+/// Ensures the given value has the shape of the golden value. A golden `null` matches any value,
+/// an array matches if each of its elements matches the first golden element, and an object matches
+/// if it contains every golden member. Otherwise, the value must have the same JSON type as the golden value.
+fn check_shape(golden: &Value, value: &Value, path: &str) -> Result<()> {
+    match (golden, value) {
+        (Value::Null, _) => Ok(()),
+        (Value::Bool(_), Value::Bool(_))
+        | (Value::Number(_), Value::Number(_))
+        | (Value::String(_), Value::String(_)) => Ok(()),
+        (Value::Array(golden), Value::Array(values)) => match golden.first() {
+            Some(golden) => values
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, value)| check_shape(golden, value, &format!("{path}/{index}"))),
+            None => Ok(()),
+        },
+        (Value::Object(golden), Value::Object(members)) => {
+            golden.iter().try_for_each(|(name, golden)| match members.get(name) {
+                Some(value) => check_shape(golden, value, &format!("{path}/{name}")),
+                None => bail!("The response is missing '{path}/{name}'"),
+            })
+        }
+        (golden, value) => bail!("Expected '{path}' to be {}, found {value}", json_type(golden)),
+    }
+}
+
+/// Returns the name of the JSON type of the given value.
+const fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_golden_files() {
+        for golden in GOLDEN_FILES {
+            let case: Case = serde_json::from_str(golden).unwrap();
+            assert!(case.route.starts_with("/testnet3/"), "{}", case.name);
+            assert!(["GET", "POST"].contains(&case.method.as_str()), "{}", case.name);
+        }
+    }
+
+    #[test]
+    fn test_check_shape() {
+        let golden = serde_json::json!({ "transaction_id": "", "sequence": null, "outputs": [""] });
+        assert!(check_shape(
+            &golden,
+            &serde_json::json!({ "transaction_id": "at1", "sequence": 3, "outputs": [] }),
+            ""
+        )
+        .is_ok());
+        assert!(check_shape(
+            &golden,
+            &serde_json::json!({ "transaction_id": "at1", "sequence": null, "outputs": ["1u32"], "extra": 1 }),
+            ""
+        )
+        .is_ok());
+        assert!(check_shape(&golden, &serde_json::json!({ "sequence": null, "outputs": [] }), "").is_err());
+        assert!(check_shape(&golden, &serde_json::json!({ "transaction_id": 1, "sequence": null, "outputs": [] }), "")
+            .is_err());
+        assert!(check_shape(
+            &golden,
+            &serde_json::json!({ "transaction_id": "at1", "sequence": null, "outputs": [1] }),
+            ""
+        )
+        .is_err());
+    }
+}
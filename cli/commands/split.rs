@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    commands::View,
+    helpers::{AccountTokens, DEFAULT_ENDPOINT},
+    messages::{NodeRequest, Signer, SplitRequest},
+};
+
+use anyhow::Result;
+use clap::Parser;
+
+/// Splits the credits records of an account into records of the given amount, in rounds of `credits.aleo/split`
+/// executions, so that the records can be spent by concurrent transactions.
+#[derive(Debug, Parser)]
+pub struct Split {
+    /// A private key.
+    #[clap(short, long, conflicts_with = "path")]
+    key: Option<String>,
+    /// A path to a directory containing a manifest file.
+    #[clap(short, long, conflicts_with = "key")]
+    path: Option<String>,
+    /// The number of gates of each record.
+    #[clap(long)]
+    amount: u64,
+    /// The number of records.
+    #[clap(long)]
+    count: usize,
+    /// The maximum number of seconds to wait for each round of splits to be confirmed.
+    #[clap(long)]
+    timeout: Option<u64>,
+    /// The base endpoint of the node. Defaults to a local development node.
+    #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+    endpoint: String,
+}
+
+impl Split {
+    /// Splits the records one round at a time, and prints the progress after each round.
+    pub async fn parse(self) -> Result<String> {
+        let private_key = View::private_key(self.key, self.path)?;
+
+        // Register the account with the node, to authorize the splits without sending the private key.
+        let registration = AccountTokens::register(&self.endpoint, private_key).await?;
+        let signer = || Signer::Token(registration.token().to_string());
+
+        let endpoint = format!("{}/testnet3/records/split", self.endpoint);
+        let mut round = 0;
+        loop {
+            round += 1;
+            let request = SplitRequest::new(signer(), self.amount, self.count, Some(1), self.timeout);
+            let response = request.send(&endpoint).await?;
+            match response.rounds().first() {
+                Some(splits) => output!(
+                    "⏳ Round {round}: split {} record(s) in block {}, with {} of {} record(s) ready",
+                    splits.transaction_ids.len(),
+                    splits.block_height,
+                    response.commitments().len().min(self.count),
+                    self.count
+                ),
+                None => {
+                    let mut message = format!(
+                        "✅ The account {} has {} record(s) of {} gates.\n\n",
+                        registration.address(),
+                        response.commitments().len(),
+                        self.amount
+                    );
+                    for commitment in response.commitments() {
+                        message.push_str(&format!(" • {commitment}\n"));
+                    }
+                    return Ok(message);
+                }
+            }
+        }
+    }
+}
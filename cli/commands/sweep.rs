@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    commands::View,
+    helpers::{AccountTokens, DEFAULT_ENDPOINT},
+    messages::{ConsolidateRequest, NodeRequest, Signer},
+};
+
+use anyhow::Result;
+use clap::Parser;
+
+/// Joins the unspent credits records of an account into fewer records, in rounds of `credits.aleo/join` executions.
+#[derive(Debug, Parser)]
+pub struct Sweep {
+    /// A private key.
+    #[clap(short, long, conflicts_with = "path")]
+    key: Option<String>,
+    /// A path to a directory containing a manifest file.
+    #[clap(short, long, conflicts_with = "key")]
+    path: Option<String>,
+    /// The number of records to join the credits into.
+    #[clap(long, default_value = "1")]
+    target: usize,
+    /// The maximum number of seconds to wait for each round of joins to be confirmed.
+    #[clap(long)]
+    timeout: Option<u64>,
+    /// The base endpoint of the node. Defaults to a local development node.
+    #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+    endpoint: String,
+}
+
+impl Sweep {
+    /// Joins the records one round at a time, and prints the progress after each round.
+    pub async fn parse(self) -> Result<String> {
+        let private_key = View::private_key(self.key, self.path)?;
+
+        // Register the account with the node, to authorize the joins without sending the private key.
+        let registration = AccountTokens::register(&self.endpoint, private_key).await?;
+        let signer = || Signer::Token(registration.token().to_string());
+
+        let endpoint = format!("{}/testnet3/records/consolidate", self.endpoint);
+        let mut round = 0;
+        loop {
+            round += 1;
+            let response =
+                ConsolidateRequest::new(signer(), Some(self.target), Some(1), self.timeout).send(&endpoint).await?;
+            match response.rounds().first() {
+                Some(joins) => output!(
+                    "⏳ Round {round}: joined {} pair(s) of records in block {}, leaving {} record(s)",
+                    joins.transaction_ids.len(),
+                    joins.block_height,
+                    joins.num_records
+                ),
+                None => {
+                    return Ok(format!(
+                        "✅ The account {} holds {} gates in {} credits record(s).",
+                        registration.address(),
+                        response.balance(),
+                        response.num_records()
+                    ));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{helpers::verify_transaction, Network};
+
+use snarkvm::{
+    prelude::{FromBytes, ToBytes, Transaction},
+    synthesizer::{Input, Output, Transition},
+};
+
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use colored::Colorize;
+use std::{
+    fmt::Write,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// Inspects transactions, e.g. to debug a transaction the node rejected.
+#[derive(Clone, Debug, Parser)]
+pub enum Tx {
+    /// Prints a breakdown of a transaction, given as JSON, as bytes, or as hex-encoded bytes.
+    Decode {
+        /// The file containing the transaction. Reads from stdin if omitted, or if it is `-`.
+        file: Option<PathBuf>,
+        /// The base endpoint of a node to verify the transaction against, without broadcasting it.
+        #[clap(short, long)]
+        endpoint: Option<String>,
+    },
+}
+
+impl Tx {
+    /// Runs the transaction command.
+    pub async fn parse(self) -> Result<String> {
+        match self {
+            Self::Decode { file, endpoint } => {
+                // Read the transaction.
+                let bytes = match file.as_deref() {
+                    Some(path) if path != Path::new("-") => std::fs::read(path)
+                        .map_err(|error| anyhow!("Failed to read the transaction '{}': {error}", path.display()))?,
+                    _ => {
+                        let mut bytes = Vec::new();
+                        std::io::stdin().read_to_end(&mut bytes)?;
+                        bytes
+                    }
+                };
+                let transaction = decode(&bytes)?;
+
+                // Describe the transaction, and verify it against the node, if an endpoint is given.
+                let mut message = describe(&transaction)?;
+                match endpoint {
+                    Some(endpoint) => match verify_transaction(&endpoint, &transaction).await? {
+                        None => writeln!(message, "\n✅ The node at '{endpoint}' accepts the transaction.")?,
+                        Some(error) => {
+                            writeln!(message, "\n❌ The node at '{endpoint}' rejects the transaction: {error}")?
+                        }
+                    },
+                    None => writeln!(message, "\nThe transaction was not verified (pass '--endpoint' to verify it).")?,
+                }
+                Ok(message)
+            }
+        }
+    }
+}
+
+/// Decodes a transaction from its JSON encoding, its bytes, or its hex-encoded bytes.
+fn decode(bytes: &[u8]) -> Result<Transaction<Network>> {
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        let text = text.trim();
+        if text.starts_with('{') {
+            return serde_json::from_str(text).map_err(|error| anyhow!("Invalid transaction JSON: {error}"));
+        }
+        if let Some(bytes) = decode_hex(text.trim_start_matches("0x")) {
+            return Transaction::from_bytes_le(&bytes).map_err(|error| anyhow!("Invalid transaction bytes: {error}"));
+        }
+    }
+    Transaction::from_bytes_le(bytes).map_err(|error| anyhow!("Invalid transaction bytes: {error}"))
+}
+
+/// Returns the bytes of the given hex string, if it is one.
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if text.is_empty() || text.len() % 2 != 0 {
+        return None;
+    }
+    (0..text.len()).step_by(2).map(|index| u8::from_str_radix(text.get(index..index + 2)?, 16).ok()).collect()
+}
+
+/// Returns a human-readable breakdown of the given transaction.
+fn describe(transaction: &Transaction<Network>) -> Result<String> {
+    let mut message = String::new();
+    writeln!(message, "🔎 Transaction {}\n", transaction.id().to_string().bold())?;
+
+    let (kind, fee_transition) = match transaction {
+        Transaction::Deploy(_, _, fee) => ("deploy", Some(fee)),
+        Transaction::Execute(_, _, fee) => ("execute", fee.as_ref()),
+    };
+    writeln!(message, " • Type: {kind}")?;
+    writeln!(message, " • Fee: {} gates", transaction.fee()?)?;
+    writeln!(message, " • Size: {} bytes", transaction.to_bytes_le()?.len())?;
+
+    // Describe the deployment.
+    if let Transaction::Deploy(_, deployment, _) = transaction {
+        writeln!(message, "\nDeployment of '{}' (edition {})", deployment.program_id(), deployment.edition())?;
+        writeln!(message, " • Program: {} bytes", deployment.program().to_bytes_le()?.len())?;
+        for (function_name, (verifying_key, certificate)) in deployment.verifying_keys() {
+            writeln!(
+                message,
+                " • Function '{function_name}': {} bytes of verifying key, {} bytes of certificate",
+                verifying_key.to_bytes_le()?.len(),
+                certificate.to_bytes_le()?.len()
+            )?;
+        }
+    }
+
+    // Describe the transitions, including the fee transition.
+    writeln!(message, "\nTransitions")?;
+    for (index, transition) in transaction.transitions().enumerate() {
+        let is_fee = fee_transition.map_or(false, |fee| fee.id() == transition.id());
+        describe_transition(&mut message, index, transition, is_fee)?;
+    }
+    Ok(message)
+}
+
+/// Appends a breakdown of the given transition to the message.
+fn describe_transition(
+    message: &mut String,
+    index: usize,
+    transition: &Transition<Network>,
+    is_fee: bool,
+) -> Result<()> {
+    writeln!(
+        message,
+        "\n {}. {}/{}{} ({} bytes)",
+        index + 1,
+        transition.program_id(),
+        transition.function_name(),
+        if is_fee { " [fee]" } else { "" },
+        transition.to_bytes_le()?.len()
+    )?;
+    writeln!(message, "    ID: {}", transition.id())?;
+    writeln!(message, "    Fee: {} gates", transition.fee())?;
+    for input in transition.inputs() {
+        let (kind, id) = match input {
+            Input::Constant(id, _) => ("constant", id),
+            Input::Public(id, _) => ("public", id),
+            Input::Private(id, _) => ("private", id),
+            Input::Record(serial_number, _) => ("record", serial_number),
+            Input::ExternalRecord(id) => ("external record", id),
+        };
+        writeln!(message, "    Input ({kind}): {id}")?;
+    }
+    for output in transition.outputs() {
+        let (kind, id) = match output {
+            Output::Constant(id, _) => ("constant", id),
+            Output::Public(id, _) => ("public", id),
+            Output::Private(id, _) => ("private", id),
+            Output::Record(commitment, ..) => ("record", commitment),
+            Output::ExternalRecord(id) => ("external record", id),
+        };
+        writeln!(message, "    Output ({kind}): {id}")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_hex() {
+        assert_eq!(decode_hex("00ff10"), Some(vec![0x00, 0xff, 0x10]));
+        assert_eq!(decode_hex("0f0"), None);
+        assert_eq!(decode_hex("zz"), None);
+        assert_eq!(decode_hex(""), None);
+    }
+}
@@ -14,53 +14,55 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::helpers::Updater;
+use crate::{errors::UpdaterError, helpers::Updater};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Parser;
+use self_update::Status;
 
-/// Update slingshot.
+/// Updates slingshot to the latest release, or to the given release, after verifying its checksum.
 #[derive(Debug, Parser)]
-pub enum Update {
-    /// Update slingshot to the latest version
-    Update {
-        /// Lists all available versions of slingshot
-        #[clap(short = 'l', long)]
-        list: bool,
-        /// Suppress outputs to terminal
-        #[clap(short = 'q', long)]
-        quiet: bool,
-    },
+pub struct Update {
+    /// Lists all available versions of slingshot
+    #[clap(short = 'l', long)]
+    list: bool,
+    /// Checks whether a newer version of slingshot is available, without installing it
+    #[clap(long, conflicts_with = "list")]
+    check: bool,
+    /// Installs the release with the given tag (e.g. `v0.3.0`), even if it is older than the current version
+    #[clap(long, conflicts_with_all = &["list", "check"])]
+    version: Option<String>,
+    /// Suppress outputs to terminal
+    #[clap(short = 'q', long)]
+    quiet: bool,
 }
 
 impl Update {
     pub fn parse(self) -> Result<String> {
-        match self {
-            Self::Update { list, quiet } => match list {
-                true => match Updater::show_available_releases() {
-                    Ok(output) => Ok(output),
-                    Err(error) => Ok(format!("Failed to list the available versions of slingshot\n{error}\n")),
-                },
-                false => {
-                    let result = Updater::update_to_latest_release(!quiet);
-                    if !quiet {
-                        match result {
-                            Ok(status) => {
-                                if status.uptodate() {
-                                    Ok("\nslingshot is already on the latest version".to_string())
-                                } else if status.updated() {
-                                    Ok(format!("\nslingshot has updated to version {}", status.version()))
-                                } else {
-                                    Ok(String::new())
-                                }
-                            }
-                            Err(e) => Ok(format!("\nFailed to update slingshot to the latest version\n{e}\n")),
-                        }
-                    } else {
-                        Ok(String::new())
-                    }
+        if self.list {
+            return Updater::show_available_releases().context("Failed to list the available versions of slingshot");
+        }
+        if self.check {
+            let current_version = env!("CARGO_PKG_VERSION");
+            return match Updater::update_available() {
+                Ok(latest_version) => Ok(format!(
+                    "slingshot {current_version} is installed, and version {latest_version} is available \
+                     (run `slingshot update`)"
+                )),
+                Err(UpdaterError::OldReleaseVersion(..)) => {
+                    Ok(format!("slingshot {current_version} is the latest version"))
+                }
+                Err(error) => {
+                    Err(anyhow::Error::from(error).context("Failed to check for a newer version of slingshot"))
                 }
-            },
+            };
+        }
+
+        let status = Updater::update(self.version.as_deref(), !self.quiet).context("Failed to update slingshot")?;
+        match (self.quiet, status) {
+            (true, _) => Ok(String::new()),
+            (false, Status::UpToDate(_)) => Ok("\nslingshot is already on the latest version".to_string()),
+            (false, Status::Updated(version)) => Ok(format!("\nslingshot has updated to version {version}")),
         }
     }
 }
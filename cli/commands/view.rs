@@ -19,7 +19,8 @@ use crate::Network;
 use snarkvm::file::Manifest;
 
 use crate::{
-    messages::{PourRequest, RecordViewRequest},
+    helpers::{base_endpoint, fetch_block, fetch_program, DEFAULT_ENDPOINT},
+    messages::{NodeRequest, PourRequest, RecordStatsRequest, RecordViewRequest},
     node::DevelopmentBeacon,
 };
 use anyhow::{bail, ensure, Result};
@@ -28,8 +29,9 @@ use colored::*;
 use rand::SeedableRng;
 use rand_chacha::ChaChaRng;
 use snarkos::account::Account;
-use snarkvm::prelude::{Block, ConsensusMemory, ConsensusStore, PrivateKey, VM};
-use std::{net::SocketAddr, path::PathBuf, str::FromStr, sync::Arc};
+use snarkvm::prelude::{Block, ConsensusMemory, ConsensusStore, PrivateKey, Program, ProgramID, Transaction, VM};
+use std::{fmt::Write, net::SocketAddr, path::PathBuf, str::FromStr, sync::Arc};
+use time::OffsetDateTime;
 use tokio::{runtime, runtime::Runtime};
 
 // TODO: Quiet option
@@ -56,37 +58,53 @@ pub enum View {
         #[clap(short, long)]
         endpoint: Option<String>,
     },
+    /// Queries the local development node for the balance of the account, and warns if it is fragmented.
+    Balance {
+        /// A private key.
+        #[clap(short, long, conflicts_with = "path")]
+        key: Option<String>,
+        /// A path to a directory containing a manifest file.
+        #[clap(short, long, conflicts_with = "key")]
+        path: Option<String>,
+        /// Uses the specified endpoint.
+        #[clap(short, long)]
+        endpoint: Option<String>,
+    },
+    /// Queries the local development node for a block, by height or by hash.
+    Block {
+        /// The height or the hash of the block.
+        height_or_hash: String,
+        /// Prints the block as JSON, as returned by the node.
+        #[clap(long)]
+        json: bool,
+        /// The base endpoint of the node. Defaults to a local development node.
+        #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+        endpoint: String,
+    },
+    /// Queries the local development node for a deployed program, and prints its functions and source.
+    Program {
+        /// The program identifier.
+        #[clap(parse(try_from_str))]
+        program_id: ProgramID<Network>,
+        /// Prints only the signatures of the functions, without the source.
+        #[clap(long)]
+        functions_only: bool,
+        /// The base endpoint of the node. Defaults to a local development node.
+        #[clap(short, long, default_value = DEFAULT_ENDPOINT)]
+        endpoint: String,
+    },
 }
 
+/// The keywords that declare the parts of an Aleo program.
+const DECLARATION_KEYWORDS: &[&str] = &["program", "import", "interface", "record", "closure", "function"];
+
 impl View {
     #[allow(unused_must_use)]
-    pub fn parse(self) -> Result<String> {
+    pub async fn parse(self) -> Result<String> {
         match self {
             // Parse the command and get the private key.
             Self::Record { key, path, spent, unspent, endpoint } => {
-                let private_key = match (key, path) {
-                    (Some(_), Some(_)) => unreachable!("Clap prevents conflicting options from being enabled"),
-                    (None, None) => panic!("Please specify either a private key or a manifest file"),
-                    (Some(key), None) => PrivateKey::<Network>::from_str(&key)?,
-                    (None, Some(path)) => {
-                        // Instantiate a path to the directory containing the manifest file.
-                        let directory = PathBuf::from_str(&path)?;
-                        // Ensure the directory path exists.
-                        ensure!(directory.exists(), "The program directory does not exist: {}", directory.display());
-                        // Ensure the manifest file exists.
-                        ensure!(
-                            Manifest::<Network>::exists_at(&directory),
-                            "Please ensure that the manifest file exists in the Aleo program directory (missing '{}' at '{}')",
-                            Manifest::<Network>::file_name(),
-                            directory.display()
-                        );
-
-                        // Open the manifest file.
-                        let manifest = Manifest::open(&directory)?;
-
-                        *manifest.development_private_key()
-                    }
-                };
+                let private_key = Self::private_key(key, path)?;
 
                 // Get the record filter.
                 let filter = match (spent, unspent) {
@@ -107,7 +125,7 @@ impl View {
                 let request = RecordViewRequest::new(*account.view_key());
 
                 // Send the request and wait for the response.
-                match request.send(&endpoint) {
+                match request.send(&endpoint).await {
                     Ok(response) => {
                         let mut message = match (spent, unspent) {
                             (false, false) => format!(
@@ -130,6 +148,170 @@ impl View {
                     Err(error) => Err(error),
                 }
             }
+            Self::Balance { key, path, endpoint } => {
+                let private_key = Self::private_key(key, path)?;
+
+                // Use the provided endpoint, or default to a local endpoint.
+                let endpoint = endpoint.unwrap_or_else(|| "http://localhost:4180/testnet3/records/stats".to_string());
+
+                // Send the request and wait for the response.
+                let account = Account::<Network>::try_from(&private_key)?;
+                let stats = RecordViewRequest::new(*account.view_key()).send_stats(&endpoint).await?;
+
+                let mut message = format!(
+                    "✅ The account {} has {} gates in {} unspent record(s).\n\n",
+                    account.address(),
+                    stats.balance(),
+                    stats.num_records()
+                );
+                message.push_str(&format!(" • Spendable in a single transaction: {} gates\n", stats.spendable()));
+                message.push_str(&format!(
+                    " • Dust: {} record(s) with {} gates in total\n",
+                    stats.dust_records(),
+                    stats.dust_gates()
+                ));
+                if let Some(warning) = stats.warning() {
+                    message.push_str(&format!("\n⚠️  {warning}\n"));
+                }
+                Ok(message)
+            }
+            Self::Block { height_or_hash, json, endpoint } => {
+                let block = fetch_block(&base_endpoint(&endpoint), &height_or_hash).await?;
+                match json {
+                    true => Ok(serde_json::to_string_pretty(&block)?),
+                    false => Self::describe_block(&block),
+                }
+            }
+            Self::Program { program_id, functions_only, endpoint } => {
+                let program = fetch_program(&base_endpoint(&endpoint), &program_id).await?;
+                Self::describe_program(&program, functions_only)
+            }
+        }
+    }
+
+    /// Returns the function signatures of the given program, followed by its highlighted source unless omitted.
+    fn describe_program(program: &Program<Network>, functions_only: bool) -> Result<String> {
+        let mut message = String::new();
+        writeln!(message, "📜 Program '{}'\n", program.id().to_string().bold())?;
+
+        // List the functions, with the types of their inputs and outputs.
+        writeln!(message, "Functions ({})", program.functions().len())?;
+        for (name, function) in program.functions() {
+            let inputs = function.inputs().iter().map(|input| input.value_type().to_string()).collect::<Vec<_>>();
+            let outputs = function.outputs().iter().map(|output| output.value_type().to_string()).collect::<Vec<_>>();
+            writeln!(message, " • {name}({}) -> ({})", inputs.join(", "), outputs.join(", "))?;
+        }
+        // Note: This version of snarkVM has no finalize step, so programs have no mappings to list.
+        writeln!(message, "\nMappings: none (this version of snarkVM does not support mappings)")?;
+
+        if !functions_only {
+            writeln!(message, "\nSource\n")?;
+            for line in program.to_string().lines() {
+                writeln!(message, "{}", Self::highlight(line))?;
+            }
+        }
+        Ok(message)
+    }
+
+    /// Returns the given line of Aleo instructions, with its keywords, opcodes, registers, and literals highlighted.
+    fn highlight(line: &str) -> String {
+        // Split off the comment, if any.
+        let (code, comment) = match line.find("//") {
+            Some(index) => line.split_at(index),
+            None => (line, ""),
+        };
+        let indentation = &code[..code.len() - code.trim_start().len()];
+
+        let mut words = Vec::new();
+        for (index, word) in code.split_whitespace().enumerate() {
+            let name = word.trim_end_matches([';', ':']);
+            let highlighted = if DECLARATION_KEYWORDS.contains(&name) {
+                word.magenta().bold()
+            } else if ["input", "output", "as"].contains(&name) {
+                word.blue()
+            } else if index == 0 {
+                // The first word of any other statement is the opcode of an instruction.
+                word.cyan()
+            } else if name.starts_with('r')
+                && name[1..].split('.').next().map_or(false, |index| {
+                    !index.is_empty() && index.chars().all(|character| character.is_ascii_digit())
+                })
+            {
+                word.yellow()
+            } else if name.starts_with(|character: char| character.is_ascii_digit() || character == '-') {
+                word.green()
+            } else {
+                word.normal()
+            };
+            words.push(highlighted.to_string());
+        }
+        format!("{indentation}{}{}", words.join(" "), comment.dimmed())
+    }
+
+    /// Returns a human-readable summary of the given block: its header, timestamp, and transactions.
+    fn describe_block(block: &Block<Network>) -> Result<String> {
+        let mut message = String::new();
+        writeln!(message, "🧱 Block {} ({})\n", block.height().to_string().bold(), block.hash())?;
+
+        // Describe the header.
+        let timestamp = OffsetDateTime::from_unix_timestamp(block.timestamp())?;
+        let age = OffsetDateTime::now_utc().unix_timestamp().saturating_sub(block.timestamp()).max(0);
+        writeln!(message, " • Timestamp: {timestamp} ({age} seconds ago)")?;
+        writeln!(message, " • Previous hash: {}", block.previous_hash())?;
+        writeln!(message, " • Round: {}", block.round())?;
+        writeln!(message, " • Coinbase target: {}", block.coinbase_target())?;
+        writeln!(message, " • Proof target: {}", block.proof_target())?;
+        writeln!(message, " • Transactions root: {}", block.header().transactions_root())?;
+        writeln!(message, " • Signed by: {}", block.signature().to_address())?;
+
+        // Describe the transactions.
+        let num_transactions = block.transactions().len();
+        match num_transactions {
+            0 => writeln!(message, "\nNo transactions")?,
+            _ => writeln!(message, "\nTransactions ({num_transactions})")?,
+        }
+        for (transaction_id, transaction) in block.transactions().iter() {
+            let kind = match transaction {
+                Transaction::Deploy(_, deployment, _) => format!("deploy '{}'", deployment.program_id()),
+                Transaction::Execute(_, _, fee) => {
+                    // List the executed functions, without the fee transition.
+                    let functions = transaction
+                        .transitions()
+                        .filter(|transition| fee.as_ref().map_or(true, |fee| fee.id() != transition.id()))
+                        .map(|transition| format!("{}/{}", transition.program_id(), transition.function_name()))
+                        .collect::<Vec<_>>();
+                    format!("execute {}", functions.join(", "))
+                }
+            };
+            writeln!(message, " • {transaction_id} ({kind}, fee {} gates)", transaction.fee()?)?;
+        }
+        Ok(message)
+    }
+
+    /// Returns the private key given on the command line, or the development private key of the manifest file.
+    pub(crate) fn private_key(key: Option<String>, path: Option<String>) -> Result<PrivateKey<Network>> {
+        match (key, path) {
+            (Some(_), Some(_)) => unreachable!("Clap prevents conflicting options from being enabled"),
+            (None, None) => bail!("Please specify either a private key or a manifest file"),
+            (Some(key), None) => PrivateKey::<Network>::from_str(&key),
+            (None, Some(path)) => {
+                // Instantiate a path to the directory containing the manifest file.
+                let directory = PathBuf::from_str(&path)?;
+                // Ensure the directory path exists.
+                ensure!(directory.exists(), "The program directory does not exist: {}", directory.display());
+                // Ensure the manifest file exists.
+                ensure!(
+                    Manifest::<Network>::exists_at(&directory),
+                    "Please ensure that the manifest file exists in the Aleo program directory (missing '{}' at '{}')",
+                    Manifest::<Network>::file_name(),
+                    directory.display()
+                );
+
+                // Open the manifest file.
+                let manifest = Manifest::open(&directory)?;
+
+                Ok(*manifest.development_private_key())
+            }
         }
     }
 }
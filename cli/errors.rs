@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
+// The errors shared with the node are defined by the core crate.
+pub use slingshot_core::errors::*;
+
 #[derive(Debug, Error)]
 pub enum UpdaterError {
     #[error("{}: {}", _0, _1)]
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::SlingshotError,
+    helpers::base_endpoint,
+    messages::{AccountRegisterRequest, AccountRegisterResponse, NodeRequest},
+    Network,
+};
+
+use snarkvm::prelude::{Address, PrivateKey};
+
+use anyhow::Result;
+use std::{collections::BTreeMap, path::PathBuf};
+
+/// The directory in which the account tokens registered with each node are cached.
+pub const ACCOUNTS_DIR: &str = ".slingshot/accounts";
+
+pub struct AccountTokens;
+
+impl AccountTokens {
+    /// Registers the account of the given private key with the node serving the given endpoint, and returns its
+    /// account token. The token is cached for each node, so that the private key is only sent the first time,
+    /// or again once the node no longer knows the cached token, e.g. after it restarted.
+    pub async fn register(
+        endpoint: &str,
+        private_key: PrivateKey<Network>,
+    ) -> Result<AccountRegisterResponse<Network>> {
+        let base = base_endpoint(endpoint);
+        let endpoint = format!("{base}/testnet3/accounts/register");
+        let address = Address::try_from(&private_key)?;
+
+        // Confirm the cached token of the account, which does not send the private key.
+        if let Some(token) = Self::cached(&base).remove(&address.to_string()) {
+            match AccountRegisterRequest::from_token(token).send(&endpoint).await {
+                Ok(registration) if *registration.address() == address => return Ok(registration),
+                Ok(_) => (),
+                Err(error) if is_unknown_token(&error) => (),
+                Err(error) => return Err(error),
+            }
+        }
+
+        let registration = AccountRegisterRequest::new(private_key).send(&endpoint).await?;
+        // Caching is best-effort, so a read-only directory does not fail the command.
+        if let Err(error) = Self::store(&base, address, registration.token()) {
+            warn!("Failed to cache the account token for {base}: {error}");
+        }
+        Ok(registration)
+    }
+
+    /// Returns the cached account tokens of the node at the given base endpoint, by account address.
+    fn cached(base: &str) -> BTreeMap<String, String> {
+        std::fs::read(Self::path(base))
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .unwrap_or_default()
+    }
+
+    /// Caches the given account token of the given account, for the node at the given base endpoint.
+    fn store(base: &str, address: Address<Network>, token: &str) -> Result<()> {
+        let mut tokens = Self::cached(base);
+        tokens.insert(address.to_string(), token.to_string());
+
+        let path = Self::path(base);
+        std::fs::create_dir_all(ACCOUNTS_DIR)?;
+        std::fs::write(&path, serde_json::to_vec_pretty(&tokens)?)?;
+        // The tokens sign requests on behalf of the accounts, so they are only readable by the user.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+        }
+        Ok(())
+    }
+
+    /// Returns the path of the cached account tokens of the node at the given base endpoint.
+    fn path(base: &str) -> PathBuf {
+        let name = base.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect::<String>();
+        PathBuf::from(ACCOUNTS_DIR).join(format!("{name}.json"))
+    }
+}
+
+/// Returns `true` if the given error is the rejection of an account token the node does not know.
+fn is_unknown_token(error: &anyhow::Error) -> bool {
+    matches!(error.downcast_ref::<SlingshotError>(), Some(SlingshotError::UnknownAccountToken))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        assert_eq!(
+            AccountTokens::path("http://localhost:4180"),
+            PathBuf::from(ACCOUNTS_DIR).join("http___localhost_4180.json")
+        );
+    }
+
+    #[test]
+    fn test_is_unknown_token() {
+        assert!(is_unknown_token(&SlingshotError::UnknownAccountToken.into()));
+        assert!(!is_unknown_token(&SlingshotError::Unauthorized.into()));
+        assert!(!is_unknown_token(&anyhow::anyhow!("connection refused")));
+    }
+}
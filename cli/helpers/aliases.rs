@@ -0,0 +1,142 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Network;
+
+use snarkvm::prelude::{Address, Value};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use indexmap::IndexMap;
+use std::{io::ErrorKind, path::Path, str::FromStr};
+
+/// The file in which the address book is stored.
+pub const ALIASES_FILE: &str = ".slingshot/aliases.json";
+
+/// The address book, whose aliases are accepted as `@alias` wherever a command expects an address.
+#[derive(Debug, Default)]
+pub struct AddressBook {
+    /// The map of aliases to addresses.
+    aliases: IndexMap<String, Address<Network>>,
+}
+
+impl AddressBook {
+    /// Loads the address book, which is empty if it does not exist yet.
+    pub fn load() -> Result<Self> {
+        let contents = match std::fs::read_to_string(ALIASES_FILE) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => bail!("Failed to read the address book '{ALIASES_FILE}': {error}"),
+        };
+        let aliases = serde_json::from_str(&contents)
+            .map_err(|error| anyhow!("Failed to parse the address book '{ALIASES_FILE}': {error}"))?;
+        Ok(Self { aliases })
+    }
+
+    /// Saves the address book.
+    pub fn save(&self) -> Result<()> {
+        if let Some(directory) = Path::new(ALIASES_FILE).parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+        std::fs::write(ALIASES_FILE, serde_json::to_string_pretty(&self.aliases)?)?;
+        Ok(())
+    }
+
+    /// Sets the address of the given alias, and returns its previous address, if any.
+    pub fn insert(&mut self, alias: &str, address: Address<Network>) -> Result<Option<Address<Network>>> {
+        ensure!(
+            !alias.is_empty() && alias.chars().all(is_alias_character),
+            "Invalid alias '{alias}' (an alias may only contain letters, digits, '_', and '-')"
+        );
+        Ok(self.aliases.insert(alias.to_string(), address))
+    }
+
+    /// Removes the given alias, and returns its address, if it exists.
+    pub fn remove(&mut self, alias: &str) -> Option<Address<Network>> {
+        self.aliases.shift_remove(alias)
+    }
+
+    /// Returns the aliases and their addresses, in the order they were added.
+    pub fn aliases(&self) -> impl '_ + Iterator<Item = (&String, &Address<Network>)> {
+        self.aliases.iter()
+    }
+
+    /// Replaces each `@alias` in the given text with the address of the alias.
+    pub fn resolve(&self, text: &str) -> Result<String> {
+        let mut result = String::new();
+        let mut rest = text;
+        while let Some(start) = rest.find('@') {
+            result.push_str(&rest[..start]);
+            rest = &rest[start + 1..];
+            let end = rest.find(|character| !is_alias_character(character)).unwrap_or(rest.len());
+            match &rest[..end] {
+                "" => result.push('@'),
+                alias => match self.aliases.get(alias) {
+                    Some(address) => result.push_str(&address.to_string()),
+                    None => bail!("Unknown alias '@{alias}' (add it with 'slingshot alias add {alias} <ADDRESS>')"),
+                },
+            }
+            rest = &rest[end..];
+        }
+        result.push_str(rest);
+        Ok(result)
+    }
+}
+
+/// Returns `true` if the given character may appear in an alias.
+fn is_alias_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '_' || character == '-'
+}
+
+/// Replaces each `@alias` in the given text with the address of the alias.
+/// The address book is only loaded if the text references an alias.
+pub fn resolve_aliases(text: &str) -> Result<String> {
+    match text.contains('@') {
+        true => AddressBook::load()?.resolve(text),
+        false => Ok(text.to_string()),
+    }
+}
+
+/// Parses an address, or an alias written as `@alias`.
+pub fn parse_address(text: &str) -> Result<Address<Network>> {
+    Address::from_str(&resolve_aliases(text)?)
+}
+
+/// Parses a value, in which each `@alias` is replaced with the address of the alias.
+pub fn parse_value(text: &str) -> Result<Value<Network>> {
+    Value::from_str(&resolve_aliases(text)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::prelude::PrivateKey;
+
+    #[test]
+    fn test_resolve() {
+        let address = Address::try_from(PrivateKey::<Network>::new(&mut rand::thread_rng()).unwrap()).unwrap();
+        let mut book = AddressBook::default();
+        book.insert("alice", address).unwrap();
+        assert!(book.insert("bob smith", address).is_err());
+
+        assert_eq!(book.resolve("@alice").unwrap(), address.to_string());
+        assert_eq!(
+            book.resolve("{ owner: @alice, amount: 5u64 }").unwrap(),
+            format!("{{ owner: {address}, amount: 5u64 }}")
+        );
+        assert_eq!(book.resolve("5u64 @ 2").unwrap(), "5u64 @ 2");
+        assert!(book.resolve("@bob").is_err());
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::{anyhow, ensure, Result};
+
+/// The number of gates in one credit.
+pub const GATES_PER_CREDIT: u64 = 1_000_000;
+
+/// Parses an amount passed on the command line into a number of gates.
+/// The amount is a number of gates, or a number of credits with the `credits` suffix (e.g. `5credits`).
+/// Underscores may be used as separators (e.g. `1_000_000gates`).
+pub fn parse_gates(amount: &str) -> Result<u64> {
+    let (number, multiplier) = match amount.trim() {
+        amount if amount.ends_with("credits") => (amount.trim_end_matches("credits"), GATES_PER_CREDIT),
+        amount => (amount.trim_end_matches("gates"), 1),
+    };
+    let number = number.replace('_', "");
+    ensure!(!number.is_empty(), "Invalid amount '{amount}', expected a number of gates or credits");
+    number
+        .parse::<u64>()
+        .ok()
+        .and_then(|number| number.checked_mul(multiplier))
+        .ok_or_else(|| anyhow!("Invalid amount '{amount}', expected a number of gates or credits (e.g. '5credits')"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gates() {
+        assert_eq!(parse_gates("100").unwrap(), 100);
+        assert_eq!(parse_gates("1_000gates").unwrap(), 1_000);
+        assert_eq!(parse_gates("5credits").unwrap(), 5 * GATES_PER_CREDIT);
+        assert!(parse_gates("credits").is_err());
+        assert!(parse_gates("1.5credits").is_err());
+        assert!(parse_gates(&format!("{}credits", u64::MAX)).is_err());
+    }
+}
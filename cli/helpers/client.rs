@@ -0,0 +1,97 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{base_endpoint, API_TOKEN_ENV},
+    node::DEFAULT_PROVING_TIMEOUT_IN_SECS,
+    Network,
+};
+
+use slingshot_client::{Client, RetryPolicy};
+use snarkvm::prelude::Address;
+
+use anyhow::Result;
+use parking_lot::{const_mutex, Mutex};
+use serde::{de::DeserializeOwned, Serialize};
+use std::time::Duration;
+use tokio::runtime::{self, Runtime};
+
+/// The default time after which a request to a node times out, which exceeds the proving timeout of the node,
+/// so that a node that is still proving replies with `504 Gateway Timeout` before the request times out.
+pub const DEFAULT_HTTP_TIMEOUT_IN_SECS: u64 = DEFAULT_PROVING_TIMEOUT_IN_SECS + 60;
+/// The default number of times a request the node did not process is retried.
+pub const DEFAULT_HTTP_RETRIES: u32 = 3;
+
+/// The timeout and the number of retries of the requests to a node, and the address of the node account that must
+/// have signed the responses, if they are verified.
+static SETTINGS: Mutex<(u64, u32, Option<Address<Network>>)> =
+    const_mutex((DEFAULT_HTTP_TIMEOUT_IN_SECS, DEFAULT_HTTP_RETRIES, None));
+/// The clients of the nodes contacted so far, which keep their connections open for the next request.
+static CLIENTS: Mutex<Vec<Client<Network>>> = const_mutex(Vec::new());
+
+/// Returns the runtime that runs the commands, and the node started by `slingshot node start`.
+pub fn runtime() -> Result<Runtime> {
+    // TODO: This should be supplied by a config file. Think infrastruct as code tool.
+    let (num_tokio_worker_threads, max_tokio_blocking_threads) = (1, 512);
+
+    // Initialize the runtime configuration.
+    Ok(runtime::Builder::new_multi_thread()
+        .enable_all()
+        .thread_stack_size(8 * 1024 * 1024)
+        .worker_threads(num_tokio_worker_threads)
+        .max_blocking_threads(max_tokio_blocking_threads)
+        .build()?)
+}
+
+/// Sets the timeout, in seconds, and the number of retries of the requests to a node, and the address of the node
+/// account that must have signed the responses, if they are verified.
+pub fn configure_node_clients(timeout_in_secs: u64, max_retries: u32, node_address: Option<Address<Network>>) {
+    *SETTINGS.lock() = (timeout_in_secs, max_retries, node_address);
+    CLIENTS.lock().clear();
+}
+
+/// Returns the address of the node account that must have signed the responses, if they are verified.
+pub fn node_address() -> Option<Address<Network>> {
+    SETTINGS.lock().2
+}
+
+/// Returns a client of the node at the given base endpoint, which carries the API token in `SLINGSHOT_API_TOKEN`,
+/// if it is set. The client is reused for each request to the same node, so that its connections are reused.
+pub fn node_client(base: &str) -> Client<Network> {
+    let mut clients = CLIENTS.lock();
+    if let Some(client) = clients.iter().find(|client| client.base() == base.trim_end_matches('/')) {
+        return client.clone();
+    }
+    let (timeout_in_secs, max_retries, node_address) = *SETTINGS.lock();
+    let mut client = Client::new(base)
+        .with_timeout(Duration::from_secs(timeout_in_secs))
+        .with_retry(RetryPolicy { max_retries, ..Default::default() });
+    if let Ok(api_token) = std::env::var(API_TOKEN_ENV) {
+        client = client.with_api_token(api_token);
+    }
+    if let Some(node_address) = node_address {
+        client = client.with_node_address(node_address);
+    }
+    clients.push(client.clone());
+    client
+}
+
+/// Sends the given body to the given endpoint, e.g. `http://localhost:4180/testnet3/program/execute`,
+/// and returns the response.
+pub async fn post_to<B: Serialize + ?Sized, R: DeserializeOwned>(endpoint: &str, body: &B) -> Result<R> {
+    let base = base_endpoint(endpoint);
+    node_client(&base).post(&endpoint[base.len()..], body).await
+}
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{base_endpoint, Context},
+    messages::{BuildInfo, HandshakeRequest, HandshakeResponse, NodeRequest},
+    output,
+    Network,
+};
+
+use slingshot_client::ClientError;
+
+use anyhow::{bail, Result};
+use parking_lot::{const_mutex, Mutex};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set if an incompatibility between the CLI and a node is an error, rather than a warning.
+static STRICT: AtomicBool = AtomicBool::new(false);
+
+/// The base endpoints of the nodes the CLI has already checked.
+static CHECKED: Mutex<Vec<String>> = const_mutex(Vec::new());
+
+pub struct Compatibility;
+
+impl Compatibility {
+    /// Enables or disables strict mode.
+    pub fn set_strict(strict: bool) {
+        STRICT.store(strict, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if the CLI is in strict mode.
+    pub fn is_strict() -> bool {
+        STRICT.load(Ordering::SeqCst)
+    }
+
+    /// Sends the build of the CLI to the node at the given base endpoint, and returns the node's response.
+    pub async fn handshake(base: &str) -> Result<HandshakeResponse> {
+        HandshakeRequest::new(BuildInfo::current::<Network>()).send(&format!("{base}/testnet3/node/handshake")).await
+    }
+
+    /// Performs the handshake with the node serving the given endpoint, once per node.
+    /// An incompatibility is printed as a warning, or returned as an error in strict mode.
+    /// If the node is unreachable, the check is skipped, so that the request itself reports the error.
+    pub async fn check(endpoint: &str) -> Result<()> {
+        let base = base_endpoint(endpoint);
+        if Context::is_offline() || CHECKED.lock().contains(&base) {
+            return Ok(());
+        }
+
+        let incompatibilities = match Self::handshake(&base).await {
+            Ok(response) => BuildInfo::incompatibilities(&BuildInfo::current::<Network>(), response.node()),
+            // A node without the handshake route predates it, and may run an older version of the messages.
+            Err(error) if error.downcast_ref::<ClientError>().and_then(ClientError::status_code) == Some(404) => {
+                vec![String::from(
+                    "The node does not support the handshake, so it may run an older version of slingshot",
+                )]
+            }
+            Err(_) => return Ok(()),
+        };
+        CHECKED.lock().push(base.clone());
+
+        if !incompatibilities.is_empty() {
+            let mut message = format!("The CLI may be incompatible with the node at {base}:\n");
+            for incompatibility in incompatibilities {
+                message.push_str(&format!(" • {incompatibility}\n"));
+            }
+            message.push_str("Run 'slingshot node version' for the full report.");
+            match Self::is_strict() {
+                true => bail!("{message}"),
+                false => output!("⚠️  {message}\n"),
+            }
+        }
+        Ok(())
+    }
+}
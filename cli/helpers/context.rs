@@ -0,0 +1,141 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{base_endpoint, node_address, node_client, ResponseSignature},
+    node::NodeInfo,
+    Network,
+};
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// The directory in which the chain context of each node is cached.
+pub const CONTEXT_DIR: &str = ".slingshot/context";
+/// The number of seconds for which a cached chain context is used without re-fetching it.
+pub const CONTEXT_TTL_IN_SECS: u64 = 10;
+
+/// Set if the CLI must not contact the node, and reads the chain context from the cache instead.
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// A chain context, and the time it was fetched at.
+#[derive(Deserialize, Serialize)]
+struct CachedContext {
+    /// The UNIX timestamp at which the context was fetched.
+    fetched_at: u64,
+    /// The response body of the chain context, which is verified again when it is read, if responses are verified.
+    body: String,
+    /// The signature of the response by the node, which is empty if the node does not sign its responses.
+    signature: ResponseSignature,
+}
+
+pub struct Context;
+
+impl Context {
+    /// Enables or disables offline mode.
+    pub fn set_offline(offline: bool) {
+        OFFLINE.store(offline, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if the CLI is in offline mode.
+    pub fn is_offline() -> bool {
+        OFFLINE.load(Ordering::SeqCst)
+    }
+
+    /// Returns the chain context of the node serving the given endpoint, and its age.
+    /// A cached context younger than the TTL is reused; in offline mode, the cached context is used regardless of age.
+    pub async fn fetch(endpoint: &str) -> Result<(NodeInfo<Network>, Duration)> {
+        let base = base_endpoint(endpoint);
+        let cached = Self::cached(&base)?;
+
+        match cached {
+            Some((info, age)) if Self::is_offline() || age.as_secs() < CONTEXT_TTL_IN_SECS => Ok((info, age)),
+            _ if Self::is_offline() => Err(anyhow!(
+                "No chain context is cached for {base} — run the command without '--offline' while the node is running"
+            )),
+            _ => {
+                let (body, signature) = node_client(&base).get_signed("/testnet3/node/info").await?;
+                let info: NodeInfo<Network> = serde_json::from_str(&body)?;
+                // Caching is best-effort, so a read-only directory does not fail the command.
+                if let Err(error) = Self::store(&base, body, signature) {
+                    warn!("Failed to cache the chain context of {base}: {error}");
+                }
+                Ok((info, Duration::ZERO))
+            }
+        }
+    }
+
+    /// Removes the cached chain context of the node serving the given endpoint, e.g. after a deployment.
+    pub fn invalidate(endpoint: &str) {
+        let _ = std::fs::remove_file(Self::path(&base_endpoint(endpoint)));
+    }
+
+    /// Returns the cached chain context of the node at the given base endpoint, and its age, if it is cached.
+    /// Returns an error if responses are verified, and the cached context was not signed by the node.
+    fn cached(base: &str) -> Result<Option<(NodeInfo<Network>, Duration)>> {
+        let bytes = match std::fs::read(Self::path(base)) {
+            Ok(bytes) => bytes,
+            Err(_) => return Ok(None),
+        };
+        let CachedContext { fetched_at, body, signature } = match serde_json::from_slice(&bytes) {
+            Ok(cached) => cached,
+            Err(_) => return Ok(None),
+        };
+        signature.verify(node_address().as_ref(), body.as_bytes())?;
+        let info = match serde_json::from_str(&body) {
+            Ok(info) => info,
+            Err(_) => return Ok(None),
+        };
+        Ok(Some((info, Duration::from_secs(now().saturating_sub(fetched_at)))))
+    }
+
+    /// Caches the chain context of the node at the given base endpoint, as the given response body and signature.
+    fn store(base: &str, body: String, signature: ResponseSignature) -> Result<()> {
+        let path = Self::path(base);
+        std::fs::create_dir_all(CONTEXT_DIR)?;
+        std::fs::write(path, serde_json::to_vec(&CachedContext { fetched_at: now(), body, signature })?)?;
+        Ok(())
+    }
+
+    /// Returns the path of the cached chain context of the node at the given base endpoint.
+    fn path(base: &str) -> PathBuf {
+        let name = base.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect::<String>();
+        PathBuf::from(CONTEXT_DIR).join(format!("{name}.json"))
+    }
+}
+
+/// Returns the current UNIX timestamp, in seconds.
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        assert_eq!(
+            Context::path("http://localhost:4180"),
+            PathBuf::from(CONTEXT_DIR).join("http___localhost_4180.json")
+        );
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::helpers::node_client;
+
+use slingshot_client::RetryPolicy;
+
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::ErrorKind,
+    path::PathBuf,
+    process::{Command, Stdio},
+    time::{Duration, Instant},
+};
+
+/// The directory under the home directory in which the state and the log of the daemon are stored.
+pub const DAEMON_DIR: &str = ".slingshot";
+
+/// The number of seconds to wait for the daemon to serve requests after it is started.
+const DAEMON_START_TIMEOUT_IN_SECS: u64 = 60;
+
+/// The number of milliseconds between checks of the state of the daemon.
+const DAEMON_POLL_INTERVAL_IN_MS: u64 = 250;
+
+/// A development node running in the background, as started by `slingshot node start --daemon`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Daemon {
+    /// The process ID of the node.
+    pub pid: u32,
+    /// The base endpoint of the REST server of the node.
+    pub endpoint: String,
+    /// The arguments of `slingshot node start`, without `--daemon`, so that the node can be restarted.
+    pub args: Vec<String>,
+    /// The working directory of the node, against which the relative paths in its arguments are resolved.
+    pub working_directory: PathBuf,
+    /// The file the output of the node is written to.
+    pub log_file: PathBuf,
+}
+
+impl Daemon {
+    /// Returns the directory in which the state and the log of the daemon are stored.
+    fn directory() -> Result<PathBuf> {
+        let home = std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .ok_or_else(|| anyhow!("Failed to locate the home directory (HOME is not set)"))?;
+        Ok(PathBuf::from(home).join(DAEMON_DIR))
+    }
+
+    /// Returns the path of the file holding the state of the daemon.
+    fn state_file() -> Result<PathBuf> {
+        Ok(Self::directory()?.join("node.json"))
+    }
+
+    /// Loads the state of the daemon, if one was started.
+    pub fn load() -> Result<Option<Self>> {
+        let path = Self::state_file()?;
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(error) => bail!("Failed to read '{}': {error}", path.display()),
+        };
+        let daemon = serde_json::from_str(&contents)
+            .map_err(|error| anyhow!("Failed to parse '{}': {error}", path.display()))?;
+        Ok(Some(daemon))
+    }
+
+    /// Starts `slingshot node start` with the given arguments in the given working directory in the background,
+    /// records its state, and waits until its REST server at the given base endpoint serves requests.
+    pub async fn start(args: Vec<String>, endpoint: String, working_directory: PathBuf) -> Result<Self> {
+        // Ensure only one daemon runs at a time.
+        if let Some(daemon) = Self::load()? {
+            if daemon.is_running() {
+                bail!("A node is already running in the background (PID {}) — run 'slingshot node stop'", daemon.pid);
+            }
+        }
+
+        // Start the node, with its output written to the log file.
+        let directory = Self::directory()?;
+        std::fs::create_dir_all(&directory)?;
+        let log_file = directory.join("node.log");
+        let log = File::create(&log_file)?;
+        let mut command = Command::new(std::env::current_exe()?);
+        command.args(&args).current_dir(&working_directory).stdin(Stdio::null()).stdout(log.try_clone()?).stderr(log);
+        // Detach the node from the process group of the shell, so that Ctrl-C in the shell does not stop it.
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut command, 0);
+        let mut child = command.spawn().map_err(|error| anyhow!("Failed to start the node: {error}"))?;
+
+        // Record the state of the daemon.
+        let daemon = Self { pid: child.id(), endpoint, args, working_directory, log_file };
+        std::fs::write(Self::state_file()?, serde_json::to_string_pretty(&daemon)?)?;
+
+        // Wait until the node serves requests, or exits.
+        let start = Instant::now();
+        loop {
+            if let Some(status) = child.try_wait()? {
+                daemon.remove()?;
+                bail!("The node exited with {status} — see '{}'", daemon.log_file.display());
+            }
+            let client = node_client(&daemon.endpoint).with_retry(RetryPolicy::none());
+            if client.latest_height().await.is_ok() {
+                return Ok(daemon);
+            }
+            if start.elapsed() > Duration::from_secs(DAEMON_START_TIMEOUT_IN_SECS) {
+                bail!(
+                    "The node (PID {}) did not serve requests within {DAEMON_START_TIMEOUT_IN_SECS} seconds — see '{}'",
+                    daemon.pid,
+                    daemon.log_file.display()
+                );
+            }
+            tokio::time::sleep(Duration::from_millis(DAEMON_POLL_INTERVAL_IN_MS)).await;
+        }
+    }
+
+    /// Stops the daemon, which shuts down as on Ctrl-C, and is killed if it does not exit within the given time.
+    pub fn stop(&self, timeout: Duration) -> Result<()> {
+        if self.is_running() {
+            self.signal(false)?;
+            let start = Instant::now();
+            while self.is_running() {
+                if start.elapsed() > timeout {
+                    warn!("The node (PID {}) did not shut down in time, and is killed", self.pid);
+                    self.signal(true)?;
+                    break;
+                }
+                std::thread::sleep(Duration::from_millis(DAEMON_POLL_INTERVAL_IN_MS));
+            }
+        }
+        self.remove()
+    }
+
+    /// Returns `true` if the process of the daemon is running.
+    pub fn is_running(&self) -> bool {
+        let pid = self.pid.to_string();
+        #[cfg(unix)]
+        let running =
+            Command::new("kill").args(["-0", &pid]).stderr(Stdio::null()).status().map(|status| status.success());
+        #[cfg(windows)]
+        let running = Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {pid}"), "/NH"])
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).contains(&pid));
+        running.unwrap_or(false)
+    }
+
+    /// Sends the interrupt signal to the daemon, or kills it, if requested.
+    fn signal(&self, kill: bool) -> Result<()> {
+        let pid = self.pid.to_string();
+        #[cfg(unix)]
+        let status = Command::new("kill").args([if kill { "-KILL" } else { "-INT" }, &pid]).status()?;
+        #[cfg(windows)]
+        let status = match kill {
+            true => Command::new("taskkill").args(["/F", "/PID", &pid]).status()?,
+            false => Command::new("taskkill").args(["/PID", &pid]).status()?,
+        };
+        match status.success() {
+            true => Ok(()),
+            false => bail!("Failed to signal the node (PID {pid})"),
+        }
+    }
+
+    /// Removes the state of the daemon.
+    fn remove(&self) -> Result<()> {
+        match std::fs::remove_file(Self::state_file()?) {
+            Err(error) if error.kind() != ErrorKind::NotFound => Err(error.into()),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::node_client,
+    messages::{RecordStatsRequest, RecordViewRequest},
+    Network,
+};
+
+use snarkvm::prelude::{Address, Block, Program, ProgramID, Transaction, ViewKey};
+
+use anyhow::{bail, Result};
+use std::time::Duration;
+
+/// Fetches the program with the given ID from the node at the given base endpoint.
+pub async fn fetch_program(base: &str, program_id: &ProgramID<Network>) -> Result<Program<Network>> {
+    node_client(base).program(program_id).await
+}
+
+/// Fetches the block with the given height or hash from the node at the given base endpoint.
+pub async fn fetch_block(base: &str, height_or_hash: &str) -> Result<Block<Network>> {
+    let client = node_client(base);
+    match height_or_hash.parse::<u32>() {
+        Ok(height) => client.block(height).await,
+        Err(_) => client.block_by_hash(&height_or_hash.parse()?).await,
+    }
+}
+
+/// Checks the given transaction against the ledger state and the memory pool of the node at the given base endpoint,
+/// without broadcasting it. Returns the reason the node would reject the transaction, if any.
+pub async fn verify_transaction(base: &str, transaction: &Transaction<Network>) -> Result<Option<String>> {
+    Ok(node_client(base).verify(transaction).await?.error)
+}
+
+/// Ensures the account of the given view key has a record that can pay the given fee, by asking the node at the given
+/// base endpoint for the statistics of its unspent records. This fails fast, before the transaction is proven.
+pub async fn ensure_funded(base: &str, view_key: &ViewKey<Network>, fee: u64) -> Result<()> {
+    let address = Address::try_from(view_key)?;
+    let stats = RecordViewRequest::new(*view_key).send_stats(&format!("{base}/testnet3/records/stats")).await?;
+    if stats.num_records() == 0 {
+        bail!("Account {address} is unfunded — run `slingshot pour {address} <AMOUNT>`");
+    }
+    if stats.spendable() < fee {
+        bail!(
+            "Account {address} has no record with {fee} gates to pay the fee (its largest record has {} gates) — \
+             run `slingshot pour {address} <AMOUNT>`, or join its records",
+            stats.spendable()
+        );
+    }
+    Ok(())
+}
+
+/// Waits until the transaction with the given ID is included in a block, polling the node at the given base endpoint.
+/// Returns the confirmed transaction, or an error if it is not confirmed within the given timeout.
+pub async fn wait_for_transaction(
+    base: &str,
+    transaction_id: &<Network as snarkvm::prelude::Network>::TransactionID,
+    timeout: Duration,
+) -> Result<Transaction<Network>> {
+    node_client(base).wait_for_transaction(transaction_id, timeout).await
+}
@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::{NodeError, SlingshotError};
+
+use slingshot_client::ClientError;
+
+/// The exit codes of the CLI. Scripts may branch on them, so they are stable across releases.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExitCode {
+    /// The command succeeded.
+    Success = 0,
+    /// The command is invalid, e.g. a missing file or a malformed input. Note that `clap` also exits with 2.
+    UserError = 2,
+    /// The node could not be reached, or its response failed signature verification.
+    NetworkError = 3,
+    /// The node rejected the request.
+    NodeRejection = 4,
+    /// The command timed out, e.g. while waiting for a transaction to be confirmed.
+    Timeout = 5,
+}
+
+impl ExitCode {
+    /// Returns the exit code for the given error, from the first error in its chain that is classified.
+    pub fn of(error: &anyhow::Error) -> Self {
+        for cause in error.chain() {
+            if let Some(error) = cause.downcast_ref::<NodeError>() {
+                return match error {
+                    NodeError::Rejected(_) => Self::NodeRejection,
+                    NodeError::Unreachable(_) | NodeError::InvalidSignature(_) => Self::NetworkError,
+                    NodeError::Timeout(_) => Self::Timeout,
+                };
+            }
+            if cause.is::<SlingshotError>() {
+                return Self::NodeRejection;
+            }
+            if let Some(error) = cause.downcast_ref::<ClientError>() {
+                return match error {
+                    ClientError::Status { .. } => Self::NodeRejection,
+                    ClientError::Transport(_) => Self::NetworkError,
+                };
+            }
+        }
+        Self::UserError
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::anyhow;
+
+    #[test]
+    fn test_exit_code() {
+        assert_eq!(ExitCode::of(&anyhow!("The program directory does not exist")), ExitCode::UserError);
+        assert_eq!(ExitCode::of(&NodeError::Rejected("invalid".to_string()).into()), ExitCode::NodeRejection);
+        assert_eq!(ExitCode::of(&NodeError::Unreachable("refused".to_string()).into()), ExitCode::NetworkError);
+        assert_eq!(ExitCode::of(&SlingshotError::MempoolFull { capacity: 1 }.into()), ExitCode::NodeRejection);
+        let error = ClientError::Status { code: 500, body: "internal error".to_string() };
+        assert_eq!(ExitCode::of(&error.into()), ExitCode::NodeRejection);
+
+        // The exit code is determined by the cause of the error, under any context.
+        let error = anyhow::Error::from(NodeError::Timeout("not confirmed".to_string())).context("Step 1 failed");
+        assert_eq!(ExitCode::of(&error), ExitCode::Timeout);
+    }
+}
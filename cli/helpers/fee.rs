@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{messages::RecordSource, node::WalletFile, Network};
+
+use snarkvm::prelude::Field;
+
+use anyhow::{anyhow, Error, Result};
+use core::str::FromStr;
+use std::path::Path;
+
+/// A fee passed on the command line: a number of gates, or `auto` to use the node's fee estimate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeOption {
+    /// Estimates the minimum fee that covers the storage of the transaction.
+    Auto,
+    /// The given number of gates.
+    Gates(u64),
+}
+
+impl FromStr for FeeOption {
+    type Err = Error;
+
+    /// Parses a fee from `auto` or a number of gates.
+    fn from_str(fee: &str) -> Result<Self> {
+        match fee {
+            "auto" => Ok(Self::Auto),
+            gates => gates
+                .parse()
+                .map(Self::Gates)
+                .map_err(|_| anyhow!("Invalid fee '{fee}', expected a number of gates or 'auto'")),
+        }
+    }
+}
+
+/// Returns the source of the record that pays the fee of a request: the records of the given wallet file,
+/// the records with the given commitments, or the faucet. Returns `None` to let the node scan for the record.
+pub fn fee_record_source(
+    wallet: Option<&Path>,
+    fee_records: &[Field<Network>],
+    auto_fund: bool,
+) -> Result<Option<RecordSource<Network>>> {
+    Ok(match (wallet, fee_records, auto_fund) {
+        (Some(wallet), _, _) => Some(RecordSource::Wallet { records: WalletFile::open(wallet)?.records().clone() }),
+        (None, [], false) => None,
+        (None, [], true) => Some(RecordSource::Faucet),
+        (None, commitments, _) => Some(RecordSource::Commitments { commitments: commitments.to_vec() }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_option_from_str() {
+        assert_eq!(FeeOption::from_str("auto").unwrap(), FeeOption::Auto);
+        assert_eq!(FeeOption::from_str("100").unwrap(), FeeOption::Gates(100));
+        assert!(FeeOption::from_str("-1").is_err());
+        assert!(FeeOption::from_str("automatic").is_err());
+    }
+}
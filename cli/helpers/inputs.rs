@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{helpers::parse_value, node::matches_plaintext_type, output, Network};
+
+use snarkvm::prelude::{Field, Identifier, LiteralType, Plaintext, PlaintextType, Program, Record, Value, ValueType};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use indexmap::IndexMap;
+use serde::Deserialize;
+use std::{future::Future, io::Write, path::Path};
+
+/// Returns an inputs template for the given function, mapping each input register to a placeholder value.
+pub fn inputs_template(
+    program: &Program<Network>,
+    function_name: &Identifier<Network>,
+) -> Result<IndexMap<String, String>> {
+    let function = program.get_function(function_name)?;
+    function
+        .inputs()
+        .iter()
+        .map(|input| Ok((input.register().to_string(), value_placeholder(program, input.value_type())?)))
+        .collect()
+}
+
+/// An input in an inputs file: a value, or an unspent record of the account, referenced by its commitment.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum InputEntry {
+    Value(String),
+    Record { commitment: Field<Network> },
+}
+
+/// Reads the inputs file at the given path, returning the inputs of the given function in the order of its signature.
+/// The file maps each input, by its register (e.g. `r0`) or its position (e.g. `0`), to its value, or to
+/// `{ "commitment": "..." }` for an unspent record of the account, which is looked up in the given unspent records.
+/// The unspent records are only fetched if a record is referenced.
+pub async fn read_inputs_file<F>(
+    path: &Path,
+    program: &Program<Network>,
+    function_name: &Identifier<Network>,
+    unspent_records: impl FnOnce() -> F,
+) -> Result<Vec<Value<Network>>>
+where
+    F: Future<Output = Result<IndexMap<Field<Network>, Record<Network, Plaintext<Network>>>>>,
+{
+    let contents = std::fs::read_to_string(path)
+        .map_err(|error| anyhow!("Failed to read the inputs file '{}': {error}", path.display()))?;
+    let entries: IndexMap<String, InputEntry> = serde_json::from_str(&contents)
+        .map_err(|error| anyhow!("Failed to parse the inputs file '{}': {error}", path.display()))?;
+
+    // Place each entry at the position of the input it names.
+    let registers = program
+        .get_function(function_name)?
+        .inputs()
+        .iter()
+        .map(|input| input.register().to_string())
+        .collect::<Vec<_>>();
+    let mut slots = registers.iter().map(|_| None).collect::<Vec<_>>();
+    for (name, entry) in entries {
+        let index = match name.parse::<usize>() {
+            Ok(index) => index,
+            Err(_) => registers.iter().position(|register| *register == name).ok_or_else(|| {
+                anyhow!("'{}/{function_name}' has no input '{name}' (expected {})", program.id(), registers.join(", "))
+            })?,
+        };
+        ensure!(
+            index < registers.len(),
+            "'{}/{function_name}' has {} inputs, so it has no input at position {index}",
+            program.id(),
+            registers.len()
+        );
+        ensure!(slots[index].is_none(), "Input '{}' is given more than once", registers[index]);
+        slots[index] = Some(entry);
+    }
+
+    // Parse each value, and look up each record by its commitment.
+    let mut unspent_records = Some(unspent_records);
+    let mut records = None;
+    let mut inputs = Vec::with_capacity(slots.len());
+    for (register, slot) in registers.iter().zip(slots) {
+        match slot.ok_or_else(|| anyhow!("Missing input '{register}' in the inputs file '{}'", path.display()))? {
+            InputEntry::Value(value) => inputs
+                .push(parse_value(&value).map_err(|error| anyhow!("Invalid value for input '{register}': {error}"))?),
+            InputEntry::Record { commitment } => {
+                if let Some(unspent_records) = unspent_records.take() {
+                    records = Some(unspent_records().await?);
+                }
+                let record = records.as_ref().and_then(|records| records.get(&commitment)).ok_or_else(|| {
+                    anyhow!(
+                        "Input '{register}' refers to '{commitment}', which is not an unspent record of the account"
+                    )
+                })?;
+                inputs.push(Value::Record(record.clone()));
+            }
+        }
+    }
+    Ok(inputs)
+}
+
+/// Prompts on the terminal for each input of the given function, with its expected type, and returns the inputs.
+/// An input that does not parse, or does not match its type, is prompted for again. A literal may omit its type,
+/// e.g. `5` for a `u64` input.
+pub fn prompt_inputs(program: &Program<Network>, function_name: &Identifier<Network>) -> Result<Vec<Value<Network>>> {
+    let function = program.get_function(function_name)?;
+    let mut inputs = Vec::with_capacity(function.inputs().len());
+    for input in function.inputs() {
+        let placeholder = value_placeholder(program, input.value_type())?;
+        loop {
+            print!("{} ({}, e.g. {placeholder}): ", input.register(), input.value_type());
+            std::io::stdout().flush()?;
+            let mut line = String::new();
+            if std::io::stdin().read_line(&mut line)? == 0 {
+                bail!("The input ended before every input of '{}/{function_name}' was given", program.id());
+            }
+            match parse_input(program, line.trim(), input.value_type()) {
+                Ok(value) => {
+                    inputs.push(value);
+                    break;
+                }
+                Err(error) => output!("⚠️  {error}"),
+            }
+        }
+    }
+    Ok(inputs)
+}
+
+/// Parses the given text as a value of the given type, appending the type of a literal if it is omitted.
+fn parse_input(program: &Program<Network>, text: &str, value_type: &ValueType<Network>) -> Result<Value<Network>> {
+    let value = match (parse_value(text), value_type) {
+        (Ok(value), _) => value,
+        (
+            Err(error),
+            ValueType::Constant(PlaintextType::Literal(literal_type))
+            | ValueType::Public(PlaintextType::Literal(literal_type))
+            | ValueType::Private(PlaintextType::Literal(literal_type)),
+        ) => parse_value(&format!("{text}{literal_type}")).map_err(|_| error)?,
+        (Err(error), _) => return Err(error),
+    };
+    let is_match = match (&value, value_type) {
+        (
+            Value::Plaintext(plaintext),
+            ValueType::Constant(plaintext_type)
+            | ValueType::Public(plaintext_type)
+            | ValueType::Private(plaintext_type),
+        ) => matches_plaintext_type(program, plaintext, plaintext_type),
+        (Value::Record(_), ValueType::Record(_) | ValueType::ExternalRecord(_)) => true,
+        _ => false,
+    };
+    match is_match {
+        true => Ok(value),
+        false => bail!("Expected a value of type '{value_type}', found '{value}'"),
+    }
+}
+
+/// Returns a placeholder for the given value type.
+fn value_placeholder(program: &Program<Network>, value_type: &ValueType<Network>) -> Result<String> {
+    match value_type {
+        ValueType::Constant(plaintext_type)
+        | ValueType::Public(plaintext_type)
+        | ValueType::Private(plaintext_type) => plaintext_placeholder(program, plaintext_type),
+        ValueType::Record(record_name) => Ok(format!("<{record_name} record plaintext>")),
+        ValueType::ExternalRecord(locator) => Ok(format!("<{locator} record plaintext>")),
+    }
+}
+
+/// Returns a placeholder for the given plaintext type.
+fn plaintext_placeholder(program: &Program<Network>, plaintext_type: &PlaintextType<Network>) -> Result<String> {
+    match plaintext_type {
+        PlaintextType::Literal(literal_type) => Ok(literal_placeholder(literal_type)),
+        PlaintextType::Interface(interface_name) => {
+            let members = program
+                .get_interface(interface_name)?
+                .members()
+                .iter()
+                .map(|(member, member_type)| Ok(format!("{member}: {}", plaintext_placeholder(program, member_type)?)))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(format!("{{ {} }}", members.join(", ")))
+        }
+    }
+}
+
+/// Returns a placeholder for the given literal type.
+fn literal_placeholder(literal_type: &LiteralType) -> String {
+    match literal_type {
+        LiteralType::Address => "aleo1...".to_string(),
+        LiteralType::Boolean => "false".to_string(),
+        LiteralType::Signature => "sign1...".to_string(),
+        LiteralType::String => "\"\"".to_string(),
+        literal_type => format!("0{literal_type}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::runtime;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_read_inputs_file() {
+        let program = Program::<Network>::from_str(
+            "program inputs.aleo;\n\nfunction main:\n    input r0 as u64.private;\n    \
+             input r1 as boolean.public;\n    output r0 as u64.private;\n",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("main").unwrap();
+        let path = std::env::temp_dir().join(format!("slingshot-inputs-{}.json", std::process::id()));
+        let runtime = runtime().unwrap();
+        let read = |contents: &str| {
+            std::fs::write(&path, contents).unwrap();
+            runtime.block_on(read_inputs_file(&path, &program, &function_name, || async { Ok(IndexMap::new()) }))
+        };
+
+        // The inputs are named by register or position, in any order.
+        let inputs = read(r#"{ "1": "true", "r0": "5u64" }"#).unwrap();
+        assert_eq!(inputs, vec![parse_value("5u64").unwrap(), parse_value("true").unwrap()]);
+
+        // Unknown, repeated, missing, and unspent record inputs are rejected.
+        assert!(read(r#"{ "r0": "5u64", "r1": "true", "r2": "1u8" }"#).is_err());
+        assert!(read(r#"{ "r0": "5u64", "0": "6u64", "r1": "true" }"#).is_err());
+        assert!(read(r#"{ "r0": "5u64" }"#).is_err());
+        assert!(read(r#"{ "r0": { "commitment": "1field" }, "r1": "true" }"#).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -14,5 +14,44 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
+// The helpers shared with the node are defined by the core crate.
+pub use slingshot_core::helpers::*;
+
+pub mod accounts;
+pub use accounts::*;
+
+pub mod aliases;
+pub use aliases::*;
+
+pub mod amount;
+pub use amount::*;
+
+pub mod client;
+pub use client::*;
+
+pub mod compatibility;
+pub use compatibility::*;
+
+pub mod context;
+pub use context::*;
+
+pub mod daemon;
+pub use daemon::*;
+
+pub mod endpoint;
+pub use endpoint::*;
+
+pub mod exit;
+pub use exit::*;
+
+pub mod fee;
+pub use fee::*;
+
+pub mod inputs;
+pub use inputs::*;
+
+pub mod project;
+pub use project::*;
+
 pub mod updater;
 pub use updater::*;
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::helpers::DEFAULT_ENDPOINT;
+
+use anyhow::{anyhow, bail, Result};
+use serde::Deserialize;
+use std::{io::ErrorKind, path::PathBuf};
+
+/// The project file, in a program directory, which configures the commands run on the program.
+pub const PROJECT_FILE: &str = "slingshot.toml";
+
+/// The configuration in a project file.
+#[derive(Debug, Deserialize)]
+pub struct ProjectConfig {
+    /// The base endpoint of the node.
+    #[serde(default)]
+    pub endpoint: Option<String>,
+}
+
+impl ProjectConfig {
+    /// Loads the project file in the given program directory, or in the current directory, if it exists.
+    pub fn load(path: Option<&str>) -> Result<Option<Self>> {
+        let path = PathBuf::from(path.unwrap_or(".")).join(PROJECT_FILE);
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(error) => bail!("Failed to read the project file '{}': {error}", path.display()),
+        };
+        toml::from_str(&contents)
+            .map(Some)
+            .map_err(|error| anyhow!("Failed to parse the project file '{}': {error}", path.display()))
+    }
+}
+
+/// Returns the base endpoint in the project file of the given program directory, or of the current directory.
+/// Defaults to a local development node, if there is no project file, or it has no endpoint.
+pub fn project_endpoint(path: Option<&str>) -> Result<String> {
+    let endpoint = ProjectConfig::load(path)?.and_then(|config| config.endpoint);
+    Ok(endpoint.unwrap_or_else(|| DEFAULT_ENDPOINT.to_string()))
+}
@@ -14,20 +14,22 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::errors::UpdaterError;
+use crate::{errors::UpdaterError, output};
 
-use anyhow::Result;
+use anyhow::{anyhow, ensure, Result};
 use colored::Colorize;
-use self_update::{backends::github, version::bump_is_greater, Status};
-use std::fmt::Write as _;
+use self_update::{backends::github, update::Release, version::bump_is_greater, Extract, Move, Status};
+use sha2::{Digest, Sha256};
+use std::{fmt::Write as _, io::Read, path::Path};
 
 pub struct Updater;
 
-// TODO Add logic for users to easily select release versions.
 impl Updater {
     const SLINGSHOT_BIN_NAME: &'static str = "slingshot";
     const SLINGSHOT_REPO_NAME: &'static str = "slingshot";
     const SLINGSHOT_REPO_OWNER: &'static str = "AleoHQ";
+    /// The extension of the asset with the SHA-256 checksum of each binary asset of a release.
+    const CHECKSUM_EXTENSION: &'static str = ".sha256";
 
     /// Show all available releases for `slingshot`.
     pub fn show_available_releases() -> Result<String> {
@@ -44,20 +46,99 @@ impl Updater {
         Ok(output)
     }
 
-    /// Update `slingshot` to the latest release.
-    pub fn update_to_latest_release(show_output: bool) -> Result<Status, UpdaterError> {
-        let status = github::Update::configure()
+    /// Update `slingshot` to the release with the given tag, or else to the latest release, if it is newer.
+    /// The binary for the current platform is only installed if it matches the checksum published with it.
+    pub fn update(tag: Option<&str>, show_output: bool) -> Result<Status> {
+        let current_version = env!("CARGO_PKG_VERSION");
+        let release = Self::release(tag)?;
+        if tag.is_none() && !bump_is_greater(current_version, &release.version)? {
+            return Ok(Status::UpToDate(current_version.to_string()));
+        }
+
+        // Find the binary for the current platform, and its checksum.
+        let target = self_update::get_target();
+        let asset = release
+            .assets
+            .iter()
+            .find(|asset| asset.name.contains(target) && !asset.name.ends_with(Self::CHECKSUM_EXTENSION))
+            .ok_or_else(|| anyhow!("The release {} has no binary for '{target}'", release.version))?;
+        let checksum_name = format!("{}{}", asset.name, Self::CHECKSUM_EXTENSION);
+        let checksum = release.assets.iter().find(|asset| asset.name == checksum_name).ok_or_else(|| {
+            anyhow!("The release {} has no checksum for '{}', so it cannot be verified", release.version, asset.name)
+        })?;
+
+        // Download the binary, and verify it before anything is replaced.
+        if show_output {
+            output!("⏳ Downloading '{}' (version {})...", asset.name, release.version);
+        }
+        let bytes = Self::download(&asset.download_url)?;
+        let expected = String::from_utf8(Self::download(&checksum.download_url)?)?;
+        let expected = expected.split_whitespace().next().unwrap_or_default().to_lowercase();
+        let actual = format!("{:x}", Sha256::digest(&bytes));
+        ensure!(
+            actual == expected,
+            "The SHA-256 checksum of '{}' is {actual}, but {expected} was published, so it was not installed",
+            asset.name
+        );
+
+        // Unpack the binary, and replace the current executable with it.
+        let directory = std::env::temp_dir().join(format!("slingshot-update-{}", release.version));
+        std::fs::create_dir_all(&directory)?;
+        let result = Self::install(&directory, &asset.name, &bytes);
+        let _ = std::fs::remove_dir_all(&directory);
+        result?;
+
+        Ok(Status::Updated(release.version))
+    }
+
+    /// Returns the release with the given tag, or else the latest release.
+    fn release(tag: Option<&str>) -> Result<Release> {
+        let updater = github::Update::configure()
             .repo_owner(Self::SLINGSHOT_REPO_OWNER)
             .repo_name(Self::SLINGSHOT_REPO_NAME)
             .bin_name(Self::SLINGSHOT_BIN_NAME)
             .current_version(env!("CARGO_PKG_VERSION"))
-            .show_download_progress(show_output)
-            .no_confirm(true)
-            .show_output(show_output)
-            .build()?
-            .update()?;
+            .build()?;
+        match tag {
+            Some(tag) => Ok(updater.get_release_version(tag)?),
+            None => Ok(updater.get_latest_release()?),
+        }
+    }
+
+    /// Returns the contents of the release asset at the given URL.
+    fn download(url: &str) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        ureq::get(url)
+            .set("Accept", "application/octet-stream")
+            .set("User-Agent", Self::SLINGSHOT_BIN_NAME)
+            .call()?
+            .into_reader()
+            .read_to_end(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Unpacks the binary from the given asset in the given directory, and replaces the current executable with it.
+    /// An asset that is not an archive is the binary itself.
+    fn install(directory: &Path, asset_name: &str, bytes: &[u8]) -> Result<()> {
+        let asset = directory.join(asset_name);
+        std::fs::write(&asset, bytes)?;
+
+        let bin_name = format!("{}{}", Self::SLINGSHOT_BIN_NAME, std::env::consts::EXE_SUFFIX);
+        let binary = match [".tar.gz", ".tgz", ".zip"].iter().any(|extension| asset_name.ends_with(extension)) {
+            true => {
+                Extract::from_source(&asset).extract_file(directory, &bin_name)?;
+                directory.join(&bin_name)
+            }
+            false => asset,
+        };
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&binary, std::fs::Permissions::from_mode(0o755))?;
+        }
 
-        Ok(status)
+        Move::from_source(&binary).replace_using_temp(&directory.join("replaced")).to_dest(&std::env::current_exe()?)?;
+        Ok(())
     }
 
     /// Check if there is an available update for `slingshot` and return the newest release.
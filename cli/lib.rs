@@ -27,9 +27,9 @@ extern crate tracing;
 pub mod commands;
 pub mod errors;
 pub mod helpers;
-// pub mod ledger;
 pub mod messages;
-pub mod node;
+
+// The node, the ledger, and the records are defined by the core crate, which embeds them without the CLI.
+pub use slingshot_core::{node, output, records};
 
 pub(crate) type Network = snarkvm::prelude::Testnet3;
-pub(crate) type _Aleo = snarkvm::circuit::AleoV0;
@@ -14,19 +14,36 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
-use slingshot::{commands::CLI, helpers::Updater};
+use slingshot_cli::{
+    commands::CLI,
+    helpers::{configure_node_clients, runtime, Compatibility, Context, ExitCode, Output, Updater},
+    output,
+};
 
 use clap::Parser;
+use std::io::IsTerminal;
 
 fn main() -> anyhow::Result<()> {
     // Parse the given arguments.
     let cli = CLI::parse();
+    // Set the output mode, which is plain unless the output is a terminal.
+    Output::set_plain(cli.plain || !std::io::stdout().is_terminal());
+    // Set the compatibility mode.
+    Compatibility::set_strict(cli.strict);
+    // Set the offline mode.
+    Context::set_offline(cli.offline);
+    // Set the timeout and the retries of the requests to the node, and the node address that signs the responses,
+    // if they are verified.
+    configure_node_clients(cli.http_timeout, cli.http_retries, cli.node_address);
     // Run the updater.
-    println!("{}", Updater::print_cli());
+    output!("{}", Updater::print_cli());
     // Run the CLI.
-    match cli.command.parse() {
-        Ok(output) => println!("{output}\n"),
-        Err(error) => println!("⚠️  {error}\n"),
+    match runtime()?.block_on(cli.command.parse()) {
+        Ok(output) => output!("{output}\n"),
+        Err(error) => {
+            output!("⚠️  {error:#}\n");
+            std::process::exit(ExitCode::of(&error) as i32);
+        }
     }
     Ok(())
 }
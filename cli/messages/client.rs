@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{post_to, Compatibility},
+    messages::*,
+};
+
+use snarkvm::prelude::Network;
+
+use anyhow::Result;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A request that the CLI sends to a node, and the response the node replies with.
+#[async_trait]
+pub trait NodeRequest: Serialize + Sync {
+    /// The response to the request.
+    type Response: DeserializeOwned;
+
+    /// Sends the request to the given endpoint, with the node client.
+    async fn send(&self, endpoint: &str) -> Result<Self::Response> {
+        Compatibility::check(endpoint).await?;
+        post_to(endpoint, self).await
+    }
+}
+
+impl<N: Network> NodeRequest for AccountRegisterRequest<N> {
+    type Response = AccountRegisterResponse<N>;
+}
+
+impl<N: Network> NodeRequest for ConsolidateRequest<N> {
+    type Response = ConsolidateResponse<N>;
+}
+
+impl<N: Network> NodeRequest for DeployRequest<N> {
+    type Response = DeployResponse<N>;
+}
+
+impl<N: Network> NodeRequest for EvaluateRequest<N> {
+    type Response = EvaluateResponse<N>;
+}
+
+impl<N: Network> NodeRequest for ExecuteRequest<N> {
+    type Response = ExecuteResponse<N>;
+}
+
+impl<N: Network> NodeRequest for FeeEstimateRequest<N> {
+    type Response = FeeEstimateResponse;
+}
+
+#[async_trait]
+impl NodeRequest for HandshakeRequest {
+    type Response = HandshakeResponse;
+
+    /// Sends the request to the given endpoint, without checking the compatibility of the node first,
+    /// as the handshake is the check.
+    async fn send(&self, endpoint: &str) -> Result<Self::Response> {
+        post_to(endpoint, self).await
+    }
+}
+
+impl<N: Network> NodeRequest for PourRequest<N> {
+    type Response = PourResponse<N>;
+}
+
+impl<N: Network> NodeRequest for RecordViewRequest<N> {
+    type Response = RecordViewResponse<N>;
+}
+
+impl<N: Network> NodeRequest for SendRecordRequest<N> {
+    type Response = SendRecordResponse<N>;
+}
+
+impl<N: Network> NodeRequest for SplitRequest<N> {
+    type Response = SplitResponse<N>;
+}
+
+impl<N: Network> NodeRequest for TransferRequest<N> {
+    type Response = TransferResponse<N>;
+}
+
+/// A request for the statistics of the unspent records of an account, which the node answers at `records/stats`.
+#[async_trait]
+pub trait RecordStatsRequest {
+    /// Sends the request to the given `records/stats` endpoint.
+    async fn send_stats(&self, endpoint: &str) -> Result<RecordStatsResponse>;
+}
+
+#[async_trait]
+impl<N: Network> RecordStatsRequest for RecordViewRequest<N> {
+    async fn send_stats(&self, endpoint: &str) -> Result<RecordStatsResponse> {
+        Compatibility::check(endpoint).await?;
+        post_to(endpoint, self).await
+    }
+}
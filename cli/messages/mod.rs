@@ -14,14 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
-pub mod deploy;
-pub use deploy::*;
+// The request and response types are defined by the core crate, and sent to a node by the CLI.
+pub use slingshot_core::messages::*;
 
-pub mod execute;
-pub use execute::*;
-
-pub mod pour;
-pub use pour::*;
-
-pub mod view;
-pub use view::*;
+pub mod client;
+pub use client::*;
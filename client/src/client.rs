@@ -0,0 +1,962 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{ClientError, RetryPolicy};
+
+use slingshot_core::{
+    errors::{NodeError, SlingshotError},
+    helpers::{ResponseSignature, SIGNATURE_HEADER, SIGNATURE_TIMESTAMP_HEADER},
+    messages::*,
+    node::{
+        BlockStats,
+        DecodedTransaction,
+        Event,
+        ExecutionProfile,
+        HistoryEntry,
+        Job,
+        JobStatus,
+        KeyCacheStats,
+        LogEntry,
+        NodeInfo,
+        RecordCiphertext,
+        ReloadableConfig,
+    },
+};
+use snarkvm::prelude::{
+    Address,
+    Block,
+    Field,
+    Header,
+    Network,
+    Program,
+    ProgramID,
+    StatePath,
+    Transaction,
+    Transactions,
+    ViewKey,
+};
+
+use anyhow::{bail, Result};
+use reqwest::{header::RETRY_AFTER, Method, RequestBuilder, Response};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+/// The interval at which the wait helpers poll the node.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A typed async client of the REST routes of a node.
+/// Each route has a method that sends the request and parses the response into the type the node replies with,
+/// so that a change to the shape of a route is a compile error, rather than a failure at runtime.
+/// The requests that the node did not process are retried with the retry policy of the client, and the signature
+/// of each response is verified, if responses are verified (see `Client::with_node_address`).
+#[derive(Clone, Debug)]
+pub struct Client<N: Network> {
+    /// The base endpoint of the node, e.g. `http://localhost:4180`.
+    base: String,
+    /// The API token sent with each request, if the node requires one.
+    api_token: Option<String>,
+    /// The time after which a request times out, if any.
+    timeout: Option<Duration>,
+    /// The policy for retrying the requests the node did not process.
+    retry: RetryPolicy,
+    /// The address of the node account that must have signed the responses, if they are verified.
+    node_address: Option<Address<N>>,
+    /// The HTTP client, which pools the connections to the node.
+    http: reqwest::Client,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> Client<N> {
+    /// Returns a client of the node at the given base endpoint, e.g. `http://localhost:4180`.
+    pub fn new(base: &str) -> Self {
+        Self {
+            base: base.trim_end_matches('/').to_string(),
+            api_token: None,
+            timeout: None,
+            retry: RetryPolicy::default(),
+            node_address: None,
+            http: reqwest::Client::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the client, sending the given API token with each request, unless it is empty.
+    pub fn with_api_token(mut self, api_token: impl Into<String>) -> Self {
+        self.api_token = Some(api_token.into()).filter(|api_token| !api_token.is_empty());
+        self
+    }
+
+    /// Returns the client, failing each request that is not answered within the given timeout.
+    /// Note that a request that constructs a transaction may take as long as the proving timeout of the node.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the client, retrying the requests the node did not process with the given policy.
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Returns the client, verifying that each response is signed by the given node account.
+    /// The node must be started with `--sign-responses`.
+    pub fn with_node_address(mut self, node_address: Address<N>) -> Self {
+        self.node_address = Some(node_address);
+        self
+    }
+
+    /// Returns the base endpoint of the node.
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Returns the URL of the given route, e.g. `/testnet3/latest/height`.
+    pub fn url(&self, route: &str) -> String {
+        format!("{}{route}", self.base)
+    }
+
+    /// Returns the WebSocket URL of the given route, e.g. `/testnet3/events/ws`.
+    pub fn ws_url(&self, route: &str) -> String {
+        // Note: This maps `http` to `ws`, and `https` to `wss`.
+        self.url(route).replacen("http", "ws", 1)
+    }
+
+    /// Sends a GET request to the given route, and returns the JSON response.
+    pub async fn get<R: DeserializeOwned>(&self, route: &str) -> Result<R> {
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::GET, route)).await?)?)
+    }
+
+    /// Sends a POST request with the given JSON body to the given route, and returns the JSON response.
+    pub async fn post<B: Serialize + ?Sized, R: DeserializeOwned>(&self, route: &str, body: &B) -> Result<R> {
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::POST, route).json(body)).await?)?)
+    }
+
+    /// Sends a GET request to the given route, and returns the body of the response and its signature,
+    /// e.g. to cache them and verify them again later.
+    pub async fn get_signed(&self, route: &str) -> Result<(String, ResponseSignature)> {
+        self.send_signed(|| self.request(Method::GET, route)).await
+    }
+
+    /// Returns a request with the given method to the given route, which carries the API token, if the client has one,
+    /// and times out after the timeout of the client, if it has one.
+    fn request(&self, method: Method, route: &str) -> RequestBuilder {
+        let mut request = self.http.request(method, self.url(route));
+        if let Some(api_token) = &self.api_token {
+            request = request.bearer_auth(api_token);
+        }
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        request
+    }
+
+    /// Sends the request returned by the given closure, and returns the body of the successful response.
+    async fn send(&self, request: impl Fn() -> RequestBuilder) -> Result<String> {
+        Ok(self.send_signed(request).await?.0)
+    }
+
+    /// Sends the request returned by the given closure, and retries it with the retry policy, if the node did not
+    /// process it. Returns the body of the successful response and its signature, once the signature is verified.
+    async fn send_signed(&self, request: impl Fn() -> RequestBuilder) -> Result<(String, ResponseSignature)> {
+        let mut retry = 0;
+        loop {
+            let request = request().build().map_err(ClientError::Transport)?;
+            // A GET request does not change the state of the node, so it is also retried if it timed out.
+            let is_read_only = *request.method() == Method::GET;
+            let (method, path) = (request.method().to_string(), request.url().path().to_string());
+            let (error, retry_after) = match self.http.execute(request).await {
+                Ok(response) if response.status().is_success() => return self.read(response, method, path).await,
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    let retry_after = response
+                        .headers()
+                        .get(RETRY_AFTER)
+                        .and_then(|value| value.to_str().ok()?.parse().ok())
+                        .map(Duration::from_secs);
+                    let error = Self::read_error(response).await;
+                    // A node that is shutting down does not become available again.
+                    let is_shutting_down =
+                        matches!(error.downcast_ref::<SlingshotError>(), Some(SlingshotError::ShuttingDown));
+                    match RetryPolicy::is_retryable(status) && !is_shutting_down {
+                        true => (error, retry_after),
+                        false => return Err(error),
+                    }
+                }
+                // Otherwise, only a request that failed to connect is retried, as the node may have processed it.
+                Err(error) if error.is_connect() || (is_read_only && error.is_timeout()) => {
+                    (ClientError::Transport(error).into(), None)
+                }
+                Err(error) => return Err(ClientError::Transport(error).into()),
+            };
+            if retry >= self.retry.max_retries {
+                return Err(error);
+            }
+            tokio::time::sleep(retry_after.unwrap_or_else(|| self.retry.backoff(retry))).await;
+            retry += 1;
+        }
+    }
+
+    /// Reads the body of the given successful response to a request with the given method and path,
+    /// and verifies its signature, and that it is fresh, if responses are verified.
+    async fn read(&self, response: Response, method: String, path: String) -> Result<(String, ResponseSignature)> {
+        let header =
+            |name: &str| response.headers().get(name).and_then(|value| value.to_str().ok()).map(str::to_string);
+        let (signature, timestamp) = (header(SIGNATURE_HEADER), header(SIGNATURE_TIMESTAMP_HEADER));
+        let signature = ResponseSignature { method, path, timestamp, signature };
+        let body = response.text().await.map_err(ClientError::Transport)?;
+        signature.verify_fresh(self.node_address.as_ref(), body.as_bytes())?;
+        Ok((body, signature))
+    }
+
+    /// Returns the typed error in the body of the given error response, if it has one, or its status otherwise.
+    async fn read_error(response: Response) -> anyhow::Error {
+        let code = response.status().as_u16();
+        match response.text().await {
+            Ok(body) => match serde_json::from_str::<SlingshotError>(&body) {
+                Ok(error) => error.into(),
+                Err(_) => ClientError::Status { code, body }.into(),
+            },
+            Err(error) => ClientError::Transport(error).into(),
+        }
+    }
+}
+
+impl<N: Network> Client<N> {
+    // GET /testnet3/latest/height
+    /// Returns the latest block height.
+    pub async fn latest_height(&self) -> Result<u32> {
+        self.get("/testnet3/latest/height").await
+    }
+
+    // GET /testnet3/latest/hash
+    /// Returns the latest block hash.
+    pub async fn latest_hash(&self) -> Result<N::BlockHash> {
+        self.get("/testnet3/latest/hash").await
+    }
+
+    // GET /testnet3/latest/block
+    /// Returns the latest block.
+    pub async fn latest_block(&self) -> Result<Block<N>> {
+        self.get("/testnet3/latest/block").await
+    }
+
+    // GET /testnet3/latest/stateRoot
+    /// Returns the latest state root.
+    pub async fn latest_state_root(&self) -> Result<Field<N>> {
+        self.get("/testnet3/latest/stateRoot").await
+    }
+
+    // GET /testnet3/block/{height}
+    /// Returns the block at the given height.
+    pub async fn block(&self, height: u32) -> Result<Block<N>> {
+        self.get(&format!("/testnet3/block/{height}")).await
+    }
+
+    // GET /testnet3/blocks?start={start_height}&end={end_height}
+    /// Returns the blocks in the given range of heights, from `start` (inclusive) to `end` (exclusive).
+    pub async fn blocks(&self, start: u32, end: u32) -> Result<Vec<Block<N>>> {
+        let query = BlockRange { start, end };
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::GET, "/testnet3/blocks").query(&query)).await?)?)
+    }
+
+    // GET /testnet3/block/{blockHash}
+    /// Returns the block with the given hash.
+    pub async fn block_by_hash(&self, hash: &N::BlockHash) -> Result<Block<N>> {
+        self.get(&format!("/testnet3/block/{hash}")).await
+    }
+
+    // GET /testnet3/height/{blockHash}
+    /// Returns the height of the block with the given hash.
+    pub async fn block_height(&self, hash: &N::BlockHash) -> Result<u32> {
+        self.get(&format!("/testnet3/height/{hash}")).await
+    }
+
+    // GET /testnet3/block/{height}/transactions
+    /// Returns the transactions of the block at the given height.
+    pub async fn block_transactions(&self, height: u32) -> Result<Transactions<N>> {
+        self.get(&format!("/testnet3/block/{height}/transactions")).await
+    }
+
+    // GET /testnet3/transaction/{transactionID}
+    /// Returns the confirmed transaction with the given ID.
+    pub async fn transaction(&self, transaction_id: &N::TransactionID) -> Result<Transaction<N>> {
+        self.get(&format!("/testnet3/transaction/{transaction_id}")).await
+    }
+
+    // GET /testnet3/transaction/{transactionID}/events
+    /// Returns the events of the confirmed transaction with the given ID.
+    pub async fn transaction_events(&self, transaction_id: &N::TransactionID) -> Result<Vec<Event<N>>> {
+        self.get(&format!("/testnet3/transaction/{transaction_id}/events")).await
+    }
+
+    // GET /testnet3/transaction/{transactionID}/decode?view_key={viewKey}
+    /// Returns the confirmed transaction with the given ID, decomposed into its transitions, the fee, and the finalize
+    /// operations, with the private values and records the given view key can decrypt.
+    pub async fn decode_transaction(
+        &self,
+        transaction_id: &N::TransactionID,
+        view_key: Option<&ViewKey<N>>,
+    ) -> Result<DecodedTransaction<N>> {
+        let query = DecodeQuery { view_key: view_key.map(|view_key| view_key.to_string()) };
+        let route = format!("/testnet3/transaction/{transaction_id}/decode");
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::GET, &route).query(&query)).await?)?)
+    }
+
+    // GET /testnet3/memoryPool/transactions
+    /// Returns the unconfirmed transactions in the memory pool.
+    pub async fn memory_pool_transactions(&self) -> Result<Vec<Transaction<N>>> {
+        self.get("/testnet3/memoryPool/transactions").await
+    }
+
+    // GET /testnet3/memoryPool/rejected
+    /// Returns the transactions most recently rejected from the memory pool, with the reason they were rejected.
+    pub async fn memory_pool_rejected(&self) -> Result<Vec<RejectedTransaction<N>>> {
+        self.get("/testnet3/memoryPool/rejected").await
+    }
+
+    // DELETE /testnet3/memoryPool/transaction/{transactionID}
+    /// Removes the unconfirmed transaction with the given ID from the memory pool, and returns it.
+    pub async fn delete_memory_pool_transaction(&self, transaction_id: &N::TransactionID) -> Result<Transaction<N>> {
+        let route = format!("/testnet3/memoryPool/transaction/{transaction_id}");
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::DELETE, &route)).await?)?)
+    }
+
+    // GET /testnet3/program/{programID}
+    /// Returns the program with the given ID.
+    pub async fn program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        self.get(&format!("/testnet3/program/{program_id}")).await
+    }
+
+    // GET /testnet3/program/{programID}?format=string
+    /// Returns the Aleo instructions of the program with the given ID.
+    pub async fn program_source(&self, program_id: &ProgramID<N>) -> Result<String> {
+        let query = ProgramQuery { format: ProgramFormat::String, metadata: false };
+        let route = format!("/testnet3/program/{program_id}");
+        self.send(|| self.request(Method::GET, &route).query(&query)).await
+    }
+
+    // GET /testnet3/program/{programID}?format={json|string|bytes}&metadata=true
+    /// Returns the program with the given ID in the given format, with the deployment that includes it.
+    pub async fn program_with_metadata(
+        &self,
+        program_id: &ProgramID<N>,
+        format: ProgramFormat,
+    ) -> Result<ProgramWithMetadata<N>> {
+        let query = ProgramQuery { format, metadata: true };
+        let route = format!("/testnet3/program/{program_id}");
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::GET, &route).query(&query)).await?)?)
+    }
+
+    // GET /testnet3/program/{programID}/metadata
+    /// Returns the metadata attached to the deployment of the program with the given ID.
+    pub async fn program_metadata(&self, program_id: &ProgramID<N>) -> Result<ProgramMetadata> {
+        self.get(&format!("/testnet3/program/{program_id}/metadata")).await
+    }
+
+    // GET /testnet3/program/{programID}/events?start={start_height}&end={end_height}
+    /// Returns the events of the program with the given ID in the given range of blocks.
+    pub async fn program_events(&self, program_id: &ProgramID<N>, range: ProgramEventRange) -> Result<Vec<Event<N>>> {
+        let route = format!("/testnet3/program/{program_id}/events");
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::GET, &route).query(&range)).await?)?)
+    }
+
+    // GET /testnet3/statePath/{commitment}
+    /// Returns the state path for the given commitment.
+    pub async fn state_path(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get(&format!("/testnet3/statePath/{commitment}")).await
+    }
+
+    // GET /testnet3/record/{commitment}
+    /// Returns the confirmed record ciphertext with the given commitment.
+    pub async fn record_ciphertext(&self, commitment: &Field<N>) -> Result<RecordCiphertext<N>> {
+        self.get(&format!("/testnet3/record/{commitment}")).await
+    }
+
+    // GET /testnet3/records/ciphertexts?start={start_height}&end={end_height}
+    /// Returns the record ciphertexts output by the blocks in the given range of heights, from `start` (inclusive)
+    /// to `end` (exclusive).
+    pub async fn record_ciphertexts(&self, start: u32, end: u32) -> Result<Vec<RecordCiphertext<N>>> {
+        let query = BlockRange { start, end };
+        let route = "/testnet3/records/ciphertexts";
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::GET, route).query(&query)).await?)?)
+    }
+
+    // GET /testnet3/node/address
+    /// Returns the address of the node account.
+    pub async fn node_address(&self) -> Result<Address<N>> {
+        self.get("/testnet3/node/address").await
+    }
+
+    // GET /testnet3/node/info
+    /// Returns the chain context of the node.
+    pub async fn node_info(&self) -> Result<NodeInfo<N>> {
+        self.get("/testnet3/node/info").await
+    }
+
+    // POST /testnet3/node/config
+    /// Updates the configuration of the node, and returns the current configuration.
+    pub async fn update_node_config(&self, update: &NodeConfigUpdate) -> Result<NodeConfigUpdate> {
+        self.post("/testnet3/node/config", update).await
+    }
+
+    // POST /testnet3/node/config/reload
+    /// Reloads the configuration file of the node, and returns the current settings.
+    pub async fn reload_node_config(&self) -> Result<ReloadableConfig> {
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::POST, "/testnet3/node/config/reload")).await?)?)
+    }
+
+    // POST /testnet3/node/handshake
+    /// Sends the build of the client to the node, and returns the build of the node.
+    pub async fn handshake(&self, request: &HandshakeRequest) -> Result<HandshakeResponse> {
+        self.post("/testnet3/node/handshake", request).await
+    }
+
+    // GET /testnet3/node/cache/stats
+    /// Returns the statistics of the key cache of the node.
+    pub async fn key_cache_stats(&self) -> Result<KeyCacheStats> {
+        self.get("/testnet3/node/cache/stats").await
+    }
+
+    // GET /testnet3/node/logs?since={sequence}
+    /// Returns the log entries of the node after the given sequence number, or all buffered entries.
+    pub async fn logs(&self, since: Option<u64>) -> Result<Vec<LogEntry>> {
+        let query = LogsQuery { since };
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::GET, "/testnet3/node/logs").query(&query)).await?)?)
+    }
+
+    // GET /testnet3/stats/timeseries?from={start_height}&to={end_height}
+    /// Returns the statistics of the blocks in the given range of heights.
+    pub async fn stats_timeseries(&self, range: StatsRange) -> Result<Vec<BlockStats>> {
+        let route = "/testnet3/stats/timeseries";
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::GET, route).query(&range)).await?)?)
+    }
+
+    // POST /testnet3/node/mine
+    /// Produces the given number of blocks, and returns their headers.
+    pub async fn mine(&self, blocks: u32) -> Result<Vec<Header<N>>> {
+        self.post("/testnet3/node/mine", &MineBlocks { blocks }).await
+    }
+
+    // POST /testnet3/node/pause
+    /// Pauses scheduled block production.
+    pub async fn pause(&self) -> Result<BlockProduction> {
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::POST, "/testnet3/node/pause")).await?)?)
+    }
+
+    // POST /testnet3/node/resume
+    /// Resumes scheduled block production.
+    pub async fn resume(&self) -> Result<BlockProduction> {
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::POST, "/testnet3/node/resume")).await?)?)
+    }
+
+    // GET /testnet3/node/blockPolicy
+    /// Returns the policy that decides when the node produces a block.
+    pub async fn block_policy(&self) -> Result<BlockPolicyUpdate> {
+        self.get("/testnet3/node/blockPolicy").await
+    }
+
+    // POST /testnet3/node/blockPolicy
+    /// Switches the policy that decides when the node produces a block, e.g. to `instant` or `threshold:10`.
+    pub async fn set_block_policy(&self, policy: &str) -> Result<BlockPolicyUpdate> {
+        self.post("/testnet3/node/blockPolicy", &BlockPolicyUpdate { policy: policy.to_string() }).await
+    }
+
+    // POST /testnet3/node/shutdown
+    /// Shuts down the node, once its pending work is complete.
+    pub async fn shut_down(&self) -> Result<ShutdownStatus> {
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::POST, "/testnet3/node/shutdown")).await?)?)
+    }
+
+    // POST /testnet3/node/setNextBlockTimestamp
+    /// Sets the timestamp of the next block.
+    pub async fn set_next_block_timestamp(&self, timestamp: i64) -> Result<NodeTime> {
+        self.post("/testnet3/node/setNextBlockTimestamp", &NextBlockTimestamp { timestamp }).await
+    }
+
+    // POST /testnet3/node/increaseTime
+    /// Advances the time of the node by the given number of seconds.
+    pub async fn increase_time(&self, seconds: u64) -> Result<NodeTime> {
+        self.post("/testnet3/node/increaseTime", &IncreaseTime { seconds }).await
+    }
+
+    // GET /testnet3/account/{address}/sequence
+    /// Returns the sequence number of the next submission from the given account.
+    pub async fn account_sequence(&self, address: &Address<N>) -> Result<u64> {
+        self.get(&format!("/testnet3/account/{address}/sequence")).await
+    }
+
+    // GET /testnet3/find/blockHash/{transactionID}
+    /// Returns the hash of the block that includes the transaction with the given ID, if it is confirmed.
+    pub async fn find_block_hash(&self, transaction_id: &N::TransactionID) -> Result<Option<N::BlockHash>> {
+        self.get(&format!("/testnet3/find/blockHash/{transaction_id}")).await
+    }
+
+    // GET /testnet3/find/deploymentID/{programID}
+    /// Returns the ID of the transaction that deployed the program with the given ID, if it is deployed.
+    pub async fn find_deployment_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
+        self.get(&format!("/testnet3/find/deploymentID/{program_id}")).await
+    }
+
+    // GET /testnet3/find/transactionID/{transitionID}
+    /// Returns the ID of the transaction that contains the transition with the given ID, if it is confirmed.
+    pub async fn find_transaction_id(&self, transition_id: &N::TransitionID) -> Result<Option<N::TransactionID>> {
+        self.get(&format!("/testnet3/find/transactionID/{transition_id}")).await
+    }
+
+    // GET /testnet3/find/transitionID/{inputOrOutputID}
+    /// Returns the ID of the transition with the given input or output ID.
+    pub async fn find_transition_id(&self, input_or_output_id: &Field<N>) -> Result<N::TransitionID> {
+        self.get(&format!("/testnet3/find/transitionID/{input_or_output_id}")).await
+    }
+
+    // GET /testnet3/events/replay?from_block={start_height}&to_block={end_height}
+    /// Returns the events of the blocks in the given range of heights.
+    pub async fn events_replay(&self, range: EventReplay) -> Result<Vec<Event<N>>> {
+        let route = "/testnet3/events/replay";
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::GET, route).query(&range)).await?)?)
+    }
+
+    // GET /testnet3/events/ws?types={types}
+    /// Returns the WebSocket URL of the event feed, which streams the events of the given comma-separated types,
+    /// or of every type.
+    pub fn events_url(&self, types: Option<&str>) -> String {
+        match types {
+            Some(types) => self.ws_url(&format!("/testnet3/events/ws?types={types}")),
+            None => self.ws_url("/testnet3/events/ws"),
+        }
+    }
+
+    // POST /testnet3/records/all
+    /// Returns the records of the view key in the given request.
+    pub async fn records_all(&self, request: &RecordViewRequest<N>) -> Result<RecordViewResponse<N>> {
+        self.post("/testnet3/records/all", request).await
+    }
+
+    // POST /testnet3/records/spent
+    /// Returns the spent records of the view key in the given request.
+    pub async fn records_spent(&self, request: &RecordViewRequest<N>) -> Result<RecordViewResponse<N>> {
+        self.post("/testnet3/records/spent", request).await
+    }
+
+    // POST /testnet3/records/unspent
+    /// Returns the unspent records of the view key in the given request.
+    pub async fn records_unspent(&self, request: &RecordViewRequest<N>) -> Result<RecordViewResponse<N>> {
+        self.post("/testnet3/records/unspent", request).await
+    }
+
+    // POST /testnet3/records/stats
+    /// Returns the statistics of the unspent records of the view key in the given request.
+    pub async fn records_stats(&self, request: &RecordViewRequest<N>) -> Result<RecordStatsResponse> {
+        self.post("/testnet3/records/stats", request).await
+    }
+
+    // POST /testnet3/watch/records
+    /// Watches the given records, and returns the ID of the watch, to subscribe to at `watch_url`.
+    pub async fn watch_records(&self, request: &WatchRecords<N>) -> Result<WatchCreated<N>> {
+        self.post("/testnet3/watch/records", request).await
+    }
+
+    // GET /testnet3/watch/{id}/ws
+    /// Returns the WebSocket URL of the watch with the given ID.
+    pub fn watch_url(&self, id: &str) -> String {
+        self.ws_url(&format!("/testnet3/watch/{id}/ws"))
+    }
+
+    // DELETE /testnet3/watch/{id}
+    /// Removes the watch with the given ID.
+    pub async fn delete_watch(&self, id: &str) -> Result<()> {
+        let route = format!("/testnet3/watch/{id}");
+        self.send(|| self.request(Method::DELETE, &route)).await.map(|_| ())
+    }
+
+    // POST /testnet3/history
+    /// Returns the transactions that spent or received records of the view key in the given request.
+    pub async fn history(&self, request: &RecordViewRequest<N>) -> Result<Vec<HistoryEntry<N>>> {
+        self.post("/testnet3/history", request).await
+    }
+
+    // POST /testnet3/faucet/pour
+    /// Transfers gates from the faucet to the recipient in the given request.
+    pub async fn pour(&self, request: &PourRequest<N>) -> Result<PourResponse<N>> {
+        self.post("/testnet3/faucet/pour", request).await
+    }
+
+    // GET /testnet3/faucet/balance
+    /// Returns the balance of the faucet.
+    pub async fn faucet_balance(&self) -> Result<FaucetBalance<N>> {
+        self.get("/testnet3/faucet/balance").await
+    }
+
+    // POST /testnet3/accounts/register
+    /// Registers an account for server-side signing, and returns its account token.
+    pub async fn register_account(&self, request: &AccountRegisterRequest<N>) -> Result<AccountRegisterResponse<N>> {
+        self.post("/testnet3/accounts/register", request).await
+    }
+
+    // POST /testnet3/transferAndWait
+    /// Transfers gates, and waits until the transfer is confirmed.
+    pub async fn transfer_and_wait(&self, request: &TransferRequest<N>) -> Result<TransferResponse<N>> {
+        self.post("/testnet3/transferAndWait", request).await
+    }
+
+    // POST /testnet3/records/send
+    /// Sends a record of the signer to the recipient in the given request.
+    pub async fn send_record(&self, request: &SendRecordRequest<N>) -> Result<SendRecordResponse<N>> {
+        self.post("/testnet3/records/send", request).await
+    }
+
+    // POST /testnet3/records/consolidate
+    /// Joins the records of the signer into fewer records.
+    pub async fn consolidate(&self, request: &ConsolidateRequest<N>) -> Result<ConsolidateResponse<N>> {
+        self.post("/testnet3/records/consolidate", request).await
+    }
+
+    // POST /testnet3/records/split
+    /// Splits a record of the signer into spendable pieces.
+    pub async fn split(&self, request: &SplitRequest<N>) -> Result<SplitResponse<N>> {
+        self.post("/testnet3/records/split", request).await
+    }
+
+    // POST /testnet3/program/deploy
+    /// Deploys the program in the given request, and returns the ID of the transaction in the memory pool.
+    pub async fn deploy(&self, request: &DeployRequest<N>) -> Result<DeployResponse<N>> {
+        self.post("/testnet3/program/deploy", request).await
+    }
+
+    // POST /testnet3/program/deploy?async=true
+    /// Deploys the program in the given request in the background, and returns the ID of the job.
+    pub async fn deploy_async(&self, request: &DeployRequest<N>) -> Result<JobCreated> {
+        self.post("/testnet3/program/deploy?async=true", request).await
+    }
+
+    // POST /testnet3/program/execute
+    /// Executes the function in the given request, and returns the ID of the transaction in the memory pool.
+    pub async fn execute(&self, request: &ExecuteRequest<N>) -> Result<ExecuteResponse<N>> {
+        self.post("/testnet3/program/execute", request).await
+    }
+
+    // POST /testnet3/program/execute?async=true
+    /// Executes the function in the given request in the background, and returns the ID of the job.
+    pub async fn execute_async(&self, request: &ExecuteRequest<N>) -> Result<JobCreated> {
+        self.post("/testnet3/program/execute?async=true", request).await
+    }
+
+    // GET /testnet3/job/{id}
+    /// Returns the job with the given ID.
+    pub async fn job(&self, id: &str) -> Result<Job<N>> {
+        self.get(&format!("/testnet3/job/{id}")).await
+    }
+
+    // GET /testnet3/jobs?status={status}
+    /// Returns the jobs with the given status, or every job.
+    pub async fn jobs(&self, status: Option<&str>) -> Result<Vec<Job<N>>> {
+        let query = JobFilter { status: status.map(str::to_string) };
+        Ok(serde_json::from_str(&self.send(|| self.request(Method::GET, "/testnet3/jobs").query(&query)).await?)?)
+    }
+
+    // POST /testnet3/program/evaluate
+    /// Evaluates the function in the given request, without proving it.
+    pub async fn evaluate(&self, request: &EvaluateRequest<N>) -> Result<EvaluateResponse<N>> {
+        self.post("/testnet3/program/evaluate", request).await
+    }
+
+    // POST /testnet3/program/profile
+    /// Executes the function in the given request, and returns the time spent on each stage, the constraints
+    /// of each transition, and the estimated fee. The transaction is not submitted.
+    pub async fn profile(&self, request: &EvaluateRequest<N>) -> Result<ExecutionProfile<N>> {
+        self.post("/testnet3/program/profile", request).await
+    }
+
+    // POST /testnet3/fee/estimate
+    /// Estimates the fee of the transaction in the given request.
+    pub async fn estimate_fee(&self, request: &FeeEstimateRequest<N>) -> Result<FeeEstimateResponse> {
+        self.post("/testnet3/fee/estimate", request).await
+    }
+
+    // POST /testnet3/transaction/simulate
+    /// Constructs the execution in the given request, and checks it, without adding it to the memory pool.
+    pub async fn simulate(&self, request: &ExecuteRequest<N>) -> Result<SimulateResponse<N>> {
+        self.post("/testnet3/transaction/simulate", request).await
+    }
+
+    // POST /testnet3/transaction/broadcast
+    /// Broadcasts the given transaction to the memory pool, and returns its ID.
+    pub async fn broadcast(&self, transaction: &Transaction<N>) -> Result<N::TransactionID> {
+        self.post("/testnet3/transaction/broadcast", transaction).await
+    }
+
+    // POST /testnet3/transaction/verify
+    /// Checks the given transaction against the ledger state and the memory pool, without broadcasting it.
+    pub async fn verify(&self, transaction: &Transaction<N>) -> Result<TransactionVerification<N>> {
+        self.post("/testnet3/transaction/verify", transaction).await
+    }
+
+    // GET /testnet3/usage
+    /// Returns the usage of the node by each account.
+    pub async fn usage(&self) -> Result<UsageReport<N>> {
+        self.get("/testnet3/usage").await
+    }
+
+    // GET /explorer
+    /// Returns the URL of the block explorer of the node.
+    pub fn explorer_url(&self) -> String {
+        self.url("/explorer")
+    }
+}
+
+impl<N: Network> Client<N> {
+    /// Waits until the transaction with the given ID is included in a block, and returns it.
+    /// Returns a timeout error if it is not confirmed within the given timeout.
+    pub async fn wait_for_transaction(
+        &self,
+        transaction_id: &N::TransactionID,
+        timeout: Duration,
+    ) -> Result<Transaction<N>> {
+        let start = Instant::now();
+        loop {
+            if self.find_block_hash(transaction_id).await?.is_some() {
+                return self.transaction(transaction_id).await;
+            }
+            if start.elapsed() >= timeout {
+                return Err(NodeError::Timeout(format!(
+                    "Transaction '{transaction_id}' was not confirmed within {} seconds",
+                    timeout.as_secs()
+                ))
+                .into());
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Waits until the job with the given ID is complete, and returns the ID of its confirmed transaction.
+    /// Returns an error if the job failed, or a timeout error if it is not complete within the given timeout.
+    pub async fn wait_for_job(&self, id: &str, timeout: Duration) -> Result<N::TransactionID> {
+        let start = Instant::now();
+        loop {
+            match self.job(id).await?.status {
+                JobStatus::Confirmed { transaction_id, .. } => return Ok(transaction_id),
+                JobStatus::Failed { error } => bail!("Job '{id}' failed: {error}"),
+                _ => (),
+            }
+            if start.elapsed() >= timeout {
+                return Err(NodeError::Timeout(format!(
+                    "Job '{id}' was not complete within {} seconds",
+                    timeout.as_secs()
+                ))
+                .into());
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Waits until the latest block height is at least the given height, and returns the latest block height.
+    /// Returns a timeout error if the node does not reach the height within the given timeout.
+    pub async fn wait_for_height(&self, height: u32, timeout: Duration) -> Result<u32> {
+        let start = Instant::now();
+        loop {
+            let latest_height = self.latest_height().await?;
+            if latest_height >= height {
+                return Ok(latest_height);
+            }
+            if start.elapsed() >= timeout {
+                return Err(NodeError::Timeout(format!(
+                    "The node did not reach height {height} within {} seconds (latest height {latest_height})",
+                    timeout.as_secs()
+                ))
+                .into());
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use slingshot_core::{node::NodeScan, test::TestNode};
+    use snarkvm::prelude::{PrivateKey, Testnet3};
+    use std::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_urls() {
+        let client = Client::<CurrentNetwork>::new("http://localhost:4180/");
+        assert_eq!(client.url("/testnet3/latest/height"), "http://localhost:4180/testnet3/latest/height");
+        assert_eq!(
+            client.events_url(Some("block_accepted")),
+            "ws://localhost:4180/testnet3/events/ws?types=block_accepted"
+        );
+        assert_eq!(client.watch_url("1"), "ws://localhost:4180/testnet3/watch/1/ws");
+
+        let client = Client::<CurrentNetwork>::new("https://node.example.com");
+        assert_eq!(client.events_url(None), "wss://node.example.com/testnet3/events/ws");
+    }
+
+    #[tokio::test]
+    async fn test_client() {
+        let node = TestNode::start().await.unwrap();
+        let client = Client::<CurrentNetwork>::new(&node.endpoint());
+        assert_eq!(client.latest_height().await.unwrap(), 0);
+        assert_eq!(client.node_address().await.unwrap(), Address::try_from(node.private_key()).unwrap());
+
+        // Pour gates to a new account, and wait for the transfer to be confirmed.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng()).unwrap();
+        let transaction = node.pour(Address::try_from(&private_key).unwrap(), 1_000).await.unwrap();
+        assert_eq!(client.find_block_hash(&transaction.id()).await.unwrap(), None);
+        node.advance_block().await.unwrap();
+        let confirmed = client.wait_for_transaction(&transaction.id(), Duration::from_secs(5)).await.unwrap();
+        assert_eq!(confirmed.id(), transaction.id());
+        assert_eq!(client.wait_for_height(1, Duration::from_secs(5)).await.unwrap(), 1);
+
+        // The recipient finds its record among the record ciphertexts of the block, without sending its view key.
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let records = client.record_ciphertexts(1, 2).await.unwrap();
+        let record = records.iter().find(|record| record.record_ciphertext.is_owner(&view_key)).unwrap();
+        assert_eq!(record.transaction_id, transaction.id());
+        assert_eq!(client.record_ciphertext(&record.commitment).await.unwrap().block_height, 1);
+
+        // The recipient decodes the transfer, with its record decrypted.
+        let decoded = client.decode_transaction(&transaction.id(), Some(&view_key)).await.unwrap();
+        let outputs = decoded.transitions.iter().flat_map(|transition| &transition.outputs).collect::<Vec<_>>();
+        assert!(outputs.iter().any(|output| output.id == record.commitment && output.value.is_some()));
+        // Without a view key, the record is not decrypted.
+        let decoded = client.decode_transaction(&transaction.id(), None).await.unwrap();
+        let outputs = decoded.transitions.iter().flat_map(|transition| &transition.outputs).collect::<Vec<_>>();
+        assert!(outputs.iter().all(|output| output.kind != "record" || output.value.is_none()));
+
+        // A program that is not deployed is an error.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("missing.aleo").unwrap();
+        assert!(client.program(&program_id).await.is_err());
+
+        // The block policy is switched while the node is running.
+        assert_eq!(client.block_policy().await.unwrap().policy, "interval");
+        assert_eq!(client.set_block_policy("threshold:2").await.unwrap().policy, "threshold:2");
+        assert!(client.set_block_policy("eventually").await.is_err());
+
+        node.shut_down().await;
+    }
+
+    #[tokio::test]
+    async fn test_transfer_and_wait() {
+        let node = TestNode::start().await.unwrap();
+        let client = Client::<CurrentNetwork>::new(&node.endpoint());
+
+        // The faucet account sends the transfers, signed with its account token.
+        let registration = client.register_account(&AccountRegisterRequest::new(*node.private_key())).await.unwrap();
+        let signer = || Signer::Token(registration.token().to_string());
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng()).unwrap();
+        let recipient = Address::try_from(&private_key).unwrap();
+
+        // A request cannot wait longer than the maximum confirmation wait.
+        let request = TransferRequest::new(signer(), recipient, 100, Some(301), None);
+        assert!(client.transfer_and_wait(&request).await.is_err());
+
+        // The transfer is answered once a block confirms it.
+        let request = TransferRequest::new(signer(), recipient, 100, Some(60), None);
+        let transfer = tokio::spawn({
+            let client = client.clone();
+            async move { client.transfer_and_wait(&request).await }
+        });
+        while node.node().consensus().memory_pool().num_unconfirmed_transactions() == 0 {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        node.advance_block().await.unwrap();
+        let response = transfer.await.unwrap().unwrap();
+        assert_eq!(response.block_height(), 1);
+
+        // The recipient record is owned by the recipient, and the change record by the faucet.
+        assert!(response.recipient_record().is_owner(&ViewKey::try_from(&private_key).unwrap()));
+        let records = node.records(&ViewKey::try_from(node.private_key()).unwrap()).unwrap();
+        assert!(records.contains_key(response.change_commitment()));
+        assert!(!records.contains_key(response.recipient_commitment()));
+
+        // A transfer that is not confirmed in time is an error.
+        let request = TransferRequest::new(signer(), recipient, 100, Some(1), None);
+        let error = client.transfer_and_wait(&request).await.unwrap_err();
+        assert!(error.to_string().contains("was not confirmed within 1 seconds"), "{error}");
+
+        node.shut_down().await;
+    }
+
+    #[tokio::test]
+    async fn test_broadcast() {
+        let node = TestNode::start().await.unwrap();
+        let client = Client::<CurrentNetwork>::new(&node.endpoint());
+
+        // Construct a transfer outside of the memory pool of the node.
+        let recipient_key = PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng()).unwrap();
+        let recipient = Address::try_from(&recipient_key).unwrap();
+        let (ledger, private_key) = (node.node().ledger().clone(), *node.private_key());
+        let transaction =
+            tokio::task::spawn_blocking(move || ledger.create_transfer(&private_key, &NodeScan, recipient, 100))
+                .await
+                .unwrap()
+                .unwrap();
+
+        // A valid transaction is accepted into the memory pool.
+        assert_eq!(client.broadcast(&transaction).await.unwrap(), transaction.id());
+        assert!(node.node().consensus().memory_pool().contains_unconfirmed_transaction(transaction.id()));
+
+        // A transaction that is already in the memory pool is rejected.
+        assert!(client.broadcast(&transaction).await.is_err());
+
+        // Once it is confirmed, the transaction is invalid, as its records are spent, and is rejected.
+        node.advance_block().await.unwrap();
+        assert!(client.broadcast(&transaction).await.is_err());
+        assert_eq!(node.node().consensus().memory_pool().num_unconfirmed_transactions(), 0);
+
+        node.shut_down().await;
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        // A node that accepts each connection, but never replies.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let accepted = tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((connection, _)) = listener.accept().await {
+                connections.push(connection);
+            }
+        });
+
+        let retry = RetryPolicy { max_retries: 1, initial_backoff: Duration::from_millis(10), ..Default::default() };
+        let client =
+            Client::<CurrentNetwork>::new(&endpoint).with_timeout(Duration::from_millis(100)).with_retry(retry);
+
+        // A GET request that timed out is retried, and then fails.
+        let start = Instant::now();
+        let error = client.latest_height().await.unwrap_err();
+        assert!(
+            matches!(error.downcast_ref::<ClientError>(), Some(ClientError::Transport(error)) if error.is_timeout())
+        );
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        // A POST request that timed out may have been processed, so it is not retried.
+        let start = Instant::now();
+        assert!(client.pause().await.is_err());
+        assert!(start.elapsed() < Duration::from_millis(200));
+
+        accepted.abort();
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The errors of the requests of a client to a node, besides the typed errors the node responds with,
+/// which are returned as a `SlingshotError`.
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("The node responded with status {code}: {body}")]
+    Status { code: u16, body: String },
+
+    #[error("Failed to reach the node: {}", _0)]
+    Transport(#[from] reqwest::Error),
+}
+
+impl ClientError {
+    /// Returns the HTTP status code of the response, if the node responded.
+    pub const fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::Status { code, .. } => Some(*code),
+            Self::Transport(_) => None,
+        }
+    }
+}
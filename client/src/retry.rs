@@ -0,0 +1,80 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::time::Duration;
+
+/// The policy for retrying the requests a node did not process: the requests that failed to connect, the requests
+/// rejected because the node is busy (`429 Too Many Requests`) or unavailable (`503 Service Unavailable`), and the
+/// GET requests that timed out.
+/// The delay before each retry doubles, up to the maximum backoff, unless the node sets a `Retry-After` header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of retries of a request.
+    pub max_retries: u32,
+    /// The delay before the first retry.
+    pub initial_backoff: Duration,
+    /// The maximum delay before a retry.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self { max_retries: 3, initial_backoff: Duration::from_millis(500), max_backoff: Duration::from_secs(8) }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns a policy that never retries a request.
+    pub const fn none() -> Self {
+        Self { max_retries: 0, initial_backoff: Duration::ZERO, max_backoff: Duration::ZERO }
+    }
+
+    /// Returns the delay before the given retry, starting at zero.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff.saturating_mul(2u32.saturating_pow(retry)).min(self.max_backoff)
+    }
+
+    /// Returns `true` if a response with the given HTTP status code is retried.
+    pub const fn is_retryable(status: u16) -> bool {
+        matches!(status, 429 | 503)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        // The backoff is bounded, even for a large number of retries.
+        assert_eq!(policy.backoff(5), Duration::from_secs(8));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(8));
+
+        assert_eq!(RetryPolicy::none().backoff(0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(RetryPolicy::is_retryable(429));
+        assert!(RetryPolicy::is_retryable(503));
+        assert!(!RetryPolicy::is_retryable(400));
+        assert!(!RetryPolicy::is_retryable(500));
+    }
+}
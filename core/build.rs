@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+// Exposes the snarkVM revision in the manifest to the crate, as `SLINGSHOT_SNARKVM_REVISION`.
+fn export_snarkvm_revision() {
+    let manifest = std::fs::read_to_string("Cargo.toml").expect("Failed to read the manifest");
+    let revision = manifest
+        .split("[dependencies.snarkvm]")
+        .nth(1)
+        .and_then(|section| {
+            section
+                .lines()
+                .take_while(|line| !line.starts_with('['))
+                .find_map(|line| line.trim().strip_prefix("rev = "))
+        })
+        .map(|revision| revision.trim_matches('"').to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=SLINGSHOT_SNARKVM_REVISION={revision}");
+}
+
+// The build script; it exports the snarkVM revision, and compiles the gRPC service definitions.
+fn main() {
+    // Export the snarkVM revision.
+    export_snarkvm_revision();
+
+    // Compile the gRPC service definitions.
+    #[cfg(feature = "grpc")]
+    tonic_build::compile_protos("proto/slingshot.proto").expect("Failed to compile the gRPC service definitions");
+}
@@ -0,0 +1,150 @@
+// A minimal explorer for a slingshot development node, served at `/explorer`.
+// It reads the REST API of the node that serves it, and routes on the URL fragment:
+//   #/                   the latest blocks
+//   #/block/{height}     a block and its transactions
+//   #/transaction/{id}   a transaction and its transitions
+//   #/program/{id}       the source of a program
+
+const API = "/testnet3";
+const NUM_LATEST_BLOCKS = 20;
+
+async function get(route) {
+  const response = await fetch(`${API}${route}`);
+  if (!response.ok) {
+    throw new Error(`${route} failed with status ${response.status}: ${await response.text()}`);
+  }
+  return response.json();
+}
+
+function escape(text) {
+  return String(text).replace(/[&<>"']/g, (c) => `&#${c.charCodeAt(0)};`);
+}
+
+function link(route, text) {
+  return `<a href="#/${route}">${escape(text)}</a>`;
+}
+
+// Returns the transactions of a block, whether they are serialized as a list or as a map.
+function transactionsOf(block) {
+  const transactions = block.transactions || [];
+  return Array.isArray(transactions) ? transactions : Object.values(transactions);
+}
+
+// Returns the transitions of a transaction, including the transition of its fee.
+function transitionsOf(transaction) {
+  const transitions = (transaction.execution && transaction.execution.transitions) || [];
+  return transaction.additional_fee ? transitions.concat([transaction.additional_fee]) : transitions;
+}
+
+function metadataOf(block) {
+  return (block.header && block.header.metadata) || {};
+}
+
+async function latestBlocks() {
+  const height = await get("/latest/height");
+  const start = Math.max(0, height + 1 - NUM_LATEST_BLOCKS);
+  const blocks = await get(`/blocks?start=${start}&end=${height + 1}`);
+  const rows = blocks
+    .reverse()
+    .map((block) => {
+      const metadata = metadataOf(block);
+      return `<tr>
+        <td>${link(`block/${metadata.height}`, metadata.height)}</td>
+        <td class="id">${escape(block.block_hash)}</td>
+        <td>${new Date(metadata.timestamp * 1000).toLocaleString()}</td>
+        <td>${transactionsOf(block).length}</td>
+      </tr>`;
+    })
+    .join("");
+  return `<h2>Latest blocks</h2>
+    <table>
+      <tr><th>Height</th><th>Hash</th><th>Timestamp</th><th>Transactions</th></tr>
+      ${rows}
+    </table>`;
+}
+
+async function block(height) {
+  const block = await get(`/block/${height}`);
+  const metadata = metadataOf(block);
+  const rows = transactionsOf(block)
+    .map((transaction) => `<tr>
+      <td class="id">${link(`transaction/${transaction.id}`, transaction.id)}</td>
+      <td>${escape(transaction.type)}</td>
+      <td>${transitionsOf(transaction).map((transition) => escape(`${transition.program}/${transition.function}`)).join("<br>")}</td>
+    </tr>`)
+    .join("");
+  return `<h2>Block ${escape(metadata.height)}</h2>
+    <p class="id">${escape(block.block_hash)}</p>
+    <p>Previous: ${metadata.height > 0 ? link(`block/${metadata.height - 1}`, block.previous_hash) : "none"}</p>
+    <p>Timestamp: ${new Date(metadata.timestamp * 1000).toLocaleString()}</p>
+    <table>
+      <tr><th>Transaction</th><th>Type</th><th>Functions</th></tr>
+      ${rows}
+    </table>`;
+}
+
+async function transaction(id) {
+  const transaction = await get(`/transaction/${id}`);
+  const blockHash = await get(`/find/blockHash/${id}`);
+  const height = blockHash ? await get(`/height/${blockHash}`) : null;
+  const program = transaction.deployment
+    ? `<p>Deploys ${link(`program/${programIdOf(transaction.deployment.program)}`, programIdOf(transaction.deployment.program))}</p>`
+    : "";
+  const transitions = transitionsOf(transaction)
+    .map((transition) => `<h3>${link(`program/${transition.program}`, transition.program)}/${escape(transition.function)}</h3>
+      <p class="id">${escape(transition.id)}</p>
+      <pre>${escape(JSON.stringify({ inputs: transition.inputs, outputs: transition.outputs, fee: transition.fee }, null, 2))}</pre>`)
+    .join("");
+  return `<h2>Transaction</h2>
+    <p class="id">${escape(transaction.id)}</p>
+    <p>Type: ${escape(transaction.type)}</p>
+    <p>Block: ${height === null ? "unconfirmed" : link(`block/${height}`, height)}</p>
+    ${program}
+    ${transitions}`;
+}
+
+// Returns the ID of a program from its source.
+function programIdOf(source) {
+  const match = /program\s+([^\s;]+);/.exec(source);
+  return match ? match[1] : "unknown";
+}
+
+async function program(id) {
+  const source = await get(`/program/${id}`);
+  return `<h2>Program ${escape(id)}</h2><pre>${escape(source)}</pre>`;
+}
+
+async function render() {
+  const content = document.getElementById("content");
+  const [page, argument] = location.hash.replace(/^#\/?/, "").split("/");
+  try {
+    switch (page) {
+      case "block":
+        content.innerHTML = await block(argument);
+        break;
+      case "transaction":
+        content.innerHTML = await transaction(argument);
+        break;
+      case "program":
+        content.innerHTML = await program(argument);
+        break;
+      default:
+        content.innerHTML = await latestBlocks();
+    }
+  } catch (error) {
+    content.innerHTML = `<p class="error">${escape(error.message)}</p>`;
+  }
+}
+
+async function faucet() {
+  try {
+    const faucet = await get("/faucet/balance");
+    document.getElementById("faucet").textContent = `Faucet: ${faucet.address} (${faucet.balance} gates)`;
+  } catch (error) {
+    document.getElementById("faucet").textContent = "";
+  }
+}
+
+window.addEventListener("hashchange", render);
+faucet();
+render();
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+
+/// The errors of the requests of the CLI to a node, which determine its exit code.
+#[derive(Debug, Error)]
+pub enum NodeError {
+    #[error("{}", _0)]
+    Rejected(String),
+
+    #[error("{}", _0)]
+    Unreachable(String),
+
+    #[error("{}", _0)]
+    Timeout(String),
+
+    #[error("{}", _0)]
+    InvalidSignature(String),
+}
+
+/// The errors of the requests to a node, which the node responds with, and the CLI renders.
+/// The node sends each error as a JSON object, with the snake case name of the variant in `error`,
+/// its fields, and its message in `message`, in a response with the HTTP status code of the error.
+#[derive(Clone, Debug, Error, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "error", rename_all = "snake_case")]
+pub enum SlingshotError {
+    #[error("The Aleo account has no records with at least {required} gates to spend")]
+    InsufficientBalance { required: u64 },
+
+    #[error("The program '{program_id}' is not deployed")]
+    ProgramNotFound { program_id: String },
+
+    #[error("Input {index} must be of type '{expected_type}'")]
+    InvalidInput { index: usize, expected_type: String },
+
+    #[error("The function expects {expected} inputs, but {found} were given")]
+    InvalidInputCount { expected: usize, found: usize },
+
+    #[error(
+        "The record of input {index} is already spent (spent in transaction '{transaction_id}' at height {height})"
+    )]
+    RecordAlreadySpent { index: usize, transaction_id: String, height: u32 },
+
+    #[error("The record '{commitment}' of input {index} is not an unspent record of the signer")]
+    RecordNotFound { index: usize, commitment: String },
+
+    #[error(
+        "The only record with at least {required} gates to pay the fee is input {index}, which the execution spends \
+         (split the record, or pass another record as the input)"
+    )]
+    FeeRecordConflict { index: usize, required: u64 },
+
+    #[error(
+        "The record '{commitment}' is reserved by another transaction of the account, which is being constructed or \
+         is in the memory pool (retry once it is confirmed)"
+    )]
+    RecordReserved { commitment: String },
+
+    #[error("The transaction fee is too low (at least {required} gates are required)")]
+    FeeTooLow { required: u64 },
+
+    #[error("The memory pool is full ({capacity} transactions)")]
+    MempoolFull { capacity: usize },
+
+    #[error(
+        "The node is constructing {max_concurrent_proofs} transactions, and {max_queued_proofs} more are waiting \
+         (retry later)"
+    )]
+    ProverBusy { max_concurrent_proofs: usize, max_queued_proofs: usize },
+
+    #[error("The request body exceeds the limit of {limit} bytes")]
+    BodyTooLarge { limit: u64 },
+
+    #[error("Missing or invalid API token, send it in an 'Authorization: Bearer <TOKEN>' header")]
+    Unauthorized,
+
+    #[error("The account token is not registered with the node (register the account again)")]
+    UnknownAccountToken,
+
+    #[error("The node is shutting down, and accepts no further transactions")]
+    ShuttingDown,
+
+    #[error("Failed to synthesize the keys of '{locator}': {reason}")]
+    DeploySynthesisFailed { locator: String, reason: String },
+
+    #[error("Failed to construct the deployment: {reason}")]
+    DeployConstructionFailed { reason: String },
+
+    #[error("The deploy transaction '{transaction_id}' failed verification")]
+    DeployVerificationFailed { transaction_id: String },
+}
+
+impl SlingshotError {
+    /// Returns the HTTP status code of the error.
+    pub const fn status_code(&self) -> u16 {
+        match self {
+            Self::InvalidInput { .. } | Self::InvalidInputCount { .. } => 400,
+            Self::Unauthorized | Self::UnknownAccountToken => 401,
+            Self::ProgramNotFound { .. } | Self::RecordNotFound { .. } => 404,
+            Self::RecordAlreadySpent { .. } | Self::RecordReserved { .. } => 409,
+            Self::InsufficientBalance { .. } | Self::FeeRecordConflict { .. } | Self::FeeTooLow { .. } => 422,
+            Self::DeploySynthesisFailed { .. } | Self::DeployConstructionFailed { .. } => 422,
+            Self::BodyTooLarge { .. } => 413,
+            Self::MempoolFull { .. } | Self::ProverBusy { .. } => 429,
+            Self::DeployVerificationFailed { .. } => 500,
+            Self::ShuttingDown => 503,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::reject_error;
+
+    #[test]
+    fn test_deploy_error() {
+        let error = SlingshotError::DeploySynthesisFailed {
+            locator: String::from("hello.aleo/main"),
+            reason: String::from("the constraint system is not satisfied"),
+        };
+        assert_eq!(error.status_code(), 422);
+
+        // The error of a deployment stays typed when the request is rejected, so it is rendered with its status code.
+        let rejection = reject_error("failed to construct the transaction", error.clone().into());
+        assert_eq!(rejection.find::<SlingshotError>(), Some(&error));
+
+        // The client decodes the error from the body of the response.
+        let body = serde_json::to_string(&error).unwrap();
+        assert!(body.contains(r#""error":"deploy_synthesis_failed""#));
+        assert_eq!(serde_json::from_str::<SlingshotError>(&body).unwrap(), error);
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+/// The base endpoint of a local development node.
+pub const DEFAULT_ENDPOINT: &str = "http://localhost:4180";
+
+/// The environment variable holding the API token sent with mutating requests, if the node requires one.
+pub const API_TOKEN_ENV: &str = "SLINGSHOT_API_TOKEN";
+
+/// Returns the base endpoint (scheme, host, and port) of the given route endpoint.
+/// For example, `http://localhost:4180/testnet3/program/execute` becomes `http://localhost:4180`.
+pub fn base_endpoint(endpoint: &str) -> String {
+    match endpoint.find("/testnet3") {
+        Some(index) => endpoint[..index].to_string(),
+        None => endpoint.trim_end_matches('/').to_string(),
+    }
+}
+
+/// Returns the given request, carrying the API token in `SLINGSHOT_API_TOKEN`, if it is set.
+pub fn authorized(request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+    match std::env::var(API_TOKEN_ENV) {
+        Ok(api_token) if !api_token.is_empty() => request.bearer_auth(api_token),
+        _ => request,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_endpoint() {
+        assert_eq!(base_endpoint("http://localhost:4180/testnet3/program/execute"), "http://localhost:4180");
+        assert_eq!(base_endpoint("http://localhost:4180/"), "http://localhost:4180");
+        assert_eq!(base_endpoint("http://10.0.0.1:4180"), "http://10.0.0.1:4180");
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Prints a line to the terminal, stripping emoji and colors in plain output mode.
+#[macro_export]
+macro_rules! output {
+    () => {
+        println!()
+    };
+    ($($arg:tt)*) => {
+        println!("{}", $crate::helpers::Output::sanitize(&format!($($arg)*)))
+    };
+}
+
+/// Set if the CLI is in plain output mode.
+static PLAIN: AtomicBool = AtomicBool::new(false);
+
+pub struct Output;
+
+impl Output {
+    /// The ASCII replacements for the symbols used in the CLI output.
+    const REPLACEMENTS: &'static [(&'static str, &'static str)] = &[
+        ("✅", "[ok]"),
+        ("❌", "[error]"),
+        ("⚠️", "[warning]"),
+        ("⏳", "[..]"),
+        ("⏭️", "[skipped]"),
+        ("📦", "[deploy]"),
+        ("🌐", "[rest]"),
+        ("🟢", "[update]"),
+        ("✉️", "[mempool]"),
+        ("✏️", "[changed]"),
+        ("👀", "[watch]"),
+        ("💰", "[fee]"),
+        ("🔑", "[key]"),
+        ("🔗", "[connected]"),
+        ("👋", "[bye]"),
+        ("🔁", "[replay]"),
+        ("🔎", "[transaction]"),
+        ("🧱", "[block]"),
+        ("📜", "[program]"),
+        ("📸", "[snapshot]"),
+        ("➡️", "->"),
+        ("•", "*"),
+        ("—", "-"),
+    ];
+
+    /// Enables or disables plain output mode.
+    pub fn set_plain(plain: bool) {
+        PLAIN.store(plain, Ordering::SeqCst);
+        if plain {
+            colored::control::set_override(false);
+        }
+    }
+
+    /// Returns `true` if the CLI is in plain output mode.
+    pub fn is_plain() -> bool {
+        PLAIN.load(Ordering::SeqCst)
+    }
+
+    /// Returns the given message, converted to ASCII if the CLI is in plain output mode.
+    pub fn sanitize(message: &str) -> String {
+        match Self::is_plain() {
+            true => Self::to_ascii(message),
+            false => message.to_string(),
+        }
+    }
+
+    /// Returns the given message with ANSI escape codes removed, symbols replaced, and all other non-ASCII characters dropped.
+    pub fn to_ascii(message: &str) -> String {
+        // Replace the known symbols.
+        let mut message = message.to_string();
+        for (symbol, replacement) in Self::REPLACEMENTS {
+            message = message.replace(symbol, replacement);
+        }
+
+        // Remove the ANSI escape codes and the remaining non-ASCII characters.
+        let mut output = String::with_capacity(message.len());
+        let mut characters = message.chars().peekable();
+        while let Some(character) = characters.next() {
+            match character {
+                '\u{1b}' => {
+                    // Skip the escape sequence up to and including its terminating letter.
+                    if characters.peek() == Some(&'[') {
+                        for character in characters.by_ref() {
+                            if character.is_ascii_alphabetic() {
+                                break;
+                            }
+                        }
+                    }
+                }
+                character if character.is_ascii() => output.push(character),
+                _ => {}
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_ascii() {
+        assert_eq!(Output::to_ascii("✅ Poured 10 Aleo credits."), "[ok] Poured 10 Aleo credits.");
+        assert_eq!(Output::to_ascii("⚠️  Failed"), "[warning]  Failed");
+        assert_eq!(Output::to_ascii("\u{1b}[1mbold\u{1b}[0m text"), "bold text");
+        assert_eq!(Output::to_ascii("🚀 launch"), " launch");
+    }
+
+    #[test]
+    fn test_replacements_are_ascii() {
+        for (symbol, replacement) in Output::REPLACEMENTS {
+            assert!(replacement.is_ascii(), "The replacement of '{symbol}' is not ASCII");
+            assert_eq!(Output::to_ascii(symbol), *replacement);
+        }
+        assert_eq!(Output::to_ascii("🔑 Generated the API token"), "[key] Generated the API token");
+        assert_eq!(Output::to_ascii("unfunded — run `slingshot pour`"), "unfunded - run `slingshot pour`");
+    }
+}
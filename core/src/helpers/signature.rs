@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::NodeError;
+
+use snarkvm::prelude::{Address, Network, Signature};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+use time::OffsetDateTime;
+
+/// The header in which a node signs each response, if it signs responses.
+pub const SIGNATURE_HEADER: &str = "x-node-signature";
+
+/// The header with the time at which a node signed a response, in seconds since the UNIX epoch.
+pub const SIGNATURE_TIMESTAMP_HEADER: &str = "x-node-signature-timestamp";
+
+/// The maximum number of seconds between the time a response was signed and the time it is received.
+pub const MAX_SIGNATURE_AGE_IN_SECS: i64 = 300;
+
+/// The signature of a response, with the request and the time it covers.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ResponseSignature {
+    /// The method of the request.
+    pub method: String,
+    /// The path of the request.
+    pub path: String,
+    /// The time at which the response was signed, from the `X-Node-Signature-Timestamp` header, if any.
+    pub timestamp: Option<String>,
+    /// The signature of the response, from the `X-Node-Signature` header, if any.
+    pub signature: Option<String>,
+}
+
+impl ResponseSignature {
+    /// Returns the bytes a node signs for a response to a request with the given method and path, at the given time:
+    /// the method, the path, and the timestamp, each followed by a newline, and the SHA-256 hash of the body.
+    pub fn message(method: &str, path: &str, timestamp: i64, body: &[u8]) -> Vec<u8> {
+        [format!("{method}\n{path}\n{timestamp}\n").as_bytes(), &Sha256::digest(body)].concat()
+    }
+
+    /// Ensures the signature of the given body, the request, and the time was produced by the given node account,
+    /// if responses are verified, i.e. if the address of the node account is given.
+    pub fn verify<N: Network>(&self, address: Option<&Address<N>>, body: &[u8]) -> Result<()> {
+        let address = match address {
+            Some(address) => address,
+            None => return Ok(()),
+        };
+        let (signature, timestamp) = match (&self.signature, &self.timestamp) {
+            (Some(signature), Some(timestamp)) => (signature, timestamp),
+            _ => {
+                return Err(NodeError::InvalidSignature(
+                    "The response is not signed — start the node with '--sign-responses'".to_string(),
+                )
+                .into());
+            }
+        };
+        let signature = Signature::<N>::from_str(signature).map_err(|error| {
+            NodeError::InvalidSignature(format!("The response signature is malformed: {error}"))
+        })?;
+        let timestamp = timestamp.parse::<i64>().map_err(|error| {
+            NodeError::InvalidSignature(format!("The response signature timestamp is malformed: {error}"))
+        })?;
+        match signature.verify_bytes(address, &Self::message(&self.method, &self.path, timestamp, body)) {
+            true => Ok(()),
+            false => Err(NodeError::InvalidSignature(format!(
+                "The response to '{} {}' was not signed by {address}",
+                self.method, self.path
+            ))
+            .into()),
+        }
+    }
+
+    /// Ensures the given body was signed by the given node account, as in `verify`, and that it was signed within
+    /// `MAX_SIGNATURE_AGE_IN_SECS` seconds, so that an older response to the same request cannot be replayed.
+    pub fn verify_fresh<N: Network>(&self, address: Option<&Address<N>>, body: &[u8]) -> Result<()> {
+        self.verify(address, body)?;
+        // The timestamp of a verified signature is well-formed.
+        if let Some(timestamp) = self.timestamp.as_ref().filter(|_| address.is_some()) {
+            let age = OffsetDateTime::now_utc().unix_timestamp().saturating_sub(timestamp.parse()?);
+            if age.abs() > MAX_SIGNATURE_AGE_IN_SECS {
+                return Err(NodeError::InvalidSignature(format!(
+                    "The response to '{} {}' was signed {age} seconds ago, which exceeds {MAX_SIGNATURE_AGE_IN_SECS} \
+                     seconds",
+                    self.method, self.path
+                ))
+                .into());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::prelude::{PrivateKey, Testnet3};
+
+    #[test]
+    fn test_verify() {
+        let rng = &mut rand::thread_rng();
+        let private_key = PrivateKey::<Testnet3>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let (body, now) = (b"{\"height\":1}", OffsetDateTime::now_utc().unix_timestamp());
+        let mut sign = |timestamp: i64| {
+            let message = ResponseSignature::message("GET", "/testnet3/latest/height", timestamp, body);
+            ResponseSignature {
+                method: "GET".to_string(),
+                path: "/testnet3/latest/height".to_string(),
+                timestamp: Some(timestamp.to_string()),
+                signature: Some(private_key.sign_bytes(&message, rng).unwrap().to_string()),
+            }
+        };
+
+        // A fresh signature of the request and the body is accepted.
+        let signature = sign(now);
+        assert!(signature.verify_fresh(Some(&address), body).is_ok());
+
+        // A signature of another body, request, or time is rejected.
+        assert!(signature.verify(Some(&address), b"{\"height\":2}").is_err());
+        let other_path = ResponseSignature { path: "/testnet3/latest/hash".to_string(), ..signature.clone() };
+        assert!(other_path.verify(Some(&address), body).is_err());
+        let other_time = ResponseSignature { timestamp: Some((now + 1).to_string()), ..signature.clone() };
+        assert!(other_time.verify(Some(&address), body).is_err());
+
+        // An old signature is valid, but not fresh.
+        let signature = sign(now - MAX_SIGNATURE_AGE_IN_SECS - 60);
+        assert!(signature.verify(Some(&address), body).is_ok());
+        assert!(signature.verify_fresh(Some(&address), body).is_err());
+
+        // An unsigned response is rejected, unless responses are not verified.
+        let unsigned = ResponseSignature { signature: None, ..signature };
+        assert!(unsigned.verify(Some(&address), body).is_err());
+        assert!(unsigned.verify_fresh::<Testnet3>(None, body).is_ok());
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::messages::{check_version, VERSION};
+
+use snarkvm::prelude::{Address, Network, PrivateKey};
+
+use anyhow::{bail, Result};
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use warp::{reply::Response, Reply};
+
+/// The account that signs a deploy or execute request.
+#[derive(Clone, Debug)]
+pub enum Signer<N: Network> {
+    /// A token for an account registered with the node.
+    Token(String),
+    /// A raw private key (legacy).
+    PrivateKey(PrivateKey<N>),
+}
+
+impl<N: Network> Signer<N> {
+    /// Serializes the signer into the given JSON object.
+    pub(crate) fn serialize_field<S: SerializeStruct>(&self, request: &mut S) -> Result<(), S::Error> {
+        match self {
+            Self::Token(token) => request.serialize_field("account_token", token),
+            Self::PrivateKey(private_key) => request.serialize_field("private_key", &private_key.to_string()),
+        }
+    }
+
+    /// Deserializes the signer from the given JSON object.
+    pub(crate) fn from_value(request: &mut serde_json::Value) -> Result<Self> {
+        match (request["account_token"].take(), request["private_key"].take()) {
+            (serde_json::Value::Null, serde_json::Value::Null) => {
+                bail!("The request must contain either an 'account_token' or a 'private_key'")
+            }
+            (token, serde_json::Value::Null) => Ok(Self::Token(serde_json::from_value(token)?)),
+            (serde_json::Value::Null, private_key) => Ok(Self::PrivateKey(serde_json::from_value(private_key)?)),
+            (_, _) => bail!("The request cannot contain both an 'account_token' and a 'private_key'"),
+        }
+    }
+}
+
+/// A request to register an account with the node, which carries its private key.
+/// A request that carries the token of an account registered before confirms that the node still knows the token,
+/// so that a client that cached the token does not send the private key again.
+pub struct AccountRegisterRequest<N: Network> {
+    signer: Signer<N>,
+}
+
+impl<N: Network> AccountRegisterRequest<N> {
+    /// Initializes a new instance of an account register request.
+    pub fn new(private_key: PrivateKey<N>) -> Self {
+        Self { signer: Signer::PrivateKey(private_key) }
+    }
+
+    /// Initializes a new instance of an account register request, for an account registered with the given token.
+    pub fn from_token(token: String) -> Self {
+        Self { signer: Signer::Token(token) }
+    }
+
+    /// Returns the private key of the account to register, or the token of the registered account.
+    pub const fn signer(&self) -> &Signer<N> {
+        &self.signer
+    }
+}
+
+impl<N: Network> Serialize for AccountRegisterRequest<N> {
+    /// Serializes the account register request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut request = serializer.serialize_struct("AccountRegisterRequest", 1)?;
+        // Serialize the private key or the account token.
+        self.signer.serialize_field(&mut request)?;
+        request.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for AccountRegisterRequest<N> {
+    /// Deserializes the account register request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the request from a string into a value.
+        let mut request = serde_json::Value::deserialize(deserializer)?;
+        // Recover the leaf.
+        Ok(Self {
+            // Retrieve the private key or the account token.
+            signer: Signer::from_value(&mut request).map_err(de::Error::custom)?,
+        })
+    }
+}
+
+pub struct AccountRegisterResponse<N: Network> {
+    token: String,
+    address: Address<N>,
+}
+
+impl<N: Network> AccountRegisterResponse<N> {
+    /// Initializes a new account register response.
+    pub const fn new(token: String, address: Address<N>) -> Self {
+        Self { token, address }
+    }
+
+    /// Returns the token that references the registered account.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns the address of the registered account.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+}
+
+impl<N: Network> Serialize for AccountRegisterResponse<N> {
+    /// Serializes the account register response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("AccountRegisterResponse", 3)?;
+        response.serialize_field("token", &self.token)?;
+        response.serialize_field("address", &self.address)?;
+        response.serialize_field("version", &VERSION)?;
+        response.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for AccountRegisterResponse<N> {
+    /// Deserializes the account register response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version of the response is supported.
+        check_version(&response).map_err(de::Error::custom)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the token.
+            serde_json::from_value(response["token"].take()).map_err(de::Error::custom)?,
+            // Retrieve the address.
+            serde_json::from_value(response["address"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+impl<N: Network> Reply for AccountRegisterResponse<N> {
+    fn into_response(self) -> Response {
+        warp::reply::json(&self).into_response()
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::messages::{check_version, Signer, VERSION};
+
+use snarkvm::prelude::Network;
+
+use anyhow::Result;
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use warp::{reply::Response, Reply};
+
+pub struct ConsolidateRequest<N: Network> {
+    signer: Signer<N>,
+    target_records: Option<usize>,
+    max_rounds: Option<usize>,
+    timeout: Option<u64>,
+}
+
+impl<N: Network> ConsolidateRequest<N> {
+    /// Initializes a new instance of a consolidate request.
+    pub fn new(
+        signer: Signer<N>,
+        target_records: Option<usize>,
+        max_rounds: Option<usize>,
+        timeout: Option<u64>,
+    ) -> Self {
+        Self { signer, target_records, max_rounds, timeout }
+    }
+
+    /// Returns the signer of the account whose records are consolidated.
+    pub const fn signer(&self) -> &Signer<N> {
+        &self.signer
+    }
+
+    /// Returns the number of records to consolidate the credits into, if given.
+    pub const fn target_records(&self) -> Option<usize> {
+        self.target_records
+    }
+
+    /// Returns the maximum number of rounds of joins, if given.
+    pub const fn max_rounds(&self) -> Option<usize> {
+        self.max_rounds
+    }
+
+    /// Returns the number of seconds to wait for the rounds to be confirmed.
+    pub const fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<N: Network> Serialize for ConsolidateRequest<N> {
+    /// Serializes the consolidate request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut request = serializer.serialize_struct("ConsolidateRequest", 4)?;
+        // Serialize the signer.
+        self.signer.serialize_field(&mut request)?;
+        // Serialize the target_records.
+        request.serialize_field("target_records", &self.target_records)?;
+        // Serialize the max_rounds.
+        request.serialize_field("max_rounds", &self.max_rounds)?;
+        // Serialize the timeout.
+        request.serialize_field("timeout", &self.timeout)?;
+        request.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ConsolidateRequest<N> {
+    /// Deserializes the consolidate request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the request from a string into a value.
+        let mut request = serde_json::Value::deserialize(deserializer)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the signer.
+            Signer::from_value(&mut request).map_err(de::Error::custom)?,
+            // Retrieve the target_records.
+            serde_json::from_value(request["target_records"].take()).map_err(de::Error::custom)?,
+            // Retrieve the max_rounds.
+            serde_json::from_value(request["max_rounds"].take()).map_err(de::Error::custom)?,
+            // Retrieve the timeout.
+            serde_json::from_value(request["timeout"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+/// A round of a consolidation: the joins of pairs of records, which are included in the same block.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ConsolidationRound<N: Network> {
+    /// The IDs of the join transactions.
+    pub transaction_ids: Vec<N::TransactionID>,
+    /// The height of the block that includes the joins.
+    pub block_height: u32,
+    /// The number of unspent credits records after the round.
+    pub num_records: usize,
+}
+
+pub struct ConsolidateResponse<N: Network> {
+    rounds: Vec<ConsolidationRound<N>>,
+    num_records: usize,
+    balance: u64,
+}
+
+impl<N: Network> ConsolidateResponse<N> {
+    /// Initializes a new consolidate response.
+    pub const fn new(rounds: Vec<ConsolidationRound<N>>, num_records: usize, balance: u64) -> Self {
+        Self { rounds, num_records, balance }
+    }
+
+    /// Returns the rounds of joins, which is empty if the records needed no consolidation.
+    pub fn rounds(&self) -> &[ConsolidationRound<N>] {
+        &self.rounds
+    }
+
+    /// Returns the number of unspent credits records of the account.
+    pub const fn num_records(&self) -> usize {
+        self.num_records
+    }
+
+    /// Returns the gates in the unspent credits records of the account.
+    pub const fn balance(&self) -> u64 {
+        self.balance
+    }
+}
+
+impl<N: Network> Serialize for ConsolidateResponse<N> {
+    /// Serializes the consolidate response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("ConsolidateResponse", 4)?;
+        response.serialize_field("rounds", &self.rounds)?;
+        response.serialize_field("num_records", &self.num_records)?;
+        response.serialize_field("balance", &self.balance)?;
+        response.serialize_field("version", &VERSION)?;
+        response.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ConsolidateResponse<N> {
+    /// Deserializes the consolidate response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version of the response is supported.
+        check_version(&response).map_err(de::Error::custom)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the rounds.
+            serde_json::from_value(response["rounds"].take()).map_err(de::Error::custom)?,
+            // Retrieve the num_records.
+            serde_json::from_value(response["num_records"].take()).map_err(de::Error::custom)?,
+            // Retrieve the balance.
+            serde_json::from_value(response["balance"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+impl<N: Network> Reply for ConsolidateResponse<N> {
+    fn into_response(self) -> Response {
+        warp::reply::json(&self).into_response()
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::messages::{check_version, RecordSource, Signer, VERSION};
+
+use snarkvm::prelude::{Identifier, Network, Program};
+
+use anyhow::{ensure, Result};
+use indexmap::IndexMap;
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use warp::{reply::Response, Reply};
+
+/// The maximum size of the metadata of a program, in bytes, when serialized to JSON.
+pub const MAX_PROGRAM_METADATA_SIZE_IN_BYTES: usize = 16 * 1024;
+
+/// The metadata attached to the deployment of a program, which documents it for the users of a shared node.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramMetadata {
+    /// A description of the program.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// The URL of the source repository of the program.
+    #[serde(default)]
+    pub repository: Option<String>,
+    /// The documentation of each function, by function name.
+    #[serde(default)]
+    pub functions: IndexMap<String, String>,
+}
+
+impl ProgramMetadata {
+    /// Ensures the metadata is within the size limit, and only documents functions of the given program.
+    pub fn check<N: Network>(&self, program: &Program<N>) -> Result<()> {
+        let size_in_bytes = serde_json::to_vec(self)?.len();
+        ensure!(
+            size_in_bytes <= MAX_PROGRAM_METADATA_SIZE_IN_BYTES,
+            "The metadata is {size_in_bytes} bytes, which exceeds {MAX_PROGRAM_METADATA_SIZE_IN_BYTES} bytes"
+        );
+        for function_name in self.functions.keys() {
+            ensure!(
+                program.functions().keys().any(|function| function.to_string() == *function_name),
+                "The metadata documents '{function_name}', which is not a function of '{}'",
+                program.id()
+            );
+        }
+        Ok(())
+    }
+}
+
+pub struct DeployRequest<N: Network> {
+    signer: Signer<N>,
+    program: Program<N>,
+    additional_fee: u64,
+    metadata: Option<ProgramMetadata>,
+    record_source: Option<RecordSource<N>>,
+}
+
+impl<N: Network> DeployRequest<N> {
+    /// Initializes a new instance of the deploy request.
+    pub fn new(
+        signer: Signer<N>,
+        program: Program<N>,
+        additional_fee: u64,
+        metadata: Option<ProgramMetadata>,
+        record_source: Option<RecordSource<N>>,
+    ) -> Self {
+        Self { signer, program, additional_fee, metadata, record_source }
+    }
+
+    /// Returns the signer of the account deploying the program.
+    pub const fn signer(&self) -> &Signer<N> {
+        &self.signer
+    }
+
+    /// Returns the program to be deployed.
+    pub const fn program(&self) -> &Program<N> {
+        &self.program
+    }
+
+    /// Returns the additional fee associated with the request.
+    pub const fn additional_fee(&self) -> u64 {
+        self.additional_fee
+    }
+
+    /// Returns the metadata of the program, if any.
+    pub const fn metadata(&self) -> Option<&ProgramMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns the source of the record that pays the fee, if any.
+    pub const fn record_source(&self) -> Option<&RecordSource<N>> {
+        self.record_source.as_ref()
+    }
+}
+
+impl<N: Network> Serialize for DeployRequest<N> {
+    /// Serializes the deploy request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut request = serializer.serialize_struct("DeployRequest", 5)?;
+        // Serialize the signer.
+        self.signer.serialize_field(&mut request)?;
+        // Serialize the program.
+        request.serialize_field("program", &self.program)?;
+        // Serialize the additional_fee.
+        request.serialize_field("additional_fee", &self.additional_fee)?;
+        // Serialize the metadata.
+        request.serialize_field("metadata", &self.metadata)?;
+        // Serialize the record_source.
+        request.serialize_field("record_source", &self.record_source)?;
+        request.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for DeployRequest<N> {
+    /// Deserializes the deploy request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the request from a string into a value.
+        let mut request = serde_json::Value::deserialize(deserializer)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the signer.
+            Signer::from_value(&mut request).map_err(de::Error::custom)?,
+            // Retrieve the program.
+            serde_json::from_value(request["program"].take()).map_err(de::Error::custom)?,
+            // Retrieve the additional_fee.
+            serde_json::from_value(request["additional_fee"].take()).map_err(de::Error::custom)?,
+            // Retrieve the metadata.
+            serde_json::from_value(request["metadata"].take()).map_err(de::Error::custom)?,
+            // Retrieve the record_source.
+            serde_json::from_value(request["record_source"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+/// The cost of a deployment: the constraints and the key sizes of each function, and the storage fee.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct DeploymentCost<N: Network> {
+    /// The cost of each function of the program.
+    pub functions: Vec<FunctionCost<N>>,
+    /// The size of the deploy transaction in bytes.
+    pub size_in_bytes: usize,
+    /// The minimum fee in gates that covers the storage of the deploy transaction.
+    pub storage_fee: u64,
+}
+
+/// The cost of a function of a deployed program.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct FunctionCost<N: Network> {
+    /// The function name.
+    pub function_name: Identifier<N>,
+    /// The number of constraints of the circuit of the function.
+    pub num_constraints: usize,
+    /// The size of the proving key of the function in bytes.
+    pub proving_key_size_in_bytes: usize,
+    /// The size of the verifying key of the function in bytes, which is stored in the deployment.
+    pub verifying_key_size_in_bytes: usize,
+}
+
+pub struct DeployResponse<N: Network> {
+    transaction_id: N::TransactionID,
+    sequence: Option<u64>,
+    cost: DeploymentCost<N>,
+}
+
+impl<N: Network> DeployResponse<N> {
+    /// Initializes a new deploy response.
+    pub const fn new(transaction_id: N::TransactionID, sequence: Option<u64>, cost: DeploymentCost<N>) -> Self {
+        Self { transaction_id, sequence, cost }
+    }
+
+    /// Returns the associated deployment.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the sequence number assigned to the request by the node, if account sequences are enabled.
+    pub const fn sequence(&self) -> Option<u64> {
+        self.sequence
+    }
+
+    /// Returns the cost of the deployment.
+    pub const fn cost(&self) -> &DeploymentCost<N> {
+        &self.cost
+    }
+}
+
+impl<N: Network> Serialize for DeployResponse<N> {
+    /// Serializes the deploy response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("DeployResponse", 4)?;
+        response.serialize_field("transaction_id", &self.transaction_id)?;
+        response.serialize_field("sequence", &self.sequence)?;
+        response.serialize_field("cost", &self.cost)?;
+        response.serialize_field("version", &VERSION)?;
+        response.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for DeployResponse<N> {
+    /// Deserializes the deploy response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version of the response is supported.
+        check_version(&response).map_err(de::Error::custom)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the transaction_id.
+            serde_json::from_value(response["transaction_id"].take()).map_err(de::Error::custom)?,
+            // Retrieve the sequence.
+            serde_json::from_value(response["sequence"].take()).map_err(de::Error::custom)?,
+            // Retrieve the cost.
+            serde_json::from_value(response["cost"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+impl<N: Network> Reply for DeployResponse<N> {
+    fn into_response(self) -> Response {
+        warp::reply::json(&self).into_response()
+    }
+}
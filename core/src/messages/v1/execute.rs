@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::messages::{check_version, RecordSource, Signer, VERSION};
+
+use snarkvm::prelude::{Field, Identifier, Network, Plaintext, ProgramID, Record, Value};
+
+use anyhow::{bail, Result};
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use warp::{reply::Response, Reply};
+
+/// An input of an execute request: a value, or a record of the signer referenced by its commitment,
+/// as `{ "record_commitment": "..." }`, which the node resolves to the unspent record before executing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExecuteInput<N: Network> {
+    Value(Value<N>),
+    RecordCommitment(Field<N>),
+}
+
+impl<N: Network> From<Value<N>> for ExecuteInput<N> {
+    fn from(value: Value<N>) -> Self {
+        Self::Value(value)
+    }
+}
+
+impl<N: Network> Serialize for ExecuteInput<N> {
+    /// Serializes the input as a value, or as an object with the commitment of the record.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Value(value) => value.serialize(serializer),
+            Self::RecordCommitment(commitment) => {
+                let mut input = serializer.serialize_struct("ExecuteInput", 1)?;
+                input.serialize_field("record_commitment", commitment)?;
+                input.end()
+            }
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ExecuteInput<N> {
+    /// Deserializes the input from a value, or from an object with the commitment of the record.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut input = serde_json::Value::deserialize(deserializer)?;
+        match input.get("record_commitment") {
+            Some(_) => Ok(Self::RecordCommitment(
+                serde_json::from_value(input["record_commitment"].take()).map_err(de::Error::custom)?,
+            )),
+            None => Ok(Self::Value(serde_json::from_value(input).map_err(de::Error::custom)?)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ExecuteRequest<N: Network> {
+    signer: Signer<N>,
+    program_id: ProgramID<N>,
+    function_name: Identifier<N>,
+    inputs: Vec<ExecuteInput<N>>,
+    additional_fee: Option<u64>,
+    record_source: Option<RecordSource<N>>,
+}
+
+impl<N: Network> ExecuteRequest<N> {
+    /// Initializes a new instance of a execute request.
+    /// Each input is a value, or an `ExecuteInput::RecordCommitment` for a record of the signer.
+    pub fn new(
+        signer: Signer<N>,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: Vec<impl Into<ExecuteInput<N>>>,
+        additional_fee: Option<u64>,
+        record_source: Option<RecordSource<N>>,
+    ) -> Self {
+        let inputs = inputs.into_iter().map(Into::into).collect();
+        Self { signer, program_id, function_name, inputs, additional_fee, record_source }
+    }
+
+    /// Returns the signer.
+    pub const fn signer(&self) -> &Signer<N> {
+        &self.signer
+    }
+
+    /// Returns the program_id.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function_name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the inputs.
+    pub fn inputs(&self) -> &[ExecuteInput<N>] {
+        &self.inputs
+    }
+
+    /// Replaces each record input referenced by commitment with the record returned by the given function,
+    /// which is given the index of the input and the commitment.
+    pub fn resolve_inputs(
+        &mut self,
+        mut resolve: impl FnMut(usize, &Field<N>) -> Result<Record<N, Plaintext<N>>>,
+    ) -> Result<()> {
+        for (index, input) in self.inputs.iter_mut().enumerate() {
+            if let ExecuteInput::RecordCommitment(commitment) = input {
+                *input = ExecuteInput::Value(Value::Record(resolve(index, commitment)?));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the values of the inputs, which requires the record inputs referenced by commitment to be resolved.
+    pub fn values(&self) -> Result<Vec<Value<N>>> {
+        let mut values = Vec::with_capacity(self.inputs.len());
+        for (index, input) in self.inputs.iter().enumerate() {
+            match input {
+                ExecuteInput::Value(value) => values.push(value.clone()),
+                ExecuteInput::RecordCommitment(commitment) => {
+                    bail!("Input {index} refers to the record '{commitment}', which is not resolved")
+                }
+            }
+        }
+        Ok(values)
+    }
+
+    /// Returns the additional_fee.
+    pub const fn additional_fee(&self) -> Option<u64> {
+        self.additional_fee
+    }
+
+    /// Returns the source of the record that pays the additional fee, if any.
+    pub const fn record_source(&self) -> Option<&RecordSource<N>> {
+        self.record_source.as_ref()
+    }
+}
+
+impl<N: Network> Serialize for ExecuteRequest<N> {
+    /// Serializes the execute request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut request = serializer.serialize_struct("ExecuteRequest", 6)?;
+        // Serialize the signer.
+        self.signer.serialize_field(&mut request)?;
+        // Serialize the program_id.
+        request.serialize_field("program_id", &self.program_id)?;
+        // Serialize the function_name.
+        request.serialize_field("function_name", &self.function_name)?;
+        // Serialize the inputs.
+        request.serialize_field("inputs", &self.inputs)?;
+        // Serialize the additional_fee.
+        request.serialize_field("additional_fee", &self.additional_fee)?;
+        // Serialize the record_source.
+        request.serialize_field("record_source", &self.record_source)?;
+        request.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ExecuteRequest<N> {
+    /// Deserializes the execute request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the request from a string into a value.
+        let mut request = serde_json::Value::deserialize(deserializer)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the signer.
+            Signer::from_value(&mut request).map_err(de::Error::custom)?,
+            // Retrieve the program_id.
+            serde_json::from_value(request["program_id"].take()).map_err(de::Error::custom)?,
+            // Retrieve the function_name.
+            serde_json::from_value(request["function_name"].take()).map_err(de::Error::custom)?,
+            // Retrieve the inputs.
+            serde_json::from_value::<Vec<ExecuteInput<N>>>(request["inputs"].take()).map_err(de::Error::custom)?,
+            // Retrieve the additional_fee.
+            serde_json::from_value(request["additional_fee"].take()).map_err(de::Error::custom)?,
+            // Retrieve the record_source.
+            serde_json::from_value(request["record_source"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+pub struct ExecuteResponse<N: Network> {
+    transaction_id: N::TransactionID,
+    sequence: Option<u64>,
+}
+
+impl<N: Network> ExecuteResponse<N> {
+    /// Initializes a new execute response.
+    pub const fn new(transaction_id: N::TransactionID, sequence: Option<u64>) -> Self {
+        Self { transaction_id, sequence }
+    }
+
+    /// Returns the transaction ID associated with the exeucte request.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the sequence number assigned to the request by the node, if account sequences are enabled.
+    pub const fn sequence(&self) -> Option<u64> {
+        self.sequence
+    }
+}
+
+impl<N: Network> Serialize for ExecuteResponse<N> {
+    /// Serializes the execute response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("ExecuteResponse", 3)?;
+        response.serialize_field("transaction_id", &self.transaction_id)?;
+        response.serialize_field("sequence", &self.sequence)?;
+        response.serialize_field("version", &VERSION)?;
+        response.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ExecuteResponse<N> {
+    /// Deserializes the execute response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version of the response is supported.
+        check_version(&response).map_err(de::Error::custom)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the transaction_id.
+            serde_json::from_value(response["transaction_id"].take()).map_err(de::Error::custom)?,
+            // Retrieve the sequence.
+            serde_json::from_value(response["sequence"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+impl<N: Network> Reply for ExecuteResponse<N> {
+    /// Converts the execute response into a response.
+    fn into_response(self) -> Response {
+        warp::reply::json(&self).into_response()
+    }
+}
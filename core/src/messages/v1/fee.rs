@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::messages::{check_version, Signer, VERSION};
+
+use snarkvm::prelude::{Identifier, Network, Program, ProgramID, Value};
+
+use anyhow::Result;
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use warp::{reply::Response, Reply};
+
+/// The transaction to estimate the fee for.
+#[derive(Clone, Debug)]
+pub enum FeeEstimate<N: Network> {
+    /// A deployment of the given program.
+    Deploy(Program<N>),
+    /// An execution of the given function with the given inputs.
+    Execute(ProgramID<N>, Identifier<N>, Vec<Value<N>>),
+}
+
+#[derive(Debug)]
+pub struct FeeEstimateRequest<N: Network> {
+    signer: Signer<N>,
+    estimate: FeeEstimate<N>,
+}
+
+impl<N: Network> FeeEstimateRequest<N> {
+    /// Initializes a new instance of a fee estimate request.
+    pub fn new(signer: Signer<N>, estimate: FeeEstimate<N>) -> Self {
+        Self { signer, estimate }
+    }
+
+    /// Returns the signer.
+    pub const fn signer(&self) -> &Signer<N> {
+        &self.signer
+    }
+
+    /// Returns the transaction to estimate the fee for.
+    pub const fn estimate(&self) -> &FeeEstimate<N> {
+        &self.estimate
+    }
+}
+
+impl<N: Network> Serialize for FeeEstimateRequest<N> {
+    /// Serializes the fee estimate request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.estimate {
+            FeeEstimate::Deploy(program) => {
+                let mut request = serializer.serialize_struct("FeeEstimateRequest", 2)?;
+                // Serialize the signer.
+                self.signer.serialize_field(&mut request)?;
+                // Serialize the program.
+                request.serialize_field("program", program)?;
+                request.end()
+            }
+            FeeEstimate::Execute(program_id, function_name, inputs) => {
+                let mut request = serializer.serialize_struct("FeeEstimateRequest", 4)?;
+                // Serialize the signer.
+                self.signer.serialize_field(&mut request)?;
+                // Serialize the program_id.
+                request.serialize_field("program_id", program_id)?;
+                // Serialize the function_name.
+                request.serialize_field("function_name", function_name)?;
+                // Serialize the inputs.
+                request.serialize_field("inputs", inputs)?;
+                request.end()
+            }
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for FeeEstimateRequest<N> {
+    /// Deserializes the fee estimate request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the request from a string into a value.
+        let mut request = serde_json::Value::deserialize(deserializer)?;
+        // Retrieve the signer.
+        let signer = Signer::from_value(&mut request).map_err(de::Error::custom)?;
+        // Retrieve the deployment, or the execution.
+        let estimate = match request["program"].take() {
+            serde_json::Value::Null => FeeEstimate::Execute(
+                // Retrieve the program_id.
+                serde_json::from_value(request["program_id"].take()).map_err(de::Error::custom)?,
+                // Retrieve the function_name.
+                serde_json::from_value(request["function_name"].take()).map_err(de::Error::custom)?,
+                // Retrieve the inputs.
+                serde_json::from_value(request["inputs"].take()).map_err(de::Error::custom)?,
+            ),
+            program => FeeEstimate::Deploy(serde_json::from_value(program).map_err(de::Error::custom)?),
+        };
+        Ok(Self::new(signer, estimate))
+    }
+}
+
+pub struct FeeEstimateResponse {
+    size_in_bytes: usize,
+    fee: u64,
+}
+
+impl FeeEstimateResponse {
+    /// Initializes a new fee estimate response.
+    pub const fn new(size_in_bytes: usize, fee: u64) -> Self {
+        Self { size_in_bytes, fee }
+    }
+
+    /// Returns the size of the estimated transaction in bytes.
+    pub const fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
+    /// Returns the minimum fee in gates that covers the storage of the transaction.
+    pub const fn fee(&self) -> u64 {
+        self.fee
+    }
+}
+
+impl Serialize for FeeEstimateResponse {
+    /// Serializes the fee estimate response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("FeeEstimateResponse", 3)?;
+        response.serialize_field("size_in_bytes", &self.size_in_bytes)?;
+        response.serialize_field("fee", &self.fee)?;
+        response.serialize_field("version", &VERSION)?;
+        response.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for FeeEstimateResponse {
+    /// Deserializes the fee estimate response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version of the response is supported.
+        check_version(&response).map_err(de::Error::custom)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the size_in_bytes.
+            serde_json::from_value(response["size_in_bytes"].take()).map_err(de::Error::custom)?,
+            // Retrieve the fee.
+            serde_json::from_value(response["fee"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+impl Reply for FeeEstimateResponse {
+    /// Converts the fee estimate response into a response.
+    fn into_response(self) -> Response {
+        warp::reply::json(&self).into_response()
+    }
+}
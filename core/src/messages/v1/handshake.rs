@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::messages::VERSION;
+
+use snarkvm::prelude::Network;
+
+use anyhow::Result;
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use warp::{reply::Response, Reply};
+
+/// The snarkVM revision this build of slingshot depends on, read from the manifest by the build script.
+pub const SNARKVM_REVISION: &str = env!("SLINGSHOT_SNARKVM_REVISION");
+
+/// The build of a client or a node, exchanged in the handshake.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuildInfo {
+    /// The version of slingshot.
+    pub slingshot_version: String,
+    /// The snarkVM revision.
+    pub snarkvm_revision: String,
+    /// The ID of the network.
+    pub network_id: u16,
+    /// The latest supported version of the messages.
+    pub api_version: u16,
+    /// The enabled feature flags.
+    pub features: Vec<String>,
+}
+
+impl BuildInfo {
+    /// Returns the build of this binary, for the given network.
+    pub fn current<N: Network>() -> Self {
+        let features = [
+            ("contract-tests", cfg!(feature = "contract-tests")),
+            ("grpc", cfg!(feature = "grpc")),
+            ("parallel", cfg!(feature = "parallel")),
+            ("parquet", cfg!(feature = "parquet")),
+        ];
+        Self {
+            slingshot_version: env!("CARGO_PKG_VERSION").to_string(),
+            snarkvm_revision: SNARKVM_REVISION.to_string(),
+            network_id: N::ID,
+            api_version: VERSION,
+            features: features.iter().filter(|(_, enabled)| *enabled).map(|(name, _)| name.to_string()).collect(),
+        }
+    }
+
+    /// Returns the incompatibilities between the given client and node builds.
+    /// A difference in the slingshot version or the feature flags alone is not an incompatibility.
+    pub fn incompatibilities(client: &Self, node: &Self) -> Vec<String> {
+        let mut incompatibilities = Vec::new();
+        if client.api_version != node.api_version {
+            incompatibilities.push(format!(
+                "The CLI uses message version {}, but the node uses message version {}",
+                client.api_version, node.api_version
+            ));
+        }
+        if client.network_id != node.network_id {
+            incompatibilities.push(format!(
+                "The CLI is built for network {}, but the node runs network {}",
+                client.network_id, node.network_id
+            ));
+        }
+        if client.snarkvm_revision != node.snarkvm_revision {
+            incompatibilities.push(format!(
+                "The CLI is built against snarkVM '{}', but the node is built against snarkVM '{}', \
+                 so programs, transactions, and proofs may not be interoperable",
+                client.snarkvm_revision, node.snarkvm_revision
+            ));
+        }
+        incompatibilities
+    }
+}
+
+pub struct HandshakeRequest {
+    client: BuildInfo,
+}
+
+impl HandshakeRequest {
+    /// Initializes a new instance of a handshake request.
+    pub const fn new(client: BuildInfo) -> Self {
+        Self { client }
+    }
+
+    /// Returns the build of the client.
+    pub const fn client(&self) -> &BuildInfo {
+        &self.client
+    }
+}
+
+impl Serialize for HandshakeRequest {
+    /// Serializes the handshake request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut request = serializer.serialize_struct("HandshakeRequest", 1)?;
+        // Serialize the client build.
+        request.serialize_field("client", &self.client)?;
+        request.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for HandshakeRequest {
+    /// Deserializes the handshake request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the request from a string into a value.
+        let mut request = serde_json::Value::deserialize(deserializer)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the client build.
+            serde_json::from_value(request["client"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+pub struct HandshakeResponse {
+    node: BuildInfo,
+    incompatibilities: Vec<String>,
+}
+
+impl HandshakeResponse {
+    /// Initializes a new handshake response.
+    pub const fn new(node: BuildInfo, incompatibilities: Vec<String>) -> Self {
+        Self { node, incompatibilities }
+    }
+
+    /// Returns the build of the node.
+    pub const fn node(&self) -> &BuildInfo {
+        &self.node
+    }
+
+    /// Returns the incompatibilities the node found with the client.
+    pub fn incompatibilities(&self) -> &[String] {
+        &self.incompatibilities
+    }
+}
+
+impl Serialize for HandshakeResponse {
+    /// Serializes the handshake response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("HandshakeResponse", 3)?;
+        response.serialize_field("node", &self.node)?;
+        response.serialize_field("incompatibilities", &self.incompatibilities)?;
+        response.serialize_field("version", &VERSION)?;
+        response.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for HandshakeResponse {
+    /// Deserializes the handshake response from a string or bytes.
+    /// Unlike the other responses, the version is not checked, so that the handshake can report the mismatch.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the node build.
+            serde_json::from_value(response["node"].take()).map_err(de::Error::custom)?,
+            // Retrieve the incompatibilities.
+            serde_json::from_value(response["incompatibilities"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+impl Reply for HandshakeResponse {
+    fn into_response(self) -> Response {
+        warp::reply::json(&self).into_response()
+    }
+}
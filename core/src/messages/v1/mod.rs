@@ -0,0 +1,389 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod account;
+pub use account::*;
+
+pub mod consolidate;
+pub use consolidate::*;
+
+pub mod deploy;
+pub use deploy::*;
+
+pub mod evaluate;
+pub use evaluate::*;
+
+pub mod execute;
+pub use execute::*;
+
+pub mod fee;
+pub use fee::*;
+
+pub mod handshake;
+pub use handshake::*;
+
+pub mod pour;
+pub use pour::*;
+
+pub mod routes;
+pub use routes::*;
+
+pub mod send;
+pub use send::*;
+
+pub mod simulate;
+pub use simulate::*;
+
+pub mod split;
+pub use split::*;
+
+pub mod source;
+pub use source::*;
+
+pub mod transfer;
+pub use transfer::*;
+
+pub mod view;
+pub use view::*;
+
+use anyhow::{ensure, Result};
+
+/// The version of the messages in this module, included in every response.
+/// A change to the fields of a message, or to their encoding, requires a new version of the messages.
+pub const VERSION: u16 = 1;
+
+/// Ensures the version of the given response is supported. A response without a version is assumed to be version 1.
+pub(crate) fn check_version(response: &serde_json::Value) -> Result<()> {
+    if let Some(version) = response.get("version") {
+        ensure!(
+            version.as_u64() == Some(VERSION as u64),
+            "Unsupported message version {version} (expected version {VERSION})"
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Network as CurrentNetwork;
+
+    use snarkvm::prelude::{
+        Address,
+        Field,
+        Identifier,
+        Network,
+        Plaintext,
+        PrivateKey,
+        Program,
+        ProgramID,
+        Record,
+        Scalar,
+        Uniform,
+        Value,
+        ViewKey,
+    };
+
+    use core::str::FromStr;
+    use indexmap::IndexMap;
+    use serde::{de::DeserializeOwned, Serialize};
+
+    type TransactionID = <CurrentNetwork as Network>::TransactionID;
+
+    /// Returns the JSON type of the given value, as written in the golden files.
+    fn json_type(value: &serde_json::Value) -> &'static str {
+        match value {
+            serde_json::Value::Null => "null",
+            serde_json::Value::Bool(_) => "boolean",
+            serde_json::Value::Number(_) => "number",
+            serde_json::Value::String(_) => "string",
+            serde_json::Value::Array(_) => "array",
+            serde_json::Value::Object(_) => "object",
+        }
+    }
+
+    /// Ensures the given message serializes to exactly the fields of the golden file, with the golden JSON types,
+    /// and that it round-trips through serde unchanged.
+    fn check_golden<T: Serialize + DeserializeOwned>(message: T, golden: &str) {
+        let golden: IndexMap<String, String> = serde_json::from_str(golden).unwrap();
+        let json = serde_json::to_value(&message).unwrap();
+
+        // Ensure the fields match the golden file.
+        let fields = json.as_object().unwrap();
+        assert_eq!(
+            fields.keys().collect::<Vec<_>>(),
+            golden.keys().collect::<Vec<_>>(),
+            "The fields of {json} do not match the golden file"
+        );
+        for (name, value) in fields {
+            let expected = &golden[name];
+            assert!(
+                expected.split(" | ").any(|expected| expected == json_type(value)),
+                "Expected '{name}' to be {expected}, found {value}"
+            );
+        }
+
+        // Ensure the message round-trips.
+        let message: T = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&message).unwrap(), json);
+    }
+
+    /// Returns a sample account token signer.
+    fn sample_signer() -> Signer<CurrentNetwork> {
+        Signer::Token("d5f1d0a4-7c35-4b16-9b57-6a8b3ddc2d8e".to_string())
+    }
+
+    /// Returns a sample program.
+    fn sample_program() -> Program<CurrentNetwork> {
+        Program::from_str(
+            "program token.aleo;\n\nfunction echo:\n    input r0 as u64.private;\n    output r0 as u64.private;\n",
+        )
+        .unwrap()
+    }
+
+    /// Returns sample metadata of the sample program.
+    fn sample_metadata() -> ProgramMetadata {
+        ProgramMetadata {
+            description: Some("Echoes its input.".to_string()),
+            repository: None,
+            functions: IndexMap::from([("echo".to_string(), "Returns the given amount.".to_string())]),
+        }
+    }
+
+    /// Returns a sample record owned by the given address.
+    fn sample_record(address: &Address<CurrentNetwork>) -> Record<CurrentNetwork, Plaintext<CurrentNetwork>> {
+        Record::from_str(&format!("{{ owner: {address}.private, gates: 5u64.private, _nonce: 0group.public }}"))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_golden_messages() {
+        let rng = &mut rand::thread_rng();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let transaction_id = TransactionID::from(Field::rand(rng));
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("echo").unwrap();
+        let inputs = vec![Value::from_str("5u64").unwrap()];
+
+        check_golden(AccountRegisterRequest::new(private_key), include_str!("golden/account_register_request.json"));
+        check_golden(
+            AccountRegisterRequest::from_token("d5f1d0a4-7c35-4b16-9b57-6a8b3ddc2d8e".to_string()),
+            include_str!("golden/account_token_request.json"),
+        );
+        check_golden(
+            AccountRegisterResponse::new("d5f1d0a4-7c35-4b16-9b57-6a8b3ddc2d8e".to_string(), address),
+            include_str!("golden/account_register_response.json"),
+        );
+        check_golden(
+            DeployRequest::new(sample_signer(), sample_program(), 10, None, None),
+            include_str!("golden/deploy_request.json"),
+        );
+        check_golden(
+            DeployRequest::new(
+                sample_signer(),
+                sample_program(),
+                10,
+                Some(sample_metadata()),
+                Some(RecordSource::Faucet),
+            ),
+            include_str!("golden/deploy_request.json"),
+        );
+        check_golden(
+            ConsolidateRequest::new(sample_signer(), Some(2), Some(1), Some(60)),
+            include_str!("golden/consolidate_request.json"),
+        );
+        check_golden(
+            ConsolidateRequest::new(sample_signer(), None, None, None),
+            include_str!("golden/consolidate_request.json"),
+        );
+        check_golden(
+            ConsolidateResponse::<CurrentNetwork>::new(
+                vec![ConsolidationRound { transaction_ids: vec![transaction_id], block_height: 2, num_records: 1 }],
+                1,
+                100,
+            ),
+            include_str!("golden/consolidate_response.json"),
+        );
+        let cost = DeploymentCost {
+            functions: vec![FunctionCost {
+                function_name,
+                num_constraints: 1_000,
+                proving_key_size_in_bytes: 100_000,
+                verifying_key_size_in_bytes: 1_000,
+            }],
+            size_in_bytes: 2_000,
+            storage_fee: 2_000,
+        };
+        check_golden(
+            DeployResponse::<CurrentNetwork>::new(transaction_id, Some(3), cost.clone()),
+            include_str!("golden/deploy_response.json"),
+        );
+        check_golden(
+            DeployResponse::<CurrentNetwork>::new(transaction_id, None, cost),
+            include_str!("golden/deploy_response.json"),
+        );
+        check_golden(
+            EvaluateRequest::new(sample_signer(), program_id, function_name, inputs.clone()),
+            include_str!("golden/evaluate_request.json"),
+        );
+        check_golden(EvaluateResponse::new(inputs.clone()), include_str!("golden/evaluate_response.json"));
+        check_golden(
+            ExecuteRequest::new(
+                sample_signer(),
+                program_id,
+                function_name,
+                inputs.clone(),
+                Some(10),
+                Some(RecordSource::Commitments { commitments: vec![Field::rand(rng)] }),
+            ),
+            include_str!("golden/execute_request.json"),
+        );
+        check_golden(
+            ExecuteRequest::new(sample_signer(), program_id, function_name, inputs.clone(), None, None),
+            include_str!("golden/execute_request.json"),
+        );
+        check_golden(
+            ExecuteRequest::new(
+                sample_signer(),
+                program_id,
+                function_name,
+                vec![ExecuteInput::RecordCommitment(Field::rand(rng)), ExecuteInput::from(inputs[0].clone())],
+                None,
+                None,
+            ),
+            include_str!("golden/execute_request.json"),
+        );
+        check_golden(
+            ExecuteResponse::<CurrentNetwork>::new(transaction_id, None),
+            include_str!("golden/execute_response.json"),
+        );
+        check_golden(
+            FeeEstimateRequest::new(sample_signer(), FeeEstimate::Execute(program_id, function_name, inputs)),
+            include_str!("golden/fee_estimate_request.json"),
+        );
+        check_golden(FeeEstimateResponse::new(1024, 1024), include_str!("golden/fee_estimate_response.json"));
+        check_golden(
+            HandshakeRequest::new(BuildInfo::current::<CurrentNetwork>()),
+            include_str!("golden/handshake_request.json"),
+        );
+        check_golden(
+            HandshakeResponse::new(BuildInfo::current::<CurrentNetwork>(), vec![]),
+            include_str!("golden/handshake_response.json"),
+        );
+        check_golden(PourRequest::new(address, 100), include_str!("golden/pour_request.json"));
+        check_golden(PourResponse::<CurrentNetwork>::new(transaction_id), include_str!("golden/pour_response.json"));
+        check_golden(RecordViewRequest::new(view_key), include_str!("golden/record_view_request.json"));
+        check_golden(
+            RecordViewResponse::new(IndexMap::from([(Field::rand(rng), sample_record(&address))])),
+            include_str!("golden/record_view_response.json"),
+        );
+        check_golden(RecordStatsResponse::new([5, 100_000]), include_str!("golden/record_stats_response.json"));
+        check_golden(RecordStatsResponse::new(vec![5; 100]), include_str!("golden/record_stats_response.json"));
+        check_golden(
+            SendRecordRequest::new(
+                sample_signer(),
+                program_id,
+                Field::rand(rng),
+                address,
+                Some(100),
+                Some(function_name),
+                Some(10),
+                None,
+            ),
+            include_str!("golden/send_record_request.json"),
+        );
+        check_golden(
+            SendRecordRequest::new(sample_signer(), program_id, Field::rand(rng), address, None, None, None, None),
+            include_str!("golden/send_record_request.json"),
+        );
+        check_golden(
+            SendRecordResponse::<CurrentNetwork>::new(transaction_id, function_name),
+            include_str!("golden/send_record_response.json"),
+        );
+        check_golden(
+            SplitRequest::new(sample_signer(), 1000, 8, Some(1), None),
+            include_str!("golden/split_request.json"),
+        );
+        check_golden(
+            SplitResponse::<CurrentNetwork>::new(
+                vec![SplitRound { transaction_ids: vec![transaction_id], block_height: 2 }],
+                vec![Field::rand(rng)],
+            ),
+            include_str!("golden/split_response.json"),
+        );
+        check_golden(
+            TransferRequest::new(
+                sample_signer(),
+                address,
+                100,
+                Some(60),
+                Some(RecordSource::Wallet { records: IndexMap::from([(Field::rand(rng), sample_record(&address))]) }),
+            ),
+            include_str!("golden/transfer_request.json"),
+        );
+        check_golden(
+            TransferResponse::new(
+                transaction_id,
+                2,
+                Field::rand(rng),
+                sample_record(&address).encrypt(Scalar::rand(rng)).unwrap(),
+                Field::rand(rng),
+            ),
+            include_str!("golden/transfer_response.json"),
+        );
+    }
+
+    #[test]
+    fn test_build_incompatibilities() {
+        let client = BuildInfo::current::<CurrentNetwork>();
+        assert!(BuildInfo::incompatibilities(&client, &client).is_empty());
+
+        // A different slingshot version or feature flags are compatible.
+        let node = BuildInfo { slingshot_version: "0.0.0".to_string(), features: vec![], ..client.clone() };
+        assert!(BuildInfo::incompatibilities(&client, &node).is_empty());
+
+        // A different message version, network, or snarkVM revision is incompatible.
+        let node =
+            BuildInfo { api_version: VERSION + 1, network_id: 0, snarkvm_revision: "0".to_string(), ..client.clone() };
+        assert_eq!(BuildInfo::incompatibilities(&client, &node).len(), 3);
+    }
+
+    #[test]
+    fn test_check_program_metadata() {
+        let program = sample_program();
+        assert!(sample_metadata().check(&program).is_ok());
+
+        // The metadata may only document the functions of the program.
+        let mut metadata = sample_metadata();
+        metadata.functions.insert("transfer".to_string(), "Transfers the given amount.".to_string());
+        assert!(metadata.check(&program).is_err());
+
+        // The metadata must be within the size limit.
+        let metadata =
+            ProgramMetadata { description: Some("a".repeat(MAX_PROGRAM_METADATA_SIZE_IN_BYTES)), ..Default::default() };
+        assert!(metadata.check(&program).is_err());
+    }
+
+    #[test]
+    fn test_check_version() {
+        assert!(check_version(&serde_json::json!({ "transaction_id": "at1" })).is_ok());
+        assert!(check_version(&serde_json::json!({ "transaction_id": "at1", "version": VERSION })).is_ok());
+        assert!(check_version(&serde_json::json!({ "transaction_id": "at1", "version": VERSION + 1 })).is_err());
+        assert!(check_version(&serde_json::json!({ "transaction_id": "at1", "version": "1" })).is_err());
+    }
+}
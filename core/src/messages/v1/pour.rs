@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::messages::{check_version, VERSION};
+
 use snarkvm::prelude::{Address, Network};
 
 use anyhow::Result;
@@ -31,11 +33,6 @@ impl<N: Network> PourRequest<N> {
         Self { address, amount }
     }
 
-    /// Sends the request to the given endpoint.
-    pub fn send(&self, endpoint: &str) -> Result<PourResponse<N>> {
-        Ok(ureq::post(endpoint).send_json(self)?.into_json()?)
-    }
-
     /// Returns the recipient address.
     pub const fn address(&self) -> &Address<N> {
         &self.address
@@ -93,8 +90,9 @@ impl<N: Network> PourResponse<N> {
 impl<N: Network> Serialize for PourResponse<N> {
     /// Serializes the pour response into string or bytes.
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut response = serializer.serialize_struct("PourResponse", 1)?;
+        let mut response = serializer.serialize_struct("PourResponse", 2)?;
         response.serialize_field("transaction_id", &self.transaction_id)?;
+        response.serialize_field("version", &VERSION)?;
         response.end()
     }
 }
@@ -104,6 +102,8 @@ impl<'de, N: Network> Deserialize<'de> for PourResponse<N> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         // Parse the response from a string into a value.
         let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version of the response is supported.
+        check_version(&response).map_err(de::Error::custom)?;
         // Recover the leaf.
         Ok(Self::new(
             // Retrieve the transaction_id.
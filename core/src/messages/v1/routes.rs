@@ -0,0 +1,282 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+// The query, request, and response objects of the routes without a dedicated message.
+// They are shared by the node and its clients, so that both agree on the shape of each route.
+
+use snarkvm::prelude::{Address, Field, Network, ViewKey};
+
+use serde::{Deserialize, Serialize};
+
+/// The `get_blocks` query object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BlockRange {
+    /// The starting block height (inclusive).
+    pub start: u32,
+    /// The ending block height (exclusive).
+    pub end: u32,
+}
+
+/// The `stats_timeseries` query object.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct StatsRange {
+    /// The starting block height (inclusive), if any.
+    pub from: Option<u32>,
+    /// The ending block height (exclusive), if any.
+    pub to: Option<u32>,
+}
+
+/// The `list_jobs` query object.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct JobFilter {
+    /// The status of the jobs to list, if any.
+    pub status: Option<String>,
+}
+
+/// The `node_config` request and response object.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NodeConfigUpdate {
+    /// The number of seconds between blocks.
+    pub block_interval: Option<u64>,
+}
+
+/// The `node_set_next_block_timestamp` request object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NextBlockTimestamp {
+    /// The timestamp of the next block.
+    pub timestamp: i64,
+}
+
+/// The `node_increase_time` request object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IncreaseTime {
+    /// The number of seconds to advance the time by.
+    pub seconds: u64,
+}
+
+/// The `node_mine` request object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MineBlocks {
+    /// The number of blocks to produce.
+    pub blocks: u32,
+}
+
+/// The response object of the `node_pause` and `node_resume` routes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BlockProduction {
+    /// If `true`, scheduled block production is paused.
+    pub paused: bool,
+}
+
+/// The response object of the `node_shutdown` route.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ShutdownStatus {
+    /// If `true`, the node is shutting down.
+    pub shutting_down: bool,
+}
+
+/// The response object of the time manipulation routes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NodeTime {
+    /// The number of seconds added to the host clock, to timestamp blocks.
+    pub time_offset: i64,
+    /// The timestamp of the next block, if it is set.
+    pub next_block_timestamp: Option<i64>,
+}
+
+/// The `program_deploy` and `program_execute` query object.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct SubmitMode {
+    /// If `true`, the request returns a job ID immediately, and the transaction is constructed in the background.
+    #[serde(default, rename = "async")]
+    pub is_async: bool,
+}
+
+/// The response object of the asynchronous `program_deploy` and `program_execute` routes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct JobCreated {
+    /// The ID of the job, to poll at `/testnet3/job/{id}`.
+    pub job_id: String,
+}
+
+/// The `events_replay` query object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EventReplay {
+    /// The starting block height (inclusive).
+    pub from_block: u32,
+    /// The ending block height (exclusive).
+    pub to_block: Option<u32>,
+}
+
+/// The `node_block_policy` request and response object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BlockPolicyUpdate {
+    /// The policy that decides when the node produces a block, e.g. `interval`, `instant`, `manual`,
+    /// `threshold:10`, or `hybrid:10`.
+    pub policy: String,
+}
+
+/// The `events_socket` query object.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct EventTypes {
+    /// The comma-separated types of the streamed events (e.g. `program_deployed`). Defaults to every type.
+    pub types: Option<String>,
+}
+
+/// The `get_program` query object.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProgramQuery {
+    /// The format of the program.
+    #[serde(default)]
+    pub format: ProgramFormat,
+    /// If `true`, the program is returned in an object with its metadata.
+    #[serde(default)]
+    pub metadata: bool,
+}
+
+/// The format of a program in a `get_program` response.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgramFormat {
+    /// The program as JSON, i.e. its Aleo instructions as a JSON string.
+    Json,
+    /// The Aleo instructions of the program, as plain text.
+    String,
+    /// The bytes of the program, or their hex encoding in an object with metadata.
+    Bytes,
+}
+
+impl Default for ProgramFormat {
+    fn default() -> Self {
+        Self::Json
+    }
+}
+
+/// The response object of the `get_program` route, with metadata.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct ProgramWithMetadata<N: Network> {
+    /// The program, in the requested format.
+    pub program: serde_json::Value,
+    /// If `true`, the program is native to the network (i.e. `credits.aleo`), and was not deployed.
+    pub is_native: bool,
+    /// The ID of the transaction that deployed the program, unless it is native.
+    pub deployment_transaction_id: Option<N::TransactionID>,
+    /// The height of the block that includes the deployment, unless the program is native.
+    pub height: Option<u32>,
+}
+
+/// The `node_logs` query object.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LogsQuery {
+    /// The sequence number of the last entry already read, if any.
+    pub since: Option<u64>,
+}
+
+/// The `decode_transaction` query object.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct DecodeQuery {
+    /// The view key that decrypts the private values and the records of the transaction, if any.
+    pub view_key: Option<String>,
+}
+
+/// The `get_program_events` query object.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProgramEventRange {
+    /// The starting block height (inclusive).
+    #[serde(default)]
+    pub start: u32,
+    /// The ending block height (exclusive).
+    pub end: Option<u32>,
+}
+
+/// A transaction rejected from the memory pool, as returned by the `get_memory_pool_rejected` route.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct RejectedTransaction<N: Network> {
+    /// The ID of the transaction.
+    pub transaction_id: N::TransactionID,
+    /// The reason the transaction was rejected.
+    pub reason: String,
+}
+
+/// The response object of the `transaction_verify` route.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct TransactionVerification<N: Network> {
+    /// The ID of the transaction.
+    pub transaction_id: N::TransactionID,
+    /// The reason the transaction would be rejected, if any.
+    pub error: Option<String>,
+}
+
+/// The `watch_records` request object.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct WatchRecords<N: Network> {
+    /// The view key of the owner of the records.
+    pub view_key: ViewKey<N>,
+    /// The commitments of the records to watch.
+    pub commitments: Vec<Field<N>>,
+    /// The URL the notifications are posted to, if any.
+    pub webhook: Option<String>,
+}
+
+/// The response object of the `watch_records` route.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct WatchCreated<N: Network> {
+    /// The ID of the watch, to subscribe to at `/testnet3/watch/{id}/ws`.
+    pub watch_id: String,
+    /// The commitments of the records that are already spent, which are not watched.
+    pub spent: Vec<Field<N>>,
+}
+
+/// The response object of the `faucet_balance` route.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct FaucetBalance<N: Network> {
+    /// The address of the faucet.
+    pub address: Address<N>,
+    /// The number of gates in the unspent records of the faucet.
+    pub balance: u64,
+    /// The number of unspent records of the faucet.
+    pub records: usize,
+}
+
+/// The response object of the `get_usage` route.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct UsageReport<N: Network> {
+    /// The usage of each account that used the node.
+    pub accounts: Vec<AccountUsageReport<N>>,
+}
+
+/// The usage of the node by an account, metered since the node started.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct AccountUsageReport<N: Network> {
+    /// The address of the account.
+    pub address: Address<N>,
+    /// The number of seconds spent constructing the transactions of the account.
+    pub proving_seconds: f64,
+    /// The number of transactions submitted by the account to the memory pool.
+    pub transactions: u64,
+    /// The number of faucet pours into the account.
+    pub faucet_pours: u64,
+    /// The number of gates poured into the account by the faucet.
+    pub faucet_gates: u64,
+}
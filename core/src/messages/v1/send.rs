@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::messages::{check_version, RecordSource, Signer, VERSION};
+
+use snarkvm::prelude::{Address, Field, Identifier, Network, ProgramID};
+
+use anyhow::Result;
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use warp::{reply::Response, Reply};
+
+pub struct SendRecordRequest<N: Network> {
+    signer: Signer<N>,
+    program_id: ProgramID<N>,
+    commitment: Field<N>,
+    recipient: Address<N>,
+    amount: Option<u64>,
+    function_name: Option<Identifier<N>>,
+    additional_fee: Option<u64>,
+    record_source: Option<RecordSource<N>>,
+}
+
+impl<N: Network> SendRecordRequest<N> {
+    /// Initializes a new instance of a send record request.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        signer: Signer<N>,
+        program_id: ProgramID<N>,
+        commitment: Field<N>,
+        recipient: Address<N>,
+        amount: Option<u64>,
+        function_name: Option<Identifier<N>>,
+        additional_fee: Option<u64>,
+        record_source: Option<RecordSource<N>>,
+    ) -> Self {
+        Self { signer, program_id, commitment, recipient, amount, function_name, additional_fee, record_source }
+    }
+
+    /// Returns the signer of the account sending the record.
+    pub const fn signer(&self) -> &Signer<N> {
+        &self.signer
+    }
+
+    /// Returns the program that defines the record.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the commitment of the record to send.
+    pub const fn commitment(&self) -> &Field<N> {
+        &self.commitment
+    }
+
+    /// Returns the recipient address.
+    pub const fn recipient(&self) -> &Address<N> {
+        &self.recipient
+    }
+
+    /// Returns the amount to send, if the function takes one.
+    pub const fn amount(&self) -> Option<u64> {
+        self.amount
+    }
+
+    /// Returns the function that sends the record, if it is not determined by the node.
+    pub const fn function_name(&self) -> Option<&Identifier<N>> {
+        self.function_name.as_ref()
+    }
+
+    /// Returns the additional_fee.
+    pub const fn additional_fee(&self) -> Option<u64> {
+        self.additional_fee
+    }
+
+    /// Returns the source of the record that pays the additional fee, if any.
+    pub const fn record_source(&self) -> Option<&RecordSource<N>> {
+        self.record_source.as_ref()
+    }
+}
+
+impl<N: Network> Serialize for SendRecordRequest<N> {
+    /// Serializes the send record request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut request = serializer.serialize_struct("SendRecordRequest", 8)?;
+        // Serialize the signer.
+        self.signer.serialize_field(&mut request)?;
+        // Serialize the program_id.
+        request.serialize_field("program_id", &self.program_id)?;
+        // Serialize the commitment.
+        request.serialize_field("commitment", &self.commitment)?;
+        // Serialize the recipient.
+        request.serialize_field("recipient", &self.recipient)?;
+        // Serialize the amount.
+        request.serialize_field("amount", &self.amount)?;
+        // Serialize the function_name.
+        request.serialize_field("function_name", &self.function_name)?;
+        // Serialize the additional_fee.
+        request.serialize_field("additional_fee", &self.additional_fee)?;
+        // Serialize the record_source.
+        request.serialize_field("record_source", &self.record_source)?;
+        request.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for SendRecordRequest<N> {
+    /// Deserializes the send record request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the request from a string into a value.
+        let mut request = serde_json::Value::deserialize(deserializer)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the signer.
+            Signer::from_value(&mut request).map_err(de::Error::custom)?,
+            // Retrieve the program_id.
+            serde_json::from_value(request["program_id"].take()).map_err(de::Error::custom)?,
+            // Retrieve the commitment.
+            serde_json::from_value(request["commitment"].take()).map_err(de::Error::custom)?,
+            // Retrieve the recipient.
+            serde_json::from_value(request["recipient"].take()).map_err(de::Error::custom)?,
+            // Retrieve the amount.
+            serde_json::from_value(request["amount"].take()).map_err(de::Error::custom)?,
+            // Retrieve the function_name.
+            serde_json::from_value(request["function_name"].take()).map_err(de::Error::custom)?,
+            // Retrieve the additional_fee.
+            serde_json::from_value(request["additional_fee"].take()).map_err(de::Error::custom)?,
+            // Retrieve the record_source.
+            serde_json::from_value(request["record_source"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+pub struct SendRecordResponse<N: Network> {
+    transaction_id: N::TransactionID,
+    function_name: Identifier<N>,
+}
+
+impl<N: Network> SendRecordResponse<N> {
+    /// Initializes a new send record response.
+    pub const fn new(transaction_id: N::TransactionID, function_name: Identifier<N>) -> Self {
+        Self { transaction_id, function_name }
+    }
+
+    /// Returns the ID of the transaction that sends the record.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the function that sends the record.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+}
+
+impl<N: Network> Serialize for SendRecordResponse<N> {
+    /// Serializes the send record response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("SendRecordResponse", 3)?;
+        response.serialize_field("transaction_id", &self.transaction_id)?;
+        response.serialize_field("function_name", &self.function_name)?;
+        response.serialize_field("version", &VERSION)?;
+        response.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for SendRecordResponse<N> {
+    /// Deserializes the send record response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version of the response is supported.
+        check_version(&response).map_err(de::Error::custom)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the transaction_id.
+            serde_json::from_value(response["transaction_id"].take()).map_err(de::Error::custom)?,
+            // Retrieve the function_name.
+            serde_json::from_value(response["function_name"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+impl<N: Network> Reply for SendRecordResponse<N> {
+    fn into_response(self) -> Response {
+        warp::reply::json(&self).into_response()
+    }
+}
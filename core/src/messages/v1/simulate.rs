@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::messages::{check_version, VERSION};
+
+use snarkvm::prelude::{Network, Transaction};
+
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use warp::{reply::Response, Reply};
+
+/// The result of simulating an execute request, without adding the transaction to the memory pool.
+pub struct SimulateResponse<N: Network> {
+    transaction: Transaction<N>,
+    fee: i64,
+    size_in_bytes: usize,
+    error: Option<String>,
+}
+
+impl<N: Network> SimulateResponse<N> {
+    /// Initializes a new simulate response.
+    pub const fn new(transaction: Transaction<N>, fee: i64, size_in_bytes: usize, error: Option<String>) -> Self {
+        Self { transaction, fee, size_in_bytes, error }
+    }
+
+    /// Returns the simulated transaction.
+    pub const fn transaction(&self) -> &Transaction<N> {
+        &self.transaction
+    }
+
+    /// Returns the fee of the simulated transaction.
+    pub const fn fee(&self) -> i64 {
+        self.fee
+    }
+
+    /// Returns the size of the simulated transaction in bytes.
+    pub const fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
+    /// Returns the reason the transaction would be rejected, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+impl<N: Network> Serialize for SimulateResponse<N> {
+    /// Serializes the simulate response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("SimulateResponse", 5)?;
+        response.serialize_field("transaction", &self.transaction)?;
+        response.serialize_field("fee", &self.fee)?;
+        response.serialize_field("size_in_bytes", &self.size_in_bytes)?;
+        response.serialize_field("error", &self.error)?;
+        response.serialize_field("version", &VERSION)?;
+        response.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for SimulateResponse<N> {
+    /// Deserializes the simulate response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version of the response is supported.
+        check_version(&response).map_err(de::Error::custom)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the transaction.
+            serde_json::from_value(response["transaction"].take()).map_err(de::Error::custom)?,
+            // Retrieve the fee.
+            serde_json::from_value(response["fee"].take()).map_err(de::Error::custom)?,
+            // Retrieve the size_in_bytes.
+            serde_json::from_value(response["size_in_bytes"].take()).map_err(de::Error::custom)?,
+            // Retrieve the error.
+            serde_json::from_value(response["error"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+impl<N: Network> Reply for SimulateResponse<N> {
+    /// Converts the simulate response into a response.
+    fn into_response(self) -> Response {
+        warp::reply::json(&self).into_response()
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm::prelude::{Field, Network, Plaintext, Record};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// The source of the records that a transfer, deployment, or execution spends for its amount or its fee.
+/// A request without a record source spends the unspent records of the signer, as scanned by the node.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "", rename_all = "snake_case", tag = "type")]
+pub enum RecordSource<N: Network> {
+    /// Spends one of the given records of the signer, keyed by commitment, e.g. as loaded from a wallet file.
+    Wallet { records: IndexMap<Field<N>, Record<N, Plaintext<N>>> },
+    /// Spends one of the unspent records of the signer with the given commitments.
+    Commitments { commitments: Vec<Field<N>> },
+    /// Spends an unspent record of the signer, after funding the signer from the faucet if it has none with enough gates.
+    Faucet,
+}
@@ -0,0 +1,168 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::messages::{check_version, Signer, VERSION};
+
+use snarkvm::prelude::{Field, Network};
+
+use anyhow::Result;
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use warp::{reply::Response, Reply};
+
+pub struct SplitRequest<N: Network> {
+    signer: Signer<N>,
+    amount: u64,
+    count: usize,
+    max_rounds: Option<usize>,
+    timeout: Option<u64>,
+}
+
+impl<N: Network> SplitRequest<N> {
+    /// Initializes a new instance of a split request.
+    pub fn new(signer: Signer<N>, amount: u64, count: usize, max_rounds: Option<usize>, timeout: Option<u64>) -> Self {
+        Self { signer, amount, count, max_rounds, timeout }
+    }
+
+    /// Returns the signer of the account whose records are split.
+    pub const fn signer(&self) -> &Signer<N> {
+        &self.signer
+    }
+
+    /// Returns the number of gates of each piece.
+    pub const fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the number of pieces.
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the maximum number of rounds of splits, if given.
+    pub const fn max_rounds(&self) -> Option<usize> {
+        self.max_rounds
+    }
+
+    /// Returns the number of seconds to wait for the rounds to be confirmed.
+    pub const fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<N: Network> Serialize for SplitRequest<N> {
+    /// Serializes the split request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut request = serializer.serialize_struct("SplitRequest", 5)?;
+        // Serialize the signer.
+        self.signer.serialize_field(&mut request)?;
+        // Serialize the amount.
+        request.serialize_field("amount", &self.amount)?;
+        // Serialize the count.
+        request.serialize_field("count", &self.count)?;
+        // Serialize the max_rounds.
+        request.serialize_field("max_rounds", &self.max_rounds)?;
+        // Serialize the timeout.
+        request.serialize_field("timeout", &self.timeout)?;
+        request.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for SplitRequest<N> {
+    /// Deserializes the split request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the request from a string into a value.
+        let mut request = serde_json::Value::deserialize(deserializer)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the signer.
+            Signer::from_value(&mut request).map_err(de::Error::custom)?,
+            // Retrieve the amount.
+            serde_json::from_value(request["amount"].take()).map_err(de::Error::custom)?,
+            // Retrieve the count.
+            serde_json::from_value(request["count"].take()).map_err(de::Error::custom)?,
+            // Retrieve the max_rounds.
+            serde_json::from_value(request["max_rounds"].take()).map_err(de::Error::custom)?,
+            // Retrieve the timeout.
+            serde_json::from_value(request["timeout"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+/// A round of a split: the splits of the larger records, which are included in the same block.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct SplitRound<N: Network> {
+    /// The IDs of the split transactions.
+    pub transaction_ids: Vec<N::TransactionID>,
+    /// The height of the block that includes the splits.
+    pub block_height: u32,
+}
+
+pub struct SplitResponse<N: Network> {
+    rounds: Vec<SplitRound<N>>,
+    commitments: Vec<Field<N>>,
+}
+
+impl<N: Network> SplitResponse<N> {
+    /// Initializes a new split response.
+    pub const fn new(rounds: Vec<SplitRound<N>>, commitments: Vec<Field<N>>) -> Self {
+        Self { rounds, commitments }
+    }
+
+    /// Returns the rounds of splits, which is empty if the account already had the pieces.
+    pub fn rounds(&self) -> &[SplitRound<N>] {
+        &self.rounds
+    }
+
+    /// Returns the commitments of the unspent credits records with the requested number of gates.
+    pub fn commitments(&self) -> &[Field<N>] {
+        &self.commitments
+    }
+}
+
+impl<N: Network> Serialize for SplitResponse<N> {
+    /// Serializes the split response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("SplitResponse", 3)?;
+        response.serialize_field("rounds", &self.rounds)?;
+        response.serialize_field("commitments", &self.commitments)?;
+        response.serialize_field("version", &VERSION)?;
+        response.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for SplitResponse<N> {
+    /// Deserializes the split response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version of the response is supported.
+        check_version(&response).map_err(de::Error::custom)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the rounds.
+            serde_json::from_value(response["rounds"].take()).map_err(de::Error::custom)?,
+            // Retrieve the commitments.
+            serde_json::from_value(response["commitments"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+impl<N: Network> Reply for SplitResponse<N> {
+    fn into_response(self) -> Response {
+        warp::reply::json(&self).into_response()
+    }
+}
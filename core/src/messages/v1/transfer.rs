@@ -0,0 +1,197 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::messages::{check_version, RecordSource, Signer, VERSION};
+
+use snarkvm::prelude::{Address, Ciphertext, Field, Network, Record};
+
+use anyhow::Result;
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use warp::{reply::Response, Reply};
+
+pub struct TransferRequest<N: Network> {
+    signer: Signer<N>,
+    recipient: Address<N>,
+    amount: u64,
+    timeout: Option<u64>,
+    record_source: Option<RecordSource<N>>,
+}
+
+impl<N: Network> TransferRequest<N> {
+    /// Initializes a new instance of a transfer request.
+    pub fn new(
+        signer: Signer<N>,
+        recipient: Address<N>,
+        amount: u64,
+        timeout: Option<u64>,
+        record_source: Option<RecordSource<N>>,
+    ) -> Self {
+        Self { signer, recipient, amount, timeout, record_source }
+    }
+
+    /// Returns the signer of the account sending the credits.
+    pub const fn signer(&self) -> &Signer<N> {
+        &self.signer
+    }
+
+    /// Returns the recipient address.
+    pub const fn recipient(&self) -> &Address<N> {
+        &self.recipient
+    }
+
+    /// Returns the amount to be transferred.
+    pub const fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the number of seconds to wait for the transfer to be confirmed.
+    pub const fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+
+    /// Returns the source of the record to spend, if any.
+    pub const fn record_source(&self) -> Option<&RecordSource<N>> {
+        self.record_source.as_ref()
+    }
+}
+
+impl<N: Network> Serialize for TransferRequest<N> {
+    /// Serializes the transfer request into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut request = serializer.serialize_struct("TransferRequest", 5)?;
+        // Serialize the signer.
+        self.signer.serialize_field(&mut request)?;
+        // Serialize the recipient.
+        request.serialize_field("recipient", &self.recipient)?;
+        // Serialize the amount.
+        request.serialize_field("amount", &self.amount)?;
+        // Serialize the timeout.
+        request.serialize_field("timeout", &self.timeout)?;
+        // Serialize the record_source.
+        request.serialize_field("record_source", &self.record_source)?;
+        request.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for TransferRequest<N> {
+    /// Deserializes the transfer request from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the request from a string into a value.
+        let mut request = serde_json::Value::deserialize(deserializer)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the signer.
+            Signer::from_value(&mut request).map_err(de::Error::custom)?,
+            // Retrieve the recipient.
+            serde_json::from_value(request["recipient"].take()).map_err(de::Error::custom)?,
+            // Retrieve the amount.
+            serde_json::from_value(request["amount"].take()).map_err(de::Error::custom)?,
+            // Retrieve the timeout.
+            serde_json::from_value(request["timeout"].take()).map_err(de::Error::custom)?,
+            // Retrieve the record_source.
+            serde_json::from_value(request["record_source"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+pub struct TransferResponse<N: Network> {
+    transaction_id: N::TransactionID,
+    block_height: u32,
+    recipient_commitment: Field<N>,
+    recipient_record: Record<N, Ciphertext<N>>,
+    change_commitment: Field<N>,
+}
+
+impl<N: Network> TransferResponse<N> {
+    /// Initializes a new transfer response.
+    pub const fn new(
+        transaction_id: N::TransactionID,
+        block_height: u32,
+        recipient_commitment: Field<N>,
+        recipient_record: Record<N, Ciphertext<N>>,
+        change_commitment: Field<N>,
+    ) -> Self {
+        Self { transaction_id, block_height, recipient_commitment, recipient_record, change_commitment }
+    }
+
+    /// Returns the ID of the transfer transaction.
+    pub const fn transaction_id(&self) -> &N::TransactionID {
+        &self.transaction_id
+    }
+
+    /// Returns the height of the block that includes the transfer.
+    pub const fn block_height(&self) -> u32 {
+        self.block_height
+    }
+
+    /// Returns the commitment of the recipient record.
+    pub const fn recipient_commitment(&self) -> &Field<N> {
+        &self.recipient_commitment
+    }
+
+    /// Returns the recipient record ciphertext.
+    pub const fn recipient_record(&self) -> &Record<N, Ciphertext<N>> {
+        &self.recipient_record
+    }
+
+    /// Returns the commitment of the sender's change record.
+    pub const fn change_commitment(&self) -> &Field<N> {
+        &self.change_commitment
+    }
+}
+
+impl<N: Network> Serialize for TransferResponse<N> {
+    /// Serializes the transfer response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("TransferResponse", 6)?;
+        response.serialize_field("transaction_id", &self.transaction_id)?;
+        response.serialize_field("block_height", &self.block_height)?;
+        response.serialize_field("recipient_commitment", &self.recipient_commitment)?;
+        response.serialize_field("recipient_record", &self.recipient_record)?;
+        response.serialize_field("change_commitment", &self.change_commitment)?;
+        response.serialize_field("version", &VERSION)?;
+        response.end()
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for TransferResponse<N> {
+    /// Deserializes the transfer response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version of the response is supported.
+        check_version(&response).map_err(de::Error::custom)?;
+        // Recover the leaf.
+        Ok(Self::new(
+            // Retrieve the transaction_id.
+            serde_json::from_value(response["transaction_id"].take()).map_err(de::Error::custom)?,
+            // Retrieve the block_height.
+            serde_json::from_value(response["block_height"].take()).map_err(de::Error::custom)?,
+            // Retrieve the recipient_commitment.
+            serde_json::from_value(response["recipient_commitment"].take()).map_err(de::Error::custom)?,
+            // Retrieve the recipient_record.
+            serde_json::from_value(response["recipient_record"].take()).map_err(de::Error::custom)?,
+            // Retrieve the change_commitment.
+            serde_json::from_value(response["change_commitment"].take()).map_err(de::Error::custom)?,
+        ))
+    }
+}
+
+impl<N: Network> Reply for TransferResponse<N> {
+    fn into_response(self) -> Response {
+        warp::reply::json(&self).into_response()
+    }
+}
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::messages::{check_version, VERSION};
+
 use snarkvm::prelude::{Field, Network, Plaintext, PrivateKey, Program, Record, ViewKey, Visibility};
 
 use anyhow::{bail, Result};
@@ -31,11 +33,6 @@ impl<N: Network> RecordViewRequest<N> {
         Self { view_key }
     }
 
-    /// Sends the request to the given endpoint.
-    pub fn send(&self, endpoint: &str) -> Result<RecordViewResponse<N>> {
-        Ok(ureq::post(endpoint).send_json(self)?.into_json()?)
-    }
-
     /// Gets the view key associated with the request.
     pub fn view_key(&self) -> &ViewKey<N> {
         &self.view_key
@@ -81,8 +78,9 @@ impl<N: Network> RecordViewResponse<N> {
 impl<N: Network> Serialize for RecordViewResponse<N> {
     /// Serializes the record view response into string or bytes.
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut response = serializer.serialize_struct("RecordViewResponse", 1)?;
+        let mut response = serializer.serialize_struct("RecordViewResponse", 2)?;
         response.serialize_field("records", &self.records)?;
+        response.serialize_field("version", &VERSION)?;
         response.end()
     }
 }
@@ -92,6 +90,8 @@ impl<'de, N: Network> Deserialize<'de> for RecordViewResponse<N> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         // Parse the response from a string into a value.
         let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version of the response is supported.
+        check_version(&response).map_err(de::Error::custom)?;
         // Recover the leaf.
         Ok(Self::new(
             // Retrieve the transaction_id.
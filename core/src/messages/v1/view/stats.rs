@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    messages::{check_version, VERSION},
+    records::{fragmentation_warning, DUST_THRESHOLD},
+};
+
+use anyhow::Result;
+use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
+use warp::{reply::Response, Reply};
+
+pub struct RecordStatsResponse {
+    num_records: usize,
+    balance: u64,
+    spendable: u64,
+    dust_records: usize,
+    dust_gates: u64,
+    warning: Option<String>,
+}
+
+impl RecordStatsResponse {
+    /// Initializes a new record stats response from the gates of each unspent record of an account.
+    pub fn new(gates: impl IntoIterator<Item = u64>) -> Self {
+        let (mut num_records, mut balance, mut spendable, mut dust_records, mut dust_gates) = (0, 0u64, 0, 0, 0u64);
+        for gates in gates {
+            num_records += 1;
+            balance = balance.saturating_add(gates);
+            spendable = spendable.max(gates);
+            if gates < DUST_THRESHOLD {
+                dust_records += 1;
+                dust_gates += gates;
+            }
+        }
+        let warning = fragmentation_warning(balance, spendable, num_records, dust_records);
+        Self { num_records, balance, spendable, dust_records, dust_gates, warning }
+    }
+
+    /// Returns the number of unspent records.
+    pub const fn num_records(&self) -> usize {
+        self.num_records
+    }
+
+    /// Returns the total gates in the unspent records.
+    pub const fn balance(&self) -> u64 {
+        self.balance
+    }
+
+    /// Returns the gates that can be spent in a single transaction, which is the largest unspent record.
+    pub const fn spendable(&self) -> u64 {
+        self.spendable
+    }
+
+    /// Returns the number of unspent records with fewer gates than the dust threshold.
+    pub const fn dust_records(&self) -> usize {
+        self.dust_records
+    }
+
+    /// Returns the total gates in the dust records.
+    pub const fn dust_gates(&self) -> u64 {
+        self.dust_gates
+    }
+
+    /// Returns the warning for a fragmented balance, if any.
+    pub fn warning(&self) -> Option<&str> {
+        self.warning.as_deref()
+    }
+}
+
+impl Serialize for RecordStatsResponse {
+    /// Serializes the record stats response into string or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut response = serializer.serialize_struct("RecordStatsResponse", 7)?;
+        response.serialize_field("num_records", &self.num_records)?;
+        response.serialize_field("balance", &self.balance)?;
+        response.serialize_field("spendable", &self.spendable)?;
+        response.serialize_field("dust_records", &self.dust_records)?;
+        response.serialize_field("dust_gates", &self.dust_gates)?;
+        response.serialize_field("warning", &self.warning)?;
+        response.serialize_field("version", &VERSION)?;
+        response.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordStatsResponse {
+    /// Deserializes the record stats response from a string or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Parse the response from a string into a value.
+        let mut response = serde_json::Value::deserialize(deserializer)?;
+        // Ensure the version of the response is supported.
+        check_version(&response).map_err(de::Error::custom)?;
+        // Recover the leaf.
+        Ok(Self {
+            // Retrieve the num_records.
+            num_records: serde_json::from_value(response["num_records"].take()).map_err(de::Error::custom)?,
+            // Retrieve the balance.
+            balance: serde_json::from_value(response["balance"].take()).map_err(de::Error::custom)?,
+            // Retrieve the spendable.
+            spendable: serde_json::from_value(response["spendable"].take()).map_err(de::Error::custom)?,
+            // Retrieve the dust_records.
+            dust_records: serde_json::from_value(response["dust_records"].take()).map_err(de::Error::custom)?,
+            // Retrieve the dust_gates.
+            dust_gates: serde_json::from_value(response["dust_gates"].take()).map_err(de::Error::custom)?,
+            // Retrieve the warning.
+            warning: serde_json::from_value(response["warning"].take()).map_err(de::Error::custom)?,
+        })
+    }
+}
+
+impl Reply for RecordStatsResponse {
+    /// Converts the record stats response into a response.
+    fn into_response(self) -> Response {
+        warp::reply::json(&self).into_response()
+    }
+}
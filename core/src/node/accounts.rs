@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::node::generate_token;
+
+use snarkvm::prelude::{Address, Network, PrivateKey};
+
+use anyhow::Result;
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
+
+/// A registry of the accounts that the node signs requests for.
+#[derive(Clone, Debug)]
+pub struct AccountRegistry<N: Network> {
+    /// The registered accounts.
+    accounts: Arc<RwLock<Accounts<N>>>,
+}
+
+/// The registered accounts, keyed by address and by account token.
+#[derive(Debug)]
+struct Accounts<N: Network> {
+    /// The map of account addresses to account tokens.
+    tokens: HashMap<Address<N>, String>,
+    /// The map of account tokens to account addresses and private keys.
+    keys: HashMap<String, (Address<N>, PrivateKey<N>)>,
+}
+
+impl<N: Network> Default for AccountRegistry<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> AccountRegistry<N> {
+    /// Initializes a new instance of the account registry.
+    pub fn new() -> Self {
+        Self { accounts: Arc::new(RwLock::new(Accounts { tokens: HashMap::new(), keys: HashMap::new() })) }
+    }
+
+    /// Registers the given private key, returning the account token and the account address.
+    /// If the account is already registered, its existing token is returned.
+    pub fn register(&self, private_key: PrivateKey<N>) -> Result<(String, Address<N>)> {
+        let address = Address::try_from(&private_key)?;
+
+        // Acquire the write lock on the accounts.
+        let mut accounts = self.accounts.write();
+
+        // Return the existing token, if the account is already registered.
+        if let Some(token) = accounts.tokens.get(&address) {
+            return Ok((token.clone(), address));
+        }
+
+        // Generate a new token for the account.
+        let token = generate_token();
+        accounts.tokens.insert(address, token.clone());
+        accounts.keys.insert(token.clone(), (address, private_key));
+        debug!("Registered account '{address}'");
+
+        Ok((token, address))
+    }
+
+    /// Returns the private key for the given account token, if it is registered.
+    pub fn get(&self, token: &str) -> Option<PrivateKey<N>> {
+        self.accounts.read().keys.get(token).map(|(_, private_key)| *private_key)
+    }
+
+    /// Returns the address of the account for the given account token, if it is registered.
+    pub fn address(&self, token: &str) -> Option<Address<N>> {
+        self.accounts.read().keys.get(token).map(|(address, _)| *address)
+    }
+
+    /// Returns the number of registered accounts.
+    pub fn len(&self) -> usize {
+        self.accounts.read().keys.len()
+    }
+
+    /// Returns `true` if there are no registered accounts.
+    pub fn is_empty(&self) -> bool {
+        self.accounts.read().keys.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm::prelude::Testnet3;
+
+    #[test]
+    fn test_register() {
+        let rng = &mut rand::thread_rng();
+        let accounts = AccountRegistry::<CurrentNetwork>::new();
+        let private_key = PrivateKey::new(rng).unwrap();
+
+        // Registering an account again returns its existing token.
+        let (token, address) = accounts.register(private_key).unwrap();
+        assert_eq!(accounts.register(private_key).unwrap(), (token.clone(), address));
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts.get(&token), Some(private_key));
+        assert_eq!(accounts.address(&token), Some(address));
+
+        // Another account is given another token.
+        let (other, _) = accounts.register(PrivateKey::new(rng).unwrap()).unwrap();
+        assert_ne!(token, other);
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts.get("unknown"), None);
+        assert_eq!(accounts.address("unknown"), None);
+    }
+}
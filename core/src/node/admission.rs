@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::SlingshotError,
+    messages::RecordSource,
+    node::{
+        AccountSequences,
+        CapturedRequest,
+        ExplicitCommitments,
+        FaucetAutoFund,
+        NodeScan,
+        Prover,
+        RecordProvider,
+        ShutdownSignal,
+        SingleNodeConsensus,
+        UsageRegistry,
+        WalletFile,
+    },
+};
+
+use snarkvm::prelude::{Address, ConsensusStorage, Network, Transaction};
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+
+/// The admission of the transactions of deploy, execute, pour, transfer, and record requests to the memory pool.
+/// It is shared by the REST and gRPC servers, so that a request is metered, sequenced, and captured alike,
+/// and rejected once the node is shutting down, whichever server it is sent to.
+#[derive(Clone)]
+pub struct Admission<N: Network, C: ConsensusStorage<N>> {
+    /// The consensus module, unless the node is view-only.
+    consensus: Option<SingleNodeConsensus<N, C>>,
+    /// The prover that constructs the transactions of requests.
+    prover: Prover,
+    /// The usage of the node by each account.
+    usage: UsageRegistry<N>,
+    /// The sequence numbers of the submissions of each account, if enabled.
+    sequences: Option<AccountSequences<N>>,
+    /// Funds the signers of the requests with the faucet record source, unless the node is view-only.
+    auto_fund: Option<FaucetAutoFund<N>>,
+    /// The shutdown signal of the node.
+    shutdown: ShutdownSignal,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Admission<N, C> {
+    /// Initializes a new instance of the admission of transactions.
+    pub fn new(
+        consensus: Option<SingleNodeConsensus<N, C>>,
+        prover: Prover,
+        usage: UsageRegistry<N>,
+        sequences: Option<AccountSequences<N>>,
+        auto_fund: Option<FaucetAutoFund<N>>,
+        shutdown: ShutdownSignal,
+    ) -> Self {
+        Self { consensus, prover, usage, sequences, auto_fund, shutdown }
+    }
+
+    /// Returns the consensus module, or an error if the node has no memory pool.
+    pub fn consensus(&self) -> Result<&SingleNodeConsensus<N, C>> {
+        match &self.consensus {
+            Some(consensus) => Ok(consensus),
+            None => bail!("no memory pool available"),
+        }
+    }
+
+    /// Returns the record provider of the given record source of a request, which defaults to scanning the ledger.
+    /// The faucet record source requires the faucet funding of the node.
+    pub fn record_provider(&self, source: Option<&RecordSource<N>>) -> Result<Box<dyn RecordProvider<N, C>>> {
+        record_provider(source, self.auto_fund.as_ref())
+    }
+
+    /// Constructs a transaction with the given function on the prover, metering the time it takes on behalf of
+    /// the given account.
+    pub async fn construct<T: Send + 'static>(
+        &self,
+        address: Address<N>,
+        construct: impl FnOnce() -> Result<T> + Send + 'static,
+    ) -> Result<T> {
+        self.ensure_running()?;
+        self.prover.run(self.usage.meter(address, construct)).await
+    }
+
+    /// Adds the given transaction to the memory pool, submitted by the given account if it is known.
+    /// The transactions of a known sender are counted in its usage, and assigned its next sequence number,
+    /// if enabled. Returns the response built from the sequence number, which is captured with the request.
+    pub fn submit<R: Serialize>(
+        &self,
+        transaction: Transaction<N>,
+        sender: Option<Address<N>>,
+        capture: CapturedRequest,
+        respond: impl FnOnce(Option<u64>) -> R,
+    ) -> Result<R> {
+        self.ensure_running()?;
+        let consensus = self.consensus()?;
+        let capture = capture.with_transaction(&transaction);
+        if let Err(error) = consensus.add_unconfirmed_transaction_from(transaction, sender) {
+            capture.rejected(&error);
+            return Err(error);
+        }
+        let sequence = match sender {
+            Some(address) => {
+                self.usage.add_transaction(address);
+                self.sequences.as_ref().map(|sequences| sequences.increment(address))
+            }
+            None => None,
+        };
+        let response = respond(sequence);
+        capture.accepted(&response);
+        Ok(response)
+    }
+
+    /// Returns an error if the node is shutting down, as a transaction admitted after the final block is lost.
+    fn ensure_running(&self) -> Result<()> {
+        match self.shutdown.is_shutting_down() {
+            true => Err(SlingshotError::ShuttingDown.into()),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Returns the record provider of the given record source of a request, which defaults to scanning the ledger.
+/// The faucet record source requires the given faucet funding, if the request may fund the signer.
+pub(crate) fn record_provider<N: Network, C: ConsensusStorage<N>>(
+    source: Option<&RecordSource<N>>,
+    auto_fund: Option<&FaucetAutoFund<N>>,
+) -> Result<Box<dyn RecordProvider<N, C>>> {
+    match source {
+        None => Ok(Box::new(NodeScan)),
+        Some(RecordSource::Wallet { records }) => Ok(Box::new(WalletFile::new(records.clone()))),
+        Some(RecordSource::Commitments { commitments }) => Ok(Box::new(ExplicitCommitments::new(commitments.clone()))),
+        Some(RecordSource::Faucet) => match auto_fund {
+            Some(auto_fund) => Ok(Box::new(auto_fund.clone())),
+            None => bail!("the faucet record source is not available for this request"),
+        },
+    }
+}
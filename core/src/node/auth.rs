@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::SlingshotError;
+
+use snarkos::node::rest::with;
+
+use rand::Rng;
+use warp::{reject, Filter, Rejection};
+
+/// Returns a new random API token.
+pub fn generate_api_token() -> String {
+    generate_token()
+}
+
+/// Returns a new random token of 16 bytes, encoded as hex, e.g. an API token or an account token.
+pub(crate) fn generate_token() -> String {
+    rand::thread_rng().gen::<[u8; 16]>().iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Returns `true` if the given `Authorization` header carries the given API token, or if no API token is required.
+/// The tokens are compared in constant time, so that the time of a failed check does not reveal the API token.
+pub fn is_authorized(api_token: Option<&str>, authorization: Option<&str>) -> bool {
+    match api_token {
+        Some(api_token) => match authorization.and_then(|header| header.strip_prefix("Bearer ")) {
+            Some(token) => constant_time_eq(token.as_bytes(), api_token.as_bytes()),
+            None => false,
+        },
+        None => true,
+    }
+}
+
+/// Returns `true` if the given byte strings are equal, in a time that only depends on their lengths.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |difference, (a, b)| difference | (a ^ b)) == 0
+}
+
+/// Returns a filter that rejects requests without the given API token, if one is required.
+/// The token is sent in an `Authorization: Bearer <TOKEN>` header.
+pub fn authorize(api_token: Option<String>) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    warp::header::optional::<String>("authorization")
+        .and(with(api_token))
+        .and_then(|authorization: Option<String>, api_token: Option<String>| async move {
+            match is_authorized(api_token.as_deref(), authorization.as_deref()) {
+                true => Ok(()),
+                false => Err(reject::custom(SlingshotError::Unauthorized)),
+            }
+        })
+        .untuple_one()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_authorized() {
+        assert!(is_authorized(None, None));
+        assert!(is_authorized(Some("secret"), Some("Bearer secret")));
+        assert!(!is_authorized(Some("secret"), None));
+        assert!(!is_authorized(Some("secret"), Some("Bearer other")));
+        assert!(!is_authorized(Some("secret"), Some("secret")));
+        assert!(!is_authorized(Some("secret"), Some("Bearer secre")));
+        assert!(!is_authorized(Some("secret"), Some("Bearer secret2")));
+    }
+
+    #[test]
+    fn test_unauthorized() {
+        assert_eq!(SlingshotError::Unauthorized.status_code(), 401);
+        assert_eq!(generate_token().len(), 32);
+        assert_ne!(generate_token(), generate_token());
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::node::{DevelopmentBeacon, NodeConfig, StandardBlockPolicy};
+
+use snarkvm::prelude::{Block, ConsensusMemory, ConsensusStore, Network, PrivateKey, VM};
+
+use anyhow::Result;
+use std::{net::SocketAddr, sync::atomic::Ordering};
+
+/// Configures and starts a development beacon in the current process, e.g. from a `#[tokio::test]`.
+/// Unless they are provided, the node account is a new account, and the genesis block funds the faucet account.
+pub struct DevelopmentBeaconBuilder<N: Network> {
+    /// The IP at which the node serves its REST routes, if any.
+    rest_ip: Option<SocketAddr>,
+    /// The private key of the node account.
+    private_key: Option<PrivateKey<N>>,
+    /// The private key of the faucet account, which defaults to the node account.
+    faucet_key: Option<PrivateKey<N>>,
+    /// The genesis block.
+    genesis: Option<Block<N>>,
+    /// The configuration of the node.
+    config: NodeConfig,
+}
+
+impl<N: Network> Default for DevelopmentBeaconBuilder<N> {
+    fn default() -> Self {
+        Self { rest_ip: None, private_key: None, faucet_key: None, genesis: None, config: NodeConfig::default() }
+    }
+}
+
+impl<N: Network> DevelopmentBeaconBuilder<N> {
+    /// Serves the REST routes of the node at the given IP. Otherwise, the routes are only served by `node.rest()`.
+    pub fn rest_ip(mut self, rest_ip: SocketAddr) -> Self {
+        self.rest_ip = Some(rest_ip);
+        self
+    }
+
+    /// Sets the private key of the node account.
+    pub fn private_key(mut self, private_key: PrivateKey<N>) -> Self {
+        self.private_key = Some(private_key);
+        self
+    }
+
+    /// Sets the private key of the faucet account.
+    pub fn faucet_key(mut self, faucet_key: PrivateKey<N>) -> Self {
+        self.faucet_key = Some(faucet_key);
+        self
+    }
+
+    /// Sets the genesis block, which must fund the faucet account for the faucet to pour.
+    pub fn genesis(mut self, genesis: Block<N>) -> Self {
+        self.genesis = Some(genesis);
+        self
+    }
+
+    /// Sets the configuration of the node.
+    pub fn config(mut self, config: NodeConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the number of seconds between blocks.
+    pub fn block_interval(self, block_interval: u64) -> Self {
+        self.config.block_interval.store(block_interval, Ordering::SeqCst);
+        self
+    }
+
+    /// Sets the policy that decides when the node produces a block.
+    pub fn block_policy(mut self, block_policy: StandardBlockPolicy) -> Self {
+        self.config.block_policy = block_policy;
+        self
+    }
+
+    /// Starts the node.
+    pub async fn start(self) -> Result<DevelopmentBeacon<N>> {
+        // Initialize the node account, which defaults to a new account.
+        let private_key = match self.private_key {
+            Some(private_key) => private_key,
+            None => PrivateKey::<N>::new(&mut rand::thread_rng())?,
+        };
+        // Initialize the genesis block, which defaults to a block funding the faucet account.
+        let genesis = match self.genesis {
+            Some(genesis) => genesis,
+            None => {
+                let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?;
+                Block::genesis(&vm, self.faucet_key.as_ref().unwrap_or(&private_key), &mut rand::thread_rng())?
+            }
+        };
+        DevelopmentBeacon::new(self.rest_ip, private_key, self.faucet_key, Some(genesis), None, self.config).await
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm::prelude::{Network, Transaction};
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use time::OffsetDateTime;
+
+/// The manifest of a reproduction bundle, which identifies the node that captured it.
+pub const BUNDLE_MANIFEST: &str = "bundle.json";
+
+/// The fields of a request that are removed before it is captured, as they hold credentials,
+/// or records that only exist on the chain of the capturing node.
+const SANITIZED_FIELDS: [&str; 3] = ["private_key", "account_token", "record_source"];
+
+/// A reproduction bundle, to which the node writes each deploy, execute, and pour request it accepts,
+/// with the resulting transaction and receipt, so that the requests can be replayed on another node
+/// with `slingshot replay-bundle`. Each request is written to `<sequence>-<kind>.json`, in the order of acceptance.
+/// Without a directory, the bundle is disabled, and nothing is written.
+#[derive(Clone, Debug, Default)]
+pub struct CaptureBundle {
+    /// The directory of the bundle, if capturing is enabled.
+    directory: Option<PathBuf>,
+    /// The sequence number of the next entry.
+    next_sequence: Arc<AtomicU64>,
+}
+
+impl CaptureBundle {
+    /// Opens the bundle in the given directory, and writes its manifest. The entries of an existing bundle
+    /// are kept, and the new entries are numbered after them.
+    pub fn open<N: Network>(directory: &Path) -> Result<Self> {
+        std::fs::create_dir_all(directory)?;
+        let mut next_sequence = 1;
+        for entry in std::fs::read_dir(directory)? {
+            let name = entry?.file_name().to_string_lossy().to_string();
+            if let Some(sequence) = name.split('-').next().and_then(|sequence| sequence.parse::<u64>().ok()) {
+                next_sequence = next_sequence.max(sequence + 1);
+            }
+        }
+        let manifest = json!({
+            "slingshot_version": env!("CARGO_PKG_VERSION"),
+            "network_id": N::ID,
+            "created_at": OffsetDateTime::now_utc().unix_timestamp(),
+        });
+        std::fs::write(directory.join(BUNDLE_MANIFEST), serde_json::to_string_pretty(&manifest)?)?;
+        Ok(Self { directory: Some(directory.to_path_buf()), next_sequence: Arc::new(AtomicU64::new(next_sequence)) })
+    }
+
+    /// Returns the entry of the given request of the given signer, without its credentials.
+    pub fn entry<R: Serialize>(&self, kind: &'static str, request: &R, signer: Option<String>) -> CapturedRequest {
+        let entry = self.directory.as_ref().map(|_| {
+            let mut request = serde_json::to_value(request).unwrap_or_default();
+            if let Some(request) = request.as_object_mut() {
+                SANITIZED_FIELDS.iter().for_each(|field| {
+                    request.remove(*field);
+                });
+            }
+            json!({ "kind": kind, "signer": signer, "request": request })
+        });
+        CapturedRequest { bundle: self.clone(), entry }
+    }
+
+    /// Writes the given entry to the bundle, with the next sequence number.
+    fn write(&self, mut entry: Value) {
+        let directory = match &self.directory {
+            Some(directory) => directory,
+            None => return,
+        };
+        let sequence = self.next_sequence.fetch_add(1, Ordering::SeqCst);
+        entry["sequence"] = sequence.into();
+        entry["timestamp"] = OffsetDateTime::now_utc().unix_timestamp().into();
+        let path = directory.join(format!("{sequence:06}-{}.json", entry["kind"].as_str().unwrap_or_default()));
+        let result = serde_json::to_string_pretty(&entry)
+            .map_err(anyhow::Error::from)
+            .and_then(|entry| Ok(std::fs::write(&path, entry)?));
+        if let Err(error) = result {
+            warn!("Failed to capture the request to '{}': {error}", path.display());
+        }
+    }
+}
+
+/// A request that is written to its bundle once its transaction is accepted or rejected by the memory pool.
+#[derive(Debug)]
+pub struct CapturedRequest {
+    /// The bundle of the request.
+    bundle: CaptureBundle,
+    /// The entry of the request, if capturing is enabled.
+    entry: Option<Value>,
+}
+
+impl CapturedRequest {
+    /// Returns a request that is not captured, as it cannot be replayed from a bundle.
+    pub fn disabled() -> Self {
+        Self { bundle: CaptureBundle::default(), entry: None }
+    }
+
+    /// Attaches the transaction of the request.
+    pub fn with_transaction<N: Network>(mut self, transaction: &Transaction<N>) -> Self {
+        if let Some(entry) = &mut self.entry {
+            entry["transaction"] = serde_json::to_value(transaction).unwrap_or_default();
+        }
+        self
+    }
+
+    /// Writes the request, whose transaction was accepted, with the given receipt.
+    pub fn accepted<R: Serialize>(self, receipt: &R) {
+        if let Some(mut entry) = self.entry {
+            entry["receipt"] = json!({ "accepted": true, "response": receipt });
+            self.bundle.write(entry);
+        }
+    }
+
+    /// Writes the request, whose transaction was rejected with the given error.
+    pub fn rejected(self, error: &anyhow::Error) {
+        if let Some(mut entry) = self.entry {
+            entry["receipt"] = json!({ "accepted": false, "error": error.to_string() });
+            self.bundle.write(entry);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = snarkvm::prelude::Testnet3;
+
+    #[test]
+    fn test_capture_sanitizes_requests() {
+        let directory = std::env::temp_dir().join(format!("slingshot-capture-{}", std::process::id()));
+        let bundle = CaptureBundle::open::<CurrentNetwork>(&directory).unwrap();
+
+        let request = json!({ "private_key": "APrivateKey1", "program_id": "hello.aleo", "record_source": null });
+        bundle.entry("execute", &request, Some("aleo1".to_string())).accepted(&json!({ "transaction_id": "at1" }));
+
+        let entry: Value =
+            serde_json::from_str(&std::fs::read_to_string(directory.join("000001-execute.json")).unwrap()).unwrap();
+        assert_eq!(entry["request"], json!({ "program_id": "hello.aleo" }));
+        assert_eq!(entry["signer"], "aleo1");
+        assert_eq!(entry["receipt"]["accepted"], true);
+
+        // A reopened bundle numbers its entries after the existing entries.
+        let bundle = CaptureBundle::open::<CurrentNetwork>(&directory).unwrap();
+        bundle.entry("pour", &request, None).rejected(&anyhow::anyhow!("the memory pool is full"));
+        assert!(directory.join("000002-pour.json").exists());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,248 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm::prelude::{Block, FromBytes, Network, ToBytes};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+/// The magic bytes at the start of a chain file.
+const MAGIC: [u8; 8] = *b"SLSHCHN\0";
+/// The version of the format of chain files, which is incremented on each incompatible change.
+pub const CHAIN_FILE_VERSION: u32 = 1;
+
+/// A chain file, which contains every block of a chain up to some height, starting with the genesis block.
+/// The file starts with 8 magic bytes and the version of its format as a `u32`, followed by the blocks in the
+/// canonical byte encoding of snarkVM: the number of blocks as a `u32`, followed by the length of each block as
+/// a `u32`, and its bytes. Snapshots are chain files.
+pub struct ChainFile;
+
+impl ChainFile {
+    /// Writes the given number of blocks to a chain file at the given path, and returns its size in bytes.
+    pub fn write<N: Network>(
+        path: &Path,
+        num_blocks: u32,
+        blocks: impl Iterator<Item = Result<Block<N>>>,
+    ) -> Result<u64> {
+        // Write the blocks to a temporary file, so that an interrupted write does not leave a partial chain file.
+        let partial_path = path.with_extension("partial");
+        let mut writer = BufWriter::new(File::create(&partial_path)?);
+        writer.write_all(&MAGIC)?;
+        CHAIN_FILE_VERSION.write_le(&mut writer)?;
+        num_blocks.write_le(&mut writer)?;
+        let mut num_written = 0;
+        for block in blocks.take(num_blocks as usize) {
+            let bytes = block?.to_bytes_le()?;
+            (bytes.len() as u32).write_le(&mut writer)?;
+            writer.write_all(&bytes)?;
+            num_written += 1;
+        }
+        ensure!(num_written == num_blocks, "Expected {num_blocks} blocks, found {num_written}");
+        writer.flush()?;
+        drop(writer);
+        std::fs::rename(&partial_path, path)?;
+
+        Ok(std::fs::metadata(path)?.len())
+    }
+
+    /// Reads the blocks of the chain file at the given path, starting with the genesis block.
+    pub fn read<N: Network>(path: &Path) -> Result<Vec<Block<N>>> {
+        ChainReader::open(path, false)?.collect()
+    }
+
+    /// Reads the genesis block of the chain file at the given path, without reading the other blocks.
+    pub fn read_genesis<N: Network>(path: &Path) -> Result<Block<N>> {
+        match ChainReader::open(path, false)?.next() {
+            Some(genesis) => genesis,
+            None => bail!("'{}' contains no blocks", path.display()),
+        }
+    }
+
+    /// Upgrades the chain file at the given path to the current version of the format, in place,
+    /// and returns the version it was upgraded from, or `None` if it is already of the current version.
+    /// A chain file without a header, written before chain files were versioned, is of version 0.
+    pub fn migrate<N: Network>(path: &Path) -> Result<Option<u32>> {
+        match Self::version(path)? {
+            Some(CHAIN_FILE_VERSION) => return Ok(None),
+            Some(version) => bail!(
+                "'{}' is a chain file of version {version}, which is not supported by this version of slingshot \
+                 (expected version {CHAIN_FILE_VERSION})",
+                path.display()
+            ),
+            None => (),
+        }
+        // Rewrite the blocks of version 0 after the header of the current version.
+        let blocks = ChainReader::open(path, true)?.collect::<Result<Vec<Block<N>>>>()?;
+        Self::write(path, blocks.len() as u32, blocks.into_iter().map(Ok))?;
+        Ok(Some(0))
+    }
+
+    /// Returns the version of the chain file at the given path, or `None` if it has no header.
+    fn version(path: &Path) -> Result<Option<u32>> {
+        let mut header = [0u8; MAGIC.len() + 4];
+        let mut file = File::open(path).map_err(|error| anyhow!("Failed to open '{}': {error}", path.display()))?;
+        match file.read_exact(&mut header) {
+            Ok(()) if header[..MAGIC.len()] == MAGIC => Ok(Some(u32::from_le_bytes(header[MAGIC.len()..].try_into()?))),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Reads the blocks of a chain file one at a time.
+/// Note: The sizes in the file are bounded by its length, so that a corrupt file cannot exhaust the memory.
+struct ChainReader<N: Network> {
+    /// The path of the chain file.
+    path: PathBuf,
+    /// The reader of the chain file.
+    reader: BufReader<File>,
+    /// The number of bytes of the chain file that are not read yet.
+    remaining: u64,
+    /// The number of blocks that are not read yet.
+    num_blocks: u32,
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> ChainReader<N> {
+    /// Opens the chain file at the given path, and reads its header, or only the number of its blocks
+    /// if it is a chain file of version 0, without a header.
+    fn open(path: &Path, is_version_0: bool) -> Result<Self> {
+        let file = File::open(path).map_err(|error| anyhow!("Failed to open '{}': {error}", path.display()))?;
+        let remaining = file.metadata()?.len();
+        let mut reader = Self {
+            path: path.to_path_buf(),
+            reader: BufReader::new(file),
+            remaining,
+            num_blocks: 0,
+            _phantom: PhantomData,
+        };
+
+        // Ensure the file is a chain file of the current version.
+        if !is_version_0 {
+            let magic: [u8; MAGIC.len()] = reader.read_array()?;
+            ensure!(
+                magic == MAGIC,
+                "'{}' is not a chain file, or was written by an older version of slingshot \
+                 (upgrade it with 'slingshot node migrate --chain <PATH>')",
+                path.display()
+            );
+            let version = u32::from_le_bytes(reader.read_array()?);
+            ensure!(
+                version == CHAIN_FILE_VERSION,
+                "'{}' is a chain file of version {version}, which is not supported by this version of slingshot \
+                 (expected version {CHAIN_FILE_VERSION})",
+                path.display()
+            );
+        }
+
+        // Read the number of blocks, each of which takes at least the 4 bytes of its length.
+        let num_blocks = u32::from_le_bytes(reader.read_array()?);
+        ensure!(num_blocks > 0, "'{}' contains no blocks", path.display());
+        ensure!(
+            u64::from(num_blocks) * 4 <= reader.remaining,
+            "'{}' is truncated, as it is too short for {num_blocks} blocks",
+            path.display()
+        );
+        reader.num_blocks = num_blocks;
+        Ok(reader)
+    }
+
+    /// Reads the next block.
+    fn read_block(&mut self) -> Result<Block<N>> {
+        let length = u32::from_le_bytes(self.read_array()?);
+        ensure!(
+            u64::from(length) <= self.remaining,
+            "'{}' is truncated, as it is too short for a block of {length} bytes",
+            self.path.display()
+        );
+        let mut bytes = vec![0u8; length as usize];
+        self.read_exact(&mut bytes)?;
+        Block::from_bytes_le(&bytes)
+    }
+
+    /// Reads the given number of bytes.
+    fn read_array<const SIZE: usize>(&mut self) -> Result<[u8; SIZE]> {
+        let mut bytes = [0u8; SIZE];
+        self.read_exact(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Fills the given buffer with the next bytes of the file.
+    fn read_exact(&mut self, bytes: &mut [u8]) -> Result<()> {
+        self.reader
+            .read_exact(bytes)
+            .map_err(|error| anyhow!("'{}' is truncated or unreadable: {error}", self.path.display()))?;
+        self.remaining = self.remaining.saturating_sub(bytes.len() as u64);
+        Ok(())
+    }
+}
+
+impl<N: Network> Iterator for ChainReader<N> {
+    type Item = Result<Block<N>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.num_blocks {
+            0 => None,
+            _ => {
+                self.num_blocks -= 1;
+                Some(self.read_block())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::prelude::Testnet3;
+
+    /// Writes the given bytes to a new file, and returns its path.
+    fn write_file(name: &str, bytes: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("slingshot-chain-{name}-{}", std::process::id()));
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_read_corrupt_chain_file() {
+        let header = [&MAGIC[..], &CHAIN_FILE_VERSION.to_le_bytes()].concat();
+
+        // A file that is not a chain file, e.g. of an older version without a header, is rejected.
+        let path = write_file("magic", &[1, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(ChainFile::read::<Testnet3>(&path).unwrap_err().to_string().contains("slingshot node migrate"));
+
+        // A chain file of a newer version is rejected, and cannot be migrated.
+        let path = write_file("version", &[&MAGIC[..], &2u32.to_le_bytes(), &1u32.to_le_bytes()].concat());
+        assert!(ChainFile::read::<Testnet3>(&path).unwrap_err().to_string().contains("of version 2"));
+        assert!(ChainFile::migrate::<Testnet3>(&path).unwrap_err().to_string().contains("of version 2"));
+
+        // A hostile number of blocks is rejected, without allocating them.
+        let path = write_file("blocks", &[&header[..], &u32::MAX.to_le_bytes()].concat());
+        assert!(ChainFile::read::<Testnet3>(&path).unwrap_err().to_string().contains("is truncated"));
+
+        // A hostile length of a block is rejected, without allocating it.
+        let path = write_file("length", &[&header[..], &1u32.to_le_bytes(), &u32::MAX.to_le_bytes()].concat());
+        assert!(ChainFile::read_genesis::<Testnet3>(&path).unwrap_err().to_string().contains("is truncated"));
+
+        // An empty chain file is rejected.
+        let path = write_file("empty", &[&header[..], &0u32.to_le_bytes()].concat());
+        assert!(ChainFile::read_genesis::<Testnet3>(&path).unwrap_err().to_string().contains("contains no blocks"));
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::node::{
+    BlockTemplateScript,
+    CorsPolicy,
+    FaucetPolicy,
+    LogBuffer,
+    PoolLimits,
+    RestLimits,
+    SnapshotConfig,
+    StandardBlockPolicy,
+    DEFAULT_JOB_RETENTION_IN_SECS,
+};
+
+use core::time::Duration;
+use parking_lot::RwLock;
+use std::{
+    net::SocketAddr,
+    path::PathBuf,
+    sync::{atomic::AtomicU64, Arc},
+};
+
+/// The default port of the REST server.
+pub const DEFAULT_REST_PORT: u16 = 4180;
+
+/// The default number of seconds between blocks.
+pub const DEFAULT_BLOCK_INTERVAL: u64 = 15;
+
+/// The default number of transactions the node constructs at once.
+pub const DEFAULT_MAX_CONCURRENT_PROOFS: usize = 2;
+
+/// The configuration of a development node.
+#[derive(Clone, Debug)]
+pub struct NodeConfig {
+    /// If `true`, deploy and execute requests may contain a raw private key, instead of an account token.
+    pub allow_private_keys: bool,
+    /// The address of the gRPC server, if it is enabled.
+    pub grpc_ip: Option<SocketAddr>,
+    /// The number of seconds the host clock may fall behind the latest block timestamp,
+    /// in which case the next block is timestamped one second after the latest block.
+    pub clock_skew_tolerance: u64,
+    /// The policy that decides when the node produces a block. It can be changed while the node is running,
+    /// via `POST /testnet3/node/blockPolicy`.
+    pub block_policy: StandardBlockPolicy,
+    /// If `true`, the node assigns a sequence number to each deploy and execute request of an account.
+    pub account_sequences: bool,
+    /// The number of seconds between blocks. This is shared with the block production loop,
+    /// so that it can be changed while the node is running.
+    pub block_interval: Arc<AtomicU64>,
+    /// If `true`, the node produces a block every round, even if the memory pool is empty.
+    pub produce_empty_blocks: bool,
+    /// The number of faucet records to split off at initialization, and the gates in each record.
+    pub genesis_records: Option<(usize, u64)>,
+    /// The number of gates the faucet keeps at initialization. The rest of its genesis supply
+    /// is transferred to the node account, before any other block is produced.
+    pub faucet_share: Option<u64>,
+    /// The number of threads used to construct the transactions of requests.
+    pub proving_threads: usize,
+    /// The maximum number of transactions the node constructs at once. Further requests wait for their turn.
+    pub max_concurrent_proofs: usize,
+    /// The maximum number of transactions waiting for their turn, if any. Further requests are rejected.
+    pub max_queued_proofs: Option<usize>,
+    /// The directory the proving and verifying keys of program functions are persisted to, if any.
+    pub cache_dir: Option<PathBuf>,
+    /// The endpoint of the remote prover the proofs of executions are delegated to, if any.
+    pub prover_endpoint: Option<String>,
+    /// The capacity, per-sender limit, and eviction policy of the memory pool.
+    pub pool_limits: PoolLimits,
+    /// The configuration of the automatic snapshots of the ledger, if they are enabled.
+    pub auto_snapshot: Option<SnapshotConfig>,
+    /// The snapshot or chain file the node is restored from, if any. Its first block is the genesis block of the node.
+    pub restore: Option<PathBuf>,
+    /// The number of validators in the devnet, which propose blocks in turn. The node itself is the first validator.
+    pub validators: usize,
+    /// The minimum delay before a message gossiped between the validators is delivered.
+    pub gossip_latency: Duration,
+    /// The maximum random delay added to the latency of each gossiped message.
+    pub gossip_jitter: Duration,
+    /// The base endpoint of the slingshot node the node replicates its blocks from, if any.
+    /// A replica does not produce blocks; its genesis block must be the genesis block of the peer.
+    pub peer: Option<String>,
+    /// The token required by deploy, execute, pour, and node control requests, if any.
+    pub api_token: Option<String>,
+    /// If `true`, the node signs each response, with the request it answers and the time, with its account key.
+    pub sign_responses: bool,
+    /// The body size limit, timeouts, and concurrent request limit of the REST server.
+    pub rest_limits: RestLimits,
+    /// The time a completed background job is retained after its last status update.
+    pub job_retention: Duration,
+    /// The script that changes each proposed block before it is signed, if any. For consensus experiments only.
+    pub block_template: Option<BlockTemplateScript>,
+    /// The recent log entries of the node, served at `/testnet3/node/logs`.
+    pub logs: LogBuffer,
+    /// The policy of the faucet. This is shared with the REST server, so that it can be reloaded.
+    pub faucet_policy: Arc<RwLock<FaucetPolicy>>,
+    /// The CORS policy of the REST server. This is shared with the REST server, so that it can be reloaded.
+    pub cors_policy: Arc<RwLock<CorsPolicy>>,
+    /// The configuration file the reloadable settings are read from, if any.
+    pub config_file: Option<PathBuf>,
+    /// The directory of the reproduction bundle the accepted requests are captured to, if any.
+    pub capture: Option<PathBuf>,
+}
+
+impl Default for NodeConfig {
+    fn default() -> Self {
+        Self {
+            allow_private_keys: false,
+            grpc_ip: None,
+            clock_skew_tolerance: 0,
+            block_policy: StandardBlockPolicy::default(),
+            account_sequences: false,
+            block_interval: Arc::new(AtomicU64::new(DEFAULT_BLOCK_INTERVAL)),
+            produce_empty_blocks: false,
+            genesis_records: None,
+            faucet_share: None,
+            proving_threads: num_cpus::get(),
+            max_concurrent_proofs: DEFAULT_MAX_CONCURRENT_PROOFS,
+            max_queued_proofs: None,
+            cache_dir: None,
+            prover_endpoint: None,
+            pool_limits: Default::default(),
+            auto_snapshot: None,
+            restore: None,
+            validators: 1,
+            gossip_latency: Duration::ZERO,
+            gossip_jitter: Duration::ZERO,
+            peer: None,
+            api_token: None,
+            sign_responses: false,
+            rest_limits: Default::default(),
+            job_retention: Duration::from_secs(DEFAULT_JOB_RETENTION_IN_SECS),
+            block_template: None,
+            logs: Default::default(),
+            faucet_policy: Default::default(),
+            cors_policy: Default::default(),
+            config_file: None,
+            capture: None,
+        }
+    }
+}
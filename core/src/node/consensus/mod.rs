@@ -16,14 +16,29 @@
 
 #![forbid(unsafe_code)]
 
-use crate::node::{Ledger, TransactionPool};
+pub mod policy;
+pub use policy::*;
+
+use crate::{
+    errors::SlingshotError,
+    node::{storage_fee, BlockTemplate, BlockTemplateHook, ChainStats, Ledger, NodeScan, PoolLimits, TransactionPool},
+};
 
 use snarkos::node::consensus::{coinbase_target, proof_target};
 
 use snarkvm::prelude::*;
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, ensure, Result};
+use parking_lot::{Mutex, RwLock};
 use rayon::iter::ParallelIterator;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, AtomicI64, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
 use time::OffsetDateTime;
 
 #[cfg(feature = "parallel")]
@@ -35,13 +50,62 @@ pub struct SingleNodeConsensus<N: Network, C: ConsensusStorage<N>> {
     ledger: Ledger<N, C>,
     /// The memory pool.
     memory_pool: TransactionPool<N>,
+    /// The number of seconds the host clock may fall behind the latest block timestamp.
+    clock_skew_tolerance: u64,
+    /// The policy that decides when blocks are produced.
+    block_policy: Arc<RwLock<Arc<dyn BlockPolicy>>>,
+    /// The number of seconds added to the host clock, to timestamp blocks.
+    time_offset: Arc<AtomicI64>,
+    /// The timestamp of the next block, if it is set.
+    next_block_timestamp: Arc<RwLock<Option<i64>>>,
+    /// The lock held while a block is produced, so that blocks are produced one at a time.
+    block_production: Arc<Mutex<()>>,
+    /// If `true`, scheduled block production is paused. Blocks may still be produced on demand.
+    paused: Arc<AtomicBool>,
+    /// The statistics of the latest blocks added to the ledger.
+    stats: ChainStats,
+    /// The hook that changes each proposed block before it is signed, if any.
+    template_hook: Arc<RwLock<Option<Arc<dyn BlockTemplateHook<N>>>>>,
+    /// The accounts that submitted deployments to the memory pool, by transaction ID, and the height of the block
+    /// that confirmed each deployment, once it is confirmed. An entry is removed once the `program_deployed` event of
+    /// the deployment is published, or once the deployment leaves the memory pool without being confirmed.
+    deployers: Arc<RwLock<HashMap<N::TransactionID, (Address<N>, Option<u32>)>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> SingleNodeConsensus<N, C> {
-    /// Initializes a new instance of consensus.
-    pub fn new(ledger: Ledger<N, C>) -> Result<Self> {
+    /// Initializes a new instance of consensus, with a memory pool of the given limits.
+    pub fn new(ledger: Ledger<N, C>, clock_skew_tolerance: u64, pool_limits: PoolLimits) -> Result<Self> {
+        // Release the records reserved by the transactions that leave the memory pool.
+        let memory_pool = TransactionPool::new(pool_limits);
+        let pool = memory_pool.clone();
+        ledger
+            .reservations()
+            .set_pending_check(move |transaction_id| pool.contains_unconfirmed_transaction(*transaction_id));
+
         // Initialize consensus.
-        Ok(Self { ledger, memory_pool: Default::default() })
+        Ok(Self {
+            ledger,
+            memory_pool,
+            clock_skew_tolerance,
+            block_policy: Arc::new(RwLock::new(Arc::new(StandardBlockPolicy::default()))),
+            time_offset: Default::default(),
+            next_block_timestamp: Default::default(),
+            block_production: Default::default(),
+            paused: Default::default(),
+            stats: Default::default(),
+            template_hook: Default::default(),
+            deployers: Default::default(),
+        })
+    }
+
+    /// Sets the hook that changes each proposed block before it is signed, for consensus experiments.
+    pub fn set_block_template_hook(&self, hook: Arc<dyn BlockTemplateHook<N>>) {
+        *self.template_hook.write() = Some(hook);
+    }
+
+    /// Returns the statistics of the latest blocks added to the ledger.
+    pub const fn stats(&self) -> &ChainStats {
+        &self.stats
     }
 
     /// Returns the memory pool.
@@ -49,20 +113,191 @@ impl<N: Network, C: ConsensusStorage<N>> SingleNodeConsensus<N, C> {
         &self.memory_pool
     }
 
+    /// Returns the policy that decides when blocks are produced.
+    pub fn block_policy(&self) -> Arc<dyn BlockPolicy> {
+        self.block_policy.read().clone()
+    }
+
+    /// Sets the policy that decides when blocks are produced, which takes effect in the next round.
+    pub fn set_block_policy(&self, policy: Arc<dyn BlockPolicy>) {
+        *self.block_policy.write() = policy;
+    }
+
     /// Adds the given unconfirmed transaction to the memory pool.
     pub fn add_unconfirmed_transaction(&self, transaction: Transaction<N>) -> Result<()> {
+        self.add_unconfirmed_transaction_from(transaction, None)
+    }
+
+    /// Adds the given unconfirmed transaction, submitted by the given account if it is known, to the memory pool.
+    /// The transactions of a known sender count towards its limit in the memory pool.
+    pub fn add_unconfirmed_transaction_from(
+        &self,
+        transaction: Transaction<N>,
+        sender: Option<Address<N>>,
+    ) -> Result<()> {
         // Ensure the transaction is not already in the memory pool.
         if self.memory_pool.contains_unconfirmed_transaction(transaction.id()) {
             bail!("Transaction is already in the memory pool.");
         }
+        // Check that the transaction is well-formed and unique, and insert it to the memory pool.
+        // If it is not admitted, the records it spends are released.
+        if let Err(error) = self
+            .check_transaction_basic(&transaction)
+            .and_then(|_| self.memory_pool.add_unconfirmed_transaction(&transaction, sender))
+        {
+            self.ledger.reservations().release(&transaction);
+            return Err(error);
+        }
+        // Keep the records it spends reserved, while it is in the memory pool.
+        self.ledger.reservations().submit(&transaction);
+        // Remember the account that submitted a deployment, for its `program_deployed` event.
+        if let (Transaction::Deploy(..), Some(sender)) = (&transaction, sender) {
+            self.prune_deployers();
+            self.deployers.write().insert(transaction.id(), (sender, None));
+        }
+        Ok(())
+    }
+
+    /// Returns the account that submitted the given deployment to the memory pool, if it is known.
+    pub fn deployer(&self, transaction_id: &N::TransactionID) -> Option<Address<N>> {
+        self.deployers.read().get(transaction_id).map(|(address, _)| *address)
+    }
+
+    /// Removes and returns the account that submitted the given deployment to the memory pool, if it is known,
+    /// as the `program_deployed` event of the deployment is published.
+    pub fn take_deployer(&self, transaction_id: &N::TransactionID) -> Option<Address<N>> {
+        self.deployers.write().remove(transaction_id).map(|(address, _)| address)
+    }
+
+    /// Removes the accounts that submitted the deployments confirmed up to the given height, whose events are not
+    /// published, e.g. as there are no subscribers.
+    pub fn release_deployers(&self, height: u32) {
+        self.deployers.write().retain(|_, (_, confirmed_at)| !matches!(confirmed_at, Some(at) if *at <= height));
+    }
+
+    /// Removes the accounts that submitted the deployments that left the memory pool without being confirmed,
+    /// as they were rejected, evicted, or removed.
+    fn prune_deployers(&self) {
+        self.deployers.write().retain(|transaction_id, (_, confirmed_at)| {
+            confirmed_at.is_some() || self.memory_pool.contains_unconfirmed_transaction(*transaction_id)
+        });
+    }
+
+    /// Checks that the given unconfirmed transaction would be accepted into the memory pool, without adding it.
+    pub fn check_unconfirmed_transaction(&self, transaction: &Transaction<N>) -> Result<()> {
         // Check that the transaction is well-formed and unique.
-        self.check_transaction_basic(&transaction)?;
-        // Insert the transaction to the memory pool.
-        self.memory_pool.add_unconfirmed_transaction(&transaction);
+        self.check_transaction_basic(transaction)?;
+        // Ensure the transaction does not spend a record that is spent by a transaction in the memory pool.
+        for unconfirmed_transaction in self.memory_pool.unconfirmed_transactions() {
+            if let Some(serial_number) = transaction
+                .serial_numbers()
+                .find(|serial_number| unconfirmed_transaction.serial_numbers().any(|other| other == *serial_number))
+            {
+                bail!(
+                    "Serial number '{serial_number}' is already spent by transaction '{}' in the memory pool",
+                    unconfirmed_transaction.id()
+                )
+            }
+        }
+        Ok(())
+    }
 
+    /// Proposes the next block, signed by the given private key, and advances the ledger to it.
+    /// If the block is invalid, the invalid transactions are rejected from the memory pool.
+    pub fn produce_next_block<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Block<N>> {
+        let _lock = self.block_production.lock();
+        self.produce_next_block_internal(private_key, rng)
+    }
+
+    /// Produces the next block as in `produce_next_block`, unless scheduled block production is paused,
+    /// in which case `None` is returned.
+    pub fn produce_scheduled_block<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<Option<Block<N>>> {
+        let _lock = self.block_production.lock();
+        // Check the pause flag while holding the lock, so that no block is produced once `pause` returns.
+        if self.is_paused() {
+            return Ok(None);
+        }
+        self.produce_next_block_internal(private_key, rng).map(Some)
+    }
+
+    /// Checks the given block, proposed by another validator, and advances the ledger to it.
+    pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
+        let _lock = self.block_production.lock();
+        let (start, mempool_depth) = (Instant::now(), self.memory_pool.num_unconfirmed_transactions());
+        self.check_next_block(block)?;
+        self.advance_to_next_block(block)?;
+        self.stats.record(block, start.elapsed(), mempool_depth);
         Ok(())
     }
 
+    /// Returns `true` if scheduled block production is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Pauses scheduled block production. If a block is being produced, this waits until it is complete.
+    pub fn pause(&self) {
+        let _lock = self.block_production.lock();
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Resumes scheduled block production.
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+    }
+
+    /// Proposes the next block, and advances the ledger to it. The caller must hold the block production lock.
+    fn produce_next_block_internal<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        let (start, mempool_depth) = (Instant::now(), self.memory_pool.num_unconfirmed_transactions());
+        let next_block = self.propose_next_block(private_key, rng)?;
+
+        // Ensure the block is a valid next block.
+        if let Err(error) = self.check_next_block(&next_block) {
+            // Reject the invalid transactions, and retain the valid transactions for the next proposal.
+            self.recover_memory_pool(&next_block, &error);
+            bail!("Proposed an invalid block: {error}")
+        }
+
+        // Advance to the next block.
+        match self.advance_to_next_block(&next_block) {
+            Ok(()) => {
+                // Record the statistics of the next block.
+                self.stats.record(&next_block, start.elapsed(), mempool_depth);
+                // Log the next block.
+                match serde_json::to_string_pretty(&next_block.header()) {
+                    Ok(header) => info!("Block {}: {header}", next_block.height()),
+                    Err(error) => info!("Block {}: (serde failed: {error})", next_block.height()),
+                }
+            }
+            Err(error) => {
+                // Reject the invalid transactions, and retain the valid transactions for the next proposal.
+                self.recover_memory_pool(&next_block, &error);
+                bail!("Failed to advance to the next block: {error}")
+            }
+        }
+
+        Ok(next_block)
+    }
+
+    /// Adds a transfer of zero gates from the account of the given private key to itself to the memory pool,
+    /// so that the next block is not empty.
+    pub fn add_empty_block_transaction(&self, private_key: &PrivateKey<N>) -> Result<()> {
+        let transaction = self
+            .ledger
+            .create_transfer(private_key, &NodeScan, Address::try_from(private_key)?, 0)
+            .map_err(|error| anyhow!("Failed to create a transaction for an empty block: {error}"))?;
+        trace!("Adding transaction '{}' to produce an empty block...", transaction.id());
+        self.add_unconfirmed_transaction(transaction)
+    }
+
     /// Returns a candidate for the next block in the ledger.
     pub fn propose_next_block<R: Rng + CryptoRng>(&self, private_key: &PrivateKey<N>, rng: &mut R) -> Result<Block<N>> {
         // Retrieve the latest state root.
@@ -72,16 +307,32 @@ impl<N: Network, C: ConsensusStorage<N>> SingleNodeConsensus<N, C> {
         // Retrieve the latest height.
         let latest_height = latest_block.height();
 
-        // Select the transactions from the memory pool.
-        let transactions = self.memory_pool.candidate_transactions(self).into_iter().collect::<Transactions<N>>();
+        // Fetch the next height.
+        let next_height = latest_height.saturating_add(1);
+
+        // Select the transactions from the memory pool, and timestamp the block.
+        let mut template = BlockTemplate {
+            transactions: self.memory_pool.candidate_transactions(self),
+            timestamp: self.next_timestamp(latest_block.timestamp()),
+        };
+        // Apply the block template hook, if any.
+        if let Some(hook) = self.template_hook.read().clone() {
+            hook.apply(next_height, &mut template)?;
+            ensure!(
+                template.timestamp > latest_block.timestamp(),
+                "The block template hook set the timestamp {} at or before the latest block timestamp {}",
+                template.timestamp,
+                latest_block.timestamp()
+            );
+        }
+        let transactions = template.transactions.into_iter().collect::<Transactions<N>>();
 
         // Construct the coinbase solution.
         let coinbase = None;
         let coinbase_accumulator_point = Field::<N>::zero();
 
         // Fetch the next round state.
-        let next_timestamp = OffsetDateTime::now_utc().unix_timestamp();
-        let next_height = latest_height.saturating_add(1);
+        let next_timestamp = template.timestamp;
         let next_round = latest_block.round().saturating_add(1);
 
         // Construct the next coinbase target.
@@ -119,13 +370,83 @@ impl<N: Network, C: ConsensusStorage<N>> SingleNodeConsensus<N, C> {
         Block::new(private_key, latest_block.hash(), header, transactions, coinbase, rng)
     }
 
+    /// Returns the timestamp for the block after a block with the given timestamp.
+    /// If the host clock has fallen behind the latest block timestamp (e.g. after a suspend or an NTP adjustment)
+    /// by at most the clock skew tolerance, the timestamp is one second after the latest block timestamp instead.
+    /// If the timestamp of the next block is set, it is used instead.
+    fn next_timestamp(&self, latest_timestamp: i64) -> i64 {
+        if let Some(next_block_timestamp) = *self.next_block_timestamp.read() {
+            return next_block_timestamp;
+        }
+        let now = self.now();
+        if now <= latest_timestamp && latest_timestamp.saturating_sub(now) as u64 <= self.clock_skew_tolerance {
+            trace!(
+                "The host clock is {}s behind the latest block, using a monotonic timestamp",
+                latest_timestamp - now
+            );
+            return latest_timestamp.saturating_add(1);
+        }
+        now
+    }
+
+    /// Returns the current time, which is the host clock advanced by the time offset.
+    fn now(&self) -> i64 {
+        OffsetDateTime::now_utc().unix_timestamp().saturating_add(self.time_offset.load(Ordering::SeqCst))
+    }
+
+    /// Returns the number of seconds added to the host clock, to timestamp blocks.
+    pub fn time_offset(&self) -> i64 {
+        self.time_offset.load(Ordering::SeqCst)
+    }
+
+    /// Returns the timestamp of the next block, if it is set.
+    pub fn next_block_timestamp(&self) -> Option<i64> {
+        *self.next_block_timestamp.read()
+    }
+
+    /// Advances the time used to timestamp blocks by the given number of seconds, and returns the new time offset.
+    pub fn increase_time(&self, seconds: u64) -> Result<i64> {
+        let seconds = i64::try_from(seconds).map_err(|_| anyhow!("Cannot increase the time by {seconds} seconds"))?;
+        Ok(self.time_offset.fetch_add(seconds, Ordering::SeqCst).saturating_add(seconds))
+    }
+
+    /// Sets the timestamp of the next block. Subsequent blocks are timestamped relative to it.
+    pub fn set_next_block_timestamp(&self, timestamp: i64) -> Result<()> {
+        let latest_timestamp = self.ledger.latest_timestamp();
+        ensure!(
+            timestamp > latest_timestamp,
+            "The next block timestamp {timestamp} must be after the latest block timestamp {latest_timestamp}"
+        );
+        *self.next_block_timestamp.write() = Some(timestamp);
+        Ok(())
+    }
+
     /// Advances the ledger to the next block.
     pub fn advance_to_next_block(&self, block: &Block<N>) -> Result<()> {
         // Adds the next block to the ledger.
         self.ledger.add_next_block(block)?;
 
+        // Keep the accounts that submitted the deployments in the block, until their events are published.
+        let mut deployers = self.deployers.write();
+        for transaction_id in block.transaction_ids() {
+            if let Some((_, confirmed_at)) = deployers.get_mut(transaction_id) {
+                *confirmed_at = Some(block.height());
+            }
+        }
+        drop(deployers);
+
+        // If the block used the set timestamp, continue the clock from it.
+        let mut next_block_timestamp = self.next_block_timestamp.write();
+        if *next_block_timestamp == Some(block.timestamp()) {
+            let now = OffsetDateTime::now_utc().unix_timestamp();
+            self.time_offset.store(block.timestamp().saturating_sub(now), Ordering::SeqCst);
+            *next_block_timestamp = None;
+        }
+        drop(next_block_timestamp);
+
         // Clear the memory pool of unconfirmed transactions that are now invalid.
         self.memory_pool.clear_invalid_transactions(self);
+        self.prune_deployers();
 
         Ok(())
     }
@@ -137,6 +458,33 @@ impl<N: Network, C: ConsensusStorage<N>> SingleNodeConsensus<N, C> {
         Ok(())
     }
 
+    /// Recovers the memory pool after the given block failed validation.
+    /// Each transaction in the block is re-checked individually; the invalid transactions are rejected,
+    /// and the remaining transactions stay in the memory pool for the next proposal.
+    pub fn recover_memory_pool(&self, block: &Block<N>, error: &anyhow::Error) {
+        let mut num_rejected = 0;
+        for (transaction_id, transaction) in block.transactions().iter() {
+            if let Err(reason) = self.check_transaction_basic(transaction) {
+                warn!(
+                    transaction_id = %transaction_id,
+                    block_height = block.height(),
+                    reason = %reason,
+                    "Rejected transaction '{transaction_id}' from an invalid block proposal"
+                );
+                self.memory_pool.reject_transaction(*transaction_id, reason.to_string());
+                num_rejected += 1;
+            }
+        }
+        // If every transaction is valid on its own, the failure is not caused by a transaction.
+        if num_rejected == 0 {
+            warn!(
+                block_height = block.height(),
+                reason = %error,
+                "Retaining the memory pool after an invalid block proposal"
+            );
+        }
+    }
+
     /// Clears the memory pool of all solutions and transactions.
     pub fn clear_memory_pool(&self) -> Result<()> {
         // Clear the memory pool of unconfirmed transactions that are now invalid.
@@ -180,7 +528,10 @@ impl<N: Network, C: ConsensusStorage<N>> SingleNodeConsensus<N, C> {
             let next_timestamp = block.header().timestamp();
             let latest_timestamp = self.ledger.latest_block().header().timestamp();
             if next_timestamp <= latest_timestamp {
-                bail!("The next block timestamp {next_timestamp} is before the current timestamp {latest_timestamp}")
+                bail!(
+                    "The next block timestamp {next_timestamp} is before the current timestamp {latest_timestamp} \
+                    (if the host clock was adjusted, increase the clock skew tolerance)"
+                )
             }
         }
 
@@ -371,8 +722,9 @@ impl<N: Network, C: ConsensusStorage<N>> SingleNodeConsensus<N, C> {
 
         // Ensure transactions with a positive balance must pay for its storage in bytes.
         let fee = transaction.fee()?;
-        if fee > 0 && transaction.to_bytes_le()?.len() > usize::try_from(fee)? {
-            bail!("Transaction '{transaction_id}' has insufficient fee to cover its storage in bytes")
+        let (_, required) = storage_fee(transaction)?;
+        if fee > 0 && u64::try_from(fee)? < required {
+            return Err(SlingshotError::FeeTooLow { required }.into());
         }
 
         /* Proof(s) */
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use anyhow::{bail, Result};
+use core::{fmt, str::FromStr, time::Duration};
+
+/// The state of block production, which a block policy decides whether to produce a block from.
+#[derive(Copy, Clone, Debug)]
+pub struct ProductionState {
+    /// The number of unconfirmed transactions in the memory pool.
+    pub pending_transactions: usize,
+    /// The time since the end of the previous production round, whether or not it produced a block.
+    pub since_last_round: Duration,
+    /// The configured time between blocks, which may change while the node is running.
+    pub block_interval: Duration,
+    /// The time it took to produce the previous block.
+    pub block_generation_time: Duration,
+}
+
+impl ProductionState {
+    /// Returns `true` if a block interval has elapsed since the end of the previous round, less the time to
+    /// produce a block, so that blocks are produced at intervals of approximately `block_interval`.
+    pub fn is_interval_elapsed(&self) -> bool {
+        self.since_last_round + self.block_generation_time >= self.block_interval
+    }
+}
+
+/// A policy that decides when the node produces a block. The node polls the policy of its consensus module,
+/// which can be replaced while the node is running, e.g. with `POST /testnet3/node/blockPolicy`.
+///
+/// Note: A block is only produced from an empty memory pool if the node produces empty blocks, and never while
+/// block production is paused. `POST /testnet3/node/mine` produces blocks regardless of the policy.
+pub trait BlockPolicy: Send + Sync {
+    /// Returns the name of the policy, e.g. `threshold:10`.
+    fn name(&self) -> String;
+
+    /// Returns `true` if a block should be produced, given the state of block production.
+    fn is_due(&self, state: &ProductionState) -> bool;
+}
+
+/// The built-in block policies.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StandardBlockPolicy {
+    /// Produces a block every block interval.
+    Interval,
+    /// Produces a block as soon as a transaction is pending.
+    Instant,
+    /// Produces blocks only on demand, with `POST /testnet3/node/mine`.
+    Manual,
+    /// Produces a block once the given number of transactions are pending.
+    Threshold(usize),
+    /// Produces a block once the given number of transactions are pending, or every block interval otherwise.
+    Hybrid(usize),
+}
+
+impl Default for StandardBlockPolicy {
+    fn default() -> Self {
+        Self::Interval
+    }
+}
+
+impl BlockPolicy for StandardBlockPolicy {
+    fn name(&self) -> String {
+        self.to_string()
+    }
+
+    fn is_due(&self, state: &ProductionState) -> bool {
+        match self {
+            Self::Interval => state.is_interval_elapsed(),
+            Self::Instant => state.pending_transactions > 0,
+            Self::Manual => false,
+            Self::Threshold(transactions) => state.pending_transactions >= *transactions,
+            Self::Hybrid(transactions) => state.pending_transactions >= *transactions || state.is_interval_elapsed(),
+        }
+    }
+}
+
+impl FromStr for StandardBlockPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(policy: &str) -> Result<Self> {
+        let (name, transactions) = match policy.split_once(':') {
+            Some((name, transactions)) => match transactions.parse::<usize>() {
+                Ok(transactions) if transactions > 0 => (name, Some(transactions)),
+                _ => bail!("Invalid number of transactions '{transactions}' in block policy '{policy}'"),
+            },
+            None => (policy, None),
+        };
+        match (name.to_lowercase().as_str(), transactions) {
+            ("interval", None) => Ok(Self::Interval),
+            ("instant", None) => Ok(Self::Instant),
+            ("manual", None) => Ok(Self::Manual),
+            ("threshold", Some(transactions)) => Ok(Self::Threshold(transactions)),
+            ("hybrid", Some(transactions)) => Ok(Self::Hybrid(transactions)),
+            _ => bail!(
+                "Unsupported block policy '{policy}' (expected 'interval', 'instant', 'manual', 'threshold:<COUNT>', \
+                 or 'hybrid:<COUNT>')"
+            ),
+        }
+    }
+}
+
+impl fmt::Display for StandardBlockPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Interval => write!(f, "interval"),
+            Self::Instant => write!(f, "instant"),
+            Self::Manual => write!(f, "manual"),
+            Self::Threshold(transactions) => write!(f, "threshold:{transactions}"),
+            Self::Hybrid(transactions) => write!(f, "hybrid:{transactions}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a production state with the given pending transactions and time since the end of the previous
+    /// round, where blocks are 15 seconds apart and take 2 seconds to produce.
+    fn state(pending_transactions: usize, since_last_round: u64) -> ProductionState {
+        ProductionState {
+            pending_transactions,
+            since_last_round: Duration::from_secs(since_last_round),
+            block_interval: Duration::from_secs(15),
+            block_generation_time: Duration::from_secs(2),
+        }
+    }
+
+    #[test]
+    fn test_standard_policies() {
+        // The next round starts 13 seconds after the previous round ended, and ends 2 seconds later,
+        // 15 seconds after the previous block.
+        let interval = StandardBlockPolicy::Interval;
+        assert!(!interval.is_due(&state(5, 12)));
+        assert!(interval.is_due(&state(0, 13)));
+
+        let instant = StandardBlockPolicy::Instant;
+        assert!(!instant.is_due(&state(0, 60)));
+        assert!(instant.is_due(&state(1, 0)));
+
+        assert!(!StandardBlockPolicy::Manual.is_due(&state(100, 60)));
+
+        let threshold = StandardBlockPolicy::Threshold(3);
+        assert!(!threshold.is_due(&state(2, 60)));
+        assert!(threshold.is_due(&state(3, 0)));
+
+        let hybrid = StandardBlockPolicy::Hybrid(3);
+        assert!(!hybrid.is_due(&state(2, 5)));
+        assert!(hybrid.is_due(&state(3, 0)));
+        assert!(hybrid.is_due(&state(2, 13)));
+    }
+
+    #[test]
+    fn test_interval_excludes_generation_time() {
+        // A block that takes as long as the interval to produce is followed by the next round immediately.
+        let slow = ProductionState { block_generation_time: Duration::from_secs(15), ..state(0, 0) };
+        assert!(StandardBlockPolicy::Interval.is_due(&slow));
+        // A block that takes no time to produce waits for the full interval.
+        let fast = ProductionState { block_generation_time: Duration::ZERO, ..state(0, 14) };
+        assert!(!StandardBlockPolicy::Interval.is_due(&fast));
+        let fast = ProductionState { since_last_round: Duration::from_secs(15), ..fast };
+        assert!(StandardBlockPolicy::Interval.is_due(&fast));
+    }
+
+    #[test]
+    fn test_parse_policies() {
+        for policy in ["interval", "instant", "manual", "threshold:10", "hybrid:5"] {
+            assert_eq!(StandardBlockPolicy::from_str(policy).unwrap().to_string(), policy);
+        }
+        assert_eq!(StandardBlockPolicy::from_str("Instant").unwrap(), StandardBlockPolicy::Instant);
+        assert!(StandardBlockPolicy::from_str("threshold").is_err());
+        assert!(StandardBlockPolicy::from_str("threshold:0").is_err());
+        assert!(StandardBlockPolicy::from_str("interval:5").is_err());
+        assert!(StandardBlockPolicy::from_str("eventually").is_err());
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::node::SingleNodeConsensus;
+
+use snarkvm::prelude::{Address, Block, ConsensusStorage, Identifier, Network, ProgramID, Transaction};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+/// The number of events buffered for each WebSocket subscriber of the event feed.
+const EVENT_FEED_CAPACITY: usize = 1024;
+
+/// An event emitted by the node when a block is accepted.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case", bound = "")]
+pub enum Event<N: Network> {
+    /// A block was added to the ledger.
+    BlockAccepted { height: u32, hash: N::BlockHash, timestamp: i64, num_transactions: usize },
+    /// A transaction was included in a block.
+    TransactionConfirmed { height: u32, transaction_id: N::TransactionID, kind: String },
+    /// A program was deployed. The deployer is only known to the node the deployment was submitted to.
+    ProgramDeployed {
+        height: u32,
+        transaction_id: N::TransactionID,
+        program_id: ProgramID<N>,
+        deployer: Option<Address<N>>,
+        functions: Vec<Identifier<N>>,
+    },
+    /// A function was executed.
+    TransitionExecuted {
+        height: u32,
+        transaction_id: N::TransactionID,
+        transition_id: N::TransitionID,
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+    },
+}
+
+impl<N: Network> Event<N> {
+    /// Returns the events for the given block, in the order they are emitted.
+    pub fn from_block(block: &Block<N>) -> Vec<Self> {
+        let height = block.height();
+
+        let mut events = vec![Self::BlockAccepted {
+            height,
+            hash: block.hash(),
+            timestamp: block.timestamp(),
+            num_transactions: block.transactions().len(),
+        }];
+
+        for (_, transaction) in block.transactions().iter() {
+            events.extend(Self::from_transaction(height, transaction));
+        }
+
+        events
+    }
+
+    /// Returns the events for the given transaction, included in the block at the given height.
+    pub fn from_transaction(height: u32, transaction: &Transaction<N>) -> Vec<Self> {
+        let transaction_id = transaction.id();
+
+        let mut events = Vec::new();
+        match transaction {
+            Transaction::Deploy(_, deployment, _) => {
+                events.push(Self::TransactionConfirmed { height, transaction_id, kind: "deploy".to_string() });
+                events.push(Self::ProgramDeployed {
+                    height,
+                    transaction_id,
+                    program_id: *deployment.program_id(),
+                    deployer: None,
+                    functions: deployment.program().functions().keys().copied().collect(),
+                });
+            }
+            Transaction::Execute(..) => {
+                events.push(Self::TransactionConfirmed { height, transaction_id, kind: "execute".to_string() });
+            }
+        }
+        events.extend(transaction.transitions().map(|transition| Self::TransitionExecuted {
+            height,
+            transaction_id,
+            transition_id: *transition.id(),
+            program_id: *transition.program_id(),
+            function_name: *transition.function_name(),
+        }));
+
+        events
+    }
+
+    /// Returns the type of the event, as serialized.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::BlockAccepted { .. } => "block_accepted",
+            Self::TransactionConfirmed { .. } => "transaction_confirmed",
+            Self::ProgramDeployed { .. } => "program_deployed",
+            Self::TransitionExecuted { .. } => "transition_executed",
+        }
+    }
+
+    /// Sets the deployer of a `program_deployed` event, if the deployment was submitted to the given consensus module.
+    pub fn set_deployer<C: ConsensusStorage<N>>(&mut self, consensus: &SingleNodeConsensus<N, C>) {
+        if let Self::ProgramDeployed { transaction_id, deployer, .. } = self {
+            *deployer = consensus.deployer(transaction_id);
+        }
+    }
+
+    /// Sets the deployer of a `program_deployed` event as in `set_deployer`, as the event is published,
+    /// after which the deployer is no longer kept.
+    pub fn take_deployer<C: ConsensusStorage<N>>(&mut self, consensus: &SingleNodeConsensus<N, C>) {
+        if let Self::ProgramDeployed { transaction_id, deployer, .. } = self {
+            *deployer = consensus.take_deployer(transaction_id);
+        }
+    }
+
+    /// Returns the program the event concerns, if any.
+    pub fn program_id(&self) -> Option<&ProgramID<N>> {
+        match self {
+            Self::ProgramDeployed { program_id, .. } | Self::TransitionExecuted { program_id, .. } => Some(program_id),
+            Self::BlockAccepted { .. } | Self::TransactionConfirmed { .. } => None,
+        }
+    }
+}
+
+/// The feed of the events of accepted blocks, streamed to WebSocket subscribers as the blocks are accepted.
+#[derive(Clone, Debug)]
+pub struct EventFeed<N: Network> {
+    /// The channel of the events.
+    sender: broadcast::Sender<Event<N>>,
+}
+
+impl<N: Network> Default for EventFeed<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> EventFeed<N> {
+    /// Initializes a new instance of the event feed.
+    pub fn new() -> Self {
+        Self { sender: broadcast::channel(EVENT_FEED_CAPACITY).0 }
+    }
+
+    /// Returns a receiver of the events published after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<Event<N>> {
+        self.sender.subscribe()
+    }
+
+    /// Returns `true` if the feed has no subscribers, so that the events need not be published.
+    pub fn is_empty(&self) -> bool {
+        self.sender.receiver_count() == 0
+    }
+
+    /// Publishes the given events to the subscribers.
+    pub fn publish(&self, events: Vec<Event<N>>) {
+        for event in events {
+            // An error only means that there are no subscribers.
+            let _ = self.sender.send(event);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use include_dir::{include_dir, Dir};
+use warp::{http::StatusCode, reply, Filter, Rejection, Reply};
+
+/// The static files of the explorer.
+static EXPLORER: Dir<'_> = include_dir!("$CARGO_MANIFEST_DIR/explorer");
+
+/// Returns the explorer routes: `GET /explorer` serves the page, and `GET /explorer/{file}` serves its assets.
+pub fn explorer_routes() -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    // GET /explorer
+    let index = warp::get().and(warp::path!("explorer")).map(|| serve("index.html"));
+
+    // GET /explorer/{file}
+    let asset = warp::get().and(warp::path!("explorer" / String)).map(|file: String| serve(&file));
+
+    index.or(asset)
+}
+
+/// Returns the explorer file at the given path, with its content type.
+fn serve(path: &str) -> reply::Response {
+    match EXPLORER.get_file(path) {
+        Some(file) => reply::with_header(file.contents(), "content-type", content_type(path)).into_response(),
+        None => reply::with_status("Not found", StatusCode::NOT_FOUND).into_response(),
+    }
+}
+
+/// Returns the content type of the file at the given path.
+fn content_type(path: &str) -> &'static str {
+    match path.rsplit('.').next() {
+        Some("html") => "text/html; charset=utf-8",
+        Some("js") => "text/javascript; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        _ => "application/octet-stream",
+    }
+}
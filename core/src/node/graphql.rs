@@ -0,0 +1,326 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+#![forbid(unsafe_code)]
+
+use crate::node::Ledger;
+
+use snarkos::node::ledger::RecordsFilter;
+use snarkvm::{
+    prelude::{Block, ConsensusStorage, Network, Program, ProgramID, Transaction, Transition, ViewKey},
+    synthesizer::{Input, Output},
+};
+
+use async_graphql::{
+    http::GraphiQLSource,
+    EmptyMutation,
+    EmptySubscription,
+    Enum,
+    Object,
+    Result,
+    Schema,
+    SimpleObject,
+};
+use async_graphql_warp::GraphQLResponse;
+use std::{convert::Infallible, str::FromStr};
+use warp::{reply, Filter, Rejection, Reply};
+
+/// The maximum number of blocks returned by a single `blocks` query.
+const MAX_BLOCK_RANGE: u32 = 50;
+/// The maximum depth of a query, which bounds the work of nested selections.
+const MAX_QUERY_DEPTH: usize = 16;
+
+/// The GraphQL schema over the ledger.
+pub type LedgerSchema<N, C> = Schema<Query<N, C>, EmptyMutation, EmptySubscription>;
+
+/// Returns the GraphQL routes: `POST /graphql` executes a query, and `GET /graphql` serves GraphiQL.
+pub fn graphql_routes<N: Network, C: 'static + ConsensusStorage<N>>(
+    ledger: Ledger<N, C>,
+) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    let schema =
+        Schema::build(Query { ledger }, EmptyMutation, EmptySubscription).limit_depth(MAX_QUERY_DEPTH).finish();
+
+    // POST /graphql
+    let graphql_query = warp::post().and(warp::path!("graphql")).and(async_graphql_warp::graphql(schema)).and_then(
+        |(schema, request): (LedgerSchema<N, C>, async_graphql::Request)| async move {
+            Ok::<_, Infallible>(GraphQLResponse::from(schema.execute(request).await))
+        },
+    );
+
+    // GET /graphql
+    let graphiql = warp::get()
+        .and(warp::path!("graphql"))
+        .map(|| reply::html(GraphiQLSource::build().endpoint("/graphql").finish()));
+
+    graphql_query.or(graphiql)
+}
+
+/// The root of the GraphQL queries.
+pub struct Query<N: Network, C: ConsensusStorage<N>> {
+    /// The ledger.
+    ledger: Ledger<N, C>,
+}
+
+#[Object]
+impl<N: Network, C: 'static + ConsensusStorage<N>> Query<N, C> {
+    /// The latest block height.
+    async fn latest_height(&self) -> u32 {
+        self.ledger.latest_height()
+    }
+
+    /// The block with the given height or hash. Defaults to the latest block.
+    async fn block(&self, height: Option<u32>, hash: Option<String>) -> Result<BlockObject<N>> {
+        let block = match (height, hash) {
+            (Some(_), Some(_)) => return Err("Specify either the height or the hash of the block".into()),
+            (Some(height), None) => self.ledger.get_block(height)?,
+            (None, Some(hash)) => self.ledger.get_block_by_hash(&N::BlockHash::from_str(&hash)?)?,
+            (None, None) => self.ledger.latest_block(),
+        };
+        Ok(BlockObject(block))
+    }
+
+    /// The blocks in the given range of heights, where `end` is exclusive. At most 50 blocks are returned.
+    async fn blocks(&self, start: u32, end: u32) -> Result<Vec<BlockObject<N>>> {
+        if start > end {
+            return Err("Invalid block range".into());
+        }
+        if end - start > MAX_BLOCK_RANGE {
+            return Err(format!("Cannot fetch more than {MAX_BLOCK_RANGE} blocks per query").into());
+        }
+        (start..end).map(|height| Ok(BlockObject(self.ledger.get_block(height)?))).collect()
+    }
+
+    /// The confirmed transaction with the given ID.
+    async fn transaction(&self, id: String) -> Result<TransactionObject<N>> {
+        Ok(TransactionObject(self.ledger.get_transaction(N::TransactionID::from_str(&id)?)?))
+    }
+
+    /// The confirmed transition with the given ID.
+    async fn transition(&self, id: String) -> Result<TransitionObject<N>> {
+        let transition_id = N::TransitionID::from_str(&id)?;
+        let transaction_id = match self.ledger.find_transaction_id(&transition_id)? {
+            Some(transaction_id) => transaction_id,
+            None => return Err(format!("Transition '{id}' is not confirmed").into()),
+        };
+        let transaction = self.ledger.get_transaction(transaction_id)?;
+        match transaction.transitions().find(|transition| *transition.id() == transition_id) {
+            Some(transition) => Ok(TransitionObject(transition.clone())),
+            None => Err(format!("Transition '{id}' is not confirmed").into()),
+        }
+    }
+
+    /// The deployed program with the given ID.
+    async fn program(&self, id: String) -> Result<ProgramObject<N>> {
+        let program_id = ProgramID::<N>::from_str(&id)?;
+        let program = match program_id == ProgramID::<N>::from_str("credits.aleo")? {
+            true => Program::<N>::credits()?,
+            false => self.ledger.get_program(program_id)?,
+        };
+        Ok(ProgramObject(program))
+    }
+
+    /// The records owned by the given view key.
+    async fn records(&self, view_key: String, filter: Option<RecordFilter>) -> Result<Vec<RecordObject>> {
+        let view_key = ViewKey::<N>::from_str(&view_key)?;
+        let filter = match filter.unwrap_or(RecordFilter::All) {
+            RecordFilter::All => RecordsFilter::All,
+            RecordFilter::Spent => RecordsFilter::Spent,
+            RecordFilter::Unspent => RecordsFilter::Unspent,
+        };
+        Ok(self
+            .ledger
+            .find_records(&view_key, filter)?
+            .map(|(commitment, record)| RecordObject {
+                commitment: commitment.to_string(),
+                owner: record.owner().to_string(),
+                gates: ***record.gates(),
+                plaintext: record.to_string(),
+            })
+            .collect())
+    }
+}
+
+/// The records to return for a view key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Enum)]
+pub enum RecordFilter {
+    All,
+    Spent,
+    Unspent,
+}
+
+/// A block.
+pub struct BlockObject<N: Network>(Block<N>);
+
+#[Object(name = "Block")]
+impl<N: Network> BlockObject<N> {
+    async fn height(&self) -> u32 {
+        self.0.height()
+    }
+
+    async fn hash(&self) -> String {
+        self.0.hash().to_string()
+    }
+
+    async fn previous_hash(&self) -> String {
+        self.0.previous_hash().to_string()
+    }
+
+    async fn timestamp(&self) -> i64 {
+        self.0.timestamp()
+    }
+
+    async fn transactions(&self) -> Vec<TransactionObject<N>> {
+        self.0.transactions().iter().map(|(_, transaction)| TransactionObject(transaction.clone())).collect()
+    }
+}
+
+/// A transaction.
+pub struct TransactionObject<N: Network>(Transaction<N>);
+
+#[Object(name = "Transaction")]
+impl<N: Network> TransactionObject<N> {
+    async fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Either `deploy` or `execute`.
+    async fn kind(&self) -> &'static str {
+        match self.0 {
+            Transaction::Deploy(..) => "deploy",
+            Transaction::Execute(..) => "execute",
+        }
+    }
+
+    /// The program deployed by the transaction, if it is a deployment.
+    async fn program_id(&self) -> Option<String> {
+        match &self.0 {
+            Transaction::Deploy(_, deployment, _) => Some(deployment.program_id().to_string()),
+            Transaction::Execute(..) => None,
+        }
+    }
+
+    async fn fee(&self) -> Result<i64> {
+        Ok(self.0.fee()?)
+    }
+
+    async fn transitions(&self) -> Vec<TransitionObject<N>> {
+        self.0.transitions().map(|transition| TransitionObject(transition.clone())).collect()
+    }
+}
+
+/// A transition.
+pub struct TransitionObject<N: Network>(Transition<N>);
+
+#[Object(name = "Transition")]
+impl<N: Network> TransitionObject<N> {
+    async fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    async fn program_id(&self) -> String {
+        self.0.program_id().to_string()
+    }
+
+    async fn function_name(&self) -> String {
+        self.0.function_name().to_string()
+    }
+
+    async fn fee(&self) -> i64 {
+        *self.0.fee()
+    }
+
+    async fn inputs(&self) -> Vec<IoObject> {
+        self.0
+            .inputs()
+            .iter()
+            .map(|input| match input {
+                Input::Constant(id, value) => IoObject::new("constant", id, value.as_ref()),
+                Input::Public(id, value) => IoObject::new("public", id, value.as_ref()),
+                Input::Private(id, value) => IoObject::new("private", id, value.as_ref()),
+                Input::Record(serial_number, tag) => IoObject::new("record", serial_number, Some(tag)),
+                Input::ExternalRecord(id) => IoObject::new("external_record", id, None::<&String>),
+            })
+            .collect()
+    }
+
+    async fn outputs(&self) -> Vec<IoObject> {
+        self.0
+            .outputs()
+            .iter()
+            .map(|output| match output {
+                Output::Constant(id, value) => IoObject::new("constant", id, value.as_ref()),
+                Output::Public(id, value) => IoObject::new("public", id, value.as_ref()),
+                Output::Private(id, value) => IoObject::new("private", id, value.as_ref()),
+                Output::Record(commitment, _, record) => IoObject::new("record", commitment, record.as_ref()),
+                Output::ExternalRecord(id) => IoObject::new("external_record", id, None::<&String>),
+            })
+            .collect()
+    }
+}
+
+/// An input or output of a transition.
+#[derive(SimpleObject)]
+#[graphql(name = "TransitionIo")]
+pub struct IoObject {
+    /// The kind of input or output, e.g. `public` or `record`.
+    kind: String,
+    /// The ID of the input or output. This is the serial number of a record input,
+    /// or the commitment of a record output.
+    id: String,
+    /// The value, which is the tag of a record input, or the ciphertext of a record output.
+    value: Option<String>,
+}
+
+impl IoObject {
+    /// Initializes an input or output of the given kind.
+    fn new(kind: &str, id: impl ToString, value: Option<impl ToString>) -> Self {
+        Self { kind: kind.to_string(), id: id.to_string(), value: value.map(|value| value.to_string()) }
+    }
+}
+
+/// A program.
+pub struct ProgramObject<N: Network>(Program<N>);
+
+#[Object(name = "Program")]
+impl<N: Network> ProgramObject<N> {
+    async fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// The source code of the program.
+    async fn source(&self) -> String {
+        self.0.to_string()
+    }
+
+    async fn functions(&self) -> Vec<String> {
+        self.0.functions().keys().map(|name| name.to_string()).collect()
+    }
+
+    async fn records(&self) -> Vec<String> {
+        self.0.records().keys().map(|name| name.to_string()).collect()
+    }
+}
+
+/// A decrypted record.
+#[derive(SimpleObject)]
+#[graphql(name = "Record")]
+pub struct RecordObject {
+    commitment: String,
+    owner: String,
+    gates: u64,
+    /// The record plaintext.
+    plaintext: String,
+}
@@ -0,0 +1,278 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+#![forbid(unsafe_code)]
+
+/// The types generated from `proto/slingshot.proto`.
+pub mod proto {
+    tonic::include_proto!("slingshot");
+}
+
+use crate::{
+    errors::SlingshotError,
+    messages::{DeployRequest, ExecuteRequest, PourRequest, Signer},
+    node::{
+        is_authorized,
+        AccountRegistry,
+        Admission,
+        CaptureBundle,
+        CapturedRequest,
+        Ledger,
+        NodeConfig,
+        Rest,
+        UsageRegistry,
+    },
+    output,
+};
+use proto::{
+    records_request::Filter,
+    slingshot_server::{Slingshot, SlingshotServer},
+};
+
+use snarkos::{account::Account, node::ledger::RecordsFilter};
+use snarkvm::prelude::{Address, ConsensusStorage, Network, PrivateKey, Program, Transaction, Value, ViewKey};
+
+use colored::*;
+use std::{net::SocketAddr, str::FromStr};
+use tokio::task::JoinHandle;
+use tonic::{Request, Response, Status};
+
+/// A gRPC server for the ledger, mirroring the REST API.
+#[derive(Clone)]
+pub struct Grpc<N: Network, C: ConsensusStorage<N>> {
+    /// The faucet account, unless the node is view-only.
+    faucet: Option<Account<N>>,
+    /// The admission of the transactions of requests, shared with the REST server.
+    admission: Admission<N, C>,
+    /// The ledger.
+    ledger: Ledger<N, C>,
+    /// The accounts registered for server-side signing.
+    accounts: AccountRegistry<N>,
+    /// The node configuration.
+    config: NodeConfig,
+    /// The usage of the node by each account.
+    usage: UsageRegistry<N>,
+    /// The reproduction bundle the accepted deploy, execute, and pour requests are captured to, if enabled.
+    capture: CaptureBundle,
+}
+
+impl<N: Network, C: 'static + ConsensusStorage<N>> Grpc<N, C> {
+    /// Starts a gRPC server at the given address, sharing the state of the given REST server.
+    pub fn start(grpc_ip: SocketAddr, rest: &Rest<N, C>) -> JoinHandle<()> {
+        let server = Self {
+            faucet: rest.faucet.clone(),
+            admission: rest.admission(),
+            ledger: rest.ledger.clone(),
+            accounts: rest.accounts.clone(),
+            config: rest.config.clone(),
+            usage: rest.usage.clone(),
+            capture: rest.capture.clone(),
+        };
+
+        tokio::spawn(async move {
+            output!("🌐 Starting the gRPC server at {}.\n", grpc_ip.to_string().bold());
+
+            // Start the server.
+            if let Err(error) =
+                tonic::transport::Server::builder().add_service(SlingshotServer::new(server)).serve(grpc_ip).await
+            {
+                error!("The gRPC server encountered an error: {error}");
+            }
+        })
+    }
+
+    /// Ensures the given request carries the API token of the node, if one is required.
+    fn authorize<T>(&self, request: &Request<T>) -> Result<(), Status> {
+        let authorization = request.metadata().get("authorization").and_then(|value| value.to_str().ok());
+        match is_authorized(self.config.api_token.as_deref(), authorization) {
+            true => Ok(()),
+            false => Err(Status::unauthenticated("missing or invalid API token")),
+        }
+    }
+
+    /// Returns the signer for the given account token or private key.
+    fn signer(&self, account_token: String, private_key: String) -> Result<Signer<N>, Status> {
+        match (account_token.is_empty(), private_key.is_empty()) {
+            (false, true) => Ok(Signer::Token(account_token)),
+            (true, false) => Ok(Signer::PrivateKey(PrivateKey::from_str(&private_key).map_err(invalid_argument)?)),
+            _ => Err(Status::invalid_argument("expected exactly one of 'account_token' or 'private_key'")),
+        }
+    }
+
+    /// Returns the private key of the given signer.
+    fn signer_private_key(&self, signer: &Signer<N>) -> Result<PrivateKey<N>, Status> {
+        Rest::<N, C>::signer_private_key(signer, &self.accounts, self.config.allow_private_keys)
+            .map_err(|_| Status::permission_denied("the signer is not accepted by this node"))
+    }
+
+    /// Constructs the transaction of a request on behalf of the given account, and adds it to the memory pool,
+    /// submitted by the account if it is the signer, and returns its transaction ID.
+    async fn submit(
+        &self,
+        address: Address<N>,
+        sender: Option<Address<N>>,
+        construct: impl FnOnce() -> anyhow::Result<Transaction<N>> + Send + 'static,
+        capture: CapturedRequest,
+    ) -> Result<Response<proto::TransactionResponse>, Status> {
+        // Ensure the node has a memory pool, before the request waits for the prover.
+        self.admission.consensus().map_err(|error| Status::unavailable(error.to_string()))?;
+        let transaction = self
+            .admission
+            .construct(address, construct)
+            .await
+            .map_err(|error| admission_error("failed to construct the transaction", error))?;
+        let transaction_id = transaction.id().to_string();
+        self.admission
+            .submit(transaction, sender, capture, |_| proto::TransactionResponse { transaction_id })
+            .map(Response::new)
+            .map_err(|error| admission_error("failed to add the transaction to the memory pool", error))
+    }
+}
+
+#[tonic::async_trait]
+impl<N: Network, C: 'static + ConsensusStorage<N>> Slingshot for Grpc<N, C> {
+    async fn latest_height(
+        &self,
+        _request: Request<proto::LatestHeightRequest>,
+    ) -> Result<Response<proto::LatestHeightResponse>, Status> {
+        Ok(Response::new(proto::LatestHeightResponse { height: self.ledger.latest_height() }))
+    }
+
+    async fn get_block(
+        &self,
+        request: Request<proto::GetBlockRequest>,
+    ) -> Result<Response<proto::GetBlockResponse>, Status> {
+        let block = self.ledger.get_block(request.into_inner().height).map_err(not_found)?;
+        let block = serde_json::to_string(&block).map_err(internal)?;
+        Ok(Response::new(proto::GetBlockResponse { block }))
+    }
+
+    async fn records(
+        &self,
+        request: Request<proto::RecordsRequest>,
+    ) -> Result<Response<proto::RecordsResponse>, Status> {
+        let request = request.into_inner();
+        let view_key = ViewKey::<N>::from_str(&request.view_key).map_err(invalid_argument)?;
+        let filter = match Filter::from_i32(request.filter) {
+            Some(Filter::All) => RecordsFilter::All,
+            Some(Filter::Spent) => RecordsFilter::Spent,
+            Some(Filter::Unspent) => RecordsFilter::Unspent,
+            None => return Err(Status::invalid_argument("invalid records filter")),
+        };
+        let records = self
+            .ledger
+            .find_records(&view_key, filter)
+            .map_err(internal)?
+            .map(|(commitment, record)| proto::Record {
+                commitment: commitment.to_string(),
+                record: record.to_string(),
+            })
+            .collect();
+        Ok(Response::new(proto::RecordsResponse { records }))
+    }
+
+    async fn pour(&self, request: Request<proto::PourRequest>) -> Result<Response<proto::TransactionResponse>, Status> {
+        self.authorize(&request)?;
+        let request = request.into_inner();
+        let address = Address::<N>::from_str(&request.address).map_err(invalid_argument)?;
+        let faucet = self.faucet.as_ref().ok_or_else(|| Status::failed_precondition("the node is view-only"))?;
+        let records = self.admission.record_provider(None).map_err(failed_precondition)?;
+        let (ledger, private_key, amount) = (self.ledger.clone(), *faucet.private_key(), request.amount);
+        let construct = move || ledger.create_transfer(&private_key, &*records, address, amount);
+        let capture = self.capture.entry("pour", &PourRequest::new(address, amount), None);
+        let response = self.submit(address, None, construct, capture).await?;
+        self.usage.add_pour(address, amount);
+        Ok(response)
+    }
+
+    async fn deploy(
+        &self,
+        request: Request<proto::DeployRequest>,
+    ) -> Result<Response<proto::TransactionResponse>, Status> {
+        self.authorize(&request)?;
+        let request = request.into_inner();
+        let signer = self.signer(request.account_token, request.private_key)?;
+        let private_key = self.signer_private_key(&signer)?;
+        let address = Address::try_from(&private_key).map_err(internal)?;
+        let program = Program::<N>::from_str(&request.program).map_err(invalid_argument)?;
+        let records = self.admission.record_provider(None).map_err(failed_precondition)?;
+        let request = DeployRequest::new(signer, program, request.additional_fee, None, None);
+        let construct = {
+            let (ledger, program) = (self.ledger.clone(), request.program().clone());
+            let additional_fee = request.additional_fee();
+            move || ledger.create_deploy(&private_key, &*records, &program, additional_fee)
+        };
+        let capture = self.capture.entry("deploy", &request, Some(address.to_string()));
+        self.submit(address, Some(address), construct, capture).await
+    }
+
+    async fn execute(
+        &self,
+        request: Request<proto::ExecuteRequest>,
+    ) -> Result<Response<proto::TransactionResponse>, Status> {
+        self.authorize(&request)?;
+        let request = request.into_inner();
+        let signer = self.signer(request.account_token, request.private_key)?;
+        let private_key = self.signer_private_key(&signer)?;
+        let address = Address::try_from(&private_key).map_err(internal)?;
+        let program_id = FromStr::from_str(&request.program_id).map_err(invalid_argument)?;
+        let function_name = FromStr::from_str(&request.function_name).map_err(invalid_argument)?;
+        let inputs = request
+            .inputs
+            .iter()
+            .map(|input| Value::<N>::from_str(input))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(invalid_argument)?;
+        let records = self.admission.record_provider(None).map_err(failed_precondition)?;
+        let request = ExecuteRequest::new(signer, program_id, function_name, inputs, request.additional_fee, None);
+        let construct = Rest::execute_transaction(&request, private_key, records, &self.ledger);
+        let capture = self.capture.entry("execute", &request, Some(address.to_string()));
+        self.submit(address, Some(address), construct, capture).await
+    }
+}
+
+/// Returns the status for the given error of the admission of a transaction, by the status code of its typed error,
+/// or a `FAILED_PRECONDITION` status if it has none.
+fn admission_error(context: &str, error: anyhow::Error) -> Status {
+    let message = format!("{context}: {error}");
+    match error.downcast_ref::<SlingshotError>().map(SlingshotError::status_code) {
+        Some(400) => Status::invalid_argument(message),
+        Some(404) => Status::not_found(message),
+        Some(429) => Status::resource_exhausted(message),
+        Some(503) => Status::unavailable(message),
+        _ => Status::failed_precondition(message),
+    }
+}
+
+/// Returns a `FAILED_PRECONDITION` status for the given error.
+fn failed_precondition(error: impl std::fmt::Display) -> Status {
+    Status::failed_precondition(error.to_string())
+}
+
+/// Returns an `INVALID_ARGUMENT` status for the given error.
+fn invalid_argument(error: impl std::fmt::Display) -> Status {
+    Status::invalid_argument(error.to_string())
+}
+
+/// Returns a `NOT_FOUND` status for the given error.
+fn not_found(error: impl std::fmt::Display) -> Status {
+    Status::not_found(error.to_string())
+}
+
+/// Returns an `INTERNAL` status for the given error.
+fn internal(error: impl std::fmt::Display) -> Status {
+    Status::internal(error.to_string())
+}
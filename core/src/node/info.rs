@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::Ledger;
+
+use snarkvm::prelude::{ConsensusStorage, Network, ProgramID};
+
+use serde::{Deserialize, Serialize};
+
+/// The chain context of a node, as returned by `GET /testnet3/node/info`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct NodeInfo<N: Network> {
+    /// The network ID.
+    pub network_id: u16,
+    /// The latest block height.
+    pub latest_height: u32,
+    /// The latest block hash.
+    pub latest_hash: N::BlockHash,
+    /// The IDs of the deployed programs.
+    pub programs: Vec<ProgramID<N>>,
+    /// The resource limits of the node, and its current load.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limits: Option<NodeLimits>,
+}
+
+/// The resource limits of a node, and its current load against them.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NodeLimits {
+    /// The maximum number of transactions the node constructs at once.
+    pub max_concurrent_proofs: usize,
+    /// The maximum number of transactions waiting to be constructed, if any.
+    pub max_queued_proofs: Option<usize>,
+    /// The number of transactions being constructed.
+    pub active_proofs: usize,
+    /// The number of transactions waiting to be constructed.
+    pub queued_proofs: usize,
+    /// The maximum number of unconfirmed transactions in the memory pool.
+    pub pool_capacity: usize,
+    /// The number of unconfirmed transactions in the memory pool.
+    pub pool_size: usize,
+    /// The maximum size in bytes of the body of a request with a fixed shape.
+    pub max_body_size: u64,
+    /// The maximum size in bytes of the body of a deployment, an execution, or a transaction.
+    pub max_transaction_body_size: u64,
+    /// The maximum number of requests handled at once.
+    pub max_concurrent_requests: usize,
+}
+
+impl<N: Network> NodeInfo<N> {
+    /// Returns the chain context of the given ledger.
+    pub fn new<C: ConsensusStorage<N>>(ledger: &Ledger<N, C>) -> Self {
+        Self {
+            network_id: N::ID,
+            latest_height: ledger.latest_height(),
+            latest_hash: ledger.latest_hash(),
+            programs: ledger.program_ids().map(|program_id| *program_id).collect(),
+            limits: None,
+        }
+    }
+
+    /// Returns the chain context, with the given resource limits.
+    pub fn with_limits(self, limits: NodeLimits) -> Self {
+        Self { limits: Some(limits), ..self }
+    }
+}
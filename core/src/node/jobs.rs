@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm::prelude::Network;
+
+use parking_lot::RwLock;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use time::OffsetDateTime;
+
+/// The default number of seconds a completed job is retained after its last status update.
+pub const DEFAULT_JOB_RETENTION_IN_SECS: u64 = 3600;
+
+/// The status of a job, from its creation to the confirmation of its transaction.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "", tag = "status", rename_all = "snake_case")]
+pub enum JobStatus<N: Network> {
+    /// The job is waiting for the prover.
+    Queued,
+    /// The transaction is being constructed.
+    Proving,
+    /// The transaction is in the memory pool.
+    Submitted { transaction_id: N::TransactionID, sequence: Option<u64> },
+    /// The transaction is included in a block.
+    Confirmed { transaction_id: N::TransactionID, sequence: Option<u64>, block_height: u32 },
+    /// The job failed with the given error.
+    Failed { error: String },
+}
+
+impl<N: Network> JobStatus<N> {
+    /// Returns `true` if the job is complete, whether it succeeded or failed.
+    pub const fn is_complete(&self) -> bool {
+        matches!(self, Self::Confirmed { .. } | Self::Failed { .. })
+    }
+
+    /// Returns the name of the status, as it is serialized.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Proving => "proving",
+            Self::Submitted { .. } => "submitted",
+            Self::Confirmed { .. } => "confirmed",
+            Self::Failed { .. } => "failed",
+        }
+    }
+}
+
+/// A deploy or execute request, processed in the background.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct Job<N: Network> {
+    /// The job ID.
+    pub id: String,
+    /// The kind of request, `deploy` or `execute`.
+    pub kind: String,
+    /// The status of the job.
+    #[serde(flatten)]
+    pub status: JobStatus<N>,
+    /// The UNIX timestamp of the creation of the job.
+    pub created_at: i64,
+    /// The UNIX timestamp of the last status update of the job.
+    pub updated_at: i64,
+}
+
+/// The jobs of the deploy and execute requests that are processed in the background.
+/// A completed job is removed once it has been retained for the retention period after its last status update.
+#[derive(Clone, Debug)]
+pub struct Jobs<N: Network> {
+    /// The map of job IDs to jobs.
+    jobs: Arc<RwLock<HashMap<String, Job<N>>>>,
+    /// The time a completed job is retained after its last status update.
+    retention: Duration,
+}
+
+impl<N: Network> Default for Jobs<N> {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(DEFAULT_JOB_RETENTION_IN_SECS))
+    }
+}
+
+impl<N: Network> Jobs<N> {
+    /// Initializes a new instance of the jobs, which retains completed jobs for the given time.
+    pub fn new(retention: Duration) -> Self {
+        Self { jobs: Default::default(), retention }
+    }
+
+    /// Creates a queued job of the given kind, and returns its ID.
+    pub fn create(&self, kind: &'static str) -> String {
+        // Remove the expired jobs, so that the jobs of a long-running node do not grow without bound.
+        self.prune();
+
+        let id = rand::thread_rng().gen::<[u8; 16]>().iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let job =
+            Job { id: id.clone(), kind: kind.to_string(), status: JobStatus::Queued, created_at: now, updated_at: now };
+        self.jobs.write().insert(id.clone(), job);
+        id
+    }
+
+    /// Updates the status of the given job.
+    pub fn update(&self, id: &str, status: JobStatus<N>) {
+        if let Some(job) = self.jobs.write().get_mut(id) {
+            debug!("Job '{id}' is {}", serde_json::to_string(&status).unwrap_or_default());
+            job.status = status;
+            job.updated_at = OffsetDateTime::now_utc().unix_timestamp();
+        }
+    }
+
+    /// Returns the job with the given ID, if it exists.
+    pub fn get(&self, id: &str) -> Option<Job<N>> {
+        self.jobs.read().get(id).cloned()
+    }
+
+    /// Returns the jobs with the given status, or every job if no status is given, from the oldest to the newest.
+    pub fn list(&self, status: Option<&str>) -> Vec<Job<N>> {
+        self.prune();
+
+        let mut jobs = self
+            .jobs
+            .read()
+            .values()
+            .filter(|job| status.map_or(true, |status| job.status.name() == status))
+            .cloned()
+            .collect::<Vec<_>>();
+        jobs.sort_by_key(|job| job.created_at);
+        jobs
+    }
+
+    /// Removes the completed jobs whose last status update is older than the retention period.
+    pub fn prune(&self) {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+        let retention = self.retention.as_secs() as i64;
+        self.jobs.write().retain(|id, job| {
+            let is_expired = job.status.is_complete() && now - job.updated_at >= retention;
+            if is_expired {
+                debug!("Job '{id}' expired");
+            }
+            !is_expired
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::prelude::Testnet3;
+
+    #[test]
+    fn test_prune() {
+        let jobs = Jobs::<Testnet3>::new(Duration::ZERO);
+        let (failed, queued) = (jobs.create("deploy"), jobs.create("execute"));
+        jobs.update(&failed, JobStatus::Failed { error: "error".to_string() });
+
+        // Only the completed job is removed.
+        let remaining = jobs.list(None);
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, queued);
+        assert!(jobs.get(&failed).is_none());
+        assert_eq!(jobs.list(Some("queued")).len(), 1);
+        assert!(jobs.list(Some("failed")).is_empty());
+    }
+}
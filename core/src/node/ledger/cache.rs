@@ -0,0 +1,270 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm::prelude::{
+    FromBytes,
+    Identifier,
+    Network,
+    Process,
+    Program,
+    ProgramID,
+    ProvingKey,
+    ToBytes,
+    VerifyingKey,
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+/// The proving key and the verifying key of a function.
+type Keys<N> = (ProvingKey<N>, VerifyingKey<N>);
+
+/// The version of the layout of the key cache directory, which is incremented on each incompatible change.
+pub const KEY_CACHE_VERSION: u32 = 1;
+/// The file of the key cache directory that contains its version.
+const VERSION_FILE: &str = "VERSION";
+
+/// A cache of the proving and verifying keys of program functions, shared by every clone of the ledger.
+/// The keys are synthesized on the first execution of a function, and may be persisted to a directory,
+/// so that they are not synthesized again after the node restarts.
+#[derive(Clone)]
+pub struct KeyCache<N: Network> {
+    /// The keys of each function.
+    keys: Arc<RwLock<HashMap<(ProgramID<N>, Identifier<N>), Keys<N>>>>,
+    /// The directory the keys are persisted to, if any.
+    directory: Arc<RwLock<Option<PathBuf>>>,
+    /// The number of executions whose keys were already synthesized, or were loaded from the cache.
+    hits: Arc<AtomicU64>,
+    /// The number of executions whose keys had to be synthesized.
+    misses: Arc<AtomicU64>,
+}
+
+/// The statistics of the key cache.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct KeyCacheStats {
+    /// The number of functions with cached keys.
+    pub entries: usize,
+    /// The number of executions whose keys were already synthesized, or were loaded from the cache.
+    pub hits: u64,
+    /// The number of executions whose keys had to be synthesized.
+    pub misses: u64,
+    /// The directory the keys are persisted to, if any.
+    pub directory: Option<PathBuf>,
+}
+
+impl<N: Network> Default for KeyCache<N> {
+    fn default() -> Self {
+        Self {
+            keys: Default::default(),
+            directory: Default::default(),
+            hits: Default::default(),
+            misses: Default::default(),
+        }
+    }
+}
+
+impl<N: Network> KeyCache<N> {
+    /// Persists the keys to the given directory. The keys persisted by earlier runs are read from it on demand.
+    /// The directory must be empty, or be a key cache of the current version.
+    pub fn set_directory(&self, directory: &Path) -> Result<()> {
+        std::fs::create_dir_all(directory)?;
+        match Self::version(directory)? {
+            Some(KEY_CACHE_VERSION) => (),
+            Some(version) => bail!(
+                "The key cache at '{}' is of version {version}, which is not supported by this version of slingshot \
+                 (expected version {KEY_CACHE_VERSION})",
+                directory.display()
+            ),
+            None if std::fs::read_dir(directory)?.next().is_none() => {
+                std::fs::write(directory.join(VERSION_FILE), KEY_CACHE_VERSION.to_string())?
+            }
+            None => bail!(
+                "The key cache at '{}' was written by an older version of slingshot \
+                 (upgrade it with 'slingshot node migrate --cache-dir <DIR>')",
+                directory.display()
+            ),
+        }
+        *self.directory.write() = Some(directory.to_path_buf());
+        Ok(())
+    }
+
+    /// Upgrades the key cache at the given directory to the current version of its layout, in place,
+    /// and returns the version it was upgraded from, or `None` if it is already of the current version.
+    /// A key cache without a version, written before key caches were versioned, is of version 0.
+    pub fn migrate(directory: &Path) -> Result<Option<u32>> {
+        ensure!(directory.is_dir(), "The key cache at '{}' does not exist", directory.display());
+        match Self::version(directory)? {
+            Some(KEY_CACHE_VERSION) => Ok(None),
+            Some(version) => bail!(
+                "The key cache at '{}' is of version {version}, which is not supported by this version of slingshot \
+                 (expected version {KEY_CACHE_VERSION})",
+                directory.display()
+            ),
+            // Note: The layout of version 0 is the layout of the current version, without its version file.
+            None => {
+                std::fs::write(directory.join(VERSION_FILE), KEY_CACHE_VERSION.to_string())?;
+                Ok(Some(0))
+            }
+        }
+    }
+
+    /// Returns the version of the key cache at the given directory, or `None` if it has no version file.
+    fn version(directory: &Path) -> Result<Option<u32>> {
+        match std::fs::read_to_string(directory.join(VERSION_FILE)) {
+            Ok(version) => Ok(Some(version.trim().parse().map_err(|_| {
+                anyhow!("The key cache at '{}' has an invalid version '{}'", directory.display(), version.trim())
+            })?)),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Returns the statistics of the cache.
+    pub fn stats(&self) -> KeyCacheStats {
+        KeyCacheStats {
+            entries: self.keys.read().len(),
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            directory: self.directory.read().clone(),
+        }
+    }
+
+    /// Inserts the cached keys of the given function into the process, unless the process already has them.
+    pub fn load(&self, process: &Process<N>, program: &Program<N>, function_name: &Identifier<N>) -> Result<()> {
+        let program_id = program.id();
+        if process.get_proving_key(*program_id, *function_name).is_ok() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(());
+        }
+
+        // Retrieve the keys from memory, or from the directory.
+        let cached = self.keys.read().get(&(*program_id, *function_name)).cloned();
+        let keys = match cached {
+            Some(keys) => Some(keys),
+            None => self.read(program, function_name)?,
+        };
+        match keys {
+            Some((proving_key, verifying_key)) => {
+                process.insert_proving_key(program_id, function_name, proving_key.clone())?;
+                process.insert_verifying_key(program_id, function_name, verifying_key.clone())?;
+                self.keys.write().insert((*program_id, *function_name), (proving_key, verifying_key));
+                self.hits.fetch_add(1, Ordering::Relaxed);
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+        Ok(())
+    }
+
+    /// Caches the keys of the given function, synthesized by the process, if they are not already cached.
+    pub fn store(&self, process: &Process<N>, program: &Program<N>, function_name: &Identifier<N>) -> Result<()> {
+        let program_id = program.id();
+        if self.keys.read().contains_key(&(*program_id, *function_name)) {
+            return Ok(());
+        }
+        let (proving_key, verifying_key) = match (
+            process.get_proving_key(*program_id, *function_name),
+            process.get_verifying_key(*program_id, *function_name),
+        ) {
+            (Ok(proving_key), Ok(verifying_key)) => (proving_key, verifying_key),
+            _ => return Ok(()),
+        };
+        self.write(program, function_name, &proving_key, &verifying_key)?;
+        self.keys.write().insert((*program_id, *function_name), (proving_key, verifying_key));
+        Ok(())
+    }
+
+    /// Reads the keys of the given function from the directory, if they were persisted for the same program.
+    fn read(&self, program: &Program<N>, function_name: &Identifier<N>) -> Result<Option<Keys<N>>> {
+        let directory = match &*self.directory.read() {
+            Some(directory) => directory.join(program.id().to_string()),
+            None => return Ok(None),
+        };
+        // Ensure the keys were synthesized for the same program, as a program ID may be reused on another chain.
+        match std::fs::read_to_string(directory.join("program.aleo")) {
+            Ok(source) if source == program.to_string() => (),
+            _ => return Ok(None),
+        }
+        let (proving_key, verifying_key) = match (
+            std::fs::read(directory.join(format!("{function_name}.prover"))),
+            std::fs::read(directory.join(format!("{function_name}.verifier"))),
+        ) {
+            (Ok(proving_key), Ok(verifying_key)) => (proving_key, verifying_key),
+            _ => return Ok(None),
+        };
+        Ok(Some((ProvingKey::from_bytes_le(&proving_key)?, VerifyingKey::from_bytes_le(&verifying_key)?)))
+    }
+
+    /// Writes the keys of the given function to the directory, if one is set.
+    fn write(
+        &self,
+        program: &Program<N>,
+        function_name: &Identifier<N>,
+        proving_key: &ProvingKey<N>,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<()> {
+        let directory = match &*self.directory.read() {
+            Some(directory) => directory.join(program.id().to_string()),
+            None => return Ok(()),
+        };
+        std::fs::create_dir_all(&directory)?;
+        std::fs::write(directory.join("program.aleo"), program.to_string())?;
+        std::fs::write(directory.join(format!("{function_name}.prover")), proving_key.to_bytes_le()?)?;
+        std::fs::write(directory.join(format!("{function_name}.verifier")), verifying_key.to_bytes_le()?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::prelude::Testnet3;
+
+    #[test]
+    fn test_key_cache_version() {
+        let directory = std::env::temp_dir().join(format!("slingshot-key-cache-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+
+        // A new key cache is of the current version.
+        KeyCache::<Testnet3>::default().set_directory(&directory).unwrap();
+        assert_eq!(KeyCache::<Testnet3>::migrate(&directory).unwrap(), None);
+
+        // A key cache of version 0 is rejected, until it is migrated.
+        std::fs::remove_file(directory.join(VERSION_FILE)).unwrap();
+        std::fs::create_dir_all(directory.join("hello.aleo")).unwrap();
+        let error = KeyCache::<Testnet3>::default().set_directory(&directory).unwrap_err();
+        assert!(error.to_string().contains("slingshot node migrate"));
+        assert_eq!(KeyCache::<Testnet3>::migrate(&directory).unwrap(), Some(0));
+        KeyCache::<Testnet3>::default().set_directory(&directory).unwrap();
+
+        // A key cache of a newer version is rejected, and cannot be migrated.
+        std::fs::write(directory.join(VERSION_FILE), (KEY_CACHE_VERSION + 1).to_string()).unwrap();
+        assert!(KeyCache::<Testnet3>::default().set_directory(&directory).is_err());
+        assert!(KeyCache::<Testnet3>::migrate(&directory).is_err());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
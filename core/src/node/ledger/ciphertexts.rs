@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm::{
+    prelude::{Ciphertext, Owner},
+    synthesizer::Output,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A record ciphertext output by a confirmed transition, as returned by `GET /testnet3/record/{commitment}` and
+/// `GET /testnet3/records/ciphertexts`. The ciphertext is the `record1...` string scanned by the Aleo SDK,
+/// which decrypts the records of a view key on the client, so the view key never reaches the node.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct RecordCiphertext<N: Network> {
+    /// The record commitment.
+    pub commitment: Field<N>,
+    /// The record ciphertext.
+    pub record_ciphertext: Record<N, Ciphertext<N>>,
+    /// The owner of the record, if it is public. A private owner is only known to the view keys that decrypt it.
+    pub owner: Option<Address<N>>,
+    /// The height of the block that contains the record.
+    pub block_height: u32,
+    /// The ID of the transaction that output the record.
+    pub transaction_id: N::TransactionID,
+    /// The ID of the transition that output the record.
+    pub transition_id: N::TransitionID,
+    /// The program of the transition.
+    pub program_id: ProgramID<N>,
+    /// The function of the transition.
+    pub function_name: Identifier<N>,
+    /// The index of the record among the outputs of the transition.
+    pub output_index: usize,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the confirmed record ciphertext with the given commitment.
+    pub fn get_record_ciphertext(&self, commitment: &Field<N>) -> Result<RecordCiphertext<N>> {
+        // Find the transaction that output the record, and the block that contains it.
+        let transition_id = self.find_transition_id(commitment)?;
+        let transaction_id = match self.find_transaction_id(&transition_id)? {
+            Some(transaction_id) => transaction_id,
+            None => bail!("Missing transaction for transition '{transition_id}'"),
+        };
+        let block_height = match self.find_block_hash(&transaction_id)? {
+            Some(block_hash) => self.get_height(&block_hash)?,
+            None => bail!("The transaction '{transaction_id}' is not confirmed"),
+        };
+        let transaction = self.get_transaction(transaction_id)?;
+        match record_ciphertexts(block_height, &transaction).find(|record| record.commitment == *commitment) {
+            Some(record) => Ok(record),
+            None => bail!("Missing record ciphertext for commitment '{commitment}'"),
+        }
+    }
+
+    /// Returns the record ciphertexts output by the blocks from the given start height up to the given end height,
+    /// in the order they were output.
+    pub fn get_record_ciphertexts(&self, start_height: u32, end_height: u32) -> Result<Vec<RecordCiphertext<N>>> {
+        let mut records = Vec::new();
+        for height in start_height..end_height {
+            for (_, transaction) in self.get_transactions(height)?.iter() {
+                records.extend(record_ciphertexts(height, transaction));
+            }
+        }
+        Ok(records)
+    }
+}
+
+/// Returns the record ciphertexts output by the given transaction, which is confirmed at the given height.
+fn record_ciphertexts<N: Network>(
+    block_height: u32,
+    transaction: &Transaction<N>,
+) -> impl '_ + Iterator<Item = RecordCiphertext<N>> {
+    let transaction_id = transaction.id();
+    transaction.transitions().flat_map(move |transition| {
+        transition.outputs().iter().enumerate().filter_map(move |(output_index, output)| match output {
+            Output::Record(commitment, _, Some(record)) => Some(RecordCiphertext {
+                commitment: *commitment,
+                record_ciphertext: record.clone(),
+                owner: match record.owner() {
+                    Owner::Public(address) => Some(*address),
+                    Owner::Private(_) => None,
+                },
+                block_height,
+                transaction_id,
+                transition_id: *transition.id(),
+                program_id: *transition.program_id(),
+                function_name: *transition.function_name(),
+                output_index,
+            }),
+            _ => None,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_helpers::CurrentLedger;
+
+    #[test]
+    fn test_get_record_ciphertexts() {
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::load(None, None).unwrap();
+
+        // Each record of the genesis block can be retrieved by its commitment.
+        let records = ledger.get_record_ciphertexts(0, 1).unwrap();
+        assert!(!records.is_empty());
+        for record in &records {
+            let candidate = ledger.get_record_ciphertext(&record.commitment).unwrap();
+            assert_eq!(candidate.record_ciphertext, record.record_ciphertext);
+            assert_eq!(candidate.transaction_id, record.transaction_id);
+            assert_eq!(candidate.block_height, 0);
+        }
+
+        // A block that does not exist is an error.
+        assert!(ledger.get_record_ciphertexts(0, 2).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::messages::{DeploymentCost, FunctionCost};
+
+use snarkvm::prelude::ToBytes;
+
+/// Returns the size of the given transaction in bytes, and the minimum fee that covers its storage:
+/// a transaction with a positive fee must pay at least one gate per byte of storage.
+pub fn storage_fee<N: Network>(transaction: &Transaction<N>) -> Result<(usize, u64)> {
+    let size_in_bytes = transaction.to_bytes_le()?.len();
+    Ok((size_in_bytes, size_in_bytes as u64))
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the cost of the given deploy transaction: the constraints and the key sizes of each function of its
+    /// program, and the minimum fee that covers its storage.
+    ///
+    /// Note: The proving keys are not part of a deployment, so they are synthesized again to measure their sizes.
+    pub fn deployment_cost(&self, transaction: &Transaction<N>) -> Result<DeploymentCost<N>> {
+        let deployment = match transaction {
+            Transaction::Deploy(_, deployment, _) => deployment,
+            _ => bail!("The transaction '{}' is not a deployment", transaction.id()),
+        };
+        let proving_key_sizes = self.proving_key_sizes(deployment.program())?;
+
+        // Retrieve the constraints and the verifying key size of each function from the deployment.
+        let functions = deployment
+            .verifying_keys()
+            .iter()
+            .map(|(function_name, (verifying_key, _))| {
+                let proving_key_size_in_bytes = match proving_key_sizes.get(function_name) {
+                    Some(size_in_bytes) => *size_in_bytes,
+                    None => bail!("Missing the proving key of '{}/{function_name}'", deployment.program_id()),
+                };
+                Ok(FunctionCost {
+                    function_name: *function_name,
+                    num_constraints: verifying_key.circuit_info.num_constraints,
+                    proving_key_size_in_bytes,
+                    verifying_key_size_in_bytes: verifying_key.to_bytes_le()?.len(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let (size_in_bytes, storage_fee) = storage_fee(transaction)?;
+        Ok(DeploymentCost { functions, size_in_bytes, storage_fee })
+    }
+
+    /// Synthesizes the keys of each function of the given program, and returns the size of each proving key in bytes.
+    fn proving_key_sizes(&self, program: &Program<N>) -> Result<IndexMap<Identifier<N>, usize>> {
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Synthesize the keys with the circuit of the network, as in `failed_synthesis`.
+        let process = self.vm.process();
+        let process = process.read();
+        match N::ID {
+            <Testnet3 as Network>::ID => {
+                let process = (&*process as &dyn Any)
+                    .downcast_ref::<Process<Testnet3>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast the process"))?;
+                let program = (program as &dyn Any)
+                    .downcast_ref::<Program<Testnet3>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast the program"))?;
+                let stack = Stack::new(process, program)?;
+                program
+                    .functions()
+                    .keys()
+                    .map(|function_name| {
+                        stack.synthesize_key::<AleoV0, _>(function_name, rng)?;
+                        let size_in_bytes = stack.get_proving_key(function_name)?.to_bytes_le()?.len();
+                        let function_name = (function_name as &dyn Any)
+                            .downcast_ref::<Identifier<N>>()
+                            .ok_or_else(|| anyhow!("Failed to downcast the function name"))?;
+                        Ok((*function_name, size_in_bytes))
+                    })
+                    .collect()
+            }
+            _ => bail!("Synthesis is not supported on network {}", N::ID),
+        }
+    }
+}
@@ -0,0 +1,280 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::records;
+
+use snarkvm::{
+    prelude::Ciphertext,
+    synthesizer::{Input, Output, Transition},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A transaction decomposed for humans, as returned by `GET /testnet3/transaction/{transactionID}/decode`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct DecodedTransaction<N: Network> {
+    /// The transaction ID.
+    pub transaction_id: N::TransactionID,
+    /// The kind of transaction, either `deploy` or `execute`.
+    pub kind: String,
+    /// The height of the block that contains the transaction.
+    pub block_height: u32,
+    /// The program deployed by the transaction, if it is a deployment.
+    pub program_id: Option<ProgramID<N>>,
+    /// The transitions of the transaction, in order, including the fee transition.
+    pub transitions: Vec<DecodedTransition<N>>,
+    /// The fee of the transaction.
+    pub fee: FeeBreakdown,
+}
+
+/// A transition of a decoded transaction.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct DecodedTransition<N: Network> {
+    /// The transition ID.
+    pub transition_id: N::TransitionID,
+    /// The program of the transition.
+    pub program_id: ProgramID<N>,
+    /// The function of the transition.
+    pub function_name: Identifier<N>,
+    /// Whether the transition pays the fee of the transaction.
+    pub is_fee: bool,
+    /// The gates consumed by the transition, which are negative if it mints gates.
+    pub fee: i64,
+    /// The inputs of the transition.
+    pub inputs: Vec<DecodedValue<N>>,
+    /// The outputs of the transition.
+    pub outputs: Vec<DecodedValue<N>>,
+    /// The finalize operations of the transition, if its function has a finalize block.
+    pub finalize: Option<DecodedFinalize>,
+}
+
+/// An input or output of a decoded transition.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct DecodedValue<N: Network> {
+    /// The kind of input or output, e.g. `public` or `record`.
+    pub kind: String,
+    /// The ID of the input or output. This is the serial number of a record input,
+    /// or the commitment of a record output.
+    pub id: Field<N>,
+    /// The plaintext value, or the record as structured JSON, if it is public or the view key can decrypt it.
+    pub value: Option<serde_json::Value>,
+}
+
+/// The finalize operations of a transition, which update the mappings of its program once it is confirmed.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DecodedFinalize {
+    /// The inputs of the finalize block.
+    pub inputs: Vec<String>,
+    /// The commands of the finalize block, as Aleo instructions.
+    pub commands: Vec<String>,
+}
+
+/// The fee of a decoded transaction.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FeeBreakdown {
+    /// The fee of the transaction in gates, i.e. the gates consumed by all of its transitions.
+    pub total: i64,
+    /// The gates consumed by the fee transition, if any.
+    pub fee_transition: Option<i64>,
+    /// The size of the transaction in bytes.
+    pub size_in_bytes: usize,
+    /// The minimum fee in gates that covers the storage of the transaction.
+    pub storage_fee: u64,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the given confirmed transaction, decomposed into its transitions, the fee, and the finalize operations.
+    /// The private inputs and outputs are decrypted if the given view key is of the signer of the transaction,
+    /// and the record outputs if they are owned by the account of the view key.
+    pub fn decode_transaction(
+        &self,
+        transaction_id: N::TransactionID,
+        view_key: Option<&ViewKey<N>>,
+    ) -> Result<DecodedTransaction<N>> {
+        let transaction = self.get_transaction(transaction_id)?;
+        let block_height = match self.find_block_hash(&transaction_id)? {
+            Some(block_hash) => self.get_height(&block_hash)?,
+            None => bail!("Transaction '{transaction_id}' is not confirmed"),
+        };
+
+        let (kind, program_id, fee_transition) = match &transaction {
+            Transaction::Deploy(_, deployment, fee) => ("deploy", Some(*deployment.program_id()), Some(fee)),
+            Transaction::Execute(_, _, fee) => ("execute", None, fee.as_ref()),
+        };
+        let transitions = transaction
+            .transitions()
+            .map(|transition| {
+                let is_fee = fee_transition.map_or(false, |fee| fee.id() == transition.id());
+                self.decode_transition(transition, is_fee, view_key)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let (size_in_bytes, storage_fee) = storage_fee(&transaction)?;
+        let fee = FeeBreakdown {
+            total: transaction.fee()?,
+            fee_transition: fee_transition.map(|fee| *fee.fee()),
+            size_in_bytes,
+            storage_fee,
+        };
+
+        Ok(DecodedTransaction { transaction_id, kind: kind.to_string(), block_height, program_id, transitions, fee })
+    }
+
+    /// Decodes the given transition, decrypting its private values and records with the given view key, if it can.
+    fn decode_transition(
+        &self,
+        transition: &Transition<N>,
+        is_fee: bool,
+        view_key: Option<&ViewKey<N>>,
+    ) -> Result<DecodedTransition<N>> {
+        // Derive the transition view key, which decrypts the private values, if the view key is of the signer.
+        let tvk = match view_key {
+            Some(view_key) => transition_view_key(transition, view_key)?,
+            None => None,
+        };
+        let plaintext = |index: usize, ciphertext: Option<&Ciphertext<N>>| match (tvk, ciphertext) {
+            (Some(tvk), Some(ciphertext)) => decrypt_value(tvk, index, ciphertext),
+            _ => None,
+        };
+        let public = |plaintext: Option<&Plaintext<N>>| {
+            plaintext.map(|plaintext| serde_json::Value::String(plaintext.to_string()))
+        };
+
+        let inputs = transition
+            .inputs()
+            .iter()
+            .enumerate()
+            .map(|(index, input)| {
+                let (kind, id, value) = match input {
+                    Input::Constant(id, value) => ("constant", id, public(value.as_ref())),
+                    Input::Public(id, value) => ("public", id, public(value.as_ref())),
+                    Input::Private(id, value) => ("private", id, plaintext(index, value.as_ref())),
+                    Input::Record(serial_number, _) => ("record", serial_number, None),
+                    Input::ExternalRecord(id) => ("external_record", id, None),
+                };
+                DecodedValue { kind: kind.to_string(), id: *id, value }
+            })
+            .collect();
+
+        // The outputs are encrypted with the indices that follow the inputs.
+        let num_inputs = transition.inputs().len();
+        let outputs = transition
+            .outputs()
+            .iter()
+            .enumerate()
+            .map(|(index, output)| {
+                let (kind, id, value) = match output {
+                    Output::Constant(id, value) => ("constant", id, public(value.as_ref())),
+                    Output::Public(id, value) => ("public", id, public(value.as_ref())),
+                    Output::Private(id, value) => ("private", id, plaintext(num_inputs + index, value.as_ref())),
+                    Output::Record(commitment, _, record) => {
+                        let record = match (view_key, record) {
+                            (Some(view_key), Some(record)) => records::decrypt(record, view_key).ok(),
+                            _ => None,
+                        };
+                        ("record", commitment, record.map(|record| records::to_json(&record)))
+                    }
+                    Output::ExternalRecord(id) => ("external_record", id, None),
+                };
+                DecodedValue { kind: kind.to_string(), id: *id, value }
+            })
+            .collect();
+
+        Ok(DecodedTransition {
+            transition_id: *transition.id(),
+            program_id: *transition.program_id(),
+            function_name: *transition.function_name(),
+            is_fee,
+            fee: *transition.fee(),
+            inputs,
+            outputs,
+            finalize: self.decode_finalize(transition)?,
+        })
+    }
+
+    /// Returns the finalize inputs of the given transition, with the finalize commands of its function.
+    fn decode_finalize(&self, transition: &Transition<N>) -> Result<Option<DecodedFinalize>> {
+        let inputs = match transition.finalize() {
+            Some(inputs) => inputs.iter().map(|input| input.to_string()).collect(),
+            None => return Ok(None),
+        };
+        let process = self.vm.process();
+        let process = process.read();
+        let function = process.get_program(transition.program_id())?.get_function(transition.function_name())?;
+        let commands = match function.finalize() {
+            Some((_, finalize)) => finalize.commands().iter().map(|command| command.to_string()).collect(),
+            None => Vec::new(),
+        };
+        Ok(Some(DecodedFinalize { inputs, commands }))
+    }
+}
+
+/// Returns the transition view key of the given transition, if the given view key is of its signer.
+fn transition_view_key<N: Network>(transition: &Transition<N>, view_key: &ViewKey<N>) -> Result<Option<Field<N>>> {
+    // The transition view key is `r * signer`, where `tpk = r * G`, and it is committed to by `tcm = Hash(tvk)`.
+    let tvk = (*transition.tpk() * **view_key).to_x_coordinate();
+    match N::hash_psd2(&[tvk])? == *transition.tcm() {
+        true => Ok(Some(tvk)),
+        false => Ok(None),
+    }
+}
+
+/// Decrypts the private input or output at the given index of a transition, with its transition view key.
+fn decrypt_value<N: Network>(tvk: Field<N>, index: usize, ciphertext: &Ciphertext<N>) -> Option<serde_json::Value> {
+    let value_view_key = N::hash_psd4(&[tvk, Field::from_u16(index as u16)]).ok()?;
+    match ciphertext.decrypt_symmetric(value_view_key) {
+        Ok(plaintext) => Some(serde_json::Value::String(plaintext.to_string())),
+        Err(error) => {
+            warn!("Failed to decrypt the private value at index {index}: {error}");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_helpers::CurrentLedger;
+
+    #[test]
+    fn test_decode_transaction() {
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::load(None, None).unwrap();
+        let block = ledger.get_block(0).unwrap();
+        let transaction_id = *block.transaction_ids().next().unwrap();
+
+        // Without a view key, the records of the genesis transaction are not decrypted.
+        let decoded = ledger.decode_transaction(transaction_id, None).unwrap();
+        assert_eq!(decoded.kind, "execute");
+        assert_eq!(decoded.block_height, 0);
+        assert!(!decoded.transitions.is_empty());
+        let records = decoded.transitions.iter().flat_map(|transition| &transition.outputs);
+        assert!(records.filter(|output| output.kind == "record").all(|output| output.value.is_none()));
+
+        // The view key of another account decrypts nothing either.
+        let private_key = PrivateKey::new(&mut rand::thread_rng()).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let decoded = ledger.decode_transaction(transaction_id, Some(&view_key)).unwrap();
+        let outputs = decoded.transitions.iter().flat_map(|transition| &transition.outputs);
+        assert!(outputs
+            .filter(|output| ["private", "record"].contains(&output.kind.as_str()))
+            .all(|output| output.value.is_none()));
+    }
+}
@@ -128,7 +128,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     pub fn get_program(&self, program_id: ProgramID<N>) -> Result<Program<N>> {
         match self.vm.transaction_store().get_program(&program_id)? {
             Some(program) => Ok(program),
-            None => bail!("Missing program for ID {program_id}"),
+            None => Err(SlingshotError::ProgramNotFound { program_id: program_id.to_string() }.into()),
         }
     }
 
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::records;
+
+use snarkvm::{
+    prelude::{GraphKey, Plaintext, Record},
+    synthesizer::Input,
+};
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A transaction that affected an account, as returned by `POST /testnet3/history`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct HistoryEntry<N: Network> {
+    /// The height of the block that contains the transaction.
+    pub block_height: u32,
+    /// The timestamp of the block that contains the transaction.
+    pub timestamp: i64,
+    /// The transaction ID.
+    pub transaction_id: N::TransactionID,
+    /// The kind of transaction, either `deploy` or `execute`.
+    pub kind: String,
+    /// The program deployed by the transaction, if it is a deployment.
+    pub program_id: Option<ProgramID<N>>,
+    /// The functions executed by the transaction, as `program/function`.
+    pub functions: Vec<String>,
+    /// The records of the account spent by the transaction.
+    pub spent: Vec<HistoryRecord<N>>,
+    /// The records received by the account in the transaction.
+    pub received: Vec<HistoryRecord<N>>,
+    /// The fee paid by the account, if the transaction spent its records.
+    pub fee: Option<i64>,
+}
+
+/// A record spent or received by an account.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct HistoryRecord<N: Network> {
+    /// The record commitment.
+    pub commitment: Field<N>,
+    /// The number of gates in the record.
+    pub gates: u64,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the transactions that spent or received records of the given view key, in chronological order.
+    pub fn history(&self, view_key: &ViewKey<N>) -> Result<Vec<HistoryEntry<N>>> {
+        // Derive the `sk_tag` from the graph key, to recognize the records spent by the account.
+        let sk_tag = match GraphKey::try_from(view_key) {
+            Ok(graph_key) => graph_key.sk_tag(),
+            Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+        };
+
+        // The records received so far, keyed by their tag.
+        let mut received_records = HashMap::new();
+        let mut history = Vec::new();
+
+        for height in 0..=self.latest_height() {
+            let block = self.get_block(height)?;
+            for (transaction_id, transaction) in block.transactions().iter() {
+                // Collect the records of the account spent by the transaction.
+                let spent = transaction
+                    .transitions()
+                    .flat_map(|transition| transition.inputs())
+                    .filter_map(|input| match input {
+                        Input::Record(_, tag) => received_records.remove(tag),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                // Collect the records received by the account in the transaction.
+                let mut received = Vec::new();
+                for (commitment, record) in records::decrypt_outputs(transaction, view_key) {
+                    let record = HistoryRecord { commitment, gates: ***record.gates() };
+                    received_records.insert(Record::<N, Plaintext<N>>::tag(sk_tag, commitment)?, record.clone());
+                    received.push(record);
+                }
+
+                // Skip transactions that did not affect the account.
+                if spent.is_empty() && received.is_empty() {
+                    continue;
+                }
+
+                let (kind, program_id) = match transaction {
+                    Transaction::Deploy(_, deployment, _) => ("deploy", Some(*deployment.program_id())),
+                    Transaction::Execute(..) => ("execute", None),
+                };
+                history.push(HistoryEntry {
+                    block_height: height,
+                    timestamp: block.timestamp(),
+                    transaction_id: *transaction_id,
+                    kind: kind.to_string(),
+                    program_id,
+                    functions: transaction
+                        .transitions()
+                        .map(|transition| format!("{}/{}", transition.program_id(), transition.function_name()))
+                        .collect(),
+                    fee: match spent.is_empty() {
+                        true => None,
+                        false => Some(transaction.fee()?),
+                    },
+                    spent,
+                    received,
+                });
+            }
+        }
+
+        Ok(history)
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm::prelude::{PlaintextType, ValueType};
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Ensures the given inputs match the input types of the given function, before the function is executed.
+    /// Returns the first input that does not match, with its index and expected type.
+    pub fn check_inputs(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<()> {
+        // Retrieve the function signature.
+        let program = match self.vm.process().read().get_program(program_id) {
+            Ok(program) => program.clone(),
+            Err(_) => return Err(SlingshotError::ProgramNotFound { program_id: program_id.to_string() }.into()),
+        };
+        let function = program.get_function(function_name)?;
+
+        // Ensure the number of inputs matches.
+        let input_types = function.inputs().iter().map(|input| input.value_type()).collect::<Vec<_>>();
+        if inputs.len() != input_types.len() {
+            return Err(SlingshotError::InvalidInputCount { expected: input_types.len(), found: inputs.len() }.into());
+        }
+
+        // Ensure each input matches its type.
+        for (index, (input, input_type)) in inputs.iter().zip(input_types).enumerate() {
+            let is_match = match (input, input_type) {
+                (
+                    Value::Plaintext(plaintext),
+                    ValueType::Constant(plaintext_type)
+                    | ValueType::Public(plaintext_type)
+                    | ValueType::Private(plaintext_type),
+                ) => matches_plaintext_type(&program, plaintext, plaintext_type),
+                // Note: The record type is checked when the record is decrypted, as the owner is not known here.
+                (Value::Record(_), ValueType::Record(_) | ValueType::ExternalRecord(_)) => true,
+                _ => false,
+            };
+            if !is_match {
+                return Err(SlingshotError::InvalidInput { index, expected_type: input_type.to_string() }.into());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Ensures the record inputs of the given function are not spent by the account of the given private key,
+    /// as a record from a stale cache may be. Returns the first spent record, with the transaction that spent it.
+    pub fn check_unspent_inputs(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<()> {
+        for (index, commitment) in self.record_input_commitments(program_id, function_name, inputs)? {
+            let serial_number = Record::<N, Plaintext<N>>::serial_number(*private_key, commitment)?;
+            if !self.contains_serial_number(&serial_number)? {
+                continue;
+            }
+
+            // The input ID of a record input is its serial number.
+            let transaction_id = self
+                .find_transaction_id(&self.find_transition_id(&serial_number)?)?
+                .ok_or_else(|| anyhow!("Missing the transaction that spent the record of input {index}"))?;
+            let block_hash = self
+                .find_block_hash(&transaction_id)?
+                .ok_or_else(|| anyhow!("Missing the block of transaction '{transaction_id}'"))?;
+            let height = self.get_height(&block_hash)?;
+            return Err(SlingshotError::RecordAlreadySpent {
+                index,
+                transaction_id: transaction_id.to_string(),
+                height,
+            }
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Returns the index and the commitment of each record input of the given function.
+    pub fn record_input_commitments(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<Vec<(usize, Field<N>)>> {
+        let function = self.vm.process().read().get_program(program_id)?.get_function(function_name)?;
+
+        let mut commitments = Vec::new();
+        for (index, (input, function_input)) in inputs.iter().zip(function.inputs()).enumerate() {
+            // Retrieve the program and name of the record type, which determine the commitment of the record.
+            let (record, record_program_id, record_name) = match (input, function_input.value_type()) {
+                (Value::Record(record), ValueType::Record(record_name)) => (record, program_id, record_name),
+                (Value::Record(record), ValueType::ExternalRecord(locator)) => {
+                    (record, locator.program_id(), locator.resource())
+                }
+                _ => continue,
+            };
+            commitments.push((index, record.to_commitment(record_program_id, record_name)?));
+        }
+        Ok(commitments)
+    }
+}
+
+/// Returns `true` if the given plaintext is of the given type, with the interfaces defined by the given program.
+pub fn matches_plaintext_type<N: Network>(
+    program: &Program<N>,
+    plaintext: &Plaintext<N>,
+    plaintext_type: &PlaintextType<N>,
+) -> bool {
+    match (plaintext, plaintext_type) {
+        (Plaintext::Literal(literal, _), PlaintextType::Literal(literal_type)) => literal.to_type() == *literal_type,
+        (Plaintext::Interface(members, _), PlaintextType::Interface(interface_name)) => {
+            let interface = match program.get_interface(interface_name) {
+                Ok(interface) => interface,
+                Err(_) => return false,
+            };
+            members.len() == interface.members().len()
+                && interface.members().iter().all(|(member_name, member_type)| match members.get(member_name) {
+                    Some(member) => matches_plaintext_type(program, member, member_type),
+                    None => false,
+                })
+        }
+        _ => false,
+    }
+}
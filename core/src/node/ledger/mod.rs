@@ -0,0 +1,648 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod cache;
+pub use cache::*;
+
+pub mod ciphertexts;
+pub use ciphertexts::*;
+
+pub mod contains;
+pub use contains::*;
+
+pub mod cost;
+pub use cost::*;
+
+pub mod decode;
+pub use decode::*;
+
+pub mod find;
+pub use find::*;
+
+pub mod get;
+pub use get::*;
+
+pub mod history;
+pub use history::*;
+
+pub mod inputs;
+pub use inputs::*;
+
+pub mod iterators;
+pub use iterators::*;
+
+pub mod profile;
+pub use profile::*;
+
+pub mod provider;
+pub use provider::*;
+
+pub mod remote;
+pub use remote::*;
+
+pub mod reservations;
+pub use reservations::*;
+
+pub mod send;
+pub use send::*;
+
+pub mod split;
+pub use split::*;
+
+use crate::errors::SlingshotError;
+
+use snarkos::node::ledger::{Ledger as InternalLedger, RecordMap, RecordsFilter};
+
+use snarkvm::prelude::{
+    Address,
+    AleoV0,
+    Authorization,
+    Block,
+    ConsensusStorage,
+    ConsensusStore,
+    CryptoRng,
+    EpochChallenge,
+    Field,
+    FromBytes,
+    Header,
+    Identifier,
+    Network,
+    Plaintext,
+    PrivateKey,
+    Process,
+    Program,
+    ProgramID,
+    Record,
+    Rng,
+    Testnet3,
+    Transaction,
+    Transactions,
+    Value,
+    ViewKey,
+    Zero,
+    VM,
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use snarkvm::{circuit::has_duplicates, synthesizer::Stack};
+use std::{any::Any, str::FromStr, sync::Arc};
+
+#[derive(Clone)]
+pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
+    /// The VM state.
+    vm: VM<N, C>,
+    /// The current block.
+    current_block: Arc<RwLock<Block<N>>>,
+    /// The current epoch challenge.
+    current_epoch_challenge: Arc<RwLock<Option<EpochChallenge<N>>>>,
+    /// The cache of the proving and verifying keys of program functions.
+    key_cache: KeyCache<N>,
+    /// The records reserved by the transactions that spend them.
+    reservations: RecordReservations<N>,
+    /// The remote prover the proofs of executions are delegated to, if any.
+    remote_prover: RemoteProver,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Loads the ledger from storage.
+    pub fn load(genesis: Option<Block<N>>, dev: Option<u16>) -> Result<Self> {
+        // Retrieve the genesis hash.
+        let genesis_hash = match genesis {
+            Some(ref genesis) => genesis.hash(),
+            None => Block::<N>::from_bytes_le(N::genesis_bytes())?.hash(),
+        };
+
+        // Initialize the consensus store.
+        let store = match ConsensusStore::<N, C>::open(dev) {
+            Ok(store) => store,
+            _ => bail!("Failed to load ledger (run 'snarkos clean' and try again)"),
+        };
+        // Initialize a new VM.
+        let vm = VM::from(store)?;
+        // Initialize the ledger.
+        let ledger = Self::from(vm, genesis)?;
+
+        // Ensure the ledger contains the correct genesis block.
+        match ledger.contains_block_hash(&genesis_hash)? {
+            true => Ok(ledger),
+            false => bail!("Incorrect genesis block (run 'snarkos clean' and try again)"),
+        }
+    }
+
+    /// Initializes the ledger from storage, with an optional genesis block.
+    pub fn from(vm: VM<N, C>, genesis: Option<Block<N>>) -> Result<Self> {
+        // Load the genesis block.
+        let genesis = match genesis {
+            Some(genesis) => genesis,
+            None => Block::<N>::from_bytes_le(N::genesis_bytes())?,
+        };
+
+        // Initialize the ledger.
+        let mut ledger = Self {
+            vm,
+            current_block: Arc::new(RwLock::new(genesis.clone())),
+            current_epoch_challenge: Default::default(),
+            key_cache: Default::default(),
+            reservations: Default::default(),
+            remote_prover: Default::default(),
+        };
+
+        // If the block store is empty, initialize the genesis block.
+        if ledger.vm.block_store().heights().max().is_none() {
+            // Add the genesis block.
+            ledger.add_next_block(&genesis)?;
+        }
+
+        // Retrieve the latest height.
+        let latest_height =
+            *ledger.vm.block_store().heights().max().ok_or_else(|| anyhow!("Failed to load blocks from the ledger"))?;
+        // Fetch the latest block.
+        let block = ledger
+            .get_block(latest_height)
+            .map_err(|_| anyhow!("Failed to load block {latest_height} from the ledger"))?;
+
+        // Set the current block.
+        ledger.current_block = Arc::new(RwLock::new(block));
+        // Set the current epoch challenge.
+        ledger.current_epoch_challenge = Arc::new(RwLock::new(Some(ledger.get_epoch_challenge(latest_height)?)));
+
+        // // Safety check the existence of every block.
+        // cfg_into_iter!((0..=latest_height)).try_for_each(|height| {
+        //     ledger.get_block(height)?;
+        //     Ok::<_, Error>(())
+        // })?;
+
+        Ok(ledger)
+    }
+
+    /// Returns a genesis block that mints the given number of gates to the account of the given private key.
+    /// This mirrors `Block::genesis`, which always mints the default supply of the network.
+    pub fn genesis<R: Rng + CryptoRng>(
+        vm: &VM<N, C>,
+        private_key: &PrivateKey<N>,
+        supply: u64,
+        rng: &mut R,
+    ) -> Result<Block<N>> {
+        // Prepare the inputs.
+        let address = Address::try_from(private_key)?;
+        let inputs = [Value::from_str(&address.to_string())?, Value::from_str(&format!("{supply}u64"))?];
+
+        // Execute the genesis function.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let authorization = vm.authorize(private_key, &program_id, Identifier::from_str("genesis")?, &inputs, rng)?;
+        let transaction = Transaction::execute_authorization(vm, authorization, rng)?;
+
+        // Construct the genesis block.
+        let transactions = Transactions::from(&[transaction]);
+        let header = Header::genesis(&transactions)?;
+        let block = Block::new(private_key, N::BlockHash::default(), header, transactions, None, rng)?;
+        ensure!(block.is_genesis(), "Failed to initialize a genesis block with a supply of {supply} gates");
+        Ok(block)
+    }
+
+    /// Returns the VM.
+    pub fn vm(&self) -> &VM<N, C> {
+        &self.vm
+    }
+
+    /// Returns the cache of the proving and verifying keys of program functions.
+    pub fn key_cache(&self) -> &KeyCache<N> {
+        &self.key_cache
+    }
+
+    /// Returns the records reserved by the transactions that spend them.
+    pub fn reservations(&self) -> &RecordReservations<N> {
+        &self.reservations
+    }
+
+    /// Returns the remote prover the proofs of executions are delegated to, if any.
+    pub fn remote_prover(&self) -> &RemoteProver {
+        &self.remote_prover
+    }
+
+    /// Returns the latest state root.
+    pub fn latest_state_root(&self) -> Field<N> {
+        *self.vm.block_store().current_state_root()
+    }
+
+    /// Returns the latest block.
+    pub fn latest_block(&self) -> Block<N> {
+        self.current_block.read().clone()
+    }
+
+    /// Returns the latest block hash.
+    pub fn latest_hash(&self) -> N::BlockHash {
+        self.current_block.read().hash()
+    }
+
+    /// Returns the latest block header.
+    pub fn latest_header(&self) -> Header<N> {
+        *self.current_block.read().header()
+    }
+
+    /// Returns the latest block height.
+    pub fn latest_height(&self) -> u32 {
+        self.current_block.read().height()
+    }
+
+    /// Returns the latest round number.
+    pub fn latest_round(&self) -> u64 {
+        self.current_block.read().round()
+    }
+
+    /// Returns the latest block coinbase accumulator point.
+    pub fn latest_coinbase_accumulator_point(&self) -> Field<N> {
+        self.current_block.read().header().coinbase_accumulator_point()
+    }
+
+    /// Returns the latest block coinbase target.
+    pub fn latest_coinbase_target(&self) -> u64 {
+        self.current_block.read().coinbase_target()
+    }
+
+    /// Returns the latest block proof target.
+    pub fn latest_proof_target(&self) -> u64 {
+        self.current_block.read().proof_target()
+    }
+
+    /// Returns the last coinbase target.
+    pub fn last_coinbase_target(&self) -> u64 {
+        self.current_block.read().last_coinbase_target()
+    }
+
+    /// Returns the last coinbase timestamp.
+    pub fn last_coinbase_timestamp(&self) -> i64 {
+        self.current_block.read().last_coinbase_timestamp()
+    }
+
+    /// Returns the latest block timestamp.
+    pub fn latest_timestamp(&self) -> i64 {
+        self.current_block.read().timestamp()
+    }
+
+    /// Returns the latest block transactions.
+    pub fn latest_transactions(&self) -> Transactions<N> {
+        self.current_block.read().transactions().clone()
+    }
+
+    /// Returns the latest epoch number.
+    pub fn latest_epoch_number(&self) -> u32 {
+        self.current_block.read().height() / N::NUM_BLOCKS_PER_EPOCH
+    }
+
+    /// Returns the latest epoch challenge.
+    pub fn latest_epoch_challenge(&self) -> Result<EpochChallenge<N>> {
+        match self.current_epoch_challenge.read().as_ref() {
+            Some(challenge) => Ok(challenge.clone()),
+            None => self.get_epoch_challenge(self.latest_height()),
+        }
+    }
+
+    /// Adds the given block as the next block in the chain.
+    pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
+        // Acquire the write lock on the current block.
+        let mut current_block = self.current_block.write();
+        // Update the VM.
+        self.vm.add_next_block(block)?;
+        // Update the current block.
+        *current_block = block.clone();
+        // Drop the write lock on the current block.
+        drop(current_block);
+
+        // If the block is the start of a new epoch, or the epoch challenge has not been set, update the current epoch challenge.
+        if block.height() % N::NUM_BLOCKS_PER_EPOCH == 0 || self.current_epoch_challenge.read().is_none() {
+            // Update the current epoch challenge.
+            self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(block.height()).ok());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the unspent records.
+    pub fn find_unspent_records(&self, view_key: &ViewKey<N>) -> Result<RecordMap<N>> {
+        Ok(self
+            .find_records(view_key, RecordsFilter::Unspent)?
+            .filter(|(_, record)| !record.gates().is_zero())
+            .collect::<IndexMap<_, _>>())
+    }
+
+    /// Returns the unspent `credits.aleo` records.
+    pub fn find_unspent_credits(&self, view_key: &ViewKey<N>) -> Result<RecordMap<N>> {
+        Ok(self
+            .find_unspent_records(view_key)?
+            .into_iter()
+            .filter(|(commitment, record)| credits_commitment(record).ok().as_ref() == Some(commitment))
+            .collect::<IndexMap<_, _>>())
+    }
+
+    /// Creates a transfer transaction, which spends a record of the given provider.
+    pub fn create_transfer(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: &dyn RecordProvider<N, C>,
+        to: Address<N>,
+        amount: u64,
+    ) -> Result<Transaction<N>> {
+        // Select and reserve an unspent record with sufficient balance.
+        let (candidate, reservation) = self.select_record(records, private_key, amount, &[])?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Prepare the inputs.
+        let inputs =
+            [Value::Record(candidate), Value::from_str(&format!("{to}"))?, Value::from_str(&format!("{amount}u64"))?];
+
+        // Create a new transaction.
+        let transaction = Transaction::execute(
+            &self.vm,
+            private_key,
+            ProgramID::from_str("credits.aleo")?,
+            Identifier::from_str("transfer")?,
+            inputs.iter(),
+            None,
+            None,
+            rng,
+        );
+
+        match transaction {
+            Ok(result) => {
+                reservation.constructed();
+                Ok(result)
+            }
+            other => other,
+        }
+    }
+
+    /// Creates a transaction that splits the given record into a record with the given amount, and a record with
+    /// the remaining gates.
+    pub fn create_split(
+        &self,
+        private_key: &PrivateKey<N>,
+        record: Record<N, Plaintext<N>>,
+        amount: u64,
+    ) -> Result<Transaction<N>> {
+        // Reserve the record.
+        let reservation = self.reservations.reserve(private_key, &[credits_commitment(&record)?])?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Prepare the inputs.
+        let inputs = [Value::Record(record), Value::from_str(&format!("{amount}u64"))?];
+
+        // Create a new transaction.
+        let transaction = Transaction::execute(
+            &self.vm,
+            private_key,
+            ProgramID::from_str("credits.aleo")?,
+            Identifier::from_str("split")?,
+            inputs.iter(),
+            None,
+            None,
+            rng,
+        )?;
+        reservation.constructed();
+        Ok(transaction)
+    }
+
+    /// Creates a transaction that joins the given records into a record with the gates of both.
+    pub fn create_join(
+        &self,
+        private_key: &PrivateKey<N>,
+        first: Record<N, Plaintext<N>>,
+        second: Record<N, Plaintext<N>>,
+    ) -> Result<Transaction<N>> {
+        // Reserve the records.
+        let commitments = [credits_commitment(&first)?, credits_commitment(&second)?];
+        let reservation = self.reservations.reserve(private_key, &commitments)?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Prepare the inputs.
+        let inputs = [Value::Record(first), Value::Record(second)];
+
+        // Create a new transaction.
+        let transaction = Transaction::execute(
+            &self.vm,
+            private_key,
+            ProgramID::from_str("credits.aleo")?,
+            Identifier::from_str("join")?,
+            inputs.iter(),
+            None,
+            None,
+            rng,
+        )?;
+        reservation.constructed();
+        Ok(transaction)
+    }
+
+    // TODO: Cleanup and optimize.
+    // TODO: If fee is zero, then you don't need to find a record.
+
+    /// Creates a deploy transaction, which pays its fee with a record of the given provider.
+    pub fn create_deploy(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: &dyn RecordProvider<N, C>,
+        program: &Program<N>,
+        additional_fee: u64,
+    ) -> Result<Transaction<N>> {
+        // Select and reserve an unspent record with sufficient balance.
+        let (candidate, reservation) = self.select_record(records, private_key, additional_fee, &[])?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Create a new transaction.
+        let transaction =
+            match Transaction::deploy(&self.vm, private_key, program, (candidate, additional_fee), None, rng) {
+                Ok(transaction) => transaction,
+                // Determine the function that failed synthesis, if any.
+                Err(error) => match self.failed_synthesis(program) {
+                    Some((function_name, synthesis_error)) => {
+                        let locator = format!("{}/{function_name}", program.id());
+                        let reason = synthesis_error.to_string();
+                        return Err(SlingshotError::DeploySynthesisFailed { locator, reason }.into());
+                    }
+                    None => {
+                        return Err(SlingshotError::DeployConstructionFailed { reason: error.to_string() }.into());
+                    }
+                },
+            };
+
+        // Ensure the transaction is valid, before it is submitted.
+        if !self.vm.verify(&transaction) {
+            let transaction_id = transaction.id().to_string();
+            return Err(SlingshotError::DeployVerificationFailed { transaction_id }.into());
+        }
+        reservation.constructed();
+        Ok(transaction)
+    }
+
+    /// Returns the first function of the given program whose keys fail to synthesize, with the error.
+    /// Returns `None` if the keys of every function are synthesized, or if the program cannot be loaded.
+    fn failed_synthesis(&self, program: &Program<N>) -> Option<(Identifier<N>, anyhow::Error)> {
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Synthesize the keys of each function with the circuit of the network, as in `evaluate`.
+        let process = self.vm.process();
+        let process = process.read();
+        match N::ID {
+            <Testnet3 as Network>::ID => {
+                let process = (&*process as &dyn Any).downcast_ref::<Process<Testnet3>>()?;
+                let program = (program as &dyn Any).downcast_ref::<Program<Testnet3>>()?;
+                let stack = Stack::new(process, program).ok()?;
+                program.functions().keys().find_map(|function_name| {
+                    let error = stack.synthesize_key::<AleoV0, _>(function_name, rng).err()?;
+                    let function_name = (function_name as &dyn Any).downcast_ref::<Identifier<N>>()?;
+                    Some((*function_name, error))
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Creates an execute transaction, which pays its additional fee, if any, with a record of the given provider.
+    pub fn create_execute(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: &dyn RecordProvider<N, C>,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+        additional_fee: Option<u64>,
+    ) -> Result<Transaction<N>> {
+        // Ensure the program is deployed.
+        let program = match self.vm.process().read().get_program(program_id) {
+            Ok(program) => program.clone(),
+            Err(_) => return Err(SlingshotError::ProgramNotFound { program_id: program_id.to_string() }.into()),
+        };
+        // Ensure the inputs match the function signature, and the record inputs are unspent,
+        // before the fee record is selected.
+        self.check_inputs(program_id, function_name, inputs)?;
+        self.check_unspent_inputs(private_key, program_id, function_name, inputs)?;
+
+        // Reserve the record inputs.
+        let record_inputs = self.record_input_commitments(program_id, function_name, inputs)?;
+        let commitments = record_inputs.iter().map(|(_, commitment)| *commitment).collect::<Vec<_>>();
+        let input_reservation = self.reservations.reserve(private_key, &commitments)?;
+
+        let (additional_fee, fee_reservation) = match additional_fee {
+            Some(additional_fee) => {
+                // Select and reserve an unspent record with sufficient balance, that is not spent by the execution.
+                let (candidate, reservation) =
+                    self.select_record(records, private_key, additional_fee, &record_inputs)?;
+                (Some((candidate, additional_fee)), Some(reservation))
+            }
+            None => (None, None),
+        };
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Delegate the proofs to the remote prover, if any, unless the transaction pays an additional fee,
+        // as the fee is only proven locally.
+        let delegated = match (self.remote_prover.endpoint(), &additional_fee) {
+            (Some(_), None) => {
+                let authorization = self.vm.authorize(private_key, program_id, *function_name, inputs, rng)?;
+                self.remote_prover.prove(&self.vm, authorization.to_vec_deque().into_iter().collect())
+            }
+            _ => None,
+        };
+
+        let transaction = match delegated {
+            Some(transaction) => transaction,
+            None => {
+                // Load the cached keys of the function, to avoid synthesizing them again.
+                // Note: The process is only locked while the keys are loaded, as the execution locks it again.
+                self.key_cache.load(&self.vm.process().read(), &program, function_name)?;
+
+                // Create a new transaction.
+                let transaction = Transaction::execute(
+                    &self.vm,
+                    private_key,
+                    program_id.clone(),
+                    function_name.clone(),
+                    inputs.iter(),
+                    additional_fee,
+                    None,
+                    rng,
+                )?;
+
+                // Cache the keys of the function, which are synthesized by the first execution.
+                if let Err(error) = self.key_cache.store(&self.vm.process().read(), &program, function_name) {
+                    warn!("Failed to cache the keys of '{program_id}/{function_name}': {error}");
+                }
+                transaction
+            }
+        };
+        input_reservation.constructed();
+        if let Some(reservation) = fee_reservation {
+            reservation.constructed();
+        }
+        Ok(transaction)
+    }
+
+    /// Evaluates the given function on the current ledger state, and returns its outputs.
+    /// Unlike `create_execute`, this does not synthesize a proof, nor create a transaction.
+    pub fn evaluate(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<Vec<Value<N>>> {
+        // Ensure the inputs match the function signature.
+        self.check_inputs(program_id, function_name, inputs)?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Authorize the function call.
+        let authorization = self.vm.authorize(private_key, program_id, *function_name, inputs, rng)?;
+
+        // Evaluate the function call with the circuit of the network.
+        // Note: The process is generic over the network, so it is downcast to select the circuit, as in the VM.
+        let process = self.vm.process();
+        let process = process.read();
+        let outputs: Box<dyn Any> = match N::ID {
+            <Testnet3 as Network>::ID => {
+                let process = (&*process as &dyn Any)
+                    .downcast_ref::<Process<Testnet3>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast the process"))?;
+                let authorization = (Box::new(authorization) as Box<dyn Any>)
+                    .downcast::<Authorization<Testnet3>>()
+                    .map_err(|_| anyhow!("Failed to downcast the authorization"))?;
+                Box::new(process.evaluate::<AleoV0>(*authorization)?.outputs().to_vec())
+            }
+            _ => bail!("Evaluation is not supported on network {}", N::ID),
+        };
+        outputs
+            .downcast::<Vec<Value<N>>>()
+            .map(|outputs| *outputs)
+            .map_err(|_| anyhow!("Failed to downcast the outputs"))
+    }
+}
+
+/// Returns the commitment of the given record, as a `credits.aleo` record.
+fn credits_commitment<N: Network>(record: &Record<N, Plaintext<N>>) -> Result<Field<N>> {
+    record.to_commitment(&ProgramID::from_str("credits.aleo")?, &Identifier::from_str("credits")?)
+}
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// The profile of an execution, as returned by `POST /testnet3/program/profile`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct ExecutionProfile<N: Network> {
+    /// The program of the profiled function.
+    pub program_id: ProgramID<N>,
+    /// The profiled function.
+    pub function_name: Identifier<N>,
+    /// The milliseconds spent synthesizing the keys of the function, which is zero if they were cached.
+    pub synthesis_ms: u64,
+    /// The milliseconds spent constructing the transaction, including its proofs.
+    pub proving_ms: u64,
+    /// The milliseconds spent verifying the transaction.
+    pub verification_ms: u64,
+    /// The transitions of the transaction, in order.
+    pub transitions: Vec<TransitionProfile<N>>,
+    /// The size of the transaction in bytes.
+    pub size_in_bytes: usize,
+    /// The estimated minimum fee in gates, which covers the storage of the transaction.
+    pub fee: u64,
+}
+
+/// The profile of a transition of an execution.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct TransitionProfile<N: Network> {
+    /// The program of the transition.
+    pub program_id: ProgramID<N>,
+    /// The function of the transition.
+    pub function_name: Identifier<N>,
+    /// The number of constraints of the circuit of the function.
+    pub num_constraints: usize,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Executes the given function, with a fee transition, and returns the time spent on each stage, the constraints
+    /// of each transition, and the estimated fee. The transaction is not submitted, and its records are released.
+    pub fn profile(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        inputs: &[Value<N>],
+    ) -> Result<ExecutionProfile<N>> {
+        // Ensure the program is deployed, and the inputs match the function signature.
+        let program = match self.vm.process().read().get_program(program_id) {
+            Ok(program) => program.clone(),
+            Err(_) => return Err(SlingshotError::ProgramNotFound { program_id: program_id.to_string() }.into()),
+        };
+        self.check_inputs(program_id, function_name, inputs)?;
+
+        // Synthesize the keys of the function, unless they are cached.
+        // Note: The keys of the functions it calls are synthesized while the transaction is constructed.
+        let start = Instant::now();
+        self.key_cache.load(&self.vm.process().read(), &program, function_name)?;
+        if self.vm.process().read().get_proving_key(*program_id, *function_name).is_err() {
+            self.synthesize_keys(program_id, function_name)?;
+        }
+        let synthesis_ms = elapsed_ms(start.elapsed());
+
+        // Construct the transaction, with a fee transition, as its size does not depend on the fee amount.
+        let start = Instant::now();
+        let transaction = self.create_execute(private_key, &NodeScan, program_id, function_name, inputs, Some(0))?;
+        let proving_ms = elapsed_ms(start.elapsed());
+        // Release the records of the transaction, as a profile is not submitted.
+        self.reservations.release(&transaction);
+
+        // Verify the transaction.
+        let start = Instant::now();
+        ensure!(self.vm.verify(&transaction), "The transaction '{}' failed verification", transaction.id());
+        let verification_ms = elapsed_ms(start.elapsed());
+
+        // Retrieve the constraints of the function of each transition.
+        let transitions = transaction
+            .transitions()
+            .map(|transition| {
+                let (program_id, function_name) = (*transition.program_id(), *transition.function_name());
+                let verifying_key = self.vm.process().read().get_verifying_key(program_id, function_name)?;
+                let num_constraints = verifying_key.circuit_info.num_constraints;
+                Ok(TransitionProfile { program_id, function_name, num_constraints })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let (size_in_bytes, fee) = storage_fee(&transaction)?;
+        Ok(ExecutionProfile {
+            program_id: *program_id,
+            function_name: *function_name,
+            synthesis_ms,
+            proving_ms,
+            verification_ms,
+            transitions,
+            size_in_bytes,
+            fee,
+        })
+    }
+
+    /// Synthesizes the proving and verifying keys of the given function into the process.
+    fn synthesize_keys(&self, program_id: &ProgramID<N>, function_name: &Identifier<N>) -> Result<()> {
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Synthesize the keys with the circuit of the network, as in `evaluate`.
+        let process = self.vm.process();
+        let process = process.read();
+        match N::ID {
+            <Testnet3 as Network>::ID => {
+                let process = (&*process as &dyn Any)
+                    .downcast_ref::<Process<Testnet3>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast the process"))?;
+                let program_id = (program_id as &dyn Any)
+                    .downcast_ref::<ProgramID<Testnet3>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast the program ID"))?;
+                let function_name = (function_name as &dyn Any)
+                    .downcast_ref::<Identifier<Testnet3>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast the function name"))?;
+                process.get_stack(program_id)?.synthesize_key::<AleoV0, _>(function_name, rng)
+            }
+            _ => bail!("Synthesis is not supported on network {}", N::ID),
+        }
+    }
+}
+
+/// Returns the given duration in whole milliseconds.
+fn elapsed_ms(duration: Duration) -> u64 {
+    duration.as_millis() as u64
+}
@@ -0,0 +1,258 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::{fs, path::Path};
+
+/// A source of the unspent records that a transaction may spend, selected per request.
+/// Every transaction that spends a record for its amount or its fee selects the record with `Ledger::select_record`.
+pub trait RecordProvider<N: Network, C: ConsensusStorage<N>>: Send + Sync {
+    /// Returns the unspent records of the account of the given private key, keyed by commitment.
+    /// The given number of gates is the amount the transaction needs from a single record.
+    fn records(&self, ledger: &Ledger<N, C>, private_key: &PrivateKey<N>, required: u64) -> Result<RecordMap<N>>;
+}
+
+/// Scans the ledger for the unspent records of the account. This is the default record provider.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NodeScan;
+
+impl<N: Network, C: ConsensusStorage<N>> RecordProvider<N, C> for NodeScan {
+    fn records(&self, ledger: &Ledger<N, C>, private_key: &PrivateKey<N>, _required: u64) -> Result<RecordMap<N>> {
+        ledger.find_unspent_records(&ViewKey::try_from(private_key)?)
+    }
+}
+
+/// Provides the records of a wallet, e.g. as saved from `POST /testnet3/records/unspent`.
+/// The records that are not owned by the account, or are already spent on the ledger, are skipped.
+#[derive(Clone, Debug)]
+pub struct WalletFile<N: Network> {
+    records: RecordMap<N>,
+}
+
+impl<N: Network> WalletFile<N> {
+    /// Initializes a wallet with the given records, keyed by commitment.
+    pub fn new(records: RecordMap<N>) -> Self {
+        Self { records }
+    }
+
+    /// Opens the wallet file at the given path, which holds a JSON object of the records keyed by commitment,
+    /// either at the top level or under `records`.
+    pub fn open(path: &Path) -> Result<Self> {
+        let mut wallet: serde_json::Value = serde_json::from_str(&fs::read_to_string(path)?)
+            .map_err(|error| anyhow!("Failed to parse the wallet file '{}': {error}", path.display()))?;
+        let records = match wallet.get_mut("records") {
+            Some(records) => records.take(),
+            None => wallet,
+        };
+        Ok(Self::new(serde_json::from_value(records)?))
+    }
+
+    /// Returns the records of the wallet, keyed by commitment.
+    pub const fn records(&self) -> &RecordMap<N> {
+        &self.records
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> RecordProvider<N, C> for WalletFile<N> {
+    fn records(&self, ledger: &Ledger<N, C>, private_key: &PrivateKey<N>, _required: u64) -> Result<RecordMap<N>> {
+        let address = Address::try_from(private_key)?;
+        let mut records = RecordMap::new();
+        for (commitment, record) in &self.records {
+            if **record.owner() != address || record.gates().is_zero() {
+                continue;
+            }
+            let serial_number = Record::<N, Plaintext<N>>::serial_number(*private_key, *commitment)?;
+            if !ledger.contains_serial_number(&serial_number)? {
+                records.insert(*commitment, record.clone());
+            }
+        }
+        Ok(records)
+    }
+}
+
+/// Provides the unspent records of the account with the given commitments, in the given order.
+#[derive(Clone, Debug)]
+pub struct ExplicitCommitments<N: Network> {
+    commitments: Vec<Field<N>>,
+}
+
+impl<N: Network> ExplicitCommitments<N> {
+    /// Initializes a provider of the records with the given commitments.
+    pub fn new(commitments: Vec<Field<N>>) -> Self {
+        Self { commitments }
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> RecordProvider<N, C> for ExplicitCommitments<N> {
+    fn records(&self, ledger: &Ledger<N, C>, private_key: &PrivateKey<N>, _required: u64) -> Result<RecordMap<N>> {
+        let mut unspent = ledger.find_unspent_records(&ViewKey::try_from(private_key)?)?;
+        self.commitments
+            .iter()
+            .map(|commitment| match unspent.remove(commitment) {
+                Some(record) => Ok((*commitment, record)),
+                None => bail!("The record '{commitment}' is not an unspent record of the account"),
+            })
+            .collect()
+    }
+}
+
+/// Scans the ledger for the unspent records of the account, and funds the account from the faucet
+/// if none of them has the required gates. The faucet transfer is included in a new block by `fund`,
+/// so the funded record is unspent on the ledger once it returns.
+#[derive(Clone)]
+pub struct FaucetAutoFund<N: Network> {
+    /// The private key of the faucet.
+    faucet: PrivateKey<N>,
+    /// Includes the given faucet transfer in a new block.
+    fund: Arc<dyn Fn(Transaction<N>) -> Result<()> + Send + Sync>,
+}
+
+impl<N: Network> FaucetAutoFund<N> {
+    /// Initializes a provider that funds accounts from the given faucet, with the given function to include
+    /// each faucet transfer in a new block.
+    pub fn new(faucet: PrivateKey<N>, fund: impl Fn(Transaction<N>) -> Result<()> + Send + Sync + 'static) -> Self {
+        Self { faucet, fund: Arc::new(fund) }
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> RecordProvider<N, C> for FaucetAutoFund<N> {
+    fn records(&self, ledger: &Ledger<N, C>, private_key: &PrivateKey<N>, required: u64) -> Result<RecordMap<N>> {
+        let records = NodeScan.records(ledger, private_key, required)?;
+        let is_available = |(commitment, record): (&Field<N>, &Record<N, Plaintext<N>>)| {
+            ***record.gates() >= required && !ledger.reservations().is_reserved(commitment)
+        };
+        if records.iter().any(is_available) {
+            return Ok(records);
+        }
+
+        // Fund the account with the required gates, as a record of its own.
+        let address = Address::try_from(private_key)?;
+        debug!("Funding {address} with {required} gates from the faucet");
+        (self.fund)(ledger.create_transfer(&self.faucet, &NodeScan, address, required)?)?;
+        NodeScan.records(ledger, private_key, required)
+    }
+}
+
+/// Selects the first of the given records with at least the required gates, that is not one of the given
+/// record inputs, keyed by their input index. If only a record input has the required gates, the conflict is reported.
+pub fn select_record<N: Network>(
+    records: &RecordMap<N>,
+    required: u64,
+    record_inputs: &[(usize, Field<N>)],
+) -> Result<Record<N, Plaintext<N>>> {
+    select_commitment(records, required, record_inputs).map(|(_, record)| record)
+}
+
+/// Selects a record as in `select_record`, and returns it with its commitment.
+fn select_commitment<N: Network>(
+    records: &RecordMap<N>,
+    required: u64,
+    record_inputs: &[(usize, Field<N>)],
+) -> Result<(Field<N>, Record<N, Plaintext<N>>)> {
+    let is_sufficient = |record: &Record<N, Plaintext<N>>| ***record.gates() >= required;
+    let is_input = |commitment: &Field<N>| record_inputs.iter().any(|(_, input)| input == commitment);
+    match records.iter().find(|(commitment, record)| !is_input(commitment) && is_sufficient(record)) {
+        Some((commitment, record)) => Ok((*commitment, record.clone())),
+        None => match record_inputs.iter().find(|(_, commitment)| records.get(commitment).map_or(false, is_sufficient))
+        {
+            Some((index, _)) => Err(SlingshotError::FeeRecordConflict { index: *index, required }.into()),
+            None => Err(SlingshotError::InsufficientBalance { required }.into()),
+        },
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Selects a record of the account of the given private key with at least the required gates, from the records
+    /// of the given provider, that is not one of the given record inputs of the transaction, and reserves it.
+    /// If only records reserved by other transactions have the required gates, the contention is reported.
+    pub fn select_record(
+        &self,
+        provider: &dyn RecordProvider<N, C>,
+        private_key: &PrivateKey<N>,
+        required: u64,
+        record_inputs: &[(usize, Field<N>)],
+    ) -> Result<(Record<N, Plaintext<N>>, RecordReservation<'_, N>)> {
+        let records = provider.records(self, private_key, required)?;
+        loop {
+            // Select a record that is not reserved.
+            let available = records
+                .iter()
+                .filter(|(commitment, _)| !self.reservations.is_reserved(commitment))
+                .map(|(commitment, record)| (*commitment, record.clone()))
+                .collect::<RecordMap<N>>();
+            let (commitment, record) = match select_commitment(&available, required, record_inputs) {
+                Ok(selected) => selected,
+                Err(_) => {
+                    return Err(match select_commitment(&records, required, record_inputs) {
+                        Ok((commitment, _)) => {
+                            SlingshotError::RecordReserved { commitment: commitment.to_string() }.into()
+                        }
+                        Err(error) => error,
+                    });
+                }
+            };
+            // Reserve the record, unless a concurrent request reserved it since it was selected.
+            match self.reservations.reserve(private_key, &[commitment]) {
+                Ok(reservation) => return Ok((record, reservation)),
+                Err(_) if self.reservations.is_reserved(&commitment) => continue,
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Network as CurrentNetwork;
+
+    /// Returns a record with the given gates, owned by the given address.
+    fn sample_record(
+        address: &Address<CurrentNetwork>,
+        gates: u64,
+    ) -> Record<CurrentNetwork, Plaintext<CurrentNetwork>> {
+        Record::from_str(&format!("{{ owner: {address}.private, gates: {gates}u64.private, _nonce: 0group.public }}"))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_select_record() {
+        let rng = &mut rand::thread_rng();
+        let address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let (small, large) = (Field::from_u64(1), Field::from_u64(2));
+        let records = RecordMap::from([(small, sample_record(&address, 5)), (large, sample_record(&address, 100))]);
+
+        // The first record with the required gates is selected.
+        assert_eq!(***select_record(&records, 5, &[]).unwrap().gates(), 5);
+        assert_eq!(***select_record(&records, 50, &[]).unwrap().gates(), 100);
+
+        // A record input is not selected, and is reported if it is the only record with the required gates.
+        assert_eq!(***select_record(&records, 5, &[(0, small)]).unwrap().gates(), 100);
+        let error = select_record(&records, 50, &[(1, large)]).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<SlingshotError>(),
+            Some(SlingshotError::FeeRecordConflict { index: 1, required: 50 })
+        ));
+
+        // Otherwise, the balance is insufficient.
+        let error = select_record(&records, 500, &[]).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<SlingshotError>(),
+            Some(SlingshotError::InsufficientBalance { required: 500 })
+        ));
+    }
+}
@@ -0,0 +1,226 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::node::DEFAULT_PROVING_TIMEOUT_IN_SECS;
+
+use snarkvm::prelude::{ConsensusStorage, Field, InputID, Network, Request, Transaction, Transition, VM};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{sync::Arc, time::Duration};
+use tokio::{runtime::Handle, sync::oneshot};
+
+/// The request sent to a remote prover, to prove the execution of an authorization.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(bound = "")]
+pub struct ProveRequest<N: Network> {
+    /// The signed requests of the function calls of the execution.
+    pub authorization: Vec<Request<N>>,
+    /// The base endpoint of the node, from which the prover retrieves the state paths of the record inputs.
+    pub query: Option<String>,
+}
+
+/// A remote prover service, to which the ledger delegates the proofs of its executions, shared by every clone of the
+/// ledger. The ledger sends the authorization of each execution, which is signed by the caller, so that the private
+/// key of the caller never leaves the node. A transaction that the prover fails to return, or that does not prove
+/// exactly the authorized requests, is constructed locally instead.
+#[derive(Clone, Default)]
+pub struct RemoteProver {
+    /// The endpoint of the prover, e.g. `http://prover.example.com:4200/prove`, if proofs are delegated.
+    endpoint: Arc<RwLock<Option<String>>>,
+    /// The base endpoint of the node, which is sent to the prover with each authorization, if it is known.
+    query: Arc<RwLock<Option<String>>>,
+    /// The async runtime on which the requests to the prover are sent, so that they do not block it.
+    runtime: Arc<RwLock<Option<Handle>>>,
+    /// The HTTP client of the prover.
+    client: reqwest::Client,
+}
+
+impl RemoteProver {
+    /// Delegates the proofs of the executions to the prover at the given endpoint, which retrieves the state paths
+    /// of the record inputs from the node at the given base endpoint, if any.
+    /// The requests to the prover are sent on the current async runtime, if any.
+    pub fn set_endpoint(&self, endpoint: &str, query: Option<String>) {
+        *self.endpoint.write() = Some(endpoint.to_string());
+        *self.query.write() = query;
+        *self.runtime.write() = Handle::try_current().ok();
+    }
+
+    /// Returns the endpoint of the prover, if proofs are delegated.
+    pub fn endpoint(&self) -> Option<String> {
+        self.endpoint.read().clone()
+    }
+
+    /// Sends the given requests to the prover, and returns the transaction it proved, once it is verified.
+    /// Returns `None` if proofs are not delegated, or if the prover failed, in which case the transaction
+    /// must be constructed locally.
+    pub fn prove<N: Network, C: ConsensusStorage<N>>(
+        &self,
+        vm: &VM<N, C>,
+        authorization: Vec<Request<N>>,
+    ) -> Option<Transaction<N>> {
+        let endpoint = self.endpoint()?;
+        match self.request(vm, &endpoint, authorization) {
+            Ok(transaction) => Some(transaction),
+            Err(error) => {
+                warn!("The remote prover at {endpoint} failed, proving locally instead: {error}");
+                None
+            }
+        }
+    }
+
+    /// Sends the given requests to the prover at the given endpoint, and returns the transaction it proved,
+    /// if it proves exactly the authorized requests, and is valid.
+    /// Note: This waits for the response on the current thread, e.g. of the thread pool of the prover,
+    /// while the request itself is sent on the async runtime.
+    fn request<N: Network, C: ConsensusStorage<N>>(
+        &self,
+        vm: &VM<N, C>,
+        endpoint: &str,
+        authorization: Vec<Request<N>>,
+    ) -> Result<Transaction<N>> {
+        let runtime = self.runtime.read().clone().ok_or_else(|| anyhow!("The node has no async runtime"))?;
+        ensure!(!authorization.is_empty(), "The authorization is empty");
+
+        // Send the requests to the prover on the async runtime, and wait for its response.
+        let (sender, receiver) = oneshot::channel();
+        let request = self
+            .client
+            .post(endpoint)
+            .timeout(Duration::from_secs(DEFAULT_PROVING_TIMEOUT_IN_SECS))
+            .json(&ProveRequest { authorization: authorization.clone(), query: self.query.read().clone() });
+        runtime.spawn(async move {
+            // Note: The receiver is only dropped if the thread waiting for the response is gone.
+            let _ = sender.send(Self::send(request).await);
+        });
+        let transaction = receiver.blocking_recv()??;
+
+        // Ensure the transaction proves exactly the authorized requests, and is valid.
+        ensure!(
+            matches!(transaction, Transaction::Execute(..)),
+            "The prover returned a transaction '{}' that is not an execution",
+            transaction.id()
+        );
+        check_transitions(&authorization, &transaction.transitions().collect::<Vec<_>>())?;
+        ensure!(vm.verify(&transaction), "The prover returned a transaction '{}' that is invalid", transaction.id());
+        Ok(transaction)
+    }
+
+    /// Sends the given request to the prover, and returns the transaction in its response.
+    async fn send<N: Network>(request: reqwest::RequestBuilder) -> Result<Transaction<N>> {
+        Ok(request.send().await?.error_for_status()?.json().await?)
+    }
+}
+
+/// Ensures the given transitions prove exactly the given requests, each transition matching the commitment
+/// of the transition view key, the function call, and the input IDs of one request, so that a prover cannot return
+/// another valid execution in place of the authorized one.
+fn check_transitions<N: Network>(authorization: &[Request<N>], transitions: &[&Transition<N>]) -> Result<()> {
+    ensure!(
+        transitions.len() == authorization.len(),
+        "The prover returned {} transitions, instead of an execution of {}",
+        transitions.len(),
+        authorization.len()
+    );
+    let mut proven = vec![false; authorization.len()];
+    for transition in transitions {
+        // Note: The commitment of the transition view key is bound to the signer by the signature of the request.
+        let index = match authorization.iter().position(|request| request.tcm() == transition.tcm()) {
+            Some(index) if !proven[index] => index,
+            Some(_) => bail!("The prover returned the transition '{}' twice", transition.id()),
+            None => bail!("The prover returned the transition '{}', which was not authorized", transition.id()),
+        };
+        let request = &authorization[index];
+        ensure!(
+            request.program_id() == transition.program_id() && request.function_name() == transition.function_name(),
+            "The prover returned the transition '{}' of '{}/{}', instead of '{}/{}'",
+            transition.id(),
+            transition.program_id(),
+            transition.function_name(),
+            request.program_id(),
+            request.function_name()
+        );
+        let input_ids = request.input_ids().iter().map(input_id);
+        ensure!(
+            input_ids.eq(transition.inputs().iter().map(|input| input.id())),
+            "The prover returned the transition '{}' with inputs that were not authorized",
+            transition.id()
+        );
+        proven[index] = true;
+    }
+    Ok(())
+}
+
+/// Returns the ID of the input of a transition for the given input ID of a request,
+/// which is the serial number of a record input.
+fn input_id<N: Network>(input_id: &InputID<N>) -> &Field<N> {
+    match input_id {
+        InputID::Constant(id) | InputID::Public(id) | InputID::Private(id) | InputID::ExternalRecord(id) => id,
+        InputID::Record(_, _, serial_number, _) => serial_number,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_helpers::CurrentLedger;
+    use snarkvm::prelude::{Address, Identifier, PrivateKey, ProgramID, Value};
+
+    use std::str::FromStr;
+
+    #[test]
+    fn test_prove_fallback() {
+        let ledger = CurrentLedger::load(None, None).unwrap();
+        let prover = RemoteProver::default();
+
+        // Without an endpoint, proofs are not delegated.
+        assert!(prover.prove(ledger.vm(), vec![]).is_none());
+
+        // If the prover fails, the execution is proven locally.
+        prover.set_endpoint("http://127.0.0.1:1/prove", None);
+        assert_eq!(prover.endpoint().as_deref(), Some("http://127.0.0.1:1/prove"));
+        assert!(prover.prove(ledger.vm(), vec![]).is_none());
+    }
+
+    #[test]
+    fn test_check_transitions() {
+        let ledger = CurrentLedger::load(None, None).unwrap();
+        let rng = &mut rand::thread_rng();
+
+        // Authorize and prove a new call of the genesis function.
+        let private_key = PrivateKey::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let function_name = Identifier::from_str("genesis").unwrap();
+        let authorization = ledger.vm().authorize(&private_key, &program_id, function_name, &inputs, rng).unwrap();
+        let requests = authorization.to_vec_deque().into_iter().collect::<Vec<_>>();
+        let transaction = Transaction::execute_authorization(ledger.vm(), authorization, rng).unwrap();
+
+        // The transaction proves the authorized requests.
+        let transitions = transaction.transitions().collect::<Vec<_>>();
+        assert!(check_transitions(&requests, &transitions).is_ok());
+
+        // Another valid execution of the same function, e.g. the genesis transaction, does not.
+        let genesis = ledger.get_block(0).unwrap();
+        let (_, genesis) = genesis.transactions().iter().next().unwrap();
+        assert!(check_transitions(&requests, &genesis.transitions().collect::<Vec<_>>()).is_err());
+
+        // Neither does an execution without the authorized transitions.
+        assert!(check_transitions(&requests, &[]).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use parking_lot::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// The number of seconds a record stays reserved by a constructed transaction that is not submitted to the
+/// memory pool, e.g. by a simulation.
+const UNSUBMITTED_RESERVATION_IN_SECS: u64 = 60;
+
+/// Returns `true` if the transaction with the given ID is in the memory pool.
+type PendingCheck<N> = Arc<dyn Fn(&<N as Network>::TransactionID) -> bool + Send + Sync>;
+
+/// The state of the transaction that spends a reserved record.
+#[derive(Clone, Debug)]
+enum ReservationState<N: Network> {
+    /// The transaction is being constructed.
+    Constructing,
+    /// The transaction was constructed at the given time, and is not submitted to the memory pool yet.
+    Constructed(Instant),
+    /// The transaction with the given ID is in the memory pool.
+    Submitted(N::TransactionID),
+}
+
+/// A reserved record.
+#[derive(Clone, Debug)]
+struct Reservation<N: Network> {
+    /// The serial number of the record, which identifies the transaction that spends it.
+    serial_number: Field<N>,
+    /// The state of the transaction that spends the record.
+    state: ReservationState<N>,
+}
+
+/// The records reserved by the transactions that spend them, keyed by commitment, so that concurrent requests
+/// do not select the same record. A record is reserved from its selection until the transaction that spends it
+/// is confirmed, or leaves the memory pool otherwise.
+#[derive(Clone)]
+pub struct RecordReservations<N: Network> {
+    /// The reserved records, keyed by commitment.
+    reservations: Arc<Mutex<IndexMap<Field<N>, Reservation<N>>>>,
+    /// Returns `true` if the given transaction is in the memory pool, once a memory pool is attached.
+    is_pending: Arc<RwLock<Option<PendingCheck<N>>>>,
+}
+
+impl<N: Network> Default for RecordReservations<N> {
+    fn default() -> Self {
+        Self { reservations: Default::default(), is_pending: Default::default() }
+    }
+}
+
+impl<N: Network> RecordReservations<N> {
+    /// Sets the function that returns `true` if the given transaction is in the memory pool.
+    /// Until it is set, the records spent by submitted transactions stay reserved.
+    pub fn set_pending_check(&self, is_pending: impl Fn(&N::TransactionID) -> bool + Send + Sync + 'static) {
+        *self.is_pending.write() = Some(Arc::new(is_pending));
+    }
+
+    /// Returns `true` if the record with the given commitment is reserved.
+    pub fn is_reserved(&self, commitment: &Field<N>) -> bool {
+        self.live().contains_key(commitment)
+    }
+
+    /// Reserves the records with the given commitments, which the account of the given private key spends in a
+    /// transaction that is being constructed. Returns an error if one of the records is already reserved.
+    /// The records are released when the returned reservation is dropped, unless the transaction is constructed.
+    pub fn reserve(&self, private_key: &PrivateKey<N>, commitments: &[Field<N>]) -> Result<RecordReservation<'_, N>> {
+        // Compute the serial numbers before any record is reserved, so that an error reserves none of the records.
+        let serial_numbers = commitments
+            .iter()
+            .map(|commitment| Record::<N, Plaintext<N>>::serial_number(*private_key, *commitment))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut reservations = self.live();
+        if let Some(commitment) = commitments.iter().find(|commitment| reservations.contains_key(*commitment)) {
+            return Err(SlingshotError::RecordReserved { commitment: commitment.to_string() }.into());
+        }
+        for (commitment, serial_number) in commitments.iter().zip(serial_numbers) {
+            reservations.insert(*commitment, Reservation { serial_number, state: ReservationState::Constructing });
+        }
+        Ok(RecordReservation { reservations: self, commitments: commitments.to_vec(), is_constructed: false })
+    }
+
+    /// Marks the records spent by the given transaction as spent by a transaction in the memory pool.
+    pub fn submit(&self, transaction: &Transaction<N>) {
+        let serial_numbers = transaction.serial_numbers().collect::<Vec<_>>();
+        for reservation in self.reservations.lock().values_mut() {
+            if serial_numbers.contains(&&reservation.serial_number) {
+                reservation.state = ReservationState::Submitted(transaction.id());
+            }
+        }
+    }
+
+    /// Releases the records spent by the given transaction, e.g. if it is not admitted to the memory pool.
+    /// The records spent by another transaction in the memory pool stay reserved.
+    pub fn release(&self, transaction: &Transaction<N>) {
+        let serial_numbers = transaction.serial_numbers().collect::<Vec<_>>();
+        self.reservations.lock().retain(|_, reservation| match reservation.state {
+            ReservationState::Submitted(transaction_id) if transaction_id != transaction.id() => true,
+            _ => !serial_numbers.contains(&&reservation.serial_number),
+        });
+    }
+
+    /// Returns the reservations, without the records whose transaction was constructed but not submitted in time,
+    /// or has left the memory pool, as it was confirmed or evicted.
+    fn live(&self) -> MutexGuard<'_, IndexMap<Field<N>, Reservation<N>>> {
+        let is_pending = self.is_pending.read().clone();
+        let mut reservations = self.reservations.lock();
+        reservations.retain(|_, reservation| match &reservation.state {
+            ReservationState::Constructing => true,
+            ReservationState::Constructed(constructed_at) => {
+                constructed_at.elapsed() < Duration::from_secs(UNSUBMITTED_RESERVATION_IN_SECS)
+            }
+            ReservationState::Submitted(transaction_id) => {
+                is_pending.as_ref().map_or(true, |is_pending| is_pending(transaction_id))
+            }
+        });
+        reservations
+    }
+}
+
+/// The records reserved for a transaction that is being constructed.
+pub struct RecordReservation<'a, N: Network> {
+    /// The reservations.
+    reservations: &'a RecordReservations<N>,
+    /// The commitments of the reserved records.
+    commitments: Vec<Field<N>>,
+    /// If `true`, the transaction is constructed, and the records stay reserved.
+    is_constructed: bool,
+}
+
+impl<N: Network> RecordReservation<'_, N> {
+    /// Keeps the records reserved, as the transaction that spends them is constructed.
+    pub fn constructed(mut self) {
+        let mut reservations = self.reservations.reservations.lock();
+        for commitment in &self.commitments {
+            if let Some(reservation) = reservations.get_mut(commitment) {
+                reservation.state = ReservationState::Constructed(Instant::now());
+            }
+        }
+        self.is_constructed = true;
+    }
+}
+
+impl<N: Network> Drop for RecordReservation<'_, N> {
+    /// Releases the records, unless the transaction that spends them is constructed.
+    fn drop(&mut self) {
+        if !self.is_constructed {
+            let mut reservations = self.reservations.reservations.lock();
+            for commitment in &self.commitments {
+                reservations.remove(commitment);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Network as CurrentNetwork;
+
+    #[test]
+    fn test_reservations() {
+        let rng = &mut rand::thread_rng();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let reservations = RecordReservations::<CurrentNetwork>::default();
+        let (first, second) = (Field::from_u64(1), Field::from_u64(2));
+
+        // A reserved record cannot be reserved again, until its reservation is dropped.
+        let reservation = reservations.reserve(&private_key, &[first]).unwrap();
+        assert!(reservations.is_reserved(&first));
+        let error = reservations.reserve(&private_key, &[second, first]).err().unwrap();
+        assert_eq!(
+            error.downcast_ref::<SlingshotError>(),
+            Some(&SlingshotError::RecordReserved { commitment: first.to_string() })
+        );
+        assert!(!reservations.is_reserved(&second));
+        drop(reservation);
+        assert!(!reservations.is_reserved(&first));
+
+        // The record of a constructed transaction stays reserved.
+        reservations.reserve(&private_key, &[first]).unwrap().constructed();
+        assert!(reservations.is_reserved(&first));
+        assert!(reservations.reserve(&private_key, &[first]).is_err());
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm::prelude::{LiteralType, PlaintextType, ValueType};
+
+/// The function that `create_send` prefers, if several functions of the program can send the record.
+const PREFERRED_SEND_FUNCTION: &str = "transfer";
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Creates an execute transaction that sends the unspent record with the given commitment to the recipient,
+    /// and returns the function it executes with the transaction.
+    ///
+    /// The function is found in the signature of the program: it takes a record of the type of the given record,
+    /// then an address, and then an amount if one is given. A function named `transfer` is preferred, unless
+    /// the function is given.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_send(
+        &self,
+        private_key: &PrivateKey<N>,
+        records: &dyn RecordProvider<N, C>,
+        program_id: &ProgramID<N>,
+        commitment: &Field<N>,
+        recipient: Address<N>,
+        amount: Option<u64>,
+        function_name: Option<&Identifier<N>>,
+        additional_fee: Option<u64>,
+    ) -> Result<(Identifier<N>, Transaction<N>)> {
+        let (function_name, inputs) =
+            self.send_inputs(private_key, program_id, commitment, recipient, amount, function_name)?;
+        let transaction =
+            self.create_execute(private_key, records, program_id, &function_name, &inputs, additional_fee)?;
+        Ok((function_name, transaction))
+    }
+
+    /// Returns the function that sends the unspent record with the given commitment to the recipient,
+    /// and its inputs.
+    fn send_inputs(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: &ProgramID<N>,
+        commitment: &Field<N>,
+        recipient: Address<N>,
+        amount: Option<u64>,
+        function_name: Option<&Identifier<N>>,
+    ) -> Result<(Identifier<N>, Vec<Value<N>>)> {
+        let program = match self.vm.process().read().get_program(program_id) {
+            Ok(program) => program.clone(),
+            Err(_) => return Err(SlingshotError::ProgramNotFound { program_id: program_id.to_string() }.into()),
+        };
+
+        // Retrieve the record, and the name of its record type, which determines its commitment.
+        let record = match self.find_unspent_records(&ViewKey::try_from(private_key)?)?.remove(commitment) {
+            Some(record) => record,
+            None => return Err(SlingshotError::RecordNotFound { index: 0, commitment: commitment.to_string() }.into()),
+        };
+        let record_name = program
+            .records()
+            .keys()
+            .find(|record_name| record.to_commitment(program_id, record_name).ok().as_ref() == Some(commitment))
+            .ok_or_else(|| anyhow!("The record '{commitment}' is not a record of '{program_id}'"))?;
+
+        // Find the functions that take the record, the recipient, and the amount, if any.
+        let candidates = program
+            .functions()
+            .iter()
+            .filter_map(|(name, function)| {
+                let input_types = function.inputs().iter().map(|input| input.value_type().clone()).collect::<Vec<_>>();
+                match (input_types.as_slice(), amount) {
+                    ([ValueType::Record(input_record), recipient_type], None)
+                        if input_record == record_name && is_address(recipient_type) =>
+                    {
+                        Some((*name, None))
+                    }
+                    ([ValueType::Record(input_record), recipient_type, amount_type], Some(_))
+                        if input_record == record_name && is_address(recipient_type) =>
+                    {
+                        integer_type(amount_type).map(|amount_type| (*name, Some(amount_type)))
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Select the given function, the preferred function, or the only candidate.
+        let preferred = Identifier::<N>::from_str(PREFERRED_SEND_FUNCTION)?;
+        let selected = match function_name {
+            Some(function_name) => candidates.iter().find(|(name, _)| name == function_name),
+            None => match candidates.iter().find(|(name, _)| *name == preferred) {
+                Some(candidate) => Some(candidate),
+                None if candidates.len() == 1 => candidates.first(),
+                None => None,
+            },
+        };
+        let (function_name, amount_type) = match selected {
+            Some(selected) => *selected,
+            None => {
+                let names = candidates.iter().map(|(name, _)| name.to_string()).collect::<Vec<_>>();
+                let expected = match amount {
+                    Some(_) => format!("a '{record_name}' record, an address, and an amount"),
+                    None => format!("a '{record_name}' record and an address"),
+                };
+                bail!(
+                    "Cannot determine the function of '{program_id}' that sends the record (the functions that take \
+                     {expected} are [{}]; pass the function name)",
+                    names.join(", ")
+                );
+            }
+        };
+
+        // Construct the inputs.
+        let mut inputs = vec![Value::Record(record), Value::from_str(&recipient.to_string())?];
+        if let (Some(amount), Some(amount_type)) = (amount, amount_type) {
+            inputs.push(Value::from_str(&format!("{amount}{amount_type}"))?);
+        }
+        Ok((function_name, inputs))
+    }
+}
+
+/// Returns `true` if the given value type is an address.
+fn is_address<N: Network>(value_type: &ValueType<N>) -> bool {
+    literal_type(value_type) == Some(LiteralType::Address)
+}
+
+/// Returns the type of the given value type, if it is an unsigned integer, which can hold an amount.
+fn integer_type<N: Network>(value_type: &ValueType<N>) -> Option<LiteralType> {
+    literal_type(value_type).filter(|literal_type| {
+        matches!(
+            literal_type,
+            LiteralType::U8 | LiteralType::U16 | LiteralType::U32 | LiteralType::U64 | LiteralType::U128
+        )
+    })
+}
+
+/// Returns the literal type of the given value type, if it is a literal.
+fn literal_type<N: Network>(value_type: &ValueType<N>) -> Option<LiteralType> {
+    match value_type {
+        ValueType::Constant(PlaintextType::Literal(literal_type))
+        | ValueType::Public(PlaintextType::Literal(literal_type))
+        | ValueType::Private(PlaintextType::Literal(literal_type)) => Some(*literal_type),
+        _ => None,
+    }
+}
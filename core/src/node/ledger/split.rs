@@ -0,0 +1,66 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the next round of splits towards the given number of unspent credits records with the given number
+    /// of gates, as pairs of a record and the amount to split from it, or nothing if there are enough records.
+    ///
+    /// Each round splits every record that is larger than needed in two, so the number of records roughly doubles
+    /// each round. Returns an error if the records do not have enough gates.
+    pub fn plan_splits(
+        &self,
+        view_key: &ViewKey<N>,
+        num_records: usize,
+        gates: u64,
+    ) -> Result<Vec<(Record<N, Plaintext<N>>, u64)>> {
+        ensure!(gates > 0, "Cannot split records into records of zero gates");
+
+        // Fetch the credits records that are not reserved, with the smallest records first.
+        let mut records = self
+            .find_unspent_credits(view_key)?
+            .into_iter()
+            .filter(|(commitment, _)| !self.reservations.is_reserved(commitment))
+            .map(|(_, record)| record)
+            .collect::<Vec<_>>();
+        records.sort_by_key(|record| ***record.gates());
+
+        // Determine the number of records that are still needed.
+        let num_ready = records.iter().filter(|record| ***record.gates() == gates).count();
+        if num_ready >= num_records {
+            return Ok(Vec::new());
+        }
+        let mut num_needed = num_records - num_ready;
+
+        // Assign the needed records to the larger records, and split each of them in two.
+        let mut splits = Vec::new();
+        for record in records.into_iter().filter(|record| ***record.gates() > gates) {
+            if num_needed == 0 {
+                break;
+            }
+            let num_assigned = (***record.gates() / gates).min(num_needed as u64);
+            let amount = (num_assigned / 2).max(1) * gates;
+            splits.push((record, amount));
+            num_needed -= num_assigned as usize;
+        }
+        ensure!(
+            !splits.is_empty() && num_needed == 0,
+            "The account does not have enough gates for {num_records} records of {gates} gates"
+        );
+        Ok(splits)
+    }
+}
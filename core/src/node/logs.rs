@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Write},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use time::OffsetDateTime;
+use tracing::{
+    field::{Field, Visit},
+    metadata::LevelFilter,
+    span::{Attributes, Id, Record},
+    Level,
+    Metadata,
+    Subscriber,
+};
+
+/// The default number of log entries retained by the node.
+pub const DEFAULT_LOG_CAPACITY: usize = 10_000;
+
+/// A tracing event recorded by the node.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LogEntry {
+    /// The sequence number of the entry, which increases by one for each entry.
+    pub sequence: u64,
+    /// The time of the event, in milliseconds since the Unix epoch.
+    pub timestamp_ms: i64,
+    /// The level of the event (e.g. `INFO`).
+    pub level: String,
+    /// The module path of the event.
+    pub target: String,
+    /// The message of the event, followed by its other fields.
+    pub message: String,
+}
+
+/// An in-memory ring buffer of the tracing events of the node, so that its logs can be read over the REST API.
+#[derive(Clone, Debug)]
+pub struct LogBuffer {
+    /// The retained entries, from oldest to newest.
+    entries: Arc<RwLock<VecDeque<LogEntry>>>,
+    /// The sequence number of the next entry.
+    next_sequence: Arc<AtomicU64>,
+    /// The maximum number of retained entries.
+    capacity: usize,
+    /// The most verbose level that is recorded. This is shared with the installed subscriber, so that it can change.
+    level: Arc<RwLock<Level>>,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_LOG_CAPACITY, Level::INFO)
+    }
+}
+
+impl LogBuffer {
+    /// Initializes a new log buffer, which retains the given number of entries, up to the given level.
+    pub fn new(capacity: usize, level: Level) -> Self {
+        Self {
+            entries: Default::default(),
+            next_sequence: Default::default(),
+            capacity,
+            level: Arc::new(RwLock::new(level)),
+        }
+    }
+
+    /// Returns the most verbose level that is recorded.
+    pub fn level(&self) -> Level {
+        *self.level.read()
+    }
+
+    /// Sets the most verbose level that is recorded.
+    pub fn set_level(&self, level: Level) {
+        *self.level.write() = level;
+        // Note: The interest of each callsite is cached, so it must be rebuilt for the new level to take effect.
+        tracing::callsite::rebuild_interest_cache();
+        info!("Set the log level to {level}");
+    }
+
+    /// Sets the log buffer as the global tracing subscriber of the process.
+    pub fn install(&self) -> anyhow::Result<()> {
+        tracing::subscriber::set_global_default(self.clone())
+            .map_err(|error| anyhow::anyhow!("Failed to install the log buffer: {error}"))
+    }
+
+    /// Returns the retained entries after the given sequence number, or every retained entry.
+    pub fn since(&self, sequence: Option<u64>) -> Vec<LogEntry> {
+        let entries = self.entries.read();
+        match sequence {
+            Some(sequence) => entries.iter().filter(|entry| entry.sequence > sequence).cloned().collect(),
+            None => entries.iter().cloned().collect(),
+        }
+    }
+
+    /// Appends an entry, and drops the oldest entry if the buffer is full.
+    fn push(&self, level: &Level, target: &str, message: String) {
+        let entry = LogEntry {
+            sequence: self.next_sequence.fetch_add(1, Ordering::SeqCst),
+            timestamp_ms: (OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000) as i64,
+            level: level.to_string(),
+            target: target.to_string(),
+            message,
+        };
+        let mut entries = self.entries.write();
+        if entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+}
+
+/// Formats the fields of an event as its message, followed by its other fields as `name=value`.
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        let separator = if self.0.is_empty() { "" } else { " " };
+        let _ = match field.name() {
+            "message" => write!(self.0, "{separator}{value:?}"),
+            name => write!(self.0, "{separator}{name}={value:?}"),
+        };
+    }
+}
+
+// Note: Spans are not recorded, as the node only emits events.
+impl Subscriber for LogBuffer {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        *metadata.level() <= self.level()
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        Some(LevelFilter::from_level(self.level()))
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &tracing::Event<'_>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        self.push(event.metadata().level(), event.metadata().target(), visitor.0);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_buffer() {
+        let logs = LogBuffer::new(2, Level::INFO);
+        tracing::subscriber::with_default(logs.clone(), || {
+            info!("first");
+            debug!("not recorded");
+            warn!(height = 5, "second");
+            error!("third");
+        });
+
+        // The oldest entry is dropped, and the debug entry is not recorded.
+        let entries = logs.since(None);
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[0].sequence, entries[0].level.as_str()), (1, "WARN"));
+        assert_eq!(entries[0].message, "second height=5");
+        assert_eq!(entries[1].message, "third");
+
+        // Only the entries after the given sequence number are returned.
+        assert_eq!(logs.since(Some(1)).len(), 1);
+        assert!(logs.since(Some(2)).is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::messages::ProgramMetadata;
+
+use snarkvm::prelude::{Network, ProgramID};
+
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
+
+/// A registry of the metadata attached to the deployments of programs.
+#[derive(Clone, Debug)]
+pub struct ProgramMetadataRegistry<N: Network> {
+    /// The map of program IDs to metadata.
+    metadata: Arc<RwLock<HashMap<ProgramID<N>, ProgramMetadata>>>,
+}
+
+impl<N: Network> Default for ProgramMetadataRegistry<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> ProgramMetadataRegistry<N> {
+    /// Initializes a new instance of the program metadata registry.
+    pub fn new() -> Self {
+        Self { metadata: Default::default() }
+    }
+
+    /// Sets the metadata of the given program, replacing the metadata of an earlier deployment request.
+    pub fn set(&self, program_id: ProgramID<N>, metadata: Option<ProgramMetadata>) {
+        match metadata {
+            Some(metadata) => self.metadata.write().insert(program_id, metadata),
+            None => self.metadata.write().remove(&program_id),
+        };
+    }
+
+    /// Returns the metadata of the given program, if any.
+    pub fn get(&self, program_id: &ProgramID<N>) -> Option<ProgramMetadata> {
+        self.metadata.read().get(program_id).cloned()
+    }
+}
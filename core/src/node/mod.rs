@@ -0,0 +1,929 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+// TODO: Cleanup and generalize.
+
+pub mod accounts;
+pub use accounts::*;
+
+pub mod admission;
+pub use admission::*;
+
+pub mod auth;
+pub use auth::*;
+
+pub mod builder;
+pub use builder::*;
+
+pub mod capture;
+pub use capture::*;
+
+pub mod chain;
+pub use chain::*;
+
+pub mod config;
+pub use config::*;
+
+pub mod consensus;
+pub use consensus::*;
+
+pub mod events;
+pub use events::*;
+
+pub mod explorer;
+pub use explorer::*;
+
+#[cfg(feature = "graphql")]
+pub mod graphql;
+#[cfg(feature = "graphql")]
+pub use graphql::*;
+
+#[cfg(feature = "grpc")]
+pub mod grpc;
+#[cfg(feature = "grpc")]
+pub use grpc::*;
+
+pub mod info;
+pub use info::*;
+
+pub mod jobs;
+pub use jobs::*;
+
+pub mod ledger;
+pub use ledger::*;
+
+pub mod logs;
+pub use logs::*;
+
+pub mod metadata;
+pub use metadata::*;
+
+pub mod peer;
+pub use peer::*;
+
+pub mod pool;
+pub use pool::*;
+
+pub mod prover;
+pub use prover::*;
+
+pub mod reload;
+pub use reload::*;
+
+pub mod rest;
+pub use rest::*;
+
+pub mod routes;
+pub use routes::*;
+
+pub mod sequences;
+pub use sequences::*;
+
+pub mod shutdown;
+pub use shutdown::*;
+
+pub mod simulations;
+pub use simulations::*;
+
+pub mod snapshots;
+pub use snapshots::*;
+
+pub mod stats;
+pub use stats::*;
+
+pub mod template;
+pub use template::*;
+
+pub mod tenants;
+pub use tenants::*;
+
+pub mod usage;
+pub use usage::*;
+
+pub mod validators;
+pub use validators::*;
+
+pub mod view_only;
+pub use view_only::*;
+
+pub mod watch;
+pub use watch::*;
+
+use crate::output;
+
+use snarkos::{
+    account::Account,
+    node::{ledger::RecordMap, messages::NodeType, NodeInterface},
+};
+
+use snarkvm::prelude::{
+    cfg_into_iter,
+    Address,
+    Block,
+    Identifier,
+    Network,
+    PrivateKey,
+    ProgramID,
+    ProverSolution,
+    Transaction,
+    Value,
+    ViewKey,
+    Zero,
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use core::{str::FromStr, time::Duration};
+use parking_lot::RwLock;
+use snarkvm::synthesizer::{ConsensusMemory, ConsensusStorage};
+use std::{
+    collections::HashSet,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::{task::JoinHandle, time::timeout};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The number of milliseconds between checks of the block policy, whether a block is due.
+const BLOCK_POLICY_INTERVAL_IN_MS: u64 = 100;
+/// The number of milliseconds between checks for the transactions and blocks gossiped between validators.
+const GOSSIP_INTERVAL_IN_MS: u64 = 100;
+/// The maximum number of seconds the node waits for a block proposed by another validator.
+const MAX_GOSSIP_WAIT_IN_SECS: u64 = 60;
+/// The number of milliseconds between checks for new blocks that spend watched records.
+const WATCH_INTERVAL_IN_MS: u64 = 250;
+/// The number of milliseconds between checks for new blocks whose events are published to the event feed.
+const EVENT_FEED_INTERVAL_IN_MS: u64 = 250;
+
+// TODO: Better name
+/// A development beacon is an isolated full node, capable of producing blocks.
+#[derive(Clone)]
+pub struct DevelopmentBeacon<N: Network> {
+    /// The account of the node.
+    account: Account<N>,
+    /// The account of the faucet.
+    faucet: Account<N>,
+    /// The consensus module of the node.
+    consensus: SingleNodeConsensus<N, ConsensusMemory<N>>,
+    /// The ledger of the node.
+    ledger: Ledger<N, ConsensusMemory<N>>,
+    /// The REST server of the node, whose routes may be served by the node itself or by another server.
+    rest: Arc<Rest<N, ConsensusMemory<N>>>,
+    /// The number of seconds between blocks.
+    block_interval: Arc<AtomicU64>,
+    /// The time it to generate a block.
+    block_generation_time: Arc<AtomicU64>,
+    /// If `true`, a block is produced every round, even if the memory pool is empty.
+    produce_empty_blocks: bool,
+    /// The unspent records.
+    unspent_records: Arc<RwLock<RecordMap<N>>>,
+    /// The validators of the devnet, starting with the node itself, if there is more than one validator.
+    validators: Vec<Validator<N>>,
+    /// The gossip channel between the validators.
+    gossip: Option<Gossip<N>>,
+    /// The spawned handles.
+    handles: Arc<RwLock<Vec<JoinHandle<()>>>>,
+    /// If `true`, the node serves its own REST routes, and exits the process once a requested shutdown is complete.
+    standalone: bool,
+    /// The shutdown signal, which is shared with the REST server.
+    shutdown: Arc<AtomicBool>,
+}
+
+impl<N: Network> DevelopmentBeacon<N> {
+    /// Returns a builder, which starts a beacon node in the current process.
+    pub fn builder() -> DevelopmentBeaconBuilder<N> {
+        DevelopmentBeaconBuilder::default()
+    }
+
+    /// Initializes a new beacon node.
+    pub async fn new(
+        rest_ip: Option<SocketAddr>,
+        private_key: PrivateKey<N>,
+        faucet_key: Option<PrivateKey<N>>,
+        genesis: Option<Block<N>>,
+        dev: Option<u16>,
+        config: NodeConfig,
+    ) -> Result<Self> {
+        // Initialize the node account.
+        let account = Account::try_from(private_key)?;
+        // Initialize the faucet account, which defaults to the node account.
+        let faucet = match faucet_key {
+            Some(faucet_key) => Account::try_from(faucet_key)?,
+            None => account.clone(),
+        };
+        // Initialize the ledger.
+        let ledger = Ledger::load(genesis, dev)?;
+        // Persist the keys of program functions, if requested.
+        if let Some(cache_dir) = &config.cache_dir {
+            ledger.key_cache().set_directory(cache_dir)?;
+        }
+        // Delegate the proofs of executions to the remote prover, if requested, which queries the state of the node.
+        if let Some(endpoint) = &config.prover_endpoint {
+            ledger.remote_prover().set_endpoint(endpoint, rest_ip.map(|rest_ip| format!("http://{rest_ip}")));
+        }
+        // Initialize the consensus.
+        let consensus = SingleNodeConsensus::new(ledger.clone(), config.clock_skew_tolerance, config.pool_limits)?;
+        // Produce blocks according to the block policy.
+        consensus.set_block_policy(Arc::new(config.block_policy));
+        // Change the proposed blocks with the block template script, if one is provided.
+        if let Some(script) = &config.block_template {
+            consensus.set_block_template_hook(Arc::new(script.clone()));
+        }
+        // Replay the blocks of the snapshot or chain file after the genesis block, if the node is restored from one.
+        if let Some(path) = &config.restore {
+            let blocks = ChainFile::read::<N>(path)?;
+            for block in blocks.iter().skip(1) {
+                consensus
+                    .check_next_block(block)
+                    .map_err(|error| anyhow!("Block {} of '{}' is invalid: {error}", block.height(), path.display()))?;
+                consensus.advance_to_next_block(block)?;
+            }
+            output!("✅ Restored {} blocks from '{}'.\n", blocks.len(), path.display());
+        }
+        // Retrieve the peer the node replicates its blocks from, if any.
+        let peer = config.peer.as_deref().map(Peer::new);
+        // Retrieve the configuration of the automatic snapshots.
+        let auto_snapshot = config.auto_snapshot.clone();
+        // Retrieve the number of validators, and the gossip channel between them.
+        let num_validators = config.validators;
+        let gossip = (num_validators > 1).then(|| Gossip::new(config.gossip_latency, config.gossip_jitter));
+        let (clock_skew_tolerance, pool_limits) = (config.clock_skew_tolerance, config.pool_limits);
+        // Retrieve the block interval, which the REST server may update.
+        let block_interval = config.block_interval.clone();
+        // Retrieve the empty block production mode.
+        let produce_empty_blocks = config.produce_empty_blocks;
+        // Retrieve the number of faucet records to split off.
+        let genesis_records = config.genesis_records;
+        // Retrieve the share of the genesis supply kept by the faucet.
+        let faucet_share = config.faucet_share;
+        // Retrieve the address of the gRPC server.
+        #[cfg(feature = "grpc")]
+        let grpc_ip = config.grpc_ip;
+        // Initialize the REST server, which serves its routes at the given IP, if any.
+        let rest = match rest_ip {
+            Some(rest_ip) => {
+                let (account, faucet) = (Some(account.clone()), Some(faucet.clone()));
+                Rest::start(rest_ip, account, faucet, Some(consensus.clone()), ledger.clone(), config)?
+            }
+            None => {
+                let (account, faucet) = (Some(account.clone()), Some(faucet.clone()));
+                Rest::new(account, faucet, Some(consensus.clone()), ledger.clone(), config)?
+            }
+        };
+        let rest = Arc::new(rest);
+        // Retrieve the shutdown signal of the REST server, which stops the node from accepting writes.
+        let shutdown = rest.shutdown.flag();
+        // Initialize the block generation time.
+        let block_generation_time = Arc::new(AtomicU64::new(2));
+        // Retrieve the unspent records.
+        let unspent_records = ledger.find_unspent_records(account.view_key())?;
+        // Initialize the node.
+        let mut node = Self {
+            account,
+            faucet,
+            consensus,
+            ledger,
+            rest,
+            block_interval,
+            block_generation_time,
+            produce_empty_blocks,
+            unspent_records: Arc::new(RwLock::new(unspent_records)),
+            validators: Default::default(),
+            gossip,
+            handles: Default::default(),
+            standalone: rest_ip.is_some(),
+            shutdown,
+        };
+        // Initialize the gRPC server.
+        #[cfg(feature = "grpc")]
+        if let Some(grpc_ip) = grpc_ip {
+            node.handles.write().push(Grpc::start(grpc_ip, &node.rest));
+        }
+        // Transfer the rest of the genesis supply from the faucet to the node account, if requested.
+        if let Some(faucet_share) = faucet_share {
+            node.transfer_faucet_surplus(faucet_share).await?;
+        }
+        // Split the faucet records, if requested.
+        if let Some((num_records, gates)) = genesis_records {
+            node.split_faucet_records(num_records, gates).await?;
+        }
+        // Initialize the other validators, once the initial blocks are produced.
+        if num_validators > 1 {
+            node.initialize_validators(num_validators, clock_skew_tolerance, pool_limits).await?;
+        }
+        // Initialize the block production, or the replication of the blocks of the peer.
+        match peer {
+            Some(peer) => node.initialize_peer_sync(peer).await?,
+            None => node.initialize_block_production().await,
+        }
+        // Initialize the automatic snapshots, if requested.
+        if let Some(auto_snapshot) = auto_snapshot {
+            node.initialize_auto_snapshots(auto_snapshot).await;
+        }
+        // Initialize the notifications of the watched records.
+        node.initialize_watch_notifications().await;
+        // Initialize the feed of the events of accepted blocks.
+        node.initialize_event_feed().await;
+        // Initialize the signal handler.
+        node.handle_signals();
+        // Return the node.
+        Ok(node)
+    }
+
+    /// Returns the ledger.
+    pub fn ledger(&self) -> &Ledger<N, ConsensusMemory<N>> {
+        &self.ledger
+    }
+
+    /// Returns the consensus module, e.g. to set a block template hook.
+    pub fn consensus(&self) -> &SingleNodeConsensus<N, ConsensusMemory<N>> {
+        &self.consensus
+    }
+
+    /// Returns the REST server.
+    pub fn rest(&self) -> &Arc<Rest<N, ConsensusMemory<N>>> {
+        &self.rest
+    }
+}
+
+// Note: We cannot use `NodeInterface` directly, since it requires satisfying the trait bound Routing<N>.
+// TODO: Refactor.
+impl<N: Network> DevelopmentBeacon<N> {
+    /// Returns the node type.
+    fn node_type(&self) -> NodeType {
+        NodeType::Beacon
+    }
+
+    /// Returns the account private key of the node.
+    pub fn private_key(&self) -> &PrivateKey<N> {
+        self.account.private_key()
+    }
+
+    /// Returns the account of the faucet.
+    pub fn faucet(&self) -> &Account<N> {
+        &self.faucet
+    }
+
+    /// Returns the account view key of the node.
+    fn view_key(&self) -> &ViewKey<N> {
+        self.account.view_key()
+    }
+
+    /// Returns the account address of the node.
+    fn address(&self) -> Address<N> {
+        self.account.address()
+    }
+
+    /// Returns `true` if the node is in development mode.
+    /// Note that the development beacon is always in development mode.
+    fn is_dev(&self) -> bool {
+        true
+    }
+
+    /// Handles OS signals for the node to intercept and perform a clean shutdown, and the shutdown requests of
+    /// `POST /testnet3/node/shutdown`. A requested shutdown of a node whose routes are served by another server,
+    /// e.g. a tenant, shuts down the node without exiting the process. On Unix-family systems, `SIGHUP` reloads the
+    /// configuration file of the node, if any.
+    /// Note: Only Ctrl-C shuts down the node; it should work on both Unix-family systems and Windows.
+    pub fn handle_signals(&self) {
+        let node = self.clone();
+        tokio::task::spawn(async move {
+            match tokio::signal::ctrl_c().await {
+                Ok(()) => {
+                    node.shut_down().await;
+                    std::process::exit(0);
+                }
+                Err(error) => error!("tokio::signal::ctrl_c encountered an error: {}", error),
+            }
+        });
+
+        let node = self.clone();
+        tokio::task::spawn(async move {
+            node.rest.shutdown.requested().await;
+            node.shut_down().await;
+            if node.standalone {
+                std::process::exit(0);
+            }
+        });
+
+        // Reload the configuration file, if any, on `SIGHUP`.
+        ReloadableConfig::reload_on_hangup(self.rest.config.clone());
+    }
+
+    /// Shuts down the node: stops accepting writes, finishes the in-flight block, includes the transactions in the
+    /// memory pool in a final block, and writes a final snapshot if automatic snapshots are enabled.
+    pub async fn shut_down(&self) {
+        info!("Shutting down...");
+
+        // Stop accepting writes, and stop the background tasks once their current iteration is complete.
+        self.rest.shutdown.start();
+
+        // Wait for the in-flight block, if any, and stop scheduled block production.
+        trace!("Shutting down block production...");
+        let consensus = self.consensus.clone();
+        if let Err(error) = tokio::task::spawn_blocking(move || consensus.pause()).await {
+            error!("Failed to stop block production (JoinError): {error}");
+        }
+
+        // Flush the memory pool into a final block.
+        if let Err(error) = self.flush_memory_pool().await {
+            warn!("Failed to flush the memory pool: {error}");
+        }
+
+        // Persist the ledger, if automatic snapshots are enabled.
+        if let Some(config) = self.rest.config.auto_snapshot.clone() {
+            trace!("Writing a final snapshot...");
+            let ledger = self.ledger.clone();
+            match tokio::task::spawn_blocking(move || {
+                let snapshot = Snapshot::write(&ledger, &config.directory)?;
+                Snapshot::prune(&config.directory, config.retain)?;
+                Ok::<_, anyhow::Error>(snapshot)
+            })
+            .await
+            {
+                Ok(Ok(snapshot)) => {
+                    info!("Wrote the snapshot '{}' at block {}", snapshot.path.display(), snapshot.height)
+                }
+                Ok(Err(error)) => warn!("Failed to write the final snapshot: {error}"),
+                Err(error) => error!("Failed to write the final snapshot: {error}"),
+            }
+        }
+
+        // Abort the tasks.
+        trace!("Shutting down the beacon...");
+        self.handles.read().iter().for_each(|handle| handle.abort());
+
+        info!("Node has shut down.");
+    }
+
+    /// Includes the transactions in the memory pool in a final block, unless the node replicates the blocks of a peer.
+    async fn flush_memory_pool(&self) -> Result<()> {
+        let num_transactions = self.consensus.memory_pool().num_unconfirmed_transactions();
+        if num_transactions == 0 || self.rest.config.peer.is_some() {
+            return Ok(());
+        }
+        trace!("Flushing {num_transactions} transactions from the memory pool...");
+        let beacon = self.clone();
+        match tokio::task::spawn_blocking(move || {
+            beacon.consensus.produce_next_block(beacon.private_key(), &mut rand::thread_rng())
+        })
+        .await
+        {
+            Ok(Ok(block)) => {
+                info!("Included {num_transactions} transactions of the memory pool in block {}", block.height());
+                Ok(())
+            }
+            Ok(Err(error)) => Err(error),
+            Err(error) => bail!("Failed to produce the final block (JoinError): {error}"),
+        }
+    }
+}
+
+impl<N: Network> DevelopmentBeacon<N> {
+    /// Initialize a new instance of block production, which runs a production round whenever the block policy
+    /// of the consensus module is due.
+    async fn initialize_block_production(&self) {
+        let beacon = self.clone();
+        self.handles.write().push(tokio::spawn(async move {
+            // The end of the previous production round.
+            let mut last_round = std::time::Instant::now();
+            // Produce blocks.
+            loop {
+                // Check the block policy, which may change while the node is running, as may the block interval.
+                let state = ProductionState {
+                    pending_transactions: beacon.consensus.memory_pool().num_unconfirmed_transactions(),
+                    since_last_round: last_round.elapsed(),
+                    block_interval: Duration::from_secs(beacon.block_interval.load(Ordering::SeqCst)),
+                    block_generation_time: Duration::from_secs(beacon.block_generation_time.load(Ordering::SeqCst)),
+                };
+                if beacon.consensus.block_policy().is_due(&state) {
+                    // Start a timer.
+                    let timer = std::time::Instant::now();
+                    // Produce the next block and propagate it to all peers.
+                    match beacon.produce_next_block().await {
+                        // Update the block generation time.
+                        Ok(()) => beacon.block_generation_time.store(timer.elapsed().as_secs(), Ordering::SeqCst),
+                        Err(error) => error!("{error}"),
+                    }
+                    // Measure the next interval from the end of this round, which the block policy offsets by the
+                    // block generation time.
+                    last_round = std::time::Instant::now();
+                }
+                tokio::time::sleep(Duration::from_millis(BLOCK_POLICY_INTERVAL_IN_MS)).await;
+
+                // If the Ctrl-C handler registered the signal, stop the node once the current block is complete.
+                if beacon.shutdown.load(Ordering::Relaxed) {
+                    info!("Shutting down block production");
+                    break;
+                }
+            }
+        }));
+    }
+
+    /// Initialize the given number of validators, starting with the node itself. The other validators replicate
+    /// the ledger of the node, and every validator receives the gossip of the others.
+    async fn initialize_validators(
+        &mut self,
+        num_validators: usize,
+        clock_skew_tolerance: u64,
+        pool_limits: PoolLimits,
+    ) -> Result<()> {
+        output!("⏳ Initializing {num_validators} validators...");
+        let beacon = self.clone();
+        self.validators = match tokio::task::spawn_blocking(move || {
+            let mut validators =
+                vec![Validator::new(0, *beacon.private_key(), beacon.consensus.clone(), beacon.ledger.clone())];
+            for index in 1..num_validators {
+                let private_key = PrivateKey::new(&mut rand::thread_rng())?;
+                let validator =
+                    Validator::replicate(index, private_key, &beacon.ledger, clock_skew_tolerance, pool_limits)?;
+                validators.push(validator);
+            }
+            Ok::<_, anyhow::Error>(validators)
+        })
+        .await
+        {
+            Ok(Ok(validators)) => validators,
+            Ok(Err(error)) => bail!("Failed to initialize the validators: {error}"),
+            Err(error) => bail!("Failed to initialize the validators (JoinError): {error}"),
+        };
+        for validator in &self.validators {
+            output!(" • Validator {}: {}", validator.index(), validator.address()?);
+        }
+        output!();
+
+        if let Some(gossip) = &self.gossip {
+            // Deliver the gossip to every validator.
+            for validator in &self.validators {
+                self.handles.write().push(gossip.subscribe(validator.clone()));
+            }
+            // Gossip the transactions submitted to the node, so that every validator may include them.
+            let (consensus, gossip) = (self.consensus.clone(), gossip.clone());
+            self.handles.write().push(tokio::spawn(async move {
+                let mut gossiped = HashSet::new();
+                loop {
+                    tokio::time::sleep(Duration::from_millis(GOSSIP_INTERVAL_IN_MS)).await;
+                    let transactions = consensus.memory_pool().unconfirmed_transactions();
+                    let transaction_ids =
+                        transactions.iter().map(|transaction| transaction.id()).collect::<HashSet<_>>();
+                    for transaction in transactions {
+                        if gossiped.insert(transaction.id()) {
+                            gossip.publish(GossipMessage::Transaction { sender: 0, transaction });
+                        }
+                    }
+                    // Forget the transactions that left the memory pool.
+                    gossiped.retain(|transaction_id| transaction_ids.contains(transaction_id));
+                }
+            }));
+        }
+        Ok(())
+    }
+
+    /// Returns the validator that proposes the next block, if it is not the node itself.
+    fn next_proposer(&self) -> Option<&Validator<N>> {
+        match self.validators.len() {
+            0 | 1 => None,
+            num_validators => match (self.ledger.latest_height() as usize + 1) % num_validators {
+                0 => None,
+                index => Some(&self.validators[index]),
+            },
+        }
+    }
+
+    /// Proposes the next block with the given validator, and waits until the node accepts it.
+    async fn propose_with(&self, validator: &Validator<N>) -> Result<()> {
+        // Wait for the validator to receive the latest block of the node, so that it does not propose a fork.
+        let height = self.ledger.latest_height();
+        if !Self::wait_for_height(validator.ledger(), height).await {
+            bail!("Validator {} did not receive block {height} from the node", validator.index());
+        }
+
+        // Unless empty blocks are enabled, a block is only produced if the validator has pending transactions.
+        if validator.consensus().memory_pool().num_unconfirmed_transactions() == 0 {
+            if !self.produce_empty_blocks {
+                return Ok(());
+            }
+            let (validator, faucet) = (validator.clone(), self.faucet.clone());
+            match tokio::task::spawn_blocking(move || {
+                validator.consensus().add_empty_block_transaction(faucet.private_key())
+            })
+            .await
+            {
+                Ok(result) => result?,
+                Err(error) => bail!("Failed to create a transaction for an empty block (JoinError): {error}"),
+            }
+        }
+
+        // Propose the next block, and gossip it to the other validators.
+        let proposer = validator.clone();
+        let block = match tokio::task::spawn_blocking(move || proposer.propose()).await {
+            Ok(Ok(block)) => block,
+            Ok(Err(error)) => bail!("Validator {} failed to propose the next block: {error}", validator.index()),
+            Err(error) => {
+                bail!("Validator {} failed to propose the next block (JoinError): {error}", validator.index())
+            }
+        };
+        let height = block.height();
+        info!("Validator {} proposed block {height}", validator.index());
+        if let Some(gossip) = &self.gossip {
+            gossip.publish(GossipMessage::Block { sender: validator.index(), block });
+        }
+
+        // Wait for the block to reach the node, so that the next proposer is chosen from the same height.
+        if !Self::wait_for_height(&self.ledger, height).await {
+            bail!("The node did not receive block {height} from validator {}", validator.index());
+        }
+        Ok(())
+    }
+
+    /// Waits until the given ledger reaches the given height, and returns `false` if it does not in time.
+    async fn wait_for_height(ledger: &Ledger<N, ConsensusMemory<N>>, height: u32) -> bool {
+        let wait = async {
+            while ledger.latest_height() < height {
+                tokio::time::sleep(Duration::from_millis(GOSSIP_INTERVAL_IN_MS)).await;
+            }
+        };
+        timeout(Duration::from_secs(MAX_GOSSIP_WAIT_IN_SECS), wait).await.is_ok()
+    }
+
+    /// Fetches the blocks of the given peer, and then polls it for new blocks, so that the node is a read replica.
+    async fn initialize_peer_sync(&self, peer: Peer) -> Result<()> {
+        let handle = peer.follow(self.consensus.clone(), self.ledger.clone(), self.shutdown.clone()).await?;
+        self.handles.write().push(handle);
+        Ok(())
+    }
+
+    /// Initialize the automatic snapshots of the ledger, retaining the most recent snapshots.
+    /// A snapshot is only taken if a block was produced since the previous snapshot.
+    async fn initialize_auto_snapshots(&self, config: SnapshotConfig) {
+        let beacon = self.clone();
+        self.handles.write().push(tokio::spawn(async move {
+            let mut last_height = None;
+            loop {
+                tokio::time::sleep(Duration::from_secs(config.interval)).await;
+
+                // If the Ctrl-C handler registered the signal, stop taking snapshots.
+                if beacon.shutdown.load(Ordering::Relaxed) {
+                    break;
+                }
+                if last_height == Some(beacon.ledger.latest_height()) {
+                    continue;
+                }
+
+                // Write the snapshot, and remove the oldest snapshots.
+                let (ledger, config) = (beacon.ledger.clone(), config.clone());
+                let snapshot = tokio::task::spawn_blocking(move || {
+                    let snapshot = Snapshot::write(&ledger, &config.directory)?;
+                    Snapshot::prune(&config.directory, config.retain)?;
+                    Ok::<_, anyhow::Error>(snapshot)
+                })
+                .await;
+                match snapshot {
+                    Ok(Ok(snapshot)) => {
+                        debug!("Wrote the snapshot '{}' at block {}", snapshot.path.display(), snapshot.height);
+                        last_height = Some(snapshot.height);
+                    }
+                    Ok(Err(error)) => warn!("Failed to write a snapshot: {error}"),
+                    Err(error) => error!("Failed to write a snapshot: {error}"),
+                }
+            }
+        }));
+    }
+
+    /// Initialize the notifications of the watched records that are spent in the blocks added to the ledger.
+    async fn initialize_watch_notifications(&self) {
+        let beacon = self.clone();
+        self.handles.write().push(tokio::spawn(async move {
+            let mut last_height = beacon.ledger.latest_height();
+            let client = reqwest::Client::new();
+            loop {
+                tokio::time::sleep(Duration::from_millis(WATCH_INTERVAL_IN_MS)).await;
+
+                // If the Ctrl-C handler registered the signal, stop the notifications.
+                if beacon.shutdown.load(Ordering::Relaxed) {
+                    break;
+                }
+                // If there are no watches, skip the new blocks.
+                let latest_height = beacon.ledger.latest_height();
+                if beacon.rest.watches.is_empty() {
+                    last_height = latest_height;
+                    continue;
+                }
+
+                // Notify the watches of the records spent in each new block.
+                for height in last_height.min(latest_height) + 1..=latest_height {
+                    let block = match beacon.ledger.get_block(height) {
+                        Ok(block) => block,
+                        Err(error) => {
+                            warn!("Failed to check block {height} for watched records: {error}");
+                            break;
+                        }
+                    };
+                    for (webhook, notification) in beacon.rest.watches.notify(&block) {
+                        // Post each notification separately, so that a slow webhook does not delay the others.
+                        let request = client.post(&webhook).json(&notification);
+                        tokio::spawn(async move {
+                            if let Err(error) = request.send().await.and_then(|response| response.error_for_status()) {
+                                warn!("Failed to post a notification of watch '{}': {error}", notification.watch_id);
+                            }
+                        });
+                    }
+                    last_height = height;
+                }
+            }
+        }));
+    }
+
+    /// Initializes the publication of the events of each new block to the subscribers of the event feed.
+    async fn initialize_event_feed(&self) {
+        let beacon = self.clone();
+        self.handles.write().push(tokio::spawn(async move {
+            let mut last_height = beacon.ledger.latest_height();
+            loop {
+                tokio::time::sleep(Duration::from_millis(EVENT_FEED_INTERVAL_IN_MS)).await;
+
+                // If the Ctrl-C handler registered the signal, stop the feed.
+                if beacon.shutdown.load(Ordering::Relaxed) {
+                    break;
+                }
+                // If there are no subscribers, skip the new blocks.
+                let latest_height = beacon.ledger.latest_height();
+                if beacon.rest.events.is_empty() {
+                    beacon.consensus.release_deployers(latest_height);
+                    last_height = latest_height;
+                    continue;
+                }
+
+                // Publish the events of each new block.
+                for height in last_height.min(latest_height) + 1..=latest_height {
+                    let block = match beacon.ledger.get_block(height) {
+                        Ok(block) => block,
+                        Err(error) => {
+                            warn!("Failed to publish the events of block {height}: {error}");
+                            break;
+                        }
+                    };
+                    let mut events = Event::from_block(&block);
+                    events.iter_mut().for_each(|event| event.take_deployer(&beacon.consensus));
+                    beacon.rest.events.publish(events);
+                    last_height = height;
+                }
+            }
+        }));
+    }
+
+    /// Produces the next block and propagates it to all peers.
+    /// Unless empty blocks are enabled, a block is only produced if there are pending transactions.
+    /// No block is produced while block production is paused.
+    async fn produce_next_block(&self) -> Result<()> {
+        // If block production is paused, let the transactions accumulate in the memory pool.
+        if self.consensus.is_paused() {
+            return Ok(());
+        }
+        // If another validator proposes the next block, wait for it instead.
+        if let Some(validator) = self.next_proposer() {
+            return self.propose_with(validator).await;
+        }
+
+        // Produce a transaction if the mempool is empty.
+        if self.consensus.memory_pool().num_unconfirmed_transactions() == 0 {
+            // If there are no unconfirmed transactions, then there is no need to do anything.
+            if !self.produce_empty_blocks {
+                return Ok(());
+            }
+            // Otherwise, advance the chain with a transaction that changes no balances,
+            // as a block must contain at least one transaction.
+            self.add_empty_block_transaction().await?;
+        }
+
+        // Propose the next block, and advance to it.
+        let beacon = self.clone();
+        match tokio::task::spawn_blocking(move || {
+            beacon.consensus.produce_scheduled_block(beacon.private_key(), &mut rand::thread_rng())
+        })
+        .await
+        {
+            Ok(Ok(next_block)) => {
+                // Gossip the block to the other validators.
+                if let (Some(gossip), Some(block)) = (&self.gossip, next_block) {
+                    gossip.publish(GossipMessage::Block { sender: 0, block });
+                }
+            }
+            Ok(Err(error)) => {
+                // Sleep for one second.
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                bail!("Failed to propose the next block: {error}")
+            }
+            Err(error) => {
+                // Sleep for one second.
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                bail!("Failed to propose the next block (JoinError): {error}")
+            }
+        };
+
+        Ok(())
+    }
+
+    /// Transfers the gates of the faucet in excess of the given share to the node account, in a new block.
+    async fn transfer_faucet_surplus(&self, faucet_share: u64) -> Result<()> {
+        ensure!(self.faucet.address() != self.address(), "The faucet share requires a separate faucet account");
+        let beacon = self.clone();
+        match tokio::task::spawn_blocking(move || {
+            // Compute the surplus of the faucet.
+            let records = beacon.ledger.find_unspent_records(beacon.faucet.view_key())?;
+            let balance = records.values().map(|record| ***record.gates()).sum::<u64>();
+            let surplus = balance.saturating_sub(faucet_share);
+            if surplus == 0 {
+                return Ok(0);
+            }
+
+            // Transfer the surplus to the node account.
+            let transaction =
+                beacon.ledger.create_transfer(beacon.faucet.private_key(), &NodeScan, beacon.address(), surplus)?;
+            beacon.consensus.add_unconfirmed_transaction(transaction)?;
+            beacon.consensus.produce_next_block(beacon.private_key(), &mut rand::thread_rng())?;
+            Ok::<_, anyhow::Error>(surplus)
+        })
+        .await
+        {
+            Ok(Ok(0)) => Ok(()),
+            Ok(Ok(surplus)) => {
+                output!(
+                    "✅ Transferred {surplus} gates from the faucet to the node account, keeping {faucet_share}.\n"
+                );
+                Ok(())
+            }
+            Ok(Err(error)) => bail!("Failed to transfer the faucet surplus: {error}"),
+            Err(error) => bail!("Failed to transfer the faucet surplus (JoinError): {error}"),
+        }
+    }
+
+    /// Splits the faucet records, until the faucet has the given number of records with the given number of gates.
+    /// Each round splits the faucet records in parallel, and includes the splits in a block.
+    async fn split_faucet_records(&self, num_records: usize, gates: u64) -> Result<()> {
+        output!("⏳ Splitting the faucet records into {num_records} records of {gates} gates...");
+        let beacon = self.clone();
+        match tokio::task::spawn_blocking(move || {
+            let private_key = beacon.faucet.private_key();
+            let mut round = 0;
+            loop {
+                // Determine the splits of the next round.
+                let splits = beacon.ledger.plan_splits(beacon.faucet.view_key(), num_records, gates)?;
+                if splits.is_empty() {
+                    break;
+                }
+
+                // Create the splits, and include them in the next block.
+                round += 1;
+                output!("⏳ Round {round}: splitting {} records...", splits.len());
+                let transactions = cfg_into_iter!(splits)
+                    .map(|(record, amount)| beacon.ledger.create_split(private_key, record, amount))
+                    .collect::<Result<Vec<_>>>()?;
+                for transaction in transactions {
+                    beacon.consensus.add_unconfirmed_transaction(transaction)?;
+                }
+                beacon.consensus.produce_next_block(beacon.private_key(), &mut rand::thread_rng())?;
+            }
+            Ok(())
+        })
+        .await
+        {
+            Ok(Ok(())) => {
+                output!("✅ Split the faucet records into {num_records} records of {gates} gates.\n");
+                Ok(())
+            }
+            Ok(Err(error)) => bail!("Failed to split the faucet records: {error}"),
+            Err(error) => bail!("Failed to split the faucet records (JoinError): {error}"),
+        }
+    }
+
+    /// Adds a transfer of zero gates from the faucet to itself to the memory pool, so that the next block is not empty.
+    async fn add_empty_block_transaction(&self) -> Result<()> {
+        let beacon = self.clone();
+        match tokio::task::spawn_blocking(move || {
+            beacon.consensus.add_empty_block_transaction(beacon.faucet.private_key())
+        })
+        .await
+        {
+            Ok(result) => result,
+            Err(error) => bail!("Failed to create a transaction for an empty block (JoinError): {error}"),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    helpers::{authorized, base_endpoint},
+    node::{Ledger, SingleNodeConsensus},
+    output,
+};
+
+use snarkvm::prelude::{Block, ConsensusStorage, Network, Transaction};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use serde::de::DeserializeOwned;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::task::JoinHandle;
+
+/// The number of milliseconds between polls of the peer for new blocks.
+pub const PEER_SYNC_INTERVAL_IN_MS: u64 = 1_000;
+
+/// The maximum number of blocks requested from the peer at once, as limited by its `/testnet3/blocks` route.
+const MAX_BLOCKS_PER_REQUEST: u32 = 50;
+
+/// A remote slingshot node, whose blocks are fetched over its REST API, e.g. to replicate or export its chain.
+#[derive(Clone, Debug)]
+pub struct Peer {
+    /// The base endpoint of the peer.
+    endpoint: String,
+    /// The HTTP client of the peer.
+    client: reqwest::Client,
+}
+
+impl Peer {
+    /// Initializes a peer at the given endpoint.
+    pub fn new(endpoint: &str) -> Self {
+        Self { endpoint: base_endpoint(endpoint), client: reqwest::Client::new() }
+    }
+
+    /// Returns the base endpoint of the peer.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Fetches the genesis block of the peer.
+    pub async fn genesis<N: Network>(&self) -> Result<Block<N>> {
+        self.get("/testnet3/block/0")
+            .await
+            .map_err(|error| anyhow!("Failed to fetch the genesis block from the peer at {}: {error}", self.endpoint))
+    }
+
+    /// Fetches the latest block height of the peer.
+    pub async fn latest_height(&self) -> Result<u32> {
+        self.get("/testnet3/latest/height").await
+    }
+
+    /// Fetches the blocks of the peer in the given range of heights (the end is exclusive).
+    pub async fn blocks<N: Network>(&self, start: u32, end: u32) -> Result<Vec<Block<N>>> {
+        self.get(&format!("/testnet3/blocks?start={start}&end={end}")).await
+    }
+
+    /// Fetches every block of the peer, starting with the genesis block.
+    pub async fn chain<N: Network>(&self) -> Result<Vec<Block<N>>> {
+        let latest_height = self.latest_height().await?;
+        let mut blocks = Vec::with_capacity(latest_height as usize + 1);
+        while blocks.len() as u32 <= latest_height {
+            let start = blocks.len() as u32;
+            let end = latest_height.min(start + MAX_BLOCKS_PER_REQUEST - 1) + 1;
+            let chunk = self.blocks::<N>(start, end).await?;
+            ensure!(!chunk.is_empty(), "The node at {} returned no blocks from height {start}", self.endpoint);
+            blocks.extend(chunk);
+        }
+        Ok(blocks)
+    }
+
+    /// Broadcasts the given transaction to the peer, with the API token of `SLINGSHOT_API_TOKEN`, if it is set,
+    /// and returns its transaction ID.
+    pub async fn broadcast<N: Network>(&self, transaction: &Transaction<N>) -> Result<N::TransactionID> {
+        let request = self.client.post(format!("{}/testnet3/transaction/broadcast", self.endpoint));
+        Ok(authorized(request).json(transaction).send().await?.error_for_status()?.json().await?)
+    }
+
+    /// Fetches the blocks of the peer after the latest block of the ledger, and advances the ledger to them.
+    /// Returns the number of blocks added.
+    pub async fn sync<N: Network, C: 'static + ConsensusStorage<N>>(
+        &self,
+        consensus: &SingleNodeConsensus<N, C>,
+        ledger: &Ledger<N, C>,
+    ) -> Result<u32> {
+        let latest_height = self.latest_height().await?;
+        let mut num_blocks = 0;
+        while ledger.latest_height() < latest_height {
+            let start = ledger.latest_height() + 1;
+            let end = latest_height.min(start + MAX_BLOCKS_PER_REQUEST - 1) + 1;
+            let blocks = self.blocks::<N>(start, end).await?;
+            if blocks.is_empty() {
+                break;
+            }
+            // Advance the ledger off the async runtime, as each block is verified.
+            let consensus = consensus.clone();
+            num_blocks += tokio::task::spawn_blocking(move || {
+                for block in &blocks {
+                    consensus.add_next_block(block).map_err(|error| {
+                        anyhow!("Block {} of the peer does not extend the local chain: {error}", block.height())
+                    })?;
+                }
+                Ok::<_, anyhow::Error>(blocks.len() as u32)
+            })
+            .await
+            .map_err(|error| anyhow!("Failed to add the blocks of the peer (JoinError): {error}"))??;
+        }
+        Ok(num_blocks)
+    }
+
+    /// Fetches the given route of the peer, e.g. `/testnet3/latest/height`.
+    async fn get<T: DeserializeOwned>(&self, route: &str) -> Result<T> {
+        Ok(self.client.get(format!("{}{route}", self.endpoint)).send().await?.error_for_status()?.json().await?)
+    }
+
+    /// Syncs the ledger with the peer, and then spawns a task that polls the peer for new blocks,
+    /// until the given shutdown signal is set.
+    pub async fn follow<N: Network, C: 'static + ConsensusStorage<N>>(
+        self,
+        consensus: SingleNodeConsensus<N, C>,
+        ledger: Ledger<N, C>,
+        shutdown: Arc<AtomicBool>,
+    ) -> Result<JoinHandle<()>> {
+        output!("⏳ Syncing with the peer at {}...", self.endpoint);
+        match self.sync(&consensus, &ledger).await {
+            Ok(num_blocks) => output!("✅ Synced {num_blocks} blocks from the peer at {}.\n", self.endpoint),
+            Err(error) => bail!("Failed to sync with the peer at {}: {error}", self.endpoint),
+        }
+
+        Ok(tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(Duration::from_millis(PEER_SYNC_INTERVAL_IN_MS)).await;
+
+                // If the Ctrl-C handler registered the signal, stop syncing.
+                if shutdown.load(Ordering::Relaxed) {
+                    break;
+                }
+
+                match self.sync(&consensus, &ledger).await {
+                    Ok(0) => (),
+                    Ok(num_blocks) => debug!("Synced {num_blocks} blocks from the peer"),
+                    Err(error) => warn!("Failed to sync with the peer: {error}"),
+                }
+            }
+        }))
+    }
+}
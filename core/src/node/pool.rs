@@ -0,0 +1,301 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::SlingshotError, node::SingleNodeConsensus};
+
+use snarkvm::prelude::{Address, ConsensusStorage, Network, Transaction};
+
+use anyhow::{bail, Result};
+use core::str::FromStr;
+use parking_lot::RwLock;
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+/// The maximum number of rejected transactions kept by the memory pool.
+const MAX_REJECTED_TRANSACTIONS: usize = 1000;
+
+/// The default maximum number of unconfirmed transactions in the memory pool.
+pub const DEFAULT_POOL_CAPACITY: usize = 10_000;
+
+/// The transaction that is evicted when a transaction is added to a full memory pool.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Evicts the transaction with the lowest fee, if the new transaction pays a higher fee.
+    LowestFee,
+    /// Evicts the oldest transaction.
+    Oldest,
+    /// Evicts no transaction, and rejects the new transaction.
+    None,
+}
+
+impl FromStr for EvictionPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(policy: &str) -> Result<Self> {
+        match policy.to_lowercase().as_str() {
+            "lowest-fee" => Ok(Self::LowestFee),
+            "oldest" => Ok(Self::Oldest),
+            "none" => Ok(Self::None),
+            _ => bail!("Unsupported eviction policy '{policy}' (expected 'lowest-fee', 'oldest', or 'none')"),
+        }
+    }
+}
+
+impl core::fmt::Display for EvictionPolicy {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::LowestFee => write!(f, "lowest-fee"),
+            Self::Oldest => write!(f, "oldest"),
+            Self::None => write!(f, "none"),
+        }
+    }
+}
+
+/// The limits of the memory pool.
+#[derive(Copy, Clone, Debug)]
+pub struct PoolLimits {
+    /// The maximum number of unconfirmed transactions.
+    pub capacity: usize,
+    /// The maximum number of unconfirmed transactions submitted by a single account, if any.
+    pub max_per_sender: Option<usize>,
+    /// The transaction that is evicted when the memory pool is full.
+    pub eviction: EvictionPolicy,
+}
+
+impl Default for PoolLimits {
+    fn default() -> Self {
+        Self { capacity: DEFAULT_POOL_CAPACITY, max_per_sender: None, eviction: EvictionPolicy::LowestFee }
+    }
+}
+
+/// An unconfirmed transaction in the memory pool.
+#[derive(Clone, Debug)]
+struct PoolEntry<N: Network> {
+    /// The transaction.
+    transaction: Transaction<N>,
+    /// The fee of the transaction, in gates.
+    fee: i64,
+    /// The account that submitted the transaction, if it is known.
+    sender: Option<Address<N>>,
+    /// The order in which the transaction was added to the memory pool.
+    arrival: u64,
+}
+
+#[derive(Clone, Debug)]
+#[allow(clippy::type_complexity)]
+pub struct TransactionPool<N: Network> {
+    /// The pool of unconfirmed transactions.
+    unconfirmed_transactions: Arc<RwLock<HashMap<N::TransactionID, PoolEntry<N>>>>,
+    /// The most recently rejected transactions, with the reason they were rejected.
+    rejected_transactions: Arc<RwLock<VecDeque<(N::TransactionID, String)>>>,
+    /// The limits of the memory pool.
+    limits: PoolLimits,
+    /// The arrival number of the next transaction.
+    next_arrival: Arc<AtomicU64>,
+}
+
+impl<N: Network> Default for TransactionPool<N> {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<N: Network> TransactionPool<N> {
+    /// Initializes a new instance of a memory pool with the given limits.
+    pub fn new(limits: PoolLimits) -> Self {
+        Self {
+            unconfirmed_transactions: Default::default(),
+            rejected_transactions: Default::default(),
+            limits,
+            next_arrival: Default::default(),
+        }
+    }
+
+    /// Returns the limits of the memory pool.
+    pub const fn limits(&self) -> &PoolLimits {
+        &self.limits
+    }
+
+    /// Returns `true` if the given unconfirmed transaction exists in the memory pool.
+    pub fn contains_unconfirmed_transaction(&self, transaction_id: N::TransactionID) -> bool {
+        self.unconfirmed_transactions.read().contains_key(&transaction_id)
+    }
+
+    /// Returns the number of unconfirmed transactions in the memory pool.
+    pub fn num_unconfirmed_transactions(&self) -> usize {
+        self.unconfirmed_transactions.read().len()
+    }
+
+    /// Returns the unconfirmed transactions in the memory pool, in order of priority.
+    pub fn unconfirmed_transactions(&self) -> Vec<Transaction<N>> {
+        let mut entries = self.unconfirmed_transactions.read().values().cloned().collect::<Vec<_>>();
+        // Order the transactions by the highest fee, and then by the earliest arrival.
+        entries.sort_by_key(|entry| (core::cmp::Reverse(entry.fee), entry.arrival));
+        entries.into_iter().map(|entry| entry.transaction).collect()
+    }
+
+    /// Returns the most recently rejected transactions, with the reason they were rejected.
+    pub fn rejected_transactions(&self) -> Vec<(N::TransactionID, String)> {
+        self.rejected_transactions.read().iter().cloned().collect()
+    }
+
+    /// Returns a candidate set of unconfirmed transactions for inclusion in a block.
+    /// Transactions with higher fees take priority over the transactions they collide with.
+    pub fn candidate_transactions<C: ConsensusStorage<N>>(
+        &self,
+        consensus: &SingleNodeConsensus<N, C>,
+    ) -> Vec<Transaction<N>> {
+        // Add the transactions from the memory pool that do not have input collisions.
+        let mut transactions = Vec::new();
+        let mut input_ids = Vec::new();
+        let mut output_ids = Vec::new();
+
+        'outer: for transaction in self.unconfirmed_transactions() {
+            // Ensure the transaction is well-formed.
+            if consensus.check_transaction_basic(&transaction).is_err() {
+                continue;
+            }
+
+            // Ensure the input IDs are unique.
+            for input_id in transaction.input_ids() {
+                if input_ids.contains(&input_id) {
+                    continue 'outer;
+                }
+            }
+            // Ensure the output IDs are unique.
+            for output_id in transaction.output_ids() {
+                if output_ids.contains(&output_id) {
+                    continue 'outer;
+                }
+            }
+
+            input_ids.extend(transaction.input_ids());
+            output_ids.extend(transaction.output_ids());
+            transactions.push(transaction);
+        }
+
+        transactions
+    }
+
+    /// Adds the given unconfirmed transaction, submitted by the given account if it is known, to the memory pool.
+    /// If the memory pool is full, a transaction is evicted according to the eviction policy.
+    pub fn add_unconfirmed_transaction(&self, transaction: &Transaction<N>, sender: Option<Address<N>>) -> Result<()> {
+        let transaction_id = transaction.id();
+        let fee = transaction.fee()?;
+
+        // Acquire the write lock on the unconfirmed transactions.
+        let mut unconfirmed_transactions = self.unconfirmed_transactions.write();
+
+        // Ensure the transaction does not already exist in the memory pool.
+        if unconfirmed_transactions.contains_key(&transaction_id) {
+            trace!("Transaction '{transaction_id}' already exists in memory pool");
+            bail!("Transaction is already in the memory pool.");
+        }
+        // Ensure the sender does not exceed its limit of unconfirmed transactions.
+        if let (Some(sender), Some(max_per_sender)) = (sender, self.limits.max_per_sender) {
+            let num_transactions =
+                unconfirmed_transactions.values().filter(|entry| entry.sender == Some(sender)).count();
+            if num_transactions >= max_per_sender {
+                bail!("Account '{sender}' already has {num_transactions} transactions in the memory pool");
+            }
+        }
+        // If the memory pool is full, select the transaction to evict.
+        let mut evicted = None;
+        if unconfirmed_transactions.len() >= self.limits.capacity {
+            let candidate = match self.limits.eviction {
+                EvictionPolicy::LowestFee => unconfirmed_transactions
+                    .values()
+                    .filter(|entry| entry.fee < fee)
+                    .min_by_key(|entry| (entry.fee, core::cmp::Reverse(entry.arrival))),
+                EvictionPolicy::Oldest => unconfirmed_transactions.values().min_by_key(|entry| entry.arrival),
+                EvictionPolicy::None => None,
+            };
+            match candidate.map(|entry| entry.transaction.id()) {
+                Some(evicted_id) => {
+                    unconfirmed_transactions.remove(&evicted_id);
+                    evicted = Some(evicted_id);
+                }
+                // A transaction is admitted to a memory pool that evicts by fee if it pays more than the lowest fee.
+                None if self.limits.eviction == EvictionPolicy::LowestFee => {
+                    let lowest_fee = unconfirmed_transactions.values().map(|entry| entry.fee).min().unwrap_or_default();
+                    let required = u64::try_from(lowest_fee.saturating_add(1)).unwrap_or_default();
+                    return Err(SlingshotError::FeeTooLow { required }.into());
+                }
+                None => return Err(SlingshotError::MempoolFull { capacity: self.limits.capacity }.into()),
+            }
+        }
+
+        // Add the transaction to the memory pool.
+        let arrival = self.next_arrival.fetch_add(1, Ordering::SeqCst);
+        let entry = PoolEntry { transaction: transaction.clone(), fee, sender, arrival };
+        unconfirmed_transactions.insert(transaction_id, entry);
+        drop(unconfirmed_transactions);
+        debug!("✉️  Added transaction '{transaction_id}' to the memory pool");
+
+        // Record the eviction, so that its submitter can find out why the transaction was dropped.
+        if let Some(evicted_id) = evicted {
+            debug!("Evicted transaction '{evicted_id}' from the full memory pool");
+            self.record_rejection(evicted_id, format!("evicted from the full memory pool by '{transaction_id}'"));
+        }
+        Ok(())
+    }
+
+    /// Removes the given unconfirmed transaction from the memory pool, and returns it, if it exists.
+    pub fn remove_unconfirmed_transaction(&self, transaction_id: N::TransactionID) -> Option<Transaction<N>> {
+        let entry = self.unconfirmed_transactions.write().remove(&transaction_id)?;
+        self.record_rejection(transaction_id, String::from("removed from the memory pool by request"));
+        Some(entry.transaction)
+    }
+
+    /// Clears the memory pool of unconfirmed transactions that are now invalid.
+    pub fn clear_invalid_transactions<C: ConsensusStorage<N>>(&self, consensus: &SingleNodeConsensus<N, C>) {
+        self.unconfirmed_transactions.write().retain(|transaction_id, entry| {
+            // Ensure the transaction is valid.
+            match consensus.check_transaction_basic(&entry.transaction) {
+                Ok(_) => true,
+                Err(_) => {
+                    trace!("Removed transaction '{transaction_id}' from the memory pool");
+                    false
+                }
+            }
+        });
+    }
+
+    /// Removes the given transaction from the memory pool, and records the reason it was rejected.
+    pub fn reject_transaction(&self, transaction_id: N::TransactionID, reason: String) {
+        self.unconfirmed_transactions.write().remove(&transaction_id);
+        self.record_rejection(transaction_id, reason);
+    }
+
+    /// Records the reason the given transaction was rejected.
+    fn record_rejection(&self, transaction_id: N::TransactionID, reason: String) {
+        let mut rejected_transactions = self.rejected_transactions.write();
+        if rejected_transactions.len() >= MAX_REJECTED_TRANSACTIONS {
+            rejected_transactions.pop_front();
+        }
+        rejected_transactions.push_back((transaction_id, reason));
+    }
+
+    /// Clears the memory pool of all unconfirmed transactions.
+    pub fn clear_unconfirmed_transactions(&self) {
+        self.unconfirmed_transactions.write().clear();
+    }
+}
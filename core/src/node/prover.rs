@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::SlingshotError;
+
+use anyhow::{anyhow, Result};
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use tokio::sync::Semaphore;
+
+/// Constructs transactions on a dedicated thread pool, off the async runtime,
+/// so that the REST server stays responsive while proofs are synthesized.
+#[derive(Clone)]
+pub struct Prover {
+    /// The thread pool that synthesizes the proofs.
+    pool: Arc<ThreadPool>,
+    /// The permits bounding the number of transactions constructed at once.
+    permits: Arc<Semaphore>,
+    /// The maximum number of transactions constructed at once.
+    max_concurrent_proofs: usize,
+    /// The number of transactions waiting for a permit.
+    queued: Arc<AtomicUsize>,
+    /// The maximum number of transactions waiting for a permit, if any.
+    max_queued_proofs: Option<usize>,
+}
+
+impl Prover {
+    /// Initializes a new prover with the given number of threads, constructing at most
+    /// the given number of transactions at once, with at most the given number waiting for their turn.
+    pub fn new(num_threads: usize, max_concurrent_proofs: usize, max_queued_proofs: Option<usize>) -> Result<Self> {
+        let pool = ThreadPoolBuilder::new()
+            .stack_size(8 * 1024 * 1024)
+            .num_threads(num_threads)
+            .thread_name(|index| format!("prover-{index}"))
+            .build()?;
+        let max_concurrent_proofs = max_concurrent_proofs.max(1);
+        Ok(Self {
+            pool: Arc::new(pool),
+            permits: Arc::new(Semaphore::new(max_concurrent_proofs)),
+            max_concurrent_proofs,
+            queued: Default::default(),
+            max_queued_proofs,
+        })
+    }
+
+    /// Runs the given function on the thread pool, once a permit is available, and returns its result.
+    /// Requests beyond the concurrency limit wait for a permit, without blocking the async runtime,
+    /// unless the queue is full, in which case they are rejected with `ProverBusy`.
+    pub async fn run<T: Send + 'static>(&self, function: impl FnOnce() -> Result<T> + Send + 'static) -> Result<T> {
+        let _permit = match self.permits.try_acquire() {
+            Ok(permit) => permit,
+            Err(_) => {
+                let position = self.queued.fetch_add(1, Ordering::SeqCst);
+                let _slot = QueueSlot(self.queued.clone());
+                if let Some(max_queued_proofs) = self.max_queued_proofs {
+                    if position >= max_queued_proofs {
+                        let max_concurrent_proofs = self.max_concurrent_proofs;
+                        return Err(SlingshotError::ProverBusy { max_concurrent_proofs, max_queued_proofs }.into());
+                    }
+                }
+                self.permits.acquire().await?
+            }
+        };
+        let pool = self.pool.clone();
+        tokio::task::spawn_blocking(move || pool.install(function))
+            .await
+            .map_err(|error| anyhow!("The prover failed (JoinError): {error}"))?
+    }
+
+    /// Returns the number of transactions that are being constructed.
+    pub fn num_active(&self) -> usize {
+        self.max_concurrent_proofs.saturating_sub(self.permits.available_permits())
+    }
+
+    /// Returns the number of transactions waiting for their turn.
+    pub fn num_queued(&self) -> usize {
+        self.queued.load(Ordering::SeqCst)
+    }
+
+    /// Returns the maximum number of transactions constructed at once.
+    pub fn max_concurrent_proofs(&self) -> usize {
+        self.max_concurrent_proofs
+    }
+
+    /// Returns the maximum number of transactions waiting for their turn, if any.
+    pub fn max_queued_proofs(&self) -> Option<usize> {
+        self.max_queued_proofs
+    }
+}
+
+/// A place in the queue of the prover, which is released once its request holds a permit, or is dropped.
+struct QueueSlot(Arc<AtomicUsize>);
+
+impl Drop for QueueSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::node::NodeConfig;
+
+use anyhow::{anyhow, ensure, Result};
+use serde::{Deserialize, Serialize};
+use std::{path::Path, str::FromStr, sync::atomic::Ordering};
+use tracing::Level;
+
+/// The policy of the faucet, which limits the pours of `POST /testnet3/faucet/pour`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FaucetPolicy {
+    /// If `false`, the faucet rejects every pour.
+    #[serde(default = "FaucetPolicy::default_enabled")]
+    pub enabled: bool,
+    /// The maximum number of gates poured by a single request, if any.
+    #[serde(default)]
+    pub max_pour: Option<u64>,
+}
+
+impl Default for FaucetPolicy {
+    fn default() -> Self {
+        Self { enabled: true, max_pour: None }
+    }
+}
+
+impl FaucetPolicy {
+    /// Returns `true`, as the faucet is enabled unless the policy disables it.
+    fn default_enabled() -> bool {
+        true
+    }
+
+    /// Ensures the faucet may pour the given number of gates.
+    pub fn check(&self, amount: u64) -> Result<()> {
+        ensure!(self.enabled, "the faucet is disabled");
+        if let Some(max_pour) = self.max_pour {
+            ensure!(amount <= max_pour, "the faucet pours at most {max_pour} gates per request");
+        }
+        Ok(())
+    }
+}
+
+/// The CORS policy of the REST server.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CorsPolicy {
+    /// The origins that may send cross-origin requests (e.g. `http://localhost:3000`), or every origin if `None`.
+    #[serde(default)]
+    pub allowed_origins: Option<Vec<String>>,
+}
+
+impl CorsPolicy {
+    /// Returns `true` if the given origin may send cross-origin requests.
+    pub fn allows(&self, origin: &str) -> bool {
+        match &self.allowed_origins {
+            Some(allowed_origins) => allowed_origins.iter().any(|allowed_origin| allowed_origin == origin),
+            None => true,
+        }
+    }
+}
+
+/// The settings of a node that are reloaded from its configuration file (`--config`) while it is running,
+/// on `SIGHUP` or `POST /testnet3/node/config/reload`. A setting that is omitted keeps its current value.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ReloadableConfig {
+    /// The number of seconds between blocks.
+    pub block_interval: Option<u64>,
+    /// The most verbose level of the log entries retained by the node (e.g. `debug`).
+    pub log_level: Option<String>,
+    /// The policy of the faucet.
+    pub faucet: Option<FaucetPolicy>,
+    /// The CORS policy of the REST server.
+    pub cors: Option<CorsPolicy>,
+}
+
+impl ReloadableConfig {
+    /// Reads the settings from the given JSON file.
+    pub fn open(path: &Path) -> Result<Self> {
+        let file =
+            std::fs::read_to_string(path).map_err(|error| anyhow!("failed to read '{}': {error}", path.display()))?;
+        serde_json::from_str(&file).map_err(|error| anyhow!("failed to parse '{}': {error}", path.display()))
+    }
+
+    /// Returns the current settings of the given node configuration.
+    pub fn current(config: &NodeConfig) -> Self {
+        Self {
+            block_interval: Some(config.block_interval.load(Ordering::SeqCst)),
+            log_level: Some(config.logs.level().to_string()),
+            faucet: Some(config.faucet_policy.read().clone()),
+            cors: Some(config.cors_policy.read().clone()),
+        }
+    }
+
+    /// Applies the settings to the given node configuration.
+    /// Every setting is validated first, so that an invalid file changes nothing.
+    pub fn apply(self, config: &NodeConfig) -> Result<()> {
+        ensure!(self.block_interval != Some(0), "the block interval must be at least 1 second");
+        let log_level = self
+            .log_level
+            .map(|level| Level::from_str(&level).map_err(|_| anyhow!("'{level}' is not a log level")))
+            .transpose()?;
+
+        if let Some(block_interval) = self.block_interval {
+            config.block_interval.store(block_interval, Ordering::SeqCst);
+        }
+        if let Some(log_level) = log_level {
+            config.logs.set_level(log_level);
+        }
+        if let Some(faucet) = self.faucet {
+            *config.faucet_policy.write() = faucet;
+        }
+        if let Some(cors) = self.cors {
+            *config.cors_policy.write() = cors;
+        }
+        Ok(())
+    }
+
+    /// Reloads the configuration file of the given node configuration, and returns the current settings.
+    pub fn reload(config: &NodeConfig) -> Result<Self> {
+        let path = config
+            .config_file
+            .as_ref()
+            .ok_or_else(|| anyhow!("the node was not started with a configuration file ('--config')"))?;
+        Self::open(path)?.apply(config)?;
+        info!("Reloaded the configuration from '{}'", path.display());
+        Ok(Self::current(config))
+    }
+
+    /// Reloads the configuration file of the given node configuration whenever the process receives `SIGHUP`.
+    /// Without a configuration file, `SIGHUP` keeps its default behavior.
+    pub(crate) fn reload_on_hangup(config: NodeConfig) {
+        #[cfg(unix)]
+        if config.config_file.is_some() {
+            tokio::task::spawn(async move {
+                let mut hangups = match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()) {
+                    Ok(hangups) => hangups,
+                    Err(error) => {
+                        error!("Failed to listen for SIGHUP: {error}");
+                        return;
+                    }
+                };
+                while hangups.recv().await.is_some() {
+                    if let Err(error) = Self::reload(&config) {
+                        warn!("Failed to reload the configuration: {error}");
+                    }
+                }
+            });
+        }
+        #[cfg(not(unix))]
+        let _ = config;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_validates_every_setting() {
+        let config = NodeConfig::default();
+        let update: ReloadableConfig =
+            serde_json::from_str(r#"{ "block_interval": 5, "log_level": "loud", "faucet": { "max_pour": 10 } }"#)
+                .unwrap();
+        assert!(update.apply(&config).is_err());
+        assert_eq!(config.block_interval.load(Ordering::SeqCst), crate::node::DEFAULT_BLOCK_INTERVAL);
+        assert!(config.faucet_policy.read().max_pour.is_none());
+
+        let update: ReloadableConfig = serde_json::from_value(serde_json::json!({
+            "block_interval": 5,
+            "faucet": { "max_pour": 10 },
+            "cors": { "allowed_origins": ["http://localhost:3000"] },
+        }))
+        .unwrap();
+        update.apply(&config).unwrap();
+        assert_eq!(config.block_interval.load(Ordering::SeqCst), 5);
+        assert!(config.faucet_policy.read().check(10).is_ok());
+        assert!(config.faucet_policy.read().check(11).is_err());
+        assert!(config.cors_policy.read().allows("http://localhost:3000"));
+        assert!(!config.cors_policy.read().allows("http://example.com"));
+    }
+}
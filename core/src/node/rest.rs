@@ -0,0 +1,501 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+#![forbid(unsafe_code)]
+
+use crate::{
+    errors::SlingshotError,
+    helpers::{ResponseSignature, SIGNATURE_HEADER, SIGNATURE_TIMESTAMP_HEADER},
+    node::{
+        AccountRegistry,
+        AccountSequences,
+        Admission,
+        CaptureBundle,
+        CorsPolicy,
+        EventFeed,
+        FaucetAutoFund,
+        Jobs,
+        Ledger,
+        NodeConfig,
+        ProgramMetadataRegistry,
+        Prover,
+        ShutdownSignal,
+        SimulationCache,
+        SingleNodeConsensus,
+        UsageRegistry,
+        WatchRegistry,
+    },
+    output,
+};
+
+use snarkos::{
+    account::Account,
+    node::rest::{with, OrReject, RestError},
+};
+use snarkvm::{
+    console::account::Address,
+    prelude::{Field, Network},
+    synthesizer::ConsensusStorage,
+};
+
+use anyhow::Result;
+use colored::*;
+use parking_lot::{Mutex, RwLock};
+use std::{convert::Infallible, net::SocketAddr, sync::Arc, time::Duration};
+use time::OffsetDateTime;
+use tokio::{
+    sync::{OwnedSemaphorePermit, Semaphore},
+    task::JoinHandle,
+};
+use warp::{
+    cors::Builder,
+    http::{
+        header::{HeaderName, HeaderValue, ACCESS_CONTROL_ALLOW_CREDENTIALS, ACCESS_CONTROL_ALLOW_ORIGIN, ORIGIN},
+        Method,
+        Request,
+        StatusCode,
+    },
+    hyper::{
+        server::conn::AddrStream,
+        service::{make_service_fn, service_fn, Service},
+        Body,
+        Server,
+    },
+    path::FullPath,
+    reject,
+    reply::{self, Response},
+    Filter,
+    Rejection,
+    Reply,
+};
+
+/// The default maximum size in bytes of the body of a request with a fixed shape.
+pub const DEFAULT_MAX_BODY_SIZE: u64 = 16 * 1024;
+/// The default maximum number of seconds to handle a request, other than a request that constructs a transaction.
+pub const DEFAULT_REQUEST_TIMEOUT_IN_SECS: u64 = 30;
+/// The default maximum number of seconds to handle a request that constructs a transaction.
+pub const DEFAULT_PROVING_TIMEOUT_IN_SECS: u64 = 600;
+/// The default maximum number of requests handled at once.
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 256;
+
+/// The maximum number of seconds a request waits for its transaction to be confirmed, once it is constructed.
+pub const MAX_CONFIRMATION_WAIT_IN_SECS: u64 = 300;
+
+/// The routes that construct a transaction, which have the proving timeout.
+const PROVING_ROUTES: &[&str] = &[
+    "/testnet3/faucet/pour",
+    "/testnet3/program/deploy",
+    "/testnet3/program/execute",
+    "/testnet3/program/profile",
+    "/testnet3/records/send",
+    "/testnet3/records/consolidate",
+    "/testnet3/records/split",
+    "/testnet3/fee/estimate",
+    "/testnet3/transaction/simulate",
+    "/testnet3/node/mine",
+];
+
+/// The routes that construct a transaction, and then wait for it to be confirmed, which have the proving timeout
+/// extended by the maximum confirmation wait.
+const WAITING_ROUTES: &[&str] = &["/testnet3/transferAndWait"];
+
+/// A REST API server for the ledger.
+#[derive(Clone)]
+pub struct Rest<N: Network, C: ConsensusStorage<N>> {
+    /// The node account, unless the node is view-only.
+    pub(crate) account: Option<Account<N>>,
+    /// The faucet account, unless the node is view-only.
+    pub(crate) faucet: Option<Account<N>>,
+    /// Funds the signers of the requests with the faucet record source, unless the node is view-only.
+    pub(crate) auto_fund: Option<FaucetAutoFund<N>>,
+    /// The consensus module.
+    pub(crate) consensus: Option<SingleNodeConsensus<N, C>>,
+    /// The ledger.
+    pub(crate) ledger: Ledger<N, C>,
+    /// The accounts registered for server-side signing.
+    pub(crate) accounts: AccountRegistry<N>,
+    /// The sequence numbers of the submissions of each account, if enabled.
+    pub(crate) sequences: Option<AccountSequences<N>>,
+    /// The node configuration.
+    pub(crate) config: NodeConfig,
+    /// The prover that constructs the transactions of requests.
+    pub(crate) prover: Prover,
+    /// The jobs of the deploy and execute requests that are processed in the background.
+    pub(crate) jobs: Jobs<N>,
+    /// The metadata attached to the deployments of programs.
+    pub(crate) program_metadata: ProgramMetadataRegistry<N>,
+    /// The transactions of the simulations at the latest state root.
+    pub(crate) simulations: SimulationCache<N>,
+    /// The usage of the node by each account.
+    pub(crate) usage: UsageRegistry<N>,
+    /// The watch lists of record commitments.
+    pub(crate) watches: WatchRegistry<N>,
+    /// The feed of the events of accepted blocks.
+    pub(crate) events: EventFeed<N>,
+    /// The shutdown signal of the node, which is requested by `POST /testnet3/node/shutdown`.
+    pub(crate) shutdown: ShutdownSignal,
+    /// The reproduction bundle the accepted deploy, execute, and pour requests are captured to, if enabled.
+    pub(crate) capture: CaptureBundle,
+    /// The server handles.
+    pub(crate) handles: Vec<Arc<JoinHandle<()>>>,
+}
+
+impl<N: Network, C: 'static + ConsensusStorage<N>> Rest<N, C> {
+    /// Initializes a new instance of the server, and serves its routes at the given IP.
+    pub fn start(
+        rest_ip: SocketAddr,
+        account: Option<Account<N>>,
+        faucet: Option<Account<N>>,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+        ledger: Ledger<N, C>,
+        config: NodeConfig,
+    ) -> Result<Self> {
+        let mut server = Self::new(account, faucet, consensus, ledger, config)?;
+        // Spawn the server.
+        server.spawn_server(rest_ip);
+        // Return the server.
+        Ok(server)
+    }
+
+    /// Initializes a new instance of the server, without serving its routes.
+    /// The routes may be served by another server, e.g. under the prefix of a tenant.
+    /// Without accounts, the node is view-only, and rejects the requests that require its keys.
+    pub fn new(
+        account: Option<Account<N>>,
+        faucet: Option<Account<N>>,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+        ledger: Ledger<N, C>,
+        config: NodeConfig,
+    ) -> Result<Self> {
+        // Initialize the account sequences, if enabled.
+        let sequences = config.account_sequences.then(AccountSequences::new);
+        // Initialize the prover.
+        let prover = Prover::new(config.proving_threads, config.max_concurrent_proofs, config.max_queued_proofs)?;
+        // Initialize the jobs, which are retained for the configured time once they are complete.
+        let jobs = Jobs::new(config.job_retention);
+        // Initialize the faucet funding, which includes each faucet transfer in a new block of the node.
+        let auto_fund = match (&account, &faucet, &consensus) {
+            (Some(account), Some(faucet), Some(consensus)) => {
+                let (private_key, consensus) = (*account.private_key(), consensus.clone());
+                Some(FaucetAutoFund::new(*faucet.private_key(), move |transaction| {
+                    consensus.add_unconfirmed_transaction(transaction)?;
+                    consensus.produce_next_block(&private_key, &mut rand::thread_rng()).map(|_| ())
+                }))
+            }
+            _ => None,
+        };
+        // Initialize the reproduction bundle, if requests are captured.
+        let capture = match &config.capture {
+            Some(directory) => CaptureBundle::open::<N>(directory)?,
+            None => CaptureBundle::default(),
+        };
+        // Initialize the server.
+        Ok(Self {
+            account,
+            faucet,
+            auto_fund,
+            consensus,
+            ledger,
+            accounts: Default::default(),
+            sequences,
+            config,
+            prover,
+            jobs,
+            program_metadata: Default::default(),
+            simulations: Default::default(),
+            usage: Default::default(),
+            watches: Default::default(),
+            events: Default::default(),
+            shutdown: Default::default(),
+            capture,
+            handles: vec![],
+        })
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
+    /// Returns the ledger.
+    pub const fn ledger(&self) -> &Ledger<N, C> {
+        &self.ledger
+    }
+
+    /// Returns the handles.
+    pub const fn handles(&self) -> &Vec<Arc<JoinHandle<()>>> {
+        &self.handles
+    }
+
+    /// Returns the admission of the transactions of requests, which shares the state of the server.
+    pub(crate) fn admission(&self) -> Admission<N, C> {
+        Admission::new(
+            self.consensus.clone(),
+            self.prover.clone(),
+            self.usage.clone(),
+            self.sequences.clone(),
+            self.auto_fund.clone(),
+            self.shutdown.clone(),
+        )
+    }
+}
+
+impl<N: Network, C: 'static + ConsensusStorage<N>> Rest<N, C> {
+    /// Initializes the server.
+    fn spawn_server(&mut self, rest_ip: SocketAddr) {
+        // Initialize the routes.
+        let routes = self.routes();
+        // Add the GraphQL routes, if enabled.
+        #[cfg(feature = "graphql")]
+        let routes = routes.or(crate::node::graphql_routes(self.ledger.clone()));
+
+        // Sign the responses, if enabled, with the method and path of the request they answer.
+        // Note: A rejection without a typed error, e.g. of an unknown route, is rendered by warp, and is unsigned.
+        let signer = self.config.sign_responses.then(|| self.account.clone()).flatten();
+        let routes = warp::method().and(warp::path::full()).and(routes).and(with(signer)).and_then(sign_response);
+
+        // Spawn the server.
+        let (limits, cors_policy) = (self.config.rest_limits, self.config.cors_policy.clone());
+        self.handles.push(Arc::new(tokio::spawn(async move {
+            output!("🌐 Starting the REST server at {}.\n", rest_ip.to_string().bold());
+
+            // Start the server.
+            serve(routes, rest_ip, limits, cors_policy).await
+        })))
+    }
+}
+
+/// The limits of the REST server.
+#[derive(Copy, Clone, Debug)]
+pub struct RestLimits {
+    /// The maximum size in bytes of the body of a request with a fixed shape, e.g. a pour or a view key.
+    /// The bodies of deployments, executions, and transactions are limited by `max_transaction_body_size` instead.
+    pub max_body_size: u64,
+    /// The maximum size in bytes of the body of a deployment, an execution, or a transaction, if it is lower
+    /// than the limit of the network parameters.
+    pub max_transaction_body_size: Option<u64>,
+    /// The maximum time to handle a request, other than a request that constructs a transaction.
+    pub request_timeout: Duration,
+    /// The maximum time to handle a request that constructs a transaction. A request that then waits for the
+    /// transaction to be confirmed may take up to `MAX_CONFIRMATION_WAIT_IN_SECS` longer.
+    pub proving_timeout: Duration,
+    /// The maximum number of requests handled at once. Further requests are rejected with `503 Service Unavailable`.
+    pub max_concurrent_requests: usize,
+}
+
+impl Default for RestLimits {
+    fn default() -> Self {
+        Self {
+            max_body_size: DEFAULT_MAX_BODY_SIZE,
+            max_transaction_body_size: None,
+            request_timeout: Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_IN_SECS),
+            proving_timeout: Duration::from_secs(DEFAULT_PROVING_TIMEOUT_IN_SECS),
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+        }
+    }
+}
+
+impl RestLimits {
+    /// Returns the maximum size in bytes of the body of a deployment, an execution, or a transaction.
+    pub fn transaction_body_size<N: Network>(&self) -> u64 {
+        // Determine Content Length based on Input Size supported by the Network.
+        let max_data_size = N::MAX_DATA_SIZE_IN_FIELDS * Field::<N>::SIZE_IN_DATA_BITS as u32;
+        let max_data_inputs = N::MAX_DATA_DEPTH * N::MAX_DATA_ENTRIES * N::MAX_INPUTS;
+        let max_content_length = (max_data_inputs as u32 * max_data_size) as u64;
+        match self.max_transaction_body_size {
+            Some(max_transaction_body_size) => max_transaction_body_size.min(max_content_length),
+            None => max_content_length,
+        }
+    }
+
+    /// Returns the maximum time to handle a request to the given path.
+    fn timeout(&self, path: &str) -> Duration {
+        if WAITING_ROUTES.iter().any(|route| path.ends_with(route)) {
+            return self.proving_timeout + Duration::from_secs(MAX_CONFIRMATION_WAIT_IN_SECS);
+        }
+        match PROVING_ROUTES.iter().any(|route| path.ends_with(route)) {
+            true => self.proving_timeout,
+            false => self.request_timeout,
+        }
+    }
+}
+
+/// The slot of a request among the requests handled at once, which is passed to the handler in the extensions of
+/// the request. A handler that waits for a transaction to be confirmed releases the slot before it waits, so that
+/// the waiting requests do not hold back the other requests.
+#[derive(Clone)]
+pub(crate) struct RequestSlot(Arc<Mutex<Option<OwnedSemaphorePermit>>>);
+
+impl RequestSlot {
+    /// Releases the slot, if it is not released yet.
+    pub(crate) fn release(&self) {
+        self.0.lock().take();
+    }
+}
+
+/// Serves the given routes at the given IP, with the given CORS policy, and within the given limits.
+/// Each request is logged, with its remote address and response status.
+pub(crate) async fn serve<F>(routes: F, rest_ip: SocketAddr, limits: RestLimits, cors_policy: Arc<RwLock<CorsPolicy>>)
+where
+    F: Filter<Error = Rejection> + Clone + Send + Sync + 'static,
+    F::Extract: Reply,
+{
+    let service = warp::service(routes.with(cors()));
+    let permits = Arc::new(Semaphore::new(limits.max_concurrent_requests));
+
+    let make_service = make_service_fn(move |connection: &AddrStream| {
+        let remote_addr = connection.remote_addr();
+        let (service, permits, cors_policy) = (service.clone(), permits.clone(), cors_policy.clone());
+        async move {
+            Ok::<_, Infallible>(service_fn(move |mut request: Request<Body>| {
+                let (mut service, permits, cors_policy) = (service.clone(), permits.clone(), cors_policy.clone());
+                async move {
+                    let (method, path) = (request.method().clone(), request.uri().path().to_string());
+                    let origin =
+                        request.headers().get(ORIGIN).and_then(|origin| origin.to_str().ok()).map(String::from);
+                    let mut response = match permits.try_acquire_owned() {
+                        // Handle the request, unless it exceeds the timeout of its route.
+                        Ok(permit) => {
+                            request.extensions_mut().insert(RequestSlot(Arc::new(Mutex::new(Some(permit)))));
+                            let timeout = limits.timeout(&path);
+                            match tokio::time::timeout(timeout, service.call(request)).await {
+                                Ok(Ok(response)) => response,
+                                Ok(Err(infallible)) => match infallible {},
+                                Err(_) => reply::with_status(
+                                    format!("the request timed out after {} seconds", timeout.as_secs()),
+                                    StatusCode::GATEWAY_TIMEOUT,
+                                )
+                                .into_response(),
+                            }
+                        }
+                        Err(_) => reply::with_header(
+                            reply::with_status("too many concurrent requests", StatusCode::SERVICE_UNAVAILABLE),
+                            "retry-after",
+                            "1",
+                        )
+                        .into_response(),
+                    };
+                    // Withhold the CORS headers from an origin the CORS policy does not allow, so that browsers
+                    // reject the response. The policy is checked here, as it may be reloaded while the node runs.
+                    if let Some(origin) = origin {
+                        if !cors_policy.read().allows(&origin) {
+                            response.headers_mut().remove(ACCESS_CONTROL_ALLOW_ORIGIN);
+                            response.headers_mut().remove(ACCESS_CONTROL_ALLOW_CREDENTIALS);
+                        }
+                    }
+                    debug!("Received '{method} {path}' from '{remote_addr}' ({})", response.status());
+                    Ok::<_, Infallible>(response)
+                }
+            }))
+        }
+    });
+
+    match Server::try_bind(&rest_ip) {
+        Ok(server) => {
+            if let Err(error) = server.serve(make_service).await {
+                error!("The REST server encountered an error: {error}");
+            }
+        }
+        Err(error) => error!("Failed to start the REST server at {rest_ip}: {error}"),
+    }
+}
+
+/// Limits the body of a request to the given number of bytes.
+/// A larger body is rejected with `413 body_too_large`, before it is read.
+pub(crate) fn body_limit(limit: u64) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    warp::header::optional::<u64>("content-length")
+        .and_then(move |length: Option<u64>| async move {
+            match length {
+                Some(length) if length > limit => Err(reject::custom(SlingshotError::BodyTooLarge { limit })),
+                _ => Ok(()),
+            }
+        })
+        .untuple_one()
+        .and(warp::body::content_length_limit(limit))
+}
+
+/// Returns the CORS policy of the REST server.
+pub(crate) fn cors() -> Builder {
+    warp::cors()
+        .allow_any_origin()
+        .allow_header(HeaderName::from_static("content-type"))
+        .allow_header(HeaderName::from_static("authorization"))
+        .allow_methods(vec!["GET", "POST", "DELETE", "OPTIONS"])
+        .expose_header(HeaderName::from_static(SIGNATURE_HEADER))
+        .expose_header(HeaderName::from_static(SIGNATURE_TIMESTAMP_HEADER))
+}
+
+impl reject::Reject for SlingshotError {}
+
+/// Returns the rejection of the given error. A typed error is rendered with its HTTP status code by `render_error`,
+/// and any other error is rejected as a request error, with the given context.
+pub(crate) fn reject_error(context: &str, error: anyhow::Error) -> Rejection {
+    match error.downcast::<SlingshotError>() {
+        Ok(error) => reject::custom(error),
+        Err(error) => reject::custom(RestError::Request(format!("{context}: {error}"))),
+    }
+}
+
+/// Returns a filter that extracts the given account, or rejects the request if the node is view-only.
+pub(crate) fn with_account<N: Network>(
+    account: Option<Account<N>>,
+) -> impl Filter<Extract = (Account<N>,), Error = Rejection> + Clone {
+    warp::any().and_then(move || {
+        let account = account.clone();
+        async move {
+            account.ok_or_else(|| {
+                reject::custom(RestError::Request(String::from("the node is view-only, and holds no private keys")))
+            })
+        }
+    })
+}
+
+/// Renders a typed error as a JSON object with its message, in a response with the HTTP status code of the error.
+/// Any other rejection is left to warp.
+pub(crate) async fn render_error(rejection: Rejection) -> Result<Response, Rejection> {
+    let error = match rejection.find::<SlingshotError>() {
+        Some(error) => error,
+        None => return Err(rejection),
+    };
+    let mut body = serde_json::to_value(error).unwrap_or_default();
+    body["message"] = error.to_string().into();
+    let status = StatusCode::from_u16(error.status_code()).unwrap_or(StatusCode::BAD_REQUEST);
+    Ok(reply::with_status(reply::json(&body), status).into_response())
+}
+
+/// Signs the given reply to a request with the given method and path with the given account, if any,
+/// and returns the signature in the `X-Node-Signature` header, and the time it was signed at in the
+/// `X-Node-Signature-Timestamp` header. The signature covers the method, the path, the time, and the body.
+pub(crate) async fn sign_response<N: Network, R: Reply>(
+    method: Method,
+    path: FullPath,
+    reply: R,
+    signer: Option<Account<N>>,
+) -> Result<Response, Rejection> {
+    let signer = match signer {
+        Some(signer) => signer,
+        None => return Ok(reply.into_response()),
+    };
+    let (mut parts, body) = reply.into_response().into_parts();
+    let body = warp::hyper::body::to_bytes(body)
+        .await
+        .map_err(|error| reject::custom(RestError::Request(format!("failed to read the response: {error}"))))?;
+    let timestamp = OffsetDateTime::now_utc().unix_timestamp();
+    let message = ResponseSignature::message(method.as_str(), path.as_str(), timestamp, &body);
+    let signature = signer.private_key().sign_bytes(&message, &mut rand::thread_rng()).or_reject()?;
+    let signature = HeaderValue::from_str(&signature.to_string())
+        .map_err(|error| reject::custom(RestError::Request(format!("failed to sign the response: {error}"))))?;
+    parts.headers.insert(SIGNATURE_HEADER, signature);
+    parts.headers.insert(SIGNATURE_TIMESTAMP_HEADER, HeaderValue::from(timestamp));
+    Ok(Response::from_parts(parts, Body::from(body)))
+}
@@ -0,0 +1,2517 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::SlingshotError,
+    node::{
+        admission,
+        authorize,
+        body_limit,
+        explorer_routes,
+        reject_error,
+        reject_writes,
+        render_error,
+        storage_fee,
+        with_account,
+        AccountRegistry,
+        AccountSequences,
+        Admission,
+        CaptureBundle,
+        CapturedRequest,
+        Event,
+        EventFeed,
+        FaucetAutoFund,
+        FaucetPolicy,
+        JobStatus,
+        Jobs,
+        Ledger,
+        LogBuffer,
+        NodeConfig,
+        NodeInfo,
+        NodeLimits,
+        NodeScan,
+        Peer,
+        ProgramMetadataRegistry,
+        Prover,
+        RecordProvider,
+        ReloadableConfig,
+        RequestSlot,
+        Rest,
+        ShutdownSignal,
+        SimulationCache,
+        SimulationKey,
+        SingleNodeConsensus,
+        StandardBlockPolicy,
+        UsageRegistry,
+        WatchRegistry,
+        MAX_CONFIRMATION_WAIT_IN_SECS,
+    },
+    records,
+};
+
+use snarkos::{
+    account::Account,
+    node::{
+        ledger::RecordsFilter,
+        rest::{with, OrReject, RestError},
+    },
+};
+
+use snarkvm::prelude::{
+    cfg_into_iter,
+    Address,
+    ConsensusStorage,
+    Field,
+    Network,
+    PrivateKey,
+    Program,
+    ProgramID,
+    ToBytes,
+    Transaction,
+    Value,
+    ViewKey,
+};
+
+use futures_util::{SinkExt, StreamExt};
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use std::{
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tokio::sync::broadcast::{self, error::RecvError};
+use warp::{
+    http::StatusCode,
+    reject,
+    reply,
+    ws::{Message, Ws},
+    Filter,
+    Rejection,
+    Reply,
+};
+
+use crate::messages::{
+    AccountRegisterRequest,
+    AccountRegisterResponse,
+    AccountUsageReport,
+    BlockPolicyUpdate,
+    BlockProduction,
+    BlockRange,
+    BuildInfo,
+    ConsolidateRequest,
+    ConsolidateResponse,
+    ConsolidationRound,
+    DecodeQuery,
+    DeployRequest,
+    DeployResponse,
+    EvaluateRequest,
+    EvaluateResponse,
+    EventReplay,
+    EventTypes,
+    ExecuteRequest,
+    ExecuteResponse,
+    FaucetBalance,
+    FeeEstimate,
+    FeeEstimateRequest,
+    FeeEstimateResponse,
+    HandshakeRequest,
+    HandshakeResponse,
+    IncreaseTime,
+    JobCreated,
+    JobFilter,
+    LogsQuery,
+    MineBlocks,
+    NextBlockTimestamp,
+    NodeConfigUpdate,
+    NodeTime,
+    PourRequest,
+    PourResponse,
+    ProgramEventRange,
+    ProgramFormat,
+    ProgramQuery,
+    ProgramWithMetadata,
+    RecordSource,
+    RecordStatsResponse,
+    RecordViewRequest,
+    RecordViewResponse,
+    RejectedTransaction,
+    SendRecordRequest,
+    SendRecordResponse,
+    ShutdownStatus,
+    Signer,
+    SimulateResponse,
+    SplitRequest,
+    SplitResponse,
+    SplitRound,
+    StatsRange,
+    SubmitMode,
+    TransactionVerification,
+    TransferRequest,
+    TransferResponse,
+    UsageReport,
+    WatchCreated,
+    WatchRecords,
+};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
+    /// Initializes the routes, given the ledger and ledger sender.
+    pub fn routes(&self) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+        // The body size limit of requests with a fixed shape.
+        let max_body_size = self.config.rest_limits.max_body_size;
+
+        // GET /testnet3/latest/height
+        let latest_height = warp::get()
+            .and(warp::path!("testnet3" / "latest" / "height"))
+            .and(with(self.ledger.clone()))
+            .and_then(Self::latest_height);
+
+        // GET /testnet3/latest/hash
+        let latest_hash = warp::get()
+            .and(warp::path!("testnet3" / "latest" / "hash"))
+            .and(with(self.ledger.clone()))
+            .and_then(Self::latest_hash);
+
+        // GET /testnet3/latest/block
+        let latest_block = warp::get()
+            .and(warp::path!("testnet3" / "latest" / "block"))
+            .and(with(self.ledger.clone()))
+            .and_then(Self::latest_block);
+
+        // GET /testnet3/latest/stateRoot
+        let latest_state_root = warp::get()
+            .and(warp::path!("testnet3" / "latest" / "stateRoot"))
+            .and(with(self.ledger.clone()))
+            .and_then(Self::latest_state_root);
+
+        // GET /testnet3/block/{height}
+        let get_block = warp::get()
+            .and(warp::path!("testnet3" / "block" / u32))
+            .and(with(self.ledger.clone()))
+            .and_then(Self::get_block);
+
+        // GET /testnet3/blocks?start={start_height}&end={end_height}
+        let get_blocks = warp::get()
+            .and(warp::path!("testnet3" / "blocks"))
+            .and(warp::query::<BlockRange>())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::get_blocks);
+
+        // GET /testnet3/block/{blockHash}
+        let get_block_by_hash = warp::get()
+            .and(warp::path!("testnet3" / "block" / ..))
+            .and(warp::path::param::<N::BlockHash>())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::get_block_by_hash);
+
+        // GET /testnet3/height/{blockHash}
+        let get_block_height_by_hash = warp::get()
+            .and(warp::path!("testnet3" / "height" / ..))
+            .and(warp::path::param::<N::BlockHash>())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::get_block_height_by_hash);
+
+        // GET /testnet3/block/{height}/transactions
+        let get_block_transactions = warp::get()
+            .and(warp::path!("testnet3" / "block" / u32 / "transactions"))
+            .and(with(self.ledger.clone()))
+            .and_then(Self::get_block_transactions);
+
+        // GET /testnet3/transaction/{transactionID}
+        let get_transaction = warp::get()
+            .and(warp::path!("testnet3" / "transaction" / ..))
+            .and(warp::path::param::<N::TransactionID>())
+            .and(warp::path::end())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::get_transaction);
+
+        // GET /testnet3/transaction/{transactionID}/events
+        let get_transaction_events = warp::get()
+            .and(warp::path!("testnet3" / "transaction" / ..))
+            .and(warp::path::param::<N::TransactionID>())
+            .and(warp::path!("events"))
+            .and(with(self.ledger.clone()))
+            .and(with(self.consensus.clone()))
+            .and_then(Self::get_transaction_events);
+
+        // GET /testnet3/transaction/{transactionID}/decode?view_key={viewKey}
+        let decode_transaction = warp::get()
+            .and(warp::path!("testnet3" / "transaction" / ..))
+            .and(warp::path::param::<N::TransactionID>())
+            .and(warp::path!("decode"))
+            .and(warp::query::<DecodeQuery>())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::decode_transaction);
+
+        // GET /testnet3/memoryPool/transactions
+        let get_memory_pool_transactions = warp::get()
+            .and(warp::path!("testnet3" / "memoryPool" / "transactions"))
+            .and(with(self.consensus.clone()))
+            .and_then(Self::get_memory_pool_transactions);
+
+        // GET /testnet3/memoryPool/rejected
+        let get_memory_pool_rejected = warp::get()
+            .and(warp::path!("testnet3" / "memoryPool" / "rejected"))
+            .and(with(self.consensus.clone()))
+            .and_then(Self::get_memory_pool_rejected);
+
+        // DELETE /testnet3/memoryPool/transaction/{transactionID}
+        let delete_memory_pool_transaction = warp::delete()
+            .and(warp::path!("testnet3" / "memoryPool" / "transaction" / ..))
+            .and(warp::path::param::<N::TransactionID>())
+            .and(warp::path::end())
+            .and(authorize(self.config.api_token.clone()))
+            .and(with(self.consensus.clone()))
+            .and_then(Self::delete_memory_pool_transaction);
+
+        // GET /testnet3/program/{programID}?format={json|string|bytes}&metadata={bool}
+        let get_program = warp::get()
+            .and(warp::path!("testnet3" / "program" / ..))
+            .and(warp::path::param::<ProgramID<N>>())
+            .and(warp::path::end())
+            .and(warp::query::<ProgramQuery>())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::get_program);
+
+        // GET /testnet3/program/{programID}/metadata
+        let get_program_metadata = warp::get()
+            .and(warp::path!("testnet3" / "program" / ..))
+            .and(warp::path::param::<ProgramID<N>>())
+            .and(warp::path!("metadata"))
+            .and(with(self.ledger.clone()))
+            .and(with(self.program_metadata.clone()))
+            .and_then(Self::get_program_metadata);
+
+        // GET /testnet3/program/{programID}/events?start={start_height}&end={end_height}
+        let get_program_events = warp::get()
+            .and(warp::path!("testnet3" / "program" / ..))
+            .and(warp::path::param::<ProgramID<N>>())
+            .and(warp::path!("events"))
+            .and(warp::query::<ProgramEventRange>())
+            .and(with(self.ledger.clone()))
+            .and(with(self.consensus.clone()))
+            .and_then(Self::get_program_events);
+
+        // GET /testnet3/statePath/{commitment}
+        let get_state_path_for_commitment = warp::get()
+            .and(warp::path!("testnet3" / "statePath" / ..))
+            .and(warp::path::param::<Field<N>>())
+            .and(warp::path::end())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::get_state_path_for_commitment);
+
+        // GET /testnet3/record/{commitment}
+        let get_record_ciphertext = warp::get()
+            .and(warp::path!("testnet3" / "record" / ..))
+            .and(warp::path::param::<Field<N>>())
+            .and(warp::path::end())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::get_record_ciphertext);
+
+        // GET /testnet3/records/ciphertexts?start={start_height}&end={end_height}
+        let get_record_ciphertexts = warp::get()
+            .and(warp::path!("testnet3" / "records" / "ciphertexts"))
+            .and(warp::query::<BlockRange>())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::get_record_ciphertexts);
+
+        // GET /testnet3/node/address
+        let get_node_address = warp::get()
+            .and(warp::path!("testnet3" / "node" / "address"))
+            .and(with_account(self.account.clone()))
+            .and_then(
+                |account: Account<N>| async move { Ok::<_, Rejection>(reply::json(&account.address().to_string())) },
+            );
+
+        // GET /testnet3/node/blockPolicy
+        let get_block_policy = warp::get()
+            .and(warp::path!("testnet3" / "node" / "blockPolicy"))
+            .and(with(self.consensus.clone()))
+            .and_then(Self::get_block_policy);
+
+        // POST /testnet3/node/blockPolicy
+        let node_block_policy = warp::post()
+            .and(warp::path!("testnet3" / "node" / "blockPolicy"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.consensus.clone()))
+            .and_then(Self::node_block_policy);
+
+        // GET /testnet3/node/info
+        let get_node_info = warp::get()
+            .and(warp::path!("testnet3" / "node" / "info"))
+            .and(with(self.ledger.clone()))
+            .and(with(self.consensus.clone()))
+            .and(with(self.prover.clone()))
+            .and(with(self.config.clone()))
+            .and_then(Self::node_info);
+
+        // POST /testnet3/node/config
+        let node_config = warp::post()
+            .and(warp::path!("testnet3" / "node" / "config"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.config.block_interval.clone()))
+            .and_then(Self::node_config);
+
+        // POST /testnet3/node/config/reload
+        let node_config_reload = warp::post()
+            .and(warp::path!("testnet3" / "node" / "config" / "reload"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(with(self.config.clone()))
+            .and_then(Self::node_config_reload);
+
+        // POST /testnet3/node/handshake
+        let node_handshake = warp::post()
+            .and(warp::path!("testnet3" / "node" / "handshake"))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and_then(Self::node_handshake);
+
+        // GET /testnet3/node/cache/stats
+        let node_cache_stats = warp::get()
+            .and(warp::path!("testnet3" / "node" / "cache" / "stats"))
+            .and(with(self.ledger.clone()))
+            .and_then(
+                |ledger: Ledger<N, C>| async move { Ok::<_, Rejection>(reply::json(&ledger.key_cache().stats())) },
+            );
+
+        // GET /testnet3/node/logs?since={sequence}
+        let node_logs = warp::get()
+            .and(warp::path!("testnet3" / "node" / "logs"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(warp::query::<LogsQuery>())
+            .and(with(self.config.logs.clone()))
+            .and_then(|query: LogsQuery, logs: LogBuffer| async move {
+                Ok::<_, Rejection>(reply::json(&logs.since(query.since)))
+            });
+
+        // GET /testnet3/stats/timeseries?from={start_height}&to={end_height}
+        let stats_timeseries = warp::get()
+            .and(warp::path!("testnet3" / "stats" / "timeseries"))
+            .and(warp::query::<StatsRange>())
+            .and(with(self.consensus.clone()))
+            .and_then(Self::stats_timeseries);
+
+        // POST /testnet3/node/mine
+        let node_mine = warp::post()
+            .and(warp::path!("testnet3" / "node" / "mine"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with_account(self.account.clone()))
+            .and(with_account(self.faucet.clone()))
+            .and(with(self.consensus.clone()))
+            .and_then(Self::node_mine);
+
+        // POST /testnet3/node/pause
+        let node_pause = warp::post()
+            .and(warp::path!("testnet3" / "node" / "pause"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(with(self.consensus.clone()))
+            .and_then(Self::node_pause);
+
+        // POST /testnet3/node/resume
+        let node_resume = warp::post()
+            .and(warp::path!("testnet3" / "node" / "resume"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(with(self.consensus.clone()))
+            .and_then(Self::node_resume);
+
+        // POST /testnet3/node/shutdown
+        let node_shutdown = warp::post()
+            .and(warp::path!("testnet3" / "node" / "shutdown"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(with(self.shutdown.clone()))
+            .and_then(Self::node_shutdown);
+
+        // POST /testnet3/node/setNextBlockTimestamp
+        let node_set_next_block_timestamp = warp::post()
+            .and(warp::path!("testnet3" / "node" / "setNextBlockTimestamp"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.consensus.clone()))
+            .and_then(Self::node_set_next_block_timestamp);
+
+        // POST /testnet3/node/increaseTime
+        let node_increase_time = warp::post()
+            .and(warp::path!("testnet3" / "node" / "increaseTime"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.consensus.clone()))
+            .and_then(Self::node_increase_time);
+
+        // GET /testnet3/account/{address}/sequence
+        let get_account_sequence = warp::get()
+            .and(warp::path!("testnet3" / "account" / ..))
+            .and(warp::path::param::<Address<N>>())
+            .and(warp::path!("sequence"))
+            .and(with(self.sequences.clone()))
+            .and_then(Self::get_account_sequence);
+
+        // GET /testnet3/find/blockHash/{transactionID}
+        let find_block_hash = warp::get()
+            .and(warp::path!("testnet3" / "find" / "blockHash" / ..))
+            .and(warp::path::param::<N::TransactionID>())
+            .and(warp::path::end())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::find_block_hash);
+
+        // GET /testnet3/find/deploymentID/{programID}
+        let find_deployment_id = warp::get()
+            .and(warp::path!("testnet3" / "find" / "deploymentID" / ..))
+            .and(warp::path::param::<ProgramID<N>>())
+            .and(warp::path::end())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::find_deployment_id);
+
+        // GET /testnet3/find/transactionID/{transitionID}
+        let find_transaction_id = warp::get()
+            .and(warp::path!("testnet3" / "find" / "transactionID" / ..))
+            .and(warp::path::param::<N::TransitionID>())
+            .and(warp::path::end())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::find_transaction_id);
+
+        // GET /testnet3/find/transitionID/{inputOrOutputID}
+        let find_transition_id = warp::get()
+            .and(warp::path!("testnet3" / "find" / "transitionID" / ..))
+            .and(warp::path::param::<Field<N>>())
+            .and(warp::path::end())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::find_transition_id);
+
+        // GET /testnet3/events/replay?from_block={start_height}&to_block={end_height}
+        let events_replay = warp::get()
+            .and(warp::path!("testnet3" / "events" / "replay"))
+            .and(warp::query::<EventReplay>())
+            .and(with(self.ledger.clone()))
+            .and(with(self.consensus.clone()))
+            .and_then(Self::events_replay);
+
+        // GET /testnet3/events/ws?types={types}
+        let events_socket = warp::get()
+            .and(warp::path!("testnet3" / "events" / "ws"))
+            .and(warp::query::<EventTypes>())
+            .and(warp::ws())
+            .and(with(self.events.clone()))
+            .and_then(Self::events_socket);
+
+        // POST /testnet3/records/all
+        let records_all = warp::post()
+            .and(warp::path!("testnet3" / "records" / "all"))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::records_all);
+
+        // POST /testnet3/records/spent
+        let records_spent = warp::post()
+            .and(warp::path!("testnet3" / "records" / "spent"))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::records_spent);
+
+        // POST /testnet3/records/unspent
+        let records_unspent = warp::post()
+            .and(warp::path!("testnet3" / "records" / "unspent"))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::records_unspent);
+
+        // POST /testnet3/records/stats
+        let records_stats = warp::post()
+            .and(warp::path!("testnet3" / "records" / "stats"))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::records_stats);
+
+        // POST /testnet3/watch/records
+        let watch_records = warp::post()
+            .and(warp::path!("testnet3" / "watch" / "records"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.ledger.clone()))
+            .and(with(self.watches.clone()))
+            .and_then(Self::watch_records);
+
+        // GET /testnet3/watch/{id}/ws
+        let watch_socket = warp::get()
+            .and(warp::path!("testnet3" / "watch" / String / "ws"))
+            .and(warp::ws())
+            .and(with(self.watches.clone()))
+            .and_then(Self::watch_socket);
+
+        // DELETE /testnet3/watch/{id}
+        let delete_watch = warp::delete()
+            .and(warp::path!("testnet3" / "watch" / String))
+            .and(authorize(self.config.api_token.clone()))
+            .and(with(self.watches.clone()))
+            .and_then(Self::delete_watch);
+
+        // POST /testnet3/history
+        let history = warp::post()
+            .and(warp::path!("testnet3" / "history"))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.ledger.clone()))
+            .and_then(Self::history);
+
+        // POST /testnet3/faucet/pour
+        let faucet_pour = warp::post()
+            .and(warp::path!("testnet3" / "faucet" / "pour"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with_account(self.faucet.clone()))
+            .and(with(self.ledger.clone()))
+            .and(with(self.admission()))
+            .and(with(self.usage.clone()))
+            .and(with(self.config.faucet_policy.clone()))
+            .and(with(self.capture.clone()))
+            .and_then(Self::faucet_pour);
+
+        // GET /testnet3/faucet/balance
+        let faucet_balance = warp::get()
+            .and(warp::path!("testnet3" / "faucet" / "balance"))
+            .and(with_account(self.faucet.clone()))
+            .and(with(self.ledger.clone()))
+            .and_then(Self::faucet_balance);
+
+        // Determine the content length of deployments, executions, and transactions.
+        let max_content_length = self.config.rest_limits.transaction_body_size::<N>();
+
+        // POST /testnet3/accounts/register
+        let accounts_register = warp::post()
+            .and(warp::path!("testnet3" / "accounts" / "register"))
+            .and(authorize(self.config.api_token.clone()))
+            // A view-only node does not hold the private keys of other accounts either.
+            .and(with_account(self.account.clone()).map(|_| ()).untuple_one())
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.accounts.clone()))
+            .and_then(Self::accounts_register);
+
+        // POST /testnet3/transferAndWait
+        let transfer_and_wait = warp::post()
+            .and(warp::path!("testnet3" / "transferAndWait"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.accounts.clone()))
+            .and(with(self.config.allow_private_keys))
+            .and(with(self.ledger.clone()))
+            .and(with(self.admission()))
+            .and(with(self.events.clone()))
+            .and(warp::ext::optional::<RequestSlot>())
+            .and_then(Self::transfer_and_wait);
+
+        // POST /testnet3/records/send
+        let records_send = warp::post()
+            .and(warp::path!("testnet3" / "records" / "send"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.accounts.clone()))
+            .and(with(self.config.allow_private_keys))
+            .and(with(self.ledger.clone()))
+            .and(with(self.admission()))
+            .and_then(Self::records_send);
+
+        // POST /testnet3/records/consolidate
+        let records_consolidate = warp::post()
+            .and(warp::path!("testnet3" / "records" / "consolidate"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.accounts.clone()))
+            .and(with(self.config.allow_private_keys))
+            .and(with(self.ledger.clone()))
+            .and(with(self.admission()))
+            .and_then(Self::records_consolidate);
+
+        // POST /testnet3/records/split
+        let records_split = warp::post()
+            .and(warp::path!("testnet3" / "records" / "split"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_body_size))
+            .and(warp::body::json())
+            .and(with(self.accounts.clone()))
+            .and(with(self.config.allow_private_keys))
+            .and(with(self.ledger.clone()))
+            .and(with(self.admission()))
+            .and_then(Self::records_split);
+
+        // POST /testnet3/program/deploy
+        let program_deploy = warp::post()
+            .and(warp::path!("testnet3" / "program" / "deploy"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_content_length))
+            .and(warp::body::json())
+            .and(warp::query::<SubmitMode>())
+            .and(with(self.accounts.clone()))
+            .and(with(self.config.allow_private_keys))
+            .and(with(self.ledger.clone()))
+            .and(with(self.admission()))
+            .and(with(self.jobs.clone()))
+            .and(with(self.program_metadata.clone()))
+            .and(with(self.capture.clone()))
+            .and_then(Self::program_deploy);
+
+        // POST /testnet3/program/execute
+        let program_execute = warp::post()
+            .and(warp::path!("testnet3" / "program" / "execute"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_content_length))
+            .and(warp::body::json())
+            .and(warp::query::<SubmitMode>())
+            .and(with(self.accounts.clone()))
+            .and(with(self.config.allow_private_keys))
+            .and(with(self.ledger.clone()))
+            .and(with(self.admission()))
+            .and(with(self.jobs.clone()))
+            .and(with(self.capture.clone()))
+            .and_then(Self::program_execute);
+
+        // GET /testnet3/job/{id}
+        let get_job = warp::get()
+            .and(warp::path!("testnet3" / "job" / String))
+            .and(with(self.jobs.clone()))
+            .and_then(Self::get_job);
+
+        // GET /testnet3/jobs?status={status}
+        let list_jobs = warp::get()
+            .and(warp::path!("testnet3" / "jobs"))
+            .and(warp::query::<JobFilter>())
+            .and(with(self.jobs.clone()))
+            .and_then(Self::list_jobs);
+
+        // POST /testnet3/program/evaluate
+        let program_evaluate = warp::post()
+            .and(warp::path!("testnet3" / "program" / "evaluate"))
+            .and(body_limit(max_content_length))
+            .and(warp::body::json())
+            .and(with(self.accounts.clone()))
+            .and(with(self.config.allow_private_keys))
+            .and(with(self.ledger.clone()))
+            .and_then(Self::program_evaluate);
+
+        // POST /testnet3/program/profile
+        let program_profile = warp::post()
+            .and(warp::path!("testnet3" / "program" / "profile"))
+            .and(body_limit(max_content_length))
+            .and(warp::body::json())
+            .and(with(self.accounts.clone()))
+            .and(with(self.config.allow_private_keys))
+            .and(with(self.ledger.clone()))
+            .and(with(self.prover.clone()))
+            .and(with(self.usage.clone()))
+            .and_then(Self::program_profile);
+
+        // POST /testnet3/fee/estimate
+        let fee_estimate = warp::post()
+            .and(warp::path!("testnet3" / "fee" / "estimate"))
+            .and(body_limit(max_content_length))
+            .and(warp::body::json())
+            .and(with(self.accounts.clone()))
+            .and(with(self.config.allow_private_keys))
+            .and(with(self.ledger.clone()))
+            .and(with(self.prover.clone()))
+            .and(with(self.usage.clone()))
+            .and_then(Self::fee_estimate);
+
+        // POST /testnet3/transaction/simulate
+        let transaction_simulate = warp::post()
+            .and(warp::path!("testnet3" / "transaction" / "simulate"))
+            .and(body_limit(max_content_length))
+            .and(warp::body::json())
+            .and(with(self.accounts.clone()))
+            .and(with(self.config.allow_private_keys))
+            .and(with(self.ledger.clone()))
+            .and(with(self.prover.clone()))
+            .and(with(self.consensus.clone()))
+            .and(with(self.simulations.clone()))
+            .and(with(self.usage.clone()))
+            .and_then(Self::transaction_simulate);
+
+        // POST /testnet3/transaction/broadcast
+        let transaction_broadcast = warp::post()
+            .and(warp::path!("testnet3" / "transaction" / "broadcast"))
+            .and(authorize(self.config.api_token.clone()))
+            .and(body_limit(max_content_length))
+            .and(warp::body::json())
+            .and(with(self.consensus.clone()))
+            .and(with(self.config.peer.as_deref().map(Peer::new)))
+            .and_then(Self::transaction_broadcast);
+
+        // POST /testnet3/transaction/verify
+        let transaction_verify = warp::post()
+            .and(warp::path!("testnet3" / "transaction" / "verify"))
+            .and(body_limit(max_content_length))
+            .and(warp::body::json())
+            .and(with(self.consensus.clone()))
+            .and_then(Self::transaction_verify);
+
+        // GET /testnet3/usage
+        let get_usage =
+            warp::get().and(warp::path!("testnet3" / "usage")).and(with(self.usage.clone())).and_then(Self::get_usage);
+
+        // GET /explorer
+        let explorer = explorer_routes();
+
+        // Collect the routes.
+        let routes = latest_height
+            .or(latest_hash)
+            .or(latest_block)
+            .or(latest_state_root)
+            .or(get_block)
+            .or(get_blocks)
+            .or(get_block_by_hash)
+            .or(get_block_height_by_hash)
+            .or(get_block_transactions)
+            .or(get_transaction)
+            .or(get_transaction_events)
+            .or(decode_transaction)
+            .or(get_memory_pool_transactions)
+            .or(get_memory_pool_rejected)
+            .or(delete_memory_pool_transaction)
+            .or(get_program)
+            .or(get_program_metadata)
+            .or(get_program_events)
+            .or(get_state_path_for_commitment)
+            .or(get_record_ciphertext)
+            .or(get_record_ciphertexts)
+            .or(get_node_address)
+            .or(get_block_policy)
+            .or(node_block_policy)
+            .or(get_node_info)
+            .or(node_config)
+            .or(node_config_reload)
+            .or(node_handshake)
+            .or(node_cache_stats)
+            .or(node_logs)
+            .or(stats_timeseries)
+            .or(node_mine)
+            .or(node_pause)
+            .or(node_resume)
+            .or(node_shutdown)
+            .or(node_set_next_block_timestamp)
+            .or(node_increase_time)
+            .or(get_account_sequence)
+            .or(find_block_hash)
+            .or(find_deployment_id)
+            .or(find_transaction_id)
+            .or(find_transition_id)
+            .or(events_replay)
+            .or(events_socket)
+            .or(records_all)
+            .or(records_spent)
+            .or(records_unspent)
+            .or(records_stats)
+            .or(watch_records)
+            .or(watch_socket)
+            .or(delete_watch)
+            .or(history)
+            .or(faucet_pour)
+            .or(faucet_balance)
+            .or(accounts_register)
+            .or(transfer_and_wait)
+            .or(records_send)
+            .or(records_consolidate)
+            .or(records_split)
+            .or(program_deploy)
+            .or(program_execute)
+            .or(get_job)
+            .or(list_jobs)
+            .or(program_evaluate)
+            .or(program_profile)
+            .or(fee_estimate)
+            .or(transaction_simulate)
+            .or(transaction_broadcast)
+            .or(transaction_verify)
+            .or(get_usage)
+            .or(explorer);
+
+        // Return the list of routes, which reject writes once the node is shutting down.
+        reject_writes(self.shutdown.clone()).and(routes).recover(render_error)
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Rest<N, C> {
+    /// Returns the latest block height.
+    async fn latest_height(ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.latest_height()))
+    }
+
+    /// Returns the latest block hash.
+    async fn latest_hash(ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.latest_hash()))
+    }
+
+    /// Returns the latest block.
+    async fn latest_block(ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.latest_block()))
+    }
+
+    /// Returns the latest state root.
+    async fn latest_state_root(ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.latest_state_root()))
+    }
+
+    /// Returns the block for the given block height.
+    async fn get_block(height: u32, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.get_block(height).or_reject()?))
+    }
+
+    /// Returns the blocks for the given block range.
+    async fn get_blocks(block_range: BlockRange, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        let (start_height, end_height) = Self::check_block_range(&block_range)?;
+
+        let blocks = cfg_into_iter!((start_height..end_height))
+            .map(|height| ledger.get_block(height).or_reject())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(reply::json(&blocks))
+    }
+
+    /// Returns the start and end heights of the given block range, or rejects the range if it is invalid or too long.
+    fn check_block_range(block_range: &BlockRange) -> Result<(u32, u32), Rejection> {
+        let start_height = block_range.start;
+        let end_height = block_range.end;
+
+        const MAX_BLOCK_RANGE: u32 = 50;
+
+        // Ensure the end height is greater than the start height.
+        if start_height > end_height {
+            return Err(reject::custom(RestError::Request("Invalid block range".to_string())));
+        }
+        // Ensure the block range is bounded.
+        else if end_height - start_height > MAX_BLOCK_RANGE {
+            return Err(reject::custom(RestError::Request(format!(
+                "Cannot request more than {MAX_BLOCK_RANGE} blocks per call (requested {})",
+                end_height - start_height
+            ))));
+        }
+
+        Ok((start_height, end_height))
+    }
+
+    /// Returns the block for the given block hash.
+    async fn get_block_by_hash(hash: N::BlockHash, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.get_block_by_hash(&hash).or_reject()?))
+    }
+
+    /// Returns the block height for the given block hash.
+    async fn get_block_height_by_hash(hash: N::BlockHash, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.get_height(&hash).or_reject()?))
+    }
+
+    /// Returns the transactions for the given block height.
+    async fn get_block_transactions(height: u32, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.get_transactions(height).or_reject()?))
+    }
+
+    /// Returns the transaction for the given transaction ID.
+    async fn get_transaction(transaction_id: N::TransactionID, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.get_transaction(transaction_id).or_reject()?))
+    }
+
+    /// Returns the events for the given confirmed transaction.
+    async fn get_transaction_events(
+        transaction_id: N::TransactionID,
+        ledger: Ledger<N, C>,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+    ) -> Result<impl Reply, Rejection> {
+        let block_hash = match ledger.find_block_hash(&transaction_id).or_reject()? {
+            Some(block_hash) => block_hash,
+            None => {
+                return Err(reject::custom(RestError::Request(format!(
+                    "Transaction '{transaction_id}' is not confirmed"
+                ))));
+            }
+        };
+        let height = ledger.get_height(&block_hash).or_reject()?;
+        let transaction = ledger.get_transaction(transaction_id).or_reject()?;
+        Ok(reply::json(&Self::set_deployers(Event::from_transaction(height, &transaction), &consensus)))
+    }
+
+    /// Returns the given confirmed transaction, decomposed into its transitions, the fee, and the finalize operations,
+    /// with the private values and records the given view key can decrypt.
+    async fn decode_transaction(
+        transaction_id: N::TransactionID,
+        query: DecodeQuery,
+        ledger: Ledger<N, C>,
+    ) -> Result<impl Reply, Rejection> {
+        let view_key = match query.view_key {
+            Some(view_key) => match ViewKey::<N>::from_str(&view_key) {
+                Ok(view_key) => Some(view_key),
+                Err(error) => return Err(reject::custom(RestError::Request(format!("invalid view key: {error}")))),
+            },
+            None => None,
+        };
+        Ok(reply::json(&ledger.decode_transaction(transaction_id, view_key.as_ref()).or_reject()?))
+    }
+
+    /// Returns the transactions in the memory pool.
+    async fn get_memory_pool_transactions(
+        consensus: Option<SingleNodeConsensus<N, C>>,
+    ) -> Result<impl Reply, Rejection> {
+        match consensus {
+            Some(consensus) => Ok(reply::json(&consensus.memory_pool().unconfirmed_transactions())),
+            None => Err(reject::custom(RestError::Request("Invalid endpoint".to_string()))),
+        }
+    }
+
+    /// Returns the policy that decides when the node produces a block.
+    async fn get_block_policy(consensus: Option<SingleNodeConsensus<N, C>>) -> Result<impl Reply, Rejection> {
+        match consensus {
+            Some(consensus) => Ok(reply::json(&BlockPolicyUpdate { policy: consensus.block_policy().name() })),
+            None => Err(reject::custom(RestError::Request("Invalid endpoint".to_string()))),
+        }
+    }
+
+    /// Switches the policy that decides when the node produces a block, from the next production round.
+    async fn node_block_policy(
+        request: BlockPolicyUpdate,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+    ) -> Result<impl Reply, Rejection> {
+        let consensus = match consensus {
+            Some(consensus) => consensus,
+            None => return Err(reject::custom(RestError::Request("Invalid endpoint".to_string()))),
+        };
+        let policy = match StandardBlockPolicy::from_str(&request.policy) {
+            Ok(policy) => policy,
+            Err(error) => return Err(reject::custom(RestError::Request(error.to_string()))),
+        };
+        consensus.set_block_policy(Arc::new(policy));
+        info!("Updated the block policy to '{policy}'");
+        Ok(reply::json(&BlockPolicyUpdate { policy: consensus.block_policy().name() }))
+    }
+
+    /// Removes a stuck transaction from the memory pool, and returns it.
+    /// The transaction is listed as rejected, so that its submitter can find out why it was dropped.
+    async fn delete_memory_pool_transaction(
+        transaction_id: N::TransactionID,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+    ) -> Result<impl Reply, Rejection> {
+        match consensus {
+            Some(consensus) => match consensus.memory_pool().remove_unconfirmed_transaction(transaction_id) {
+                Some(transaction) => Ok(reply::json(&transaction)),
+                None => Err(reject::custom(RestError::Request(format!(
+                    "transaction '{transaction_id}' is not in the memory pool"
+                )))),
+            },
+            None => Err(reject::custom(RestError::Request("Invalid endpoint".to_string()))),
+        }
+    }
+
+    /// Returns the transactions most recently rejected from the memory pool, with the reason they were rejected.
+    async fn get_memory_pool_rejected(consensus: Option<SingleNodeConsensus<N, C>>) -> Result<impl Reply, Rejection> {
+        match consensus {
+            Some(consensus) => {
+                let rejected = consensus
+                    .memory_pool()
+                    .rejected_transactions()
+                    .into_iter()
+                    .map(|(transaction_id, reason)| RejectedTransaction::<N> { transaction_id, reason })
+                    .collect::<Vec<_>>();
+                Ok(reply::json(&rejected))
+            }
+            None => Err(reject::custom(RestError::Request("Invalid endpoint".to_string()))),
+        }
+    }
+
+    /// Returns the program for the given program ID.
+    async fn get_program(
+        program_id: ProgramID<N>,
+        query: ProgramQuery,
+        ledger: Ledger<N, C>,
+    ) -> Result<reply::Response, Rejection> {
+        let is_native = program_id == ProgramID::<N>::from_str("credits.aleo").or_reject()?;
+        let program = match is_native {
+            true => Program::<N>::credits().or_reject()?,
+            false => {
+                ledger.get_program(program_id).map_err(|error| reject_error("failed to get the program", error))?
+            }
+        };
+
+        // Return the program alone, in the requested format.
+        if !query.metadata {
+            return Ok(match query.format {
+                ProgramFormat::Json => reply::json(&program).into_response(),
+                ProgramFormat::String => {
+                    reply::with_header(program.to_string(), "content-type", "text/plain; charset=utf-8").into_response()
+                }
+                ProgramFormat::Bytes => {
+                    reply::with_header(program.to_bytes_le().or_reject()?, "content-type", "application/octet-stream")
+                        .into_response()
+                }
+            });
+        }
+
+        // Otherwise, find the deployment of the program, unless it is native.
+        let (deployment_transaction_id, height) = match is_native {
+            true => (None, None),
+            false => {
+                let transaction_id = ledger.find_deployment_id(&program_id).or_reject()?;
+                let block_hash = match &transaction_id {
+                    Some(transaction_id) => ledger.find_block_hash(transaction_id).or_reject()?,
+                    None => None,
+                };
+                let height = block_hash.map(|block_hash| ledger.get_height(&block_hash)).transpose().or_reject()?;
+                (transaction_id, height)
+            }
+        };
+        let program = match query.format {
+            ProgramFormat::Json => serde_json::to_value(&program)
+                .map_err(|error| reject_error("failed to serialize the program", error.into()))?,
+            ProgramFormat::String => serde_json::Value::String(program.to_string()),
+            ProgramFormat::Bytes => serde_json::Value::String(
+                program.to_bytes_le().or_reject()?.iter().map(|byte| format!("{byte:02x}")).collect(),
+            ),
+        };
+        Ok(reply::json(&ProgramWithMetadata::<N> { program, is_native, deployment_transaction_id, height })
+            .into_response())
+    }
+
+    /// Returns the metadata attached to the deployment of the given program.
+    /// A deployed program without metadata has empty metadata.
+    async fn get_program_metadata(
+        program_id: ProgramID<N>,
+        ledger: Ledger<N, C>,
+        program_metadata: ProgramMetadataRegistry<N>,
+    ) -> Result<impl Reply, Rejection> {
+        // Ensure the program is deployed.
+        if program_id != ProgramID::<N>::from_str("credits.aleo").or_reject()? {
+            ledger.get_program(program_id).map_err(|error| reject_error("failed to get the program", error))?;
+        }
+        Ok(reply::json(&program_metadata.get(&program_id).unwrap_or_default()))
+    }
+
+    /// Returns the events of the given program in the given range of blocks.
+    async fn get_program_events(
+        program_id: ProgramID<N>,
+        range: ProgramEventRange,
+        ledger: Ledger<N, C>,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+    ) -> Result<impl Reply, Rejection> {
+        const MAX_BLOCK_RANGE: u32 = 50;
+
+        let start_height = range.start;
+        let end_height = range.end.unwrap_or_else(|| {
+            ledger.latest_height().saturating_add(1).min(start_height.saturating_add(MAX_BLOCK_RANGE))
+        });
+
+        // Ensure the end height is greater than the start height.
+        if start_height > end_height {
+            return Err(reject::custom(RestError::Request("Invalid block range".to_string())));
+        }
+        // Ensure the block range is bounded.
+        else if end_height - start_height > MAX_BLOCK_RANGE {
+            return Err(reject::custom(RestError::Request(format!(
+                "Cannot fetch events for more than {MAX_BLOCK_RANGE} blocks per call (requested {})",
+                end_height - start_height
+            ))));
+        }
+
+        let mut events = Vec::new();
+        for height in start_height..end_height {
+            events.extend(
+                Event::from_block(&ledger.get_block(height).or_reject()?)
+                    .into_iter()
+                    .filter(|event| event.program_id() == Some(&program_id)),
+            );
+        }
+
+        Ok(reply::json(&Self::set_deployers(events, &consensus)))
+    }
+
+    /// Sets the deployers of the `program_deployed` events, if the deployments were submitted to this node.
+    fn set_deployers(mut events: Vec<Event<N>>, consensus: &Option<SingleNodeConsensus<N, C>>) -> Vec<Event<N>> {
+        if let Some(consensus) = consensus {
+            events.iter_mut().for_each(|event| event.set_deployer(consensus));
+        }
+        events
+    }
+
+    /// Returns the state path for the given commitment.
+    async fn get_state_path_for_commitment(
+        commitment: Field<N>,
+        ledger: Ledger<N, C>,
+    ) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.get_state_path_for_commitment(&commitment).or_reject()?))
+    }
+
+    /// Returns the confirmed record ciphertext for the given commitment.
+    async fn get_record_ciphertext(commitment: Field<N>, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.get_record_ciphertext(&commitment).or_reject()?))
+    }
+
+    /// Returns the record ciphertexts output by the blocks in the given block range.
+    async fn get_record_ciphertexts(block_range: BlockRange, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        let (start_height, end_height) = Self::check_block_range(&block_range)?;
+        Ok(reply::json(&ledger.get_record_ciphertexts(start_height, end_height).or_reject()?))
+    }
+
+    /// Returns the sequence number of the next submission from the given account.
+    async fn get_account_sequence(
+        address: Address<N>,
+        sequences: Option<AccountSequences<N>>,
+    ) -> Result<impl Reply, Rejection> {
+        match sequences {
+            Some(sequences) => Ok(reply::json(&sequences.next(&address))),
+            None => Err(reject::custom(RestError::Request(String::from("account sequences are not enabled")))),
+        }
+    }
+
+    /// Updates the node configuration, and returns the current configuration.
+    async fn node_config(update: NodeConfigUpdate, block_interval: Arc<AtomicU64>) -> Result<impl Reply, Rejection> {
+        if let Some(interval) = update.block_interval {
+            // Ensure the block interval is positive.
+            if interval == 0 {
+                return Err(reject::custom(RestError::Request(String::from(
+                    "the block interval must be at least 1 second",
+                ))));
+            }
+            block_interval.store(interval, Ordering::SeqCst);
+            info!("Updated the block interval to {interval} seconds");
+        }
+        Ok(reply::json(&NodeConfigUpdate { block_interval: Some(block_interval.load(Ordering::SeqCst)) }))
+    }
+
+    /// Returns the chain context of the node, with its resource limits and current load.
+    async fn node_info(
+        ledger: Ledger<N, C>,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+        prover: Prover,
+        config: NodeConfig,
+    ) -> Result<impl Reply, Rejection> {
+        let limits = NodeLimits {
+            max_concurrent_proofs: prover.max_concurrent_proofs(),
+            max_queued_proofs: prover.max_queued_proofs(),
+            active_proofs: prover.num_active(),
+            queued_proofs: prover.num_queued(),
+            pool_capacity: config.pool_limits.capacity,
+            pool_size: consensus.map_or(0, |consensus| consensus.memory_pool().num_unconfirmed_transactions()),
+            max_body_size: config.rest_limits.max_body_size,
+            max_transaction_body_size: config.rest_limits.transaction_body_size::<N>(),
+            max_concurrent_requests: config.rest_limits.max_concurrent_requests,
+        };
+        Ok(reply::json(&NodeInfo::new(&ledger).with_limits(limits)))
+    }
+
+    /// Reloads the configuration file of the node, and returns the current settings.
+    async fn node_config_reload(config: NodeConfig) -> Result<impl Reply, Rejection> {
+        match ReloadableConfig::reload(&config) {
+            Ok(settings) => Ok(reply::json(&settings)),
+            Err(error) => Err(reject_error("failed to reload the configuration", error)),
+        }
+    }
+
+    /// Returns the build of the node, and its incompatibilities with the build of the client.
+    async fn node_handshake(request: HandshakeRequest) -> Result<impl Reply, Rejection> {
+        let node = BuildInfo::current::<N>();
+        let incompatibilities = BuildInfo::incompatibilities(request.client(), &node);
+        for incompatibility in &incompatibilities {
+            warn!("A client may be incompatible with the node: {incompatibility}");
+        }
+        Ok(HandshakeResponse::new(node, incompatibilities))
+    }
+
+    /// Produces the given number of blocks, and returns their headers.
+    /// A block is produced even if the memory pool is empty, with a transfer of zero gates from the faucet to itself.
+    async fn node_mine(
+        request: MineBlocks,
+        account: Account<N>,
+        faucet: Account<N>,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+    ) -> Result<impl Reply, Rejection> {
+        const MAX_MINE_BLOCKS: u32 = 100;
+
+        let consensus = match consensus {
+            Some(consensus) => consensus,
+            None => return Err(reject::custom(RestError::Request(String::from("no consensus module available")))),
+        };
+        // Ensure the number of blocks is bounded.
+        if request.blocks == 0 || request.blocks > MAX_MINE_BLOCKS {
+            return Err(reject::custom(RestError::Request(format!(
+                "Cannot mine {} blocks (expected between 1 and {MAX_MINE_BLOCKS})",
+                request.blocks
+            ))));
+        }
+
+        // Produce the blocks.
+        let headers = tokio::task::spawn_blocking(move || {
+            (0..request.blocks)
+                .map(|_| {
+                    if consensus.memory_pool().num_unconfirmed_transactions() == 0 {
+                        consensus.add_empty_block_transaction(faucet.private_key())?;
+                    }
+                    let block = consensus.produce_next_block(account.private_key(), &mut rand::thread_rng())?;
+                    Ok(block.header().clone())
+                })
+                .collect::<anyhow::Result<Vec<_>>>()
+        })
+        .await;
+
+        match headers {
+            Ok(Ok(headers)) => Ok(reply::json(&headers)),
+            Ok(Err(error)) => Err(reject::custom(RestError::Request(format!("failed to mine the blocks: {error}")))),
+            Err(error) => {
+                Err(reject::custom(RestError::Request(format!("failed to mine the blocks (JoinError): {error}"))))
+            }
+        }
+    }
+
+    /// Returns the statistics of the blocks in the given range of heights, retained since the node started.
+    async fn stats_timeseries(
+        range: StatsRange,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+    ) -> Result<impl Reply, Rejection> {
+        match consensus {
+            Some(consensus) => Ok(reply::json(&consensus.stats().range(range.from, range.to))),
+            None => Err(reject::custom(RestError::Request(String::from("no consensus module available")))),
+        }
+    }
+
+    /// Pauses scheduled block production, so that transactions accumulate in the memory pool.
+    /// Blocks may still be produced with `node/mine`.
+    async fn node_pause(consensus: Option<SingleNodeConsensus<N, C>>) -> Result<impl Reply, Rejection> {
+        match consensus {
+            Some(consensus) => {
+                // Note: This blocks until the block being produced, if any, is complete.
+                let paused = tokio::task::spawn_blocking(move || {
+                    consensus.pause();
+                    consensus.is_paused()
+                })
+                .await
+                .map_err(|error| reject::custom(RestError::Request(format!("failed to pause (JoinError): {error}"))))?;
+                info!("Paused block production");
+                Ok(reply::json(&BlockProduction { paused }))
+            }
+            None => Err(reject::custom(RestError::Request(String::from("no consensus module available")))),
+        }
+    }
+
+    /// Resumes scheduled block production.
+    async fn node_resume(consensus: Option<SingleNodeConsensus<N, C>>) -> Result<impl Reply, Rejection> {
+        match consensus {
+            Some(consensus) => {
+                consensus.resume();
+                info!("Resumed block production");
+                Ok(reply::json(&BlockProduction { paused: consensus.is_paused() }))
+            }
+            None => Err(reject::custom(RestError::Request(String::from("no consensus module available")))),
+        }
+    }
+
+    /// Requests the node to shut down. The node rejects further writes, finishes the in-flight block,
+    /// includes the memory pool in a final block, and persists its state before it exits.
+    async fn node_shutdown(shutdown: ShutdownSignal) -> Result<impl Reply, Rejection> {
+        info!("Received a shutdown request");
+        shutdown.request();
+        Ok(reply::with_status(reply::json(&ShutdownStatus { shutting_down: true }), StatusCode::ACCEPTED))
+    }
+
+    /// Sets the timestamp of the next block.
+    async fn node_set_next_block_timestamp(
+        request: NextBlockTimestamp,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+    ) -> Result<impl Reply, Rejection> {
+        match consensus {
+            Some(consensus) => match consensus.set_next_block_timestamp(request.timestamp) {
+                Ok(()) => Ok(reply::json(&NodeTime {
+                    time_offset: consensus.time_offset(),
+                    next_block_timestamp: consensus.next_block_timestamp(),
+                })),
+                Err(error) => Err(reject::custom(RestError::Request(error.to_string()))),
+            },
+            None => Err(reject::custom(RestError::Request(String::from("no consensus module available")))),
+        }
+    }
+
+    /// Advances the time used to timestamp subsequent blocks.
+    async fn node_increase_time(
+        request: IncreaseTime,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+    ) -> Result<impl Reply, Rejection> {
+        match consensus {
+            Some(consensus) => match consensus.increase_time(request.seconds) {
+                Ok(time_offset) => {
+                    Ok(reply::json(&NodeTime { time_offset, next_block_timestamp: consensus.next_block_timestamp() }))
+                }
+                Err(error) => Err(reject::custom(RestError::Request(error.to_string()))),
+            },
+            None => Err(reject::custom(RestError::Request(String::from("no consensus module available")))),
+        }
+    }
+
+    /// Returns the block hash that contains the given `transaction ID`.
+    async fn find_block_hash(transaction_id: N::TransactionID, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.find_block_hash(&transaction_id).or_reject()?))
+    }
+
+    /// Returns the transaction ID that contains the given `program ID`.
+    async fn find_deployment_id(program_id: ProgramID<N>, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.find_deployment_id(&program_id).or_reject()?))
+    }
+
+    /// Returns the transaction ID that contains the given `transition ID`.
+    async fn find_transaction_id(
+        transition_id: N::TransitionID,
+        ledger: Ledger<N, C>,
+    ) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.find_transaction_id(&transition_id).or_reject()?))
+    }
+
+    /// Returns the transition ID that contains the given `input ID` or `output ID`.
+    async fn find_transition_id(input_or_output_id: Field<N>, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.find_transition_id(&input_or_output_id).or_reject()?))
+    }
+
+    /// Returns the events for the given block range, as they were emitted when the blocks were accepted.
+    /// If no ending block height is given, replays up to the latest block, or at most `MAX_BLOCK_RANGE` blocks.
+    async fn events_replay(
+        replay: EventReplay,
+        ledger: Ledger<N, C>,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+    ) -> Result<impl Reply, Rejection> {
+        const MAX_BLOCK_RANGE: u32 = 50;
+
+        let start_height = replay.from_block;
+        let end_height = replay.to_block.unwrap_or_else(|| {
+            ledger.latest_height().saturating_add(1).min(start_height.saturating_add(MAX_BLOCK_RANGE))
+        });
+
+        // Ensure the end height is greater than the start height.
+        if start_height > end_height {
+            return Err(reject::custom(RestError::Request("Invalid block range".to_string())));
+        }
+        // Ensure the block range is bounded.
+        else if end_height - start_height > MAX_BLOCK_RANGE {
+            return Err(reject::custom(RestError::Request(format!(
+                "Cannot replay more than {MAX_BLOCK_RANGE} blocks per call (requested {})",
+                end_height - start_height
+            ))));
+        }
+
+        let mut events = Vec::new();
+        for height in start_height..end_height {
+            events.extend(Event::from_block(&ledger.get_block(height).or_reject()?));
+        }
+
+        Ok(reply::json(&Self::set_deployers(events, &consensus)))
+    }
+
+    /// Streams the events of the blocks accepted after the connection, of the given types, over a WebSocket.
+    async fn events_socket(types: EventTypes, ws: Ws, events: EventFeed<N>) -> Result<impl Reply, Rejection> {
+        let types = types.types.map(|types| types.split(',').map(|kind| kind.trim().to_string()).collect::<Vec<_>>());
+        let mut receiver = events.subscribe();
+        Ok(ws.on_upgrade(move |socket| async move {
+            let (mut sink, _) = socket.split();
+            loop {
+                let event = match receiver.recv().await {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!("A subscriber of the event feed missed {skipped} events");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+                if types.as_ref().map_or(false, |types| !types.iter().any(|kind| kind == event.kind())) {
+                    continue;
+                }
+                let message = Message::text(serde_json::to_string(&event).unwrap_or_default());
+                if sink.send(message).await.is_err() {
+                    break;
+                }
+            }
+            let _ = sink.close().await;
+        }))
+    }
+
+    /// Returns all of the records for the given view key.
+    async fn records_all(request: RecordViewRequest<N>, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        // Fetch the records using the view key.
+        let records: IndexMap<_, _> =
+            ledger.find_records(request.view_key(), RecordsFilter::All).or_reject()?.collect();
+        // Return the records.
+        Ok(reply::with_status(RecordViewResponse::new(records), StatusCode::OK))
+    }
+
+    /// Returns the spent records for the given view key.
+    async fn records_spent(request: RecordViewRequest<N>, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        // Fetch the records using the view key.
+        let records =
+            ledger.find_records(request.view_key(), RecordsFilter::Spent).or_reject()?.collect::<IndexMap<_, _>>();
+        // Return the records.
+        Ok(reply::with_status(RecordViewResponse::new(records), StatusCode::OK))
+    }
+
+    /// Returns the unspent records for the given view key.
+    async fn records_unspent(request: RecordViewRequest<N>, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        // Fetch the records using the view key.
+        let records =
+            ledger.find_records(request.view_key(), RecordsFilter::Unspent).or_reject()?.collect::<IndexMap<_, _>>();
+        // Return the records.
+        Ok(reply::with_status(RecordViewResponse::new(records), StatusCode::OK))
+    }
+
+    /// Returns the statistics of the unspent records for the given view key, and warns if the balance is fragmented.
+    async fn records_stats(request: RecordViewRequest<N>, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        // Fetch the unspent records using the view key.
+        let records = ledger.find_unspent_records(request.view_key()).or_reject()?;
+        // Return the statistics.
+        Ok(RecordStatsResponse::new(records.values().map(|record| ***record.gates())))
+    }
+
+    /// Watches the records of the view key with the given commitments, and returns the ID of the watch.
+    /// The records that are already spent are returned instead of being watched.
+    async fn watch_records(
+        request: WatchRecords<N>,
+        ledger: Ledger<N, C>,
+        watches: WatchRegistry<N>,
+    ) -> Result<impl Reply, Rejection> {
+        if let Some(webhook) = &request.webhook {
+            if !webhook.starts_with("http://") && !webhook.starts_with("https://") {
+                return Err(reject::custom(RestError::Request(format!("invalid webhook URL '{webhook}'"))));
+            }
+        }
+        let mut spent = Vec::new();
+        let mut unspent = Vec::new();
+        for (tag, commitment) in WatchRegistry::tags(&request.view_key, &request.commitments).or_reject()? {
+            if !ledger.contains_commitment(&commitment).or_reject()? {
+                return Err(reject::custom(RestError::Request(format!("unknown commitment '{commitment}'"))));
+            }
+            match ledger.contains_tag(&tag).or_reject()? {
+                true => spent.push(commitment),
+                false => unspent.push((tag, commitment)),
+            }
+        }
+        let watch_id = watches.register(unspent, request.webhook);
+        Ok(reply::json(&WatchCreated { watch_id, spent }))
+    }
+
+    /// Streams the notifications of the given watch over a WebSocket, until the watch is removed.
+    async fn watch_socket(id: String, ws: Ws, watches: WatchRegistry<N>) -> Result<impl Reply, Rejection> {
+        let mut receiver = match watches.subscribe(&id) {
+            Some(receiver) => receiver,
+            None => return Err(reject::custom(RestError::Request(format!("unknown watch '{id}'")))),
+        };
+        Ok(ws.on_upgrade(move |socket| async move {
+            let (mut sink, _) = socket.split();
+            loop {
+                let notification = match receiver.recv().await {
+                    Ok(notification) => notification,
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!("The subscriber of watch '{id}' missed {skipped} notifications");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+                let message = Message::text(serde_json::to_string(&notification).unwrap_or_default());
+                if sink.send(message).await.is_err() {
+                    break;
+                }
+            }
+            let _ = sink.close().await;
+        }))
+    }
+
+    /// Removes the given watch.
+    async fn delete_watch(id: String, watches: WatchRegistry<N>) -> Result<impl Reply, Rejection> {
+        match watches.remove(&id) {
+            true => Ok(reply::with_status(reply::reply(), StatusCode::NO_CONTENT)),
+            false => Err(reject::custom(RestError::Request(format!("unknown watch '{id}'")))),
+        }
+    }
+
+    /// Returns the transactions that spent or received records of the given view key, in chronological order.
+    async fn history(request: RecordViewRequest<N>, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        Ok(reply::json(&ledger.history(request.view_key()).or_reject()?))
+    }
+
+    /// Pours a specified number of credits from the faucet to the recipient.
+    #[allow(clippy::too_many_arguments)]
+    async fn faucet_pour(
+        request: PourRequest<N>,
+        faucet: Account<N>,
+        ledger: Ledger<N, C>,
+        admission: Admission<N, C>,
+        usage: UsageRegistry<N>,
+        policy: Arc<RwLock<FaucetPolicy>>,
+        capture: CaptureBundle,
+    ) -> Result<impl Reply, Rejection> {
+        // Ensure the faucet policy allows the pour.
+        let (address, amount) = (*request.address(), request.amount());
+        if let Err(error) = policy.read().check(amount) {
+            return Err(reject::custom(RestError::Request(error.to_string())));
+        }
+
+        // Construct the transaction.
+        let private_key = *faucet.private_key();
+        let construct = move || ledger.create_transfer(&private_key, &NodeScan, address, amount);
+        let transaction = match admission.construct(address, construct).await {
+            Ok(transaction) => transaction,
+            Err(error) => return Err(reject_error("failed to construct the transaction", error)),
+        };
+
+        // Add the transaction to the memory pool.
+        let capture = capture.entry("pour", &request, None);
+        let transaction_id = transaction.id();
+        match admission.submit(transaction, None, capture, |_| PourResponse::<N>::new(transaction_id)) {
+            Ok(response) => {
+                usage.add_pour(address, amount);
+                Ok(response)
+            }
+            Err(error) => Err(reject_error("failed to add the transaction to the memory pool", error)),
+        }
+    }
+
+    /// Returns the address and the spendable balance of the faucet.
+    async fn faucet_balance(faucet: Account<N>, ledger: Ledger<N, C>) -> Result<impl Reply, Rejection> {
+        // Fetch the unspent records of the faucet.
+        let records = ledger.find_unspent_records(faucet.view_key()).or_reject()?;
+        // Sum the gates in the unspent records.
+        let balance = records.values().map(|record| ***record.gates()).sum::<u64>();
+
+        Ok(reply::json(&FaucetBalance { address: faucet.address(), balance, records: records.len() }))
+    }
+
+    /// Registers an account for server-side signing, and returns its account token.
+    /// A request with an account token returns the token if it is registered, without the private key.
+    async fn accounts_register(
+        request: AccountRegisterRequest<N>,
+        accounts: AccountRegistry<N>,
+    ) -> Result<impl Reply, Rejection> {
+        match request.signer() {
+            Signer::PrivateKey(private_key) => {
+                let (token, address) = accounts.register(*private_key).or_reject()?;
+                Ok(AccountRegisterResponse::new(token, address))
+            }
+            Signer::Token(token) => match accounts.address(token) {
+                Some(address) => Ok(AccountRegisterResponse::new(token.clone(), address)),
+                None => Err(reject::custom(SlingshotError::UnknownAccountToken)),
+            },
+        }
+    }
+
+    /// Returns the private key for the given signer.
+    pub(crate) fn signer_private_key(
+        signer: &Signer<N>,
+        accounts: &AccountRegistry<N>,
+        allow_private_keys: bool,
+    ) -> Result<PrivateKey<N>, Rejection> {
+        match signer {
+            Signer::Token(token) => match accounts.get(token) {
+                Some(private_key) => Ok(private_key),
+                None => Err(reject::custom(SlingshotError::UnknownAccountToken)),
+            },
+            Signer::PrivateKey(private_key) => match allow_private_keys {
+                true => Ok(*private_key),
+                false => Err(reject::custom(RestError::Request(String::from(
+                    "private keys are not accepted by this node, register the account at '/testnet3/accounts/register'",
+                )))),
+            },
+        }
+    }
+
+    /// Returns the record provider of the given record source of a request, which defaults to scanning the ledger.
+    /// The faucet record source requires the given faucet funding, if the request may fund the signer.
+    fn record_provider(
+        source: Option<&RecordSource<N>>,
+        auto_fund: Option<&FaucetAutoFund<N>>,
+    ) -> Result<Box<dyn RecordProvider<N, C>>, Rejection> {
+        admission::record_provider(source, auto_fund)
+            .map_err(|error| reject::custom(RestError::Request(error.to_string())))
+    }
+
+    /// Returns a function that constructs the transaction for the given execute request, to run with the prover.
+    pub(crate) fn execute_transaction(
+        request: &ExecuteRequest<N>,
+        private_key: PrivateKey<N>,
+        records: Box<dyn RecordProvider<N, C>>,
+        ledger: &Ledger<N, C>,
+    ) -> impl 'static + Send + FnOnce() -> anyhow::Result<Transaction<N>> {
+        let ledger = ledger.clone();
+        let (program_id, function_name) = (*request.program_id(), *request.function_name());
+        let (inputs, additional_fee) = (request.values(), request.additional_fee());
+        move || ledger.create_execute(&private_key, &*records, &program_id, &function_name, &inputs?, additional_fee)
+    }
+
+    /// Resolves the record inputs of the given request that are referenced by commitment, to the unspent records
+    /// of the account of the given private key, and returns the values of the inputs.
+    fn resolve_record_inputs(
+        request: &mut ExecuteRequest<N>,
+        private_key: &PrivateKey<N>,
+        ledger: &Ledger<N, C>,
+    ) -> Result<Vec<Value<N>>, Rejection> {
+        // Note: The unspent records are only scanned if an input is referenced by commitment.
+        let view_key = ViewKey::try_from(private_key).or_reject()?;
+        let mut unspent_records = None;
+        let resolved = request.resolve_inputs(|index, commitment| {
+            if unspent_records.is_none() {
+                unspent_records = Some(ledger.find_unspent_records(&view_key)?);
+            }
+            match unspent_records.as_ref().and_then(|records| records.get(commitment)) {
+                Some(record) => Ok(record.clone()),
+                None => Err(SlingshotError::RecordNotFound { index, commitment: commitment.to_string() }.into()),
+            }
+        });
+        match resolved.and_then(|_| request.values()) {
+            Ok(values) => Ok(values),
+            Err(error) => Err(reject_error("invalid inputs", error)),
+        }
+    }
+
+    /// Processes a deploy or execute request in the background, and returns the ID of its job.
+    /// The job constructs the transaction with the prover, adds it to the memory pool, calls `on_submitted`
+    /// once the transaction is accepted into the memory pool, and waits for it to be included in a block.
+    #[allow(clippy::too_many_arguments)]
+    fn spawn_job(
+        kind: &'static str,
+        construct: impl 'static + Send + FnOnce() -> anyhow::Result<Transaction<N>>,
+        on_submitted: impl 'static + Send + FnOnce(),
+        address: Address<N>,
+        jobs: Jobs<N>,
+        ledger: Ledger<N, C>,
+        consensus: SingleNodeConsensus<N, C>,
+        admission: Admission<N, C>,
+        capture: CapturedRequest,
+    ) -> String {
+        let id = jobs.create(kind);
+        let job_id = id.clone();
+        tokio::spawn(async move {
+            // Construct the transaction, once the prover is available.
+            let transaction = {
+                let (jobs, id) = (jobs.clone(), id.clone());
+                admission
+                    .construct(address, move || {
+                        jobs.update(&id, JobStatus::Proving);
+                        construct()
+                    })
+                    .await
+            };
+            let transaction = match transaction {
+                Ok(transaction) => transaction,
+                Err(error) => {
+                    let error = format!("failed to construct the transaction: {error}");
+                    return jobs.update(&id, JobStatus::Failed { error });
+                }
+            };
+
+            // Add the transaction to the memory pool, and assign the next sequence number of the account, if enabled.
+            let transaction_id = transaction.id();
+            let mut sequence = None;
+            let submitted = admission.submit(transaction, Some(address), capture, |assigned| {
+                sequence = assigned;
+                JobCreated { job_id: id.clone() }
+            });
+            if let Err(error) = submitted {
+                let error = format!("failed to add the transaction to the memory pool: {error}");
+                return jobs.update(&id, JobStatus::Failed { error });
+            }
+            on_submitted();
+            jobs.update(&id, JobStatus::Submitted { transaction_id, sequence });
+
+            // Wait for the transaction to be included in a block, or to be rejected.
+            loop {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                let status = match Self::confirmation(&transaction_id, &ledger, &consensus) {
+                    Ok(Some(block_height)) => JobStatus::Confirmed { transaction_id, sequence, block_height },
+                    Ok(None) => continue,
+                    Err(error) => JobStatus::Failed { error: error.to_string() },
+                };
+                return jobs.update(&id, status);
+            }
+        });
+        job_id
+    }
+
+    /// Returns the height of the block that includes the given transaction, or `None` if it is still pending.
+    /// Returns an error if the transaction was rejected, or dropped from the memory pool.
+    fn confirmation(
+        transaction_id: &N::TransactionID,
+        ledger: &Ledger<N, C>,
+        consensus: &SingleNodeConsensus<N, C>,
+    ) -> anyhow::Result<Option<u32>> {
+        // Note: The memory pool is checked first, as the transaction leaves it once it is included in a block.
+        let is_pending = consensus.memory_pool().contains_unconfirmed_transaction(*transaction_id);
+        if let Some(block_hash) = ledger.find_block_hash(transaction_id)? {
+            return Ok(Some(ledger.get_height(&block_hash)?));
+        }
+        if let Some((_, reason)) =
+            consensus.memory_pool().rejected_transactions().into_iter().find(|(id, _)| id == transaction_id)
+        {
+            anyhow::bail!("the transaction was rejected: {reason}");
+        }
+        match is_pending {
+            true => Ok(None),
+            false => anyhow::bail!("the transaction was dropped from the memory pool"),
+        }
+    }
+
+    /// Waits for the given transaction to be included in a block, and returns the height of the block.
+    /// The transaction is checked again each time the given receiver of the event feed announces a block.
+    /// Returns an error if the transaction was rejected, or dropped from the memory pool.
+    async fn wait_for_confirmation(
+        transaction_id: &N::TransactionID,
+        ledger: &Ledger<N, C>,
+        consensus: &SingleNodeConsensus<N, C>,
+        mut receiver: broadcast::Receiver<Event<N>>,
+    ) -> anyhow::Result<u32> {
+        loop {
+            if let Some(block_height) = Self::confirmation(transaction_id, ledger, consensus)? {
+                return Ok(block_height);
+            }
+            // Wait for the next block. If events were missed, the transaction is checked right away.
+            loop {
+                match receiver.recv().await {
+                    Ok(Event::BlockAccepted { .. }) | Err(RecvError::Lagged(_)) => break,
+                    Ok(_) => continue,
+                    Err(RecvError::Closed) => anyhow::bail!("the event feed is closed"),
+                }
+            }
+        }
+    }
+
+    /// Returns the job with the given ID.
+    async fn get_job(id: String, jobs: Jobs<N>) -> Result<impl Reply, Rejection> {
+        match jobs.get(&id) {
+            Some(job) => Ok(reply::json(&job)),
+            None => Err(reject::custom(RestError::Request(format!("unknown job '{id}'")))),
+        }
+    }
+
+    /// Returns the jobs with the given status, or every job, from the oldest to the newest.
+    async fn list_jobs(filter: JobFilter, jobs: Jobs<N>) -> Result<impl Reply, Rejection> {
+        const STATUSES: [&str; 5] = ["queued", "proving", "submitted", "confirmed", "failed"];
+        if let Some(status) = &filter.status {
+            if !STATUSES.contains(&status.as_str()) {
+                return Err(reject::custom(RestError::Request(format!(
+                    "invalid job status '{status}' (expected one of {})",
+                    STATUSES.join(", ")
+                ))));
+            }
+        }
+        Ok(reply::json(&jobs.list(filter.status.as_deref())))
+    }
+
+    /// Transfers credits from the signer to the recipient, and waits for the transfer to be included in a block.
+    /// Returns the recipient record ciphertext and the commitment of the sender's change record.
+    /// Once the transfer is in the memory pool, the request releases its slot among the requests handled at once.
+    async fn transfer_and_wait(
+        request: TransferRequest<N>,
+        accounts: AccountRegistry<N>,
+        allow_private_keys: bool,
+        ledger: Ledger<N, C>,
+        admission: Admission<N, C>,
+        events: EventFeed<N>,
+        slot: Option<RequestSlot>,
+    ) -> Result<impl Reply, Rejection> {
+        /// The default number of seconds to wait for the transfer to be confirmed.
+        const DEFAULT_TIMEOUT_IN_SECS: u64 = 60;
+
+        // Ensure the timeout is bounded.
+        let timeout = request.timeout().unwrap_or(DEFAULT_TIMEOUT_IN_SECS);
+        if timeout > MAX_CONFIRMATION_WAIT_IN_SECS {
+            return Err(reject::custom(RestError::Request(format!(
+                "Cannot wait more than {MAX_CONFIRMATION_WAIT_IN_SECS} seconds for a transfer (requested {timeout})"
+            ))));
+        }
+
+        // Retrieve the private key of the signer, and the provider of the record to spend.
+        let private_key = Self::signer_private_key(request.signer(), &accounts, allow_private_keys)?;
+        let records = admission
+            .record_provider(request.record_source())
+            .map_err(|error| reject::custom(RestError::Request(error.to_string())))?;
+        let consensus = match admission.consensus() {
+            Ok(consensus) => consensus.clone(),
+            Err(error) => return Err(reject::custom(RestError::Request(error.to_string()))),
+        };
+
+        // Construct the transaction.
+        let sender = Address::try_from(&private_key).or_reject()?;
+        let (recipient, amount) = (*request.recipient(), request.amount());
+        let construct = {
+            let ledger = ledger.clone();
+            move || ledger.create_transfer(&private_key, &*records, recipient, amount)
+        };
+        let transaction = match admission.construct(sender, construct).await {
+            Ok(transaction) => transaction,
+            Err(error) => return Err(reject_error("failed to construct the transaction", error)),
+        };
+        let transaction_id = transaction.id();
+
+        // Add the transaction to the memory pool, once subscribed to the event feed, so that no block is missed.
+        // Note: A transfer cannot be replayed from a reproduction bundle, so it is not captured.
+        let receiver = events.subscribe();
+        if let Err(error) = admission.submit(transaction, Some(sender), CapturedRequest::disabled(), |_| ()) {
+            return Err(reject_error("failed to add the transaction to the memory pool", error));
+        }
+
+        // Release the slot of the request, as it takes no resources of the node while it waits.
+        if let Some(slot) = slot {
+            slot.release();
+        }
+
+        // Wait for the transaction to be included in a block.
+        let confirmation = Self::wait_for_confirmation(&transaction_id, &ledger, &consensus, receiver);
+        let block_height = match tokio::time::timeout(Duration::from_secs(timeout), confirmation).await {
+            Ok(Ok(block_height)) => block_height,
+            Ok(Err(error)) => {
+                return Err(reject::custom(RestError::Request(format!(
+                    "transaction '{transaction_id}' was not confirmed: {error}"
+                ))));
+            }
+            Err(_) => {
+                return Err(reject::custom(RestError::Request(format!(
+                    "transaction '{transaction_id}' was not confirmed within {timeout} seconds",
+                ))));
+            }
+        };
+
+        // Retrieve the record outputs of the transfer.
+        let transaction = ledger.get_transaction(transaction_id).or_reject()?;
+        let (recipient_commitment, recipient_record, change_commitment) =
+            records::transfer_outputs(&transaction).or_reject()?;
+
+        Ok(TransferResponse::<N>::new(
+            transaction_id,
+            block_height,
+            recipient_commitment,
+            recipient_record,
+            change_commitment,
+        ))
+    }
+
+    /// Sends a record of the signer to the recipient, with the function of its program that transfers the record.
+    async fn records_send(
+        request: SendRecordRequest<N>,
+        accounts: AccountRegistry<N>,
+        allow_private_keys: bool,
+        ledger: Ledger<N, C>,
+        admission: Admission<N, C>,
+    ) -> Result<impl Reply, Rejection> {
+        // Retrieve the private key of the signer, and the provider of the record that pays the fee.
+        let private_key = Self::signer_private_key(request.signer(), &accounts, allow_private_keys)?;
+        let records = admission
+            .record_provider(request.record_source())
+            .map_err(|error| reject::custom(RestError::Request(error.to_string())))?;
+
+        // Construct the transaction.
+        let sender = Address::try_from(&private_key).or_reject()?;
+        let (program_id, commitment, recipient) = (*request.program_id(), *request.commitment(), *request.recipient());
+        let (amount, function_name, additional_fee) =
+            (request.amount(), request.function_name().copied(), request.additional_fee());
+        let construct = move || {
+            ledger.create_send(
+                &private_key,
+                &*records,
+                &program_id,
+                &commitment,
+                recipient,
+                amount,
+                function_name.as_ref(),
+                additional_fee,
+            )
+        };
+        let (function_name, transaction) = match admission.construct(sender, construct).await {
+            Ok(constructed) => constructed,
+            Err(error) => return Err(reject_error("failed to construct the transaction", error)),
+        };
+        let transaction_id = transaction.id();
+
+        // Add the transaction to the memory pool.
+        // Note: A send cannot be replayed from a reproduction bundle, so it is not captured.
+        let respond = |_| SendRecordResponse::<N>::new(transaction_id, function_name);
+        match admission.submit(transaction, Some(sender), CapturedRequest::disabled(), respond) {
+            Ok(response) => Ok(response),
+            Err(error) => Err(reject_error("failed to add the transaction to the memory pool", error)),
+        }
+    }
+
+    /// Joins the unspent credits records of the signer into the target number of records, in rounds of joins of
+    /// the smallest records, and waits for each round to be included in a block.
+    async fn records_consolidate(
+        request: ConsolidateRequest<N>,
+        accounts: AccountRegistry<N>,
+        allow_private_keys: bool,
+        ledger: Ledger<N, C>,
+        admission: Admission<N, C>,
+    ) -> Result<impl Reply, Rejection> {
+        /// The default number of seconds to wait for each round to be confirmed.
+        const DEFAULT_TIMEOUT_IN_SECS: u64 = 60;
+        /// The maximum number of seconds to wait for each round to be confirmed.
+        const MAX_TIMEOUT_IN_SECS: u64 = 300;
+
+        // Ensure the timeout is bounded.
+        let timeout = request.timeout().unwrap_or(DEFAULT_TIMEOUT_IN_SECS);
+        if timeout > MAX_TIMEOUT_IN_SECS {
+            return Err(reject::custom(RestError::Request(format!(
+                "Cannot wait more than {MAX_TIMEOUT_IN_SECS} seconds for a round of joins (requested {timeout})"
+            ))));
+        }
+        let consensus = match admission.consensus() {
+            Ok(consensus) => consensus.clone(),
+            Err(error) => return Err(reject::custom(RestError::Request(error.to_string()))),
+        };
+
+        // Retrieve the private key of the signer.
+        let private_key = Self::signer_private_key(request.signer(), &accounts, allow_private_keys)?;
+        let address = Address::try_from(&private_key).or_reject()?;
+        let view_key = ViewKey::try_from(&private_key).or_reject()?;
+        let target_records = request.target_records().unwrap_or(1).max(1);
+
+        let mut rounds = Vec::new();
+        while request.max_rounds().map_or(true, |max_rounds| rounds.len() < max_rounds) {
+            // Retrieve the unspent credits records that are not reserved, with the smallest first.
+            let mut records = ledger
+                .find_unspent_credits(&view_key)
+                .or_reject()?
+                .into_iter()
+                .filter(|(commitment, _)| !ledger.reservations().is_reserved(commitment))
+                .map(|(_, record)| record)
+                .collect::<Vec<_>>();
+            records.sort_by_key(|record| ***record.gates());
+
+            // Pair the smallest records, without joining below the target number of records.
+            let num_joins = (records.len() / 2).min(records.len().saturating_sub(target_records));
+            if num_joins == 0 {
+                break;
+            }
+            let mut records = records.into_iter();
+            let pairs = (0..num_joins).filter_map(|_| Some((records.next()?, records.next()?))).collect::<Vec<_>>();
+            let num_records = num_joins + records.len();
+
+            // Construct the joins.
+            let transactions = {
+                let ledger = ledger.clone();
+                let construct = move || {
+                    cfg_into_iter!(pairs)
+                        .map(|(first, second)| ledger.create_join(&private_key, first, second))
+                        .collect::<anyhow::Result<Vec<_>>>()
+                };
+                admission.construct(address, construct).await
+            };
+            let transactions = match transactions {
+                Ok(transactions) => transactions,
+                Err(error) => return Err(reject_error("failed to construct the joins", error)),
+            };
+            let transaction_ids = transactions.iter().map(|transaction| transaction.id()).collect::<Vec<_>>();
+
+            // Add the joins to the memory pool, and wait for them to be included in a block.
+            let block_height =
+                Self::confirm_round(transactions, address, &ledger, &admission, &consensus, timeout).await?;
+            rounds.push(ConsolidationRound { transaction_ids, block_height, num_records });
+        }
+
+        // Report the remaining credits records.
+        let records = ledger.find_unspent_credits(&view_key).or_reject()?;
+        let balance = records.values().map(|record| ***record.gates()).sum::<u64>();
+        Ok(ConsolidateResponse::<N>::new(rounds, records.len(), balance))
+    }
+
+    /// Splits the unspent credits records of the signer until it has the requested number of records with the
+    /// requested number of gates, in rounds of splits, and waits for each round to be included in a block.
+    async fn records_split(
+        request: SplitRequest<N>,
+        accounts: AccountRegistry<N>,
+        allow_private_keys: bool,
+        ledger: Ledger<N, C>,
+        admission: Admission<N, C>,
+    ) -> Result<impl Reply, Rejection> {
+        /// The default number of seconds to wait for each round to be confirmed.
+        const DEFAULT_TIMEOUT_IN_SECS: u64 = 60;
+        /// The maximum number of seconds to wait for each round to be confirmed.
+        const MAX_TIMEOUT_IN_SECS: u64 = 300;
+
+        // Ensure the timeout is bounded.
+        let timeout = request.timeout().unwrap_or(DEFAULT_TIMEOUT_IN_SECS);
+        if timeout > MAX_TIMEOUT_IN_SECS {
+            return Err(reject::custom(RestError::Request(format!(
+                "Cannot wait more than {MAX_TIMEOUT_IN_SECS} seconds for a round of splits (requested {timeout})"
+            ))));
+        }
+        let consensus = match admission.consensus() {
+            Ok(consensus) => consensus.clone(),
+            Err(error) => return Err(reject::custom(RestError::Request(error.to_string()))),
+        };
+
+        // Retrieve the private key of the signer.
+        let private_key = Self::signer_private_key(request.signer(), &accounts, allow_private_keys)?;
+        let address = Address::try_from(&private_key).or_reject()?;
+        let view_key = ViewKey::try_from(&private_key).or_reject()?;
+        let (amount, count) = (request.amount(), request.count());
+
+        let mut rounds = Vec::new();
+        while request.max_rounds().map_or(true, |max_rounds| rounds.len() < max_rounds) {
+            // Determine the splits of the next round.
+            let splits = match ledger.plan_splits(&view_key, count, amount) {
+                Ok(splits) if splits.is_empty() => break,
+                Ok(splits) => splits,
+                Err(error) => return Err(reject_error("failed to plan the splits", error)),
+            };
+
+            // Construct the splits.
+            let transactions = {
+                let ledger = ledger.clone();
+                let construct = move || {
+                    cfg_into_iter!(splits)
+                        .map(|(record, amount)| ledger.create_split(&private_key, record, amount))
+                        .collect::<anyhow::Result<Vec<_>>>()
+                };
+                admission.construct(address, construct).await
+            };
+            let transactions = match transactions {
+                Ok(transactions) => transactions,
+                Err(error) => return Err(reject_error("failed to construct the splits", error)),
+            };
+            let transaction_ids = transactions.iter().map(|transaction| transaction.id()).collect::<Vec<_>>();
+
+            // Add the splits to the memory pool, and wait for them to be included in a block.
+            let block_height =
+                Self::confirm_round(transactions, address, &ledger, &admission, &consensus, timeout).await?;
+            rounds.push(SplitRound { transaction_ids, block_height });
+        }
+
+        // Report the credits records with the requested number of gates.
+        let commitments = ledger
+            .find_unspent_credits(&view_key)
+            .or_reject()?
+            .into_iter()
+            .filter(|(_, record)| ***record.gates() == amount)
+            .map(|(commitment, _)| commitment)
+            .collect();
+        Ok(SplitResponse::<N>::new(rounds, commitments))
+    }
+
+    /// Adds the given transactions of a round to the memory pool, and waits for all of them to be included in
+    /// a block. Returns the height of the last block that includes one of them.
+    async fn confirm_round(
+        transactions: Vec<Transaction<N>>,
+        signer: Address<N>,
+        ledger: &Ledger<N, C>,
+        admission: &Admission<N, C>,
+        consensus: &SingleNodeConsensus<N, C>,
+        timeout: u64,
+    ) -> Result<u32, Rejection> {
+        // Add the transactions to the memory pool.
+        // Note: The rounds cannot be replayed from a reproduction bundle, so they are not captured.
+        let transaction_ids = transactions.iter().map(|transaction| transaction.id()).collect::<Vec<_>>();
+        for transaction in transactions {
+            if let Err(error) = admission.submit(transaction, Some(signer), CapturedRequest::disabled(), |_| ()) {
+                return Err(reject_error("failed to add the transaction to the memory pool", error));
+            }
+        }
+
+        // Wait for the transactions to be included in a block.
+        let start = Instant::now();
+        let mut block_height = 0;
+        for transaction_id in &transaction_ids {
+            loop {
+                match Self::confirmation(transaction_id, ledger, consensus) {
+                    Ok(Some(height)) => {
+                        block_height = block_height.max(height);
+                        break;
+                    }
+                    Ok(None) => (),
+                    Err(error) => return Err(reject_error(&format!("transaction '{transaction_id}' failed"), error)),
+                }
+                if start.elapsed() >= Duration::from_secs(timeout) {
+                    return Err(reject::custom(RestError::Request(format!(
+                        "transaction '{transaction_id}' was not confirmed within {timeout} seconds",
+                    ))));
+                }
+                tokio::time::sleep(Duration::from_millis(500)).await;
+            }
+        }
+        Ok(block_height)
+    }
+
+    /// Deploys a program to the ledger.
+    /// If the request is asynchronous, returns a job ID, and deploys the program in the background.
+    #[allow(clippy::too_many_arguments)]
+    async fn program_deploy(
+        request: DeployRequest<N>,
+        mode: SubmitMode,
+        accounts: AccountRegistry<N>,
+        allow_private_keys: bool,
+        ledger: Ledger<N, C>,
+        admission: Admission<N, C>,
+        jobs: Jobs<N>,
+        program_metadata: ProgramMetadataRegistry<N>,
+        capture: CaptureBundle,
+    ) -> Result<reply::Response, Rejection> {
+        // Retrieve the private key of the signer, and the provider of the record that pays the fee.
+        let private_key = Self::signer_private_key(request.signer(), &accounts, allow_private_keys)?;
+        let records = admission
+            .record_provider(request.record_source())
+            .map_err(|error| reject::custom(RestError::Request(error.to_string())))?;
+
+        // Ensure the metadata of the program is valid.
+        if let Some(metadata) = request.metadata() {
+            if let Err(error) = metadata.check(request.program()) {
+                return Err(reject::custom(RestError::Request(format!("invalid program metadata: {error}"))));
+            }
+        }
+
+        // Construct the transaction.
+        let (program, additional_fee) = (request.program().clone(), request.additional_fee());
+        let construct = {
+            let ledger = ledger.clone();
+            move || ledger.create_deploy(&private_key, &*records, &program, additional_fee)
+        };
+
+        // Retrieve the address of the signer, which submits the transaction.
+        let address = Address::try_from(&private_key).or_reject()?;
+        let capture = capture.entry("deploy", &request, Some(address.to_string()));
+
+        // If the request is asynchronous, process it in the background.
+        if mode.is_async {
+            let consensus = match admission.consensus() {
+                Ok(consensus) => consensus.clone(),
+                Err(error) => return Err(reject::custom(RestError::Request(error.to_string()))),
+            };
+            // Attach the metadata to the program once the transaction is accepted into the memory pool.
+            let (program_id, metadata) = (*request.program().id(), request.metadata().cloned());
+            let attach = move || program_metadata.set(program_id, metadata);
+            let job_id =
+                Self::spawn_job("deploy", construct, attach, address, jobs, ledger, consensus, admission, capture);
+            return Ok(reply::json(&JobCreated { job_id }).into_response());
+        }
+
+        // Construct the transaction, and report its cost, on the prover.
+        let construct = {
+            let ledger = ledger.clone();
+            move || {
+                let transaction = construct()?;
+                match ledger.deployment_cost(&transaction) {
+                    Ok(cost) => Ok((transaction, cost)),
+                    // Release the records of the transaction, as it is not submitted.
+                    Err(error) => {
+                        ledger.reservations().release(&transaction);
+                        Err(error)
+                    }
+                }
+            }
+        };
+        let (transaction, cost) = match admission.construct(address, construct).await {
+            Ok(result) => result,
+            Err(error) => return Err(reject_error("failed to construct the transaction", error)),
+        };
+
+        // Retrieve the transaction ID.
+        let transaction_id = transaction.id();
+
+        // Add the transaction to the memory pool, with the next sequence number of the account, if enabled.
+        let respond = |sequence| DeployResponse::<N>::new(transaction_id, sequence, cost);
+        match admission.submit(transaction, Some(address), capture, respond) {
+            Ok(response) => {
+                // Attach the metadata to the program.
+                program_metadata.set(*request.program().id(), request.metadata().cloned());
+                Ok(response.into_response())
+            }
+            Err(error) => Err(reject_error("failed to add the transaction to the memory pool", error)),
+        }
+    }
+
+    /// Executes a program on the ledger.
+    /// If the request is asynchronous, returns a job ID, and executes the program in the background.
+    #[allow(clippy::too_many_arguments)]
+    async fn program_execute(
+        mut request: ExecuteRequest<N>,
+        mode: SubmitMode,
+        accounts: AccountRegistry<N>,
+        allow_private_keys: bool,
+        ledger: Ledger<N, C>,
+        admission: Admission<N, C>,
+        jobs: Jobs<N>,
+        capture: CaptureBundle,
+    ) -> Result<reply::Response, Rejection> {
+        // Retrieve the private key of the signer, and the provider of the record that pays the fee.
+        let private_key = Self::signer_private_key(request.signer(), &accounts, allow_private_keys)?;
+        let records = admission
+            .record_provider(request.record_source())
+            .map_err(|error| reject::custom(RestError::Request(error.to_string())))?;
+
+        // Resolve the record inputs referenced by commitment, and ensure the inputs match the function signature,
+        // before the request waits for the prover.
+        let inputs = Self::resolve_record_inputs(&mut request, &private_key, &ledger)?;
+        if let Err(error) = ledger.check_inputs(request.program_id(), request.function_name(), &inputs) {
+            return Err(reject_error("invalid inputs", error));
+        }
+        // Ensure the record inputs are not spent, e.g. if they were fetched from a stale cache.
+        if let Err(error) =
+            ledger.check_unspent_inputs(&private_key, request.program_id(), request.function_name(), &inputs)
+        {
+            return Err(reject_error("invalid inputs", error));
+        }
+
+        // Construct the transaction.
+        let construct = Self::execute_transaction(&request, private_key, records, &ledger);
+
+        // Retrieve the address of the signer, which submits the transaction.
+        let address = Address::try_from(&private_key).or_reject()?;
+        let capture = capture.entry("execute", &request, Some(address.to_string()));
+
+        // If the request is asynchronous, process it in the background.
+        if mode.is_async {
+            let consensus = match admission.consensus() {
+                Ok(consensus) => consensus.clone(),
+                Err(error) => return Err(reject::custom(RestError::Request(error.to_string()))),
+            };
+            let job_id =
+                Self::spawn_job("execute", construct, || (), address, jobs, ledger, consensus, admission, capture);
+            return Ok(reply::json(&JobCreated { job_id }).into_response());
+        }
+
+        let transaction = match admission.construct(address, construct).await {
+            Ok(transaction) => transaction,
+            Err(error) => return Err(reject_error("failed to construct the transaction", error)),
+        };
+
+        // Retrieve the transaction ID.
+        let transaction_id = transaction.id();
+
+        // Add the transaction to the memory pool, with the next sequence number of the account, if enabled.
+        let respond = |sequence| ExecuteResponse::<N>::new(transaction_id, sequence);
+        match admission.submit(transaction, Some(address), capture, respond) {
+            Ok(response) => Ok(response.into_response()),
+            Err(error) => Err(reject_error("failed to add the transaction to the memory pool", error)),
+        }
+    }
+
+    /// Evaluates a program function on the current ledger state, without creating a transaction.
+    async fn program_evaluate(
+        request: EvaluateRequest<N>,
+        accounts: AccountRegistry<N>,
+        allow_private_keys: bool,
+        ledger: Ledger<N, C>,
+    ) -> Result<impl Reply, Rejection> {
+        // Retrieve the private key of the signer.
+        let private_key = Self::signer_private_key(request.signer(), &accounts, allow_private_keys)?;
+
+        // Evaluate the function.
+        match ledger.evaluate(&private_key, request.program_id(), request.function_name(), request.inputs()) {
+            Ok(outputs) => Ok(EvaluateResponse::<N>::new(outputs)),
+            Err(error) => Err(reject_error("failed to evaluate the function", error)),
+        }
+    }
+
+    /// Executes a program function, and returns the time spent on each stage, the constraints of each transition,
+    /// and the estimated fee. The transaction is not submitted.
+    async fn program_profile(
+        request: EvaluateRequest<N>,
+        accounts: AccountRegistry<N>,
+        allow_private_keys: bool,
+        ledger: Ledger<N, C>,
+        prover: Prover,
+        usage: UsageRegistry<N>,
+    ) -> Result<impl Reply, Rejection> {
+        // Retrieve the private key of the signer.
+        let private_key = Self::signer_private_key(request.signer(), &accounts, allow_private_keys)?;
+        let address = Address::try_from(&private_key).or_reject()?;
+
+        // Profile the execution with the prover, as it constructs a transaction.
+        let (program_id, function_name) = (*request.program_id(), *request.function_name());
+        let inputs = request.inputs().to_vec();
+        let profile = prover
+            .run(usage.meter(address, move || ledger.profile(&private_key, &program_id, &function_name, &inputs)))
+            .await;
+        match profile {
+            Ok(profile) => Ok(reply::json(&profile)),
+            Err(error) => Err(reject_error("failed to profile the execution", error)),
+        }
+    }
+
+    /// Returns the minimum fee that covers the storage of the given deployment or execution.
+    async fn fee_estimate(
+        request: FeeEstimateRequest<N>,
+        accounts: AccountRegistry<N>,
+        allow_private_keys: bool,
+        ledger: Ledger<N, C>,
+        prover: Prover,
+        usage: UsageRegistry<N>,
+    ) -> Result<impl Reply, Rejection> {
+        // Retrieve the private key of the signer.
+        let private_key = Self::signer_private_key(request.signer(), &accounts, allow_private_keys)?;
+        let address = Address::try_from(&private_key).or_reject()?;
+
+        // Construct the transaction, with a fee transition, as its size does not depend on the fee amount.
+        let estimate = request.estimate().clone();
+        let transaction = prover
+            .run(usage.meter(address, move || {
+                let transaction = match estimate {
+                    FeeEstimate::Deploy(program) => ledger.create_deploy(&private_key, &NodeScan, &program, 0),
+                    FeeEstimate::Execute(program_id, function_name, inputs) => {
+                        ledger.create_execute(&private_key, &NodeScan, &program_id, &function_name, &inputs, Some(0))
+                    }
+                }?;
+                // Release the records of the transaction, as an estimate is not submitted.
+                ledger.reservations().release(&transaction);
+                Ok(transaction)
+            }))
+            .await;
+        let transaction = match transaction {
+            Ok(transaction) => transaction,
+            Err(error) => return Err(reject_error("failed to construct the transaction", error)),
+        };
+
+        let (size_in_bytes, fee) = storage_fee(&transaction).or_reject()?;
+        Ok(FeeEstimateResponse::new(size_in_bytes, fee))
+    }
+
+    /// Builds and verifies the transaction for the given execute request, and returns it with the reason it would be
+    /// rejected, if any. The transaction is not added to the memory pool.
+    /// The transaction of an identical request at the same state root is reused, rather than constructed again.
+    #[allow(clippy::too_many_arguments)]
+    async fn transaction_simulate(
+        mut request: ExecuteRequest<N>,
+        accounts: AccountRegistry<N>,
+        allow_private_keys: bool,
+        ledger: Ledger<N, C>,
+        prover: Prover,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+        simulations: SimulationCache<N>,
+        usage: UsageRegistry<N>,
+    ) -> Result<impl Reply, Rejection> {
+        // Retrieve the private key of the signer.
+        let private_key = Self::signer_private_key(request.signer(), &accounts, allow_private_keys)?;
+        let address = Address::try_from(&private_key).or_reject()?;
+        // Note: A simulation does not fund the signer from the faucet, as it must not change the ledger.
+        let records = Self::record_provider(request.record_source(), None)?;
+        let inputs = Self::resolve_record_inputs(&mut request, &private_key, &ledger)?;
+
+        // Retrieve the transaction of an identical simulation at the latest state root, or construct it.
+        // Note: The simulations of a request with a record source are not cached, as the fee record may differ.
+        let key = SimulationKey::new(
+            *request.program_id(),
+            *request.function_name(),
+            &inputs,
+            address,
+            request.additional_fee().unwrap_or(0),
+        );
+        let state_root = ledger.latest_state_root();
+        let cached = request.record_source().is_none().then(|| simulations.get(&key, state_root)).flatten();
+        let transaction = match cached {
+            Some(transaction) => {
+                debug!("Reusing the simulated transaction '{}'", transaction.id());
+                transaction
+            }
+            None => {
+                let construct =
+                    usage.meter(address, Self::execute_transaction(&request, private_key, records, &ledger));
+                match prover.run(construct).await {
+                    Ok(transaction) => {
+                        // Release the records of the transaction, as a simulation is not submitted.
+                        ledger.reservations().release(&transaction);
+                        // Cache the transaction, unless a block was added while it was constructed.
+                        if request.record_source().is_none() && ledger.latest_state_root() == state_root {
+                            simulations.insert(key, state_root, transaction.clone());
+                        }
+                        transaction
+                    }
+                    Err(error) => return Err(reject_error("failed to construct the transaction", error)),
+                }
+            }
+        };
+
+        // Check the transaction against the current ledger state and the memory pool.
+        let error = match consensus {
+            Some(consensus) => {
+                consensus.check_unconfirmed_transaction(&transaction).err().map(|error| error.to_string())
+            }
+            None => return Err(reject::custom(RestError::Request(String::from("no memory pool available")))),
+        };
+
+        let fee = transaction.fee().or_reject()?;
+        let size_in_bytes = transaction.to_bytes_le().or_reject()?.len();
+        Ok(SimulateResponse::<N>::new(transaction, fee, size_in_bytes, error))
+    }
+
+    /// Broadcasts a transaction, constructed outside of the node, to the memory pool.
+    /// A node that follows a peer does not produce blocks, so it forwards the transaction to the peer instead.
+    async fn transaction_broadcast(
+        transaction: Transaction<N>,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+        peer: Option<Peer>,
+    ) -> Result<impl Reply, Rejection> {
+        // Forward the transaction to the peer, if the node follows one.
+        if let Some(peer) = peer {
+            return match peer.broadcast(&transaction).await {
+                Ok(transaction_id) => Ok(reply::json(&transaction_id)),
+                Err(error) => Err(reject_error("failed to forward the transaction to the peer", error)),
+            };
+        }
+
+        // Retrieve the transaction ID.
+        let transaction_id = transaction.id();
+
+        // Add the transaction to the memory pool.
+        match consensus {
+            Some(consensus) => match consensus.add_unconfirmed_transaction(transaction) {
+                Ok(_) => Ok(reply::json(&transaction_id)),
+                Err(error) => Err(reject_error("failed to add the transaction to the memory pool", error)),
+            },
+            None => Err(reject::custom(RestError::Request(String::from("no memory pool available")))),
+        }
+    }
+
+    /// Checks a transaction, constructed outside of the node, against the ledger state and the memory pool,
+    /// without adding it to the memory pool.
+    async fn transaction_verify(
+        transaction: Transaction<N>,
+        consensus: Option<SingleNodeConsensus<N, C>>,
+    ) -> Result<impl Reply, Rejection> {
+        match consensus {
+            Some(consensus) => Ok(reply::json(&TransactionVerification::<N> {
+                transaction_id: transaction.id(),
+                error: consensus.check_unconfirmed_transaction(&transaction).err().map(|error| error.to_string()),
+            })),
+            None => Err(reject::custom(RestError::Request(String::from("no memory pool available")))),
+        }
+    }
+
+    /// Returns the proving time, transactions, and faucet pours metered for each account.
+    async fn get_usage(usage: UsageRegistry<N>) -> Result<impl Reply, Rejection> {
+        let accounts = usage
+            .accounts()
+            .into_iter()
+            .map(|(address, usage)| AccountUsageReport {
+                address,
+                proving_seconds: usage.proving_time.as_secs_f64(),
+                transactions: usage.transactions,
+                faucet_pours: usage.faucet_pours,
+                faucet_gates: usage.faucet_gates,
+            })
+            .collect::<Vec<_>>();
+        Ok(reply::json(&UsageReport { accounts }))
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm::prelude::{Address, Network};
+
+use parking_lot::RwLock;
+use std::{collections::HashMap, sync::Arc};
+
+/// The sequence numbers of the transactions submitted by each account, tracked by the node in development mode.
+/// Note that Aleo accounts have no nonce; these numbers only order the submissions to this node.
+#[derive(Clone, Debug)]
+pub struct AccountSequences<N: Network> {
+    /// The map of account addresses to the sequence number of their next submission.
+    sequences: Arc<RwLock<HashMap<Address<N>, u64>>>,
+}
+
+impl<N: Network> Default for AccountSequences<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> AccountSequences<N> {
+    /// Initializes a new instance of the account sequences.
+    pub fn new() -> Self {
+        Self { sequences: Default::default() }
+    }
+
+    /// Returns the sequence number of the next submission from the given account.
+    pub fn next(&self, address: &Address<N>) -> u64 {
+        self.sequences.read().get(address).copied().unwrap_or_default()
+    }
+
+    /// Assigns the next sequence number to a submission from the given account, and returns it.
+    pub fn increment(&self, address: Address<N>) -> u64 {
+        let mut sequences = self.sequences.write();
+        let sequence = sequences.entry(address).or_default();
+        *sequence += 1;
+        *sequence - 1
+    }
+}
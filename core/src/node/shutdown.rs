@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::SlingshotError;
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use tokio::sync::Notify;
+use warp::{http::Method, reject, Filter, Rejection};
+
+/// The shutdown signal of a node, shared by its background tasks and its REST server.
+#[derive(Clone, Default)]
+pub struct ShutdownSignal {
+    /// Set once the node is shutting down, after which the background tasks stop and writes are rejected.
+    flag: Arc<AtomicBool>,
+    /// Notified when a shutdown is requested through the REST server.
+    requested: Arc<Notify>,
+}
+
+impl ShutdownSignal {
+    /// Initializes a new shutdown signal.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the flag that is set once the node is shutting down, for the tasks that poll it.
+    pub fn flag(&self) -> Arc<AtomicBool> {
+        self.flag.clone()
+    }
+
+    /// Returns `true` if the node is shutting down.
+    pub fn is_shutting_down(&self) -> bool {
+        self.flag.load(Ordering::SeqCst)
+    }
+
+    /// Marks the node as shutting down.
+    pub fn start(&self) {
+        self.flag.store(true, Ordering::SeqCst);
+    }
+
+    /// Requests the node to shut down. The request is kept until the node waits for it.
+    pub fn request(&self) {
+        self.requested.notify_one();
+    }
+
+    /// Waits until a shutdown is requested.
+    pub async fn requested(&self) {
+        self.requested.notified().await
+    }
+}
+
+/// Returns a filter that rejects the requests that may change the state of the node, i.e. every request other
+/// than a `GET` request, once the node is shutting down.
+pub(crate) fn reject_writes(shutdown: ShutdownSignal) -> impl Filter<Extract = (), Error = Rejection> + Clone {
+    warp::method()
+        .and_then(move |method: Method| {
+            let shutdown = shutdown.clone();
+            async move {
+                match method != Method::GET && shutdown.is_shutting_down() {
+                    true => Err(reject::custom(SlingshotError::ShuttingDown)),
+                    false => Ok(()),
+                }
+            }
+        })
+        .untuple_one()
+}
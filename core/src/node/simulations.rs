@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm::prelude::{Address, Field, Identifier, Network, ProgramID, Transaction, Value};
+
+use parking_lot::RwLock;
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+/// The maximum number of simulations cached for a state root.
+const MAX_CACHED_SIMULATIONS: usize = 1_024;
+
+/// The key of a simulation: the function, a hash of its inputs, the signer, and the additional fee.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SimulationKey<N: Network> {
+    program_id: ProgramID<N>,
+    function_name: Identifier<N>,
+    inputs_hash: u64,
+    signer: Address<N>,
+    additional_fee: u64,
+}
+
+impl<N: Network> SimulationKey<N> {
+    /// Initializes the key of a simulation of the given function.
+    pub fn new(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        signer: Address<N>,
+        additional_fee: u64,
+    ) -> Self {
+        let mut hasher = DefaultHasher::new();
+        for input in inputs {
+            input.to_string().hash(&mut hasher);
+        }
+        Self { program_id, function_name, inputs_hash: hasher.finish(), signer, additional_fee }
+    }
+}
+
+/// A cache of the transactions constructed by simulations, for the state root they were constructed at.
+/// Repeated simulations of the same request (e.g. on each keystroke of a form) return the cached transaction,
+/// until a new block advances the state root, which invalidates every cached simulation.
+#[derive(Clone)]
+pub struct SimulationCache<N: Network> {
+    /// The state root of the cached simulations, and the cached transactions.
+    entries: Arc<RwLock<(Option<Field<N>>, HashMap<SimulationKey<N>, Transaction<N>>)>>,
+}
+
+impl<N: Network> Default for SimulationCache<N> {
+    fn default() -> Self {
+        Self { entries: Default::default() }
+    }
+}
+
+impl<N: Network> SimulationCache<N> {
+    /// Returns the cached transaction of the given simulation at the given state root, if it exists.
+    pub fn get(&self, key: &SimulationKey<N>, state_root: Field<N>) -> Option<Transaction<N>> {
+        let entries = self.entries.read();
+        match entries.0 == Some(state_root) {
+            true => entries.1.get(key).cloned(),
+            false => None,
+        }
+    }
+
+    /// Caches the transaction of the given simulation at the given state root.
+    /// If the state root advanced, the simulations cached at the previous state root are discarded.
+    pub fn insert(&self, key: SimulationKey<N>, state_root: Field<N>, transaction: Transaction<N>) {
+        let mut entries = self.entries.write();
+        if entries.0 != Some(state_root) {
+            *entries = (Some(state_root), HashMap::new());
+        }
+        if entries.1.len() < MAX_CACHED_SIMULATIONS {
+            entries.1.insert(key, transaction);
+        }
+    }
+}
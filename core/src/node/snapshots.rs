@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::node::{ChainFile, Ledger};
+
+use snarkvm::prelude::{ConsensusStorage, Network};
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use time::OffsetDateTime;
+
+/// The extension of snapshot files.
+const SNAPSHOT_EXTENSION: &str = "snapshot";
+
+/// The default directory of the snapshots.
+pub const DEFAULT_SNAPSHOT_DIR: &str = ".slingshot/snapshots";
+
+/// The configuration of the automatic snapshots of the ledger.
+#[derive(Clone, Debug)]
+pub struct SnapshotConfig {
+    /// The directory the snapshots are written to.
+    pub directory: PathBuf,
+    /// The number of seconds between snapshots.
+    pub interval: u64,
+    /// The number of most recent snapshots that are retained.
+    pub retain: usize,
+}
+
+/// A snapshot of the ledger, which is a chain file of every block up to its height.
+/// The snapshot is named `<timestamp>-<height>.snapshot`, so that the snapshots sort from oldest to newest.
+#[derive(Clone, Debug, Serialize)]
+pub struct Snapshot {
+    /// The name of the snapshot.
+    pub name: String,
+    /// The path of the snapshot.
+    pub path: PathBuf,
+    /// The height of the latest block in the snapshot.
+    pub height: u32,
+    /// The UNIX timestamp at which the snapshot was taken.
+    pub timestamp: i64,
+    /// The size of the snapshot, in bytes.
+    pub size_in_bytes: u64,
+}
+
+impl Snapshot {
+    /// Writes a snapshot of the given ledger to the given directory, and returns it.
+    pub fn write<N: Network, C: ConsensusStorage<N>>(ledger: &Ledger<N, C>, directory: &Path) -> Result<Self> {
+        let height = ledger.latest_height();
+        let timestamp = OffsetDateTime::now_utc().unix_timestamp();
+        let name = format!("{timestamp}-{height}.{SNAPSHOT_EXTENSION}");
+
+        std::fs::create_dir_all(directory)?;
+        let path = directory.join(&name);
+        let blocks = (0..=height).map(|block_height| ledger.get_block(block_height));
+        let size_in_bytes = ChainFile::write(&path, height + 1, blocks)?;
+        Ok(Self { name, path, height, timestamp, size_in_bytes })
+    }
+
+    /// Returns the snapshots in the given directory, from oldest to newest.
+    pub fn list(directory: &Path) -> Result<Vec<Self>> {
+        if !directory.exists() {
+            return Ok(vec![]);
+        }
+        let mut snapshots = Vec::new();
+        for entry in std::fs::read_dir(directory)? {
+            let path = entry?.path();
+            if path.extension().and_then(|extension| extension.to_str()) != Some(SNAPSHOT_EXTENSION) {
+                continue;
+            }
+            // Parse the timestamp and the height from the name of the snapshot.
+            let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default().to_string();
+            let stem = name.trim_end_matches(&format!(".{SNAPSHOT_EXTENSION}"));
+            let (timestamp, height) =
+                match stem.split_once('-').map(|(timestamp, height)| (timestamp.parse(), height.parse())) {
+                    Some((Ok(timestamp), Ok(height))) => (timestamp, height),
+                    _ => continue,
+                };
+            let size_in_bytes = std::fs::metadata(&path)?.len();
+            snapshots.push(Self { name, path, height, timestamp, size_in_bytes });
+        }
+        snapshots.sort_by_key(|snapshot| (snapshot.timestamp, snapshot.height));
+        Ok(snapshots)
+    }
+
+    /// Removes all but the given number of most recent snapshots in the given directory.
+    pub fn prune(directory: &Path, retain: usize) -> Result<()> {
+        let snapshots = Self::list(directory)?;
+        for snapshot in snapshots.iter().take(snapshots.len().saturating_sub(retain)) {
+            std::fs::remove_file(&snapshot.path)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the path of the snapshot with the given name in the given directory,
+    /// or the given path, if it is the path of a snapshot file.
+    pub fn resolve(directory: &Path, name: &str) -> Result<PathBuf> {
+        let path = PathBuf::from(name);
+        if path.is_file() {
+            return Ok(path);
+        }
+        let path = directory.join(name);
+        match path.is_file() {
+            true => Ok(path),
+            false => Err(anyhow!("The snapshot '{name}' does not exist in '{}'", directory.display())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_and_prune() {
+        let directory = std::env::temp_dir().join(format!("slingshot-snapshots-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        for name in ["20-3.snapshot", "10-5.snapshot", "30-1.snapshot", "40-2.partial", "notes.txt"] {
+            std::fs::write(directory.join(name), []).unwrap();
+        }
+
+        // The snapshots are ordered by the time they were taken, and other files are ignored.
+        let snapshots = Snapshot::list(&directory).unwrap();
+        assert_eq!(snapshots.iter().map(|snapshot| snapshot.height).collect::<Vec<_>>(), vec![5, 3, 1]);
+
+        // Only the most recent snapshots are retained.
+        Snapshot::prune(&directory, 2).unwrap();
+        let snapshots = Snapshot::list(&directory).unwrap();
+        assert_eq!(
+            snapshots.iter().map(|snapshot| snapshot.name.as_str()).collect::<Vec<_>>(),
+            vec!["20-3.snapshot", "30-1.snapshot"]
+        );
+        assert_eq!(Snapshot::resolve(&directory, "30-1.snapshot").unwrap(), directory.join("30-1.snapshot"));
+        assert!(Snapshot::resolve(&directory, "10-5.snapshot").is_err());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
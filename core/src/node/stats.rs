@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm::prelude::{Block, Network};
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, sync::Arc, time::Duration};
+
+/// The number of blocks whose statistics are retained.
+pub const STATS_CAPACITY: usize = 10_000;
+
+/// The statistics of a block, recorded when the node added it to the ledger.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BlockStats {
+    /// The block height.
+    pub height: u32,
+    /// The UNIX timestamp of the block.
+    pub timestamp: i64,
+    /// The number of transactions in the block.
+    pub transactions: usize,
+    /// The total fees of the transactions in the block, in gates.
+    pub total_fees: i64,
+    /// The number of milliseconds spent proposing (or checking) the block and adding it to the ledger.
+    pub production_ms: u64,
+    /// The number of unconfirmed transactions in the memory pool when the block was proposed.
+    pub mempool_depth: usize,
+}
+
+/// The statistics of the latest blocks added to the ledger, in a ring buffer of `STATS_CAPACITY` blocks.
+#[derive(Clone, Debug)]
+pub struct ChainStats {
+    /// The statistics of each block, from the oldest to the newest.
+    blocks: Arc<RwLock<VecDeque<BlockStats>>>,
+}
+
+impl Default for ChainStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChainStats {
+    /// Initializes a new instance of the chain statistics.
+    pub fn new() -> Self {
+        Self { blocks: Default::default() }
+    }
+
+    /// Records the statistics of the given block, which took the given time to produce,
+    /// with the given number of unconfirmed transactions in the memory pool when it was proposed.
+    pub fn record<N: Network>(&self, block: &Block<N>, production_time: Duration, mempool_depth: usize) {
+        // Note: A fee that cannot be computed is not counted, as the block is already in the ledger.
+        let total_fees = block.transactions().iter().filter_map(|(_, transaction)| transaction.fee().ok()).sum();
+        let stats = BlockStats {
+            height: block.height(),
+            timestamp: block.timestamp(),
+            transactions: block.transactions().len(),
+            total_fees,
+            production_ms: production_time.as_millis() as u64,
+            mempool_depth,
+        };
+
+        let mut blocks = self.blocks.write();
+        if blocks.len() == STATS_CAPACITY {
+            blocks.pop_front();
+        }
+        blocks.push_back(stats);
+    }
+
+    /// Returns the statistics of the retained blocks from the given height (inclusive) to the given height
+    /// (exclusive), from the oldest to the newest.
+    pub fn range(&self, from: Option<u32>, to: Option<u32>) -> Vec<BlockStats> {
+        self.blocks
+            .read()
+            .iter()
+            .filter(|stats| from.map_or(true, |from| stats.height >= from) && to.map_or(true, |to| stats.height < to))
+            .cloned()
+            .collect()
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm::prelude::{Network, Transaction};
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::path::Path;
+
+/// The contents of a proposed block that may be changed before the block is signed.
+#[derive(Clone, Debug)]
+pub struct BlockTemplate<N: Network> {
+    /// The transactions of the block, in the order they are included.
+    pub transactions: Vec<Transaction<N>>,
+    /// The timestamp of the block, which must remain after the timestamp of the latest block.
+    pub timestamp: i64,
+}
+
+/// A development hook that changes each proposed block before it is signed, e.g. to drop or reorder its transactions,
+/// so that client software can be tested against blocks a well-behaved producer would not propose.
+pub trait BlockTemplateHook<N: Network>: Send + Sync {
+    /// Changes the template of the block at the given height.
+    fn apply(&self, height: u32, template: &mut BlockTemplate<N>) -> Result<()>;
+}
+
+/// A change to the proposed blocks, scripted in the block template file.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BlockTemplateRule {
+    /// The height of the block the rule applies to. If omitted, the rule applies to every block.
+    #[serde(default)]
+    pub height: Option<u32>,
+    /// The indices of the proposed transactions to drop. The dropped transactions remain in the memory pool.
+    #[serde(default)]
+    pub drop: Vec<usize>,
+    /// If `true`, the order of the transactions is reversed.
+    #[serde(default)]
+    pub reverse: bool,
+    /// The number of seconds added to the timestamp of the block, which may be negative.
+    #[serde(default)]
+    pub timestamp_offset: i64,
+}
+
+/// The block template hook scripted by a JSON file of rules, which are applied in order.
+#[derive(Clone, Debug, Default)]
+pub struct BlockTemplateScript {
+    /// The rules of the script.
+    rules: Vec<BlockTemplateRule>,
+}
+
+impl BlockTemplateScript {
+    /// Initializes a new script with the given rules.
+    pub fn new(rules: Vec<BlockTemplateRule>) -> Self {
+        Self { rules }
+    }
+
+    /// Loads the script from the given JSON file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let rules = std::fs::read_to_string(path)
+            .map_err(|error| anyhow!("Failed to read the block template script '{}': {error}", path.display()))?;
+        let rules = serde_json::from_str(&rules)
+            .map_err(|error| anyhow!("Invalid block template script '{}': {error}", path.display()))?;
+        Ok(Self::new(rules))
+    }
+}
+
+impl<N: Network> BlockTemplateHook<N> for BlockTemplateScript {
+    fn apply(&self, height: u32, template: &mut BlockTemplate<N>) -> Result<()> {
+        for rule in self.rules.iter().filter(|rule| rule.height.map_or(true, |rule_height| rule_height == height)) {
+            // Drop the transactions at the given indices of the proposed order.
+            let mut index = 0;
+            template.transactions.retain(|_| {
+                index += 1;
+                !rule.drop.contains(&(index - 1))
+            });
+            // Reorder the transactions.
+            if rule.reverse {
+                template.transactions.reverse();
+            }
+            // Adjust the timestamp.
+            template.timestamp = template.timestamp.saturating_add(rule.timestamp_offset);
+            debug!("Applied a block template rule to block {height}: {rule:?}");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::prelude::Testnet3;
+
+    #[test]
+    fn test_script() {
+        let script: Vec<BlockTemplateRule> =
+            serde_json::from_str(r#"[{ "height": 2, "timestamp_offset": -5 }, { "timestamp_offset": 1 }]"#).unwrap();
+        let script = BlockTemplateScript::new(script);
+
+        // Only the rules of the block height are applied.
+        let mut template = BlockTemplate::<Testnet3> { transactions: vec![], timestamp: 100 };
+        script.apply(1, &mut template).unwrap();
+        assert_eq!(template.timestamp, 101);
+        script.apply(2, &mut template).unwrap();
+        assert_eq!(template.timestamp, 97);
+
+        // Unknown fields are rejected, so that a typo does not silently disable a rule.
+        assert!(serde_json::from_str::<Vec<BlockTemplateRule>>(r#"[{ "revers": true }]"#).is_err());
+    }
+}
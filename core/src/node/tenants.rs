@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    node::{serve, sign_response, CorsPolicy, DevelopmentBeacon, NodeConfig, RestLimits},
+    output,
+};
+
+use snarkos::node::rest::with;
+use snarkvm::prelude::{Block, ConsensusMemory, ConsensusStore, Network, PrivateKey, VM};
+
+use anyhow::{ensure, Result};
+use colored::*;
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use warp::{reply, Filter, Reply};
+
+/// Isolated development nodes hosted by one process. Each tenant has its own ledger, account, and block production,
+/// and the REST routes of each tenant are served under `/tenants/{name}` by a shared server.
+#[derive(Clone)]
+pub struct Tenants<N: Network> {
+    /// The node of each tenant, by name.
+    nodes: IndexMap<String, DevelopmentBeacon<N>>,
+}
+
+impl<N: Network> Tenants<N> {
+    /// Starts a node for each of the given tenants, and serves their REST routes at the given IP.
+    /// The genesis block of each tenant funds the faucet account of the given private key.
+    pub async fn start(
+        rest_ip: SocketAddr,
+        names: Vec<String>,
+        faucet_key: PrivateKey<N>,
+        config: NodeConfig,
+    ) -> Result<Self> {
+        let mut nodes = IndexMap::new();
+        for name in names {
+            ensure!(is_valid_name(&name), "Invalid tenant name '{name}' (use letters, digits, '-', and '_')");
+            ensure!(!nodes.contains_key(&name), "The tenant '{name}' is listed more than once");
+            output!("⏳ Initializing the ledger of tenant '{name}'...");
+
+            // Initialize the account of the tenant, which produces its blocks.
+            let private_key = PrivateKey::<N>::new(&mut rand::thread_rng())?;
+            // Initialize the genesis block of the tenant, which funds the faucet.
+            let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?;
+            let genesis = Block::genesis(&vm, &faucet_key, &mut rand::thread_rng())?;
+            // The block interval of a tenant is changed independently of the other tenants.
+            let block_interval = Arc::new(AtomicU64::new(config.block_interval.load(Ordering::SeqCst)));
+            let config = NodeConfig { block_interval, ..config.clone() };
+
+            let node = DevelopmentBeacon::new(None, private_key, Some(faucet_key), Some(genesis), None, config).await?;
+            nodes.insert(name, node);
+        }
+
+        let tenants = Self { nodes };
+        tenants.spawn_server(rest_ip, config.rest_limits, config.cors_policy.clone());
+        Ok(tenants)
+    }
+
+    /// Serves the REST routes of each tenant under `/tenants/{name}`, and the list of tenants at `/tenants`.
+    fn spawn_server(&self, rest_ip: SocketAddr, limits: RestLimits, cors_policy: Arc<RwLock<CorsPolicy>>) {
+        // GET /tenants
+        let list = self
+            .nodes
+            .iter()
+            .map(|(name, node)| {
+                serde_json::json!({
+                    "name": name,
+                    "address": node.address(),
+                    "faucet": node.faucet().address(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut routes =
+            warp::get().and(warp::path!("tenants")).map(move || Box::new(reply::json(&list)) as Box<dyn Reply>).boxed();
+
+        // /tenants/{name}/testnet3/...
+        for (name, node) in &self.nodes {
+            let signer = node.rest().config.sign_responses.then(|| node.rest().account.clone()).flatten();
+            let tenant_routes = warp::path("tenants")
+                .and(warp::path(name.clone()))
+                .and(node.rest().routes())
+                .and(with(signer))
+                .and_then(sign_response)
+                .map(|reply| Box::new(reply) as Box<dyn Reply>)
+                .boxed();
+            routes = routes.or(tenant_routes).unify().boxed();
+        }
+
+        let num_tenants = self.nodes.len();
+        tokio::spawn(async move {
+            output!("🌐 Starting the REST server for {num_tenants} tenants at {}.\n", rest_ip.to_string().bold());
+
+            // Start the server.
+            serve(routes, rest_ip, limits, cors_policy).await
+        });
+    }
+}
+
+/// Returns `true` if the given tenant name can be used as a path segment.
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm::prelude::{Address, Network};
+
+use anyhow::Result;
+use parking_lot::RwLock;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// The resources used by an account on the node.
+#[derive(Clone, Debug, Default)]
+pub struct AccountUsage {
+    /// The time spent constructing the transactions of the account, including simulations and fee estimates.
+    pub proving_time: Duration,
+    /// The number of transactions submitted by the account to the memory pool.
+    pub transactions: u64,
+    /// The number of faucet pours into the account.
+    pub faucet_pours: u64,
+    /// The number of gates poured into the account by the faucet.
+    pub faucet_gates: u64,
+}
+
+/// The usage of the node by each account, metered since the node started.
+/// The usage is keyed by the address of the signer, so the registered account token of each pipeline is metered
+/// separately. Faucet pours are metered for their recipient.
+#[derive(Clone, Debug)]
+pub struct UsageRegistry<N: Network> {
+    /// The map of account addresses to their usage.
+    usage: Arc<RwLock<HashMap<Address<N>, AccountUsage>>>,
+}
+
+impl<N: Network> Default for UsageRegistry<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> UsageRegistry<N> {
+    /// Initializes a new instance of the usage registry.
+    pub fn new() -> Self {
+        Self { usage: Default::default() }
+    }
+
+    /// Returns the given function, metering the time it takes on behalf of the given account.
+    pub fn meter<T>(&self, address: Address<N>, function: impl FnOnce() -> Result<T>) -> impl FnOnce() -> Result<T> {
+        let usage = self.clone();
+        move || {
+            let start = Instant::now();
+            let result = function();
+            usage.usage.write().entry(address).or_default().proving_time += start.elapsed();
+            result
+        }
+    }
+
+    /// Records a transaction submitted by the given account.
+    pub fn add_transaction(&self, address: Address<N>) {
+        self.usage.write().entry(address).or_default().transactions += 1;
+    }
+
+    /// Records a faucet pour of the given number of gates into the given account.
+    pub fn add_pour(&self, address: Address<N>, gates: u64) {
+        let mut usage = self.usage.write();
+        let usage = usage.entry(address).or_default();
+        usage.faucet_pours += 1;
+        usage.faucet_gates = usage.faucet_gates.saturating_add(gates);
+    }
+
+    /// Returns the usage of each account, starting with the account with the longest proving time.
+    pub fn accounts(&self) -> Vec<(Address<N>, AccountUsage)> {
+        let mut accounts =
+            self.usage.read().iter().map(|(address, usage)| (*address, usage.clone())).collect::<Vec<_>>();
+        accounts.sort_by(|(_, a), (_, b)| b.proving_time.cmp(&a.proving_time));
+        accounts
+    }
+}
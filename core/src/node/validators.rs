@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::node::{Ledger, PoolLimits, SingleNodeConsensus};
+
+use snarkvm::{
+    prelude::{Address, Block, Network, PrivateKey, Transaction},
+    synthesizer::ConsensusMemory,
+};
+
+use anyhow::Result;
+use core::time::Duration;
+use parking_lot::Mutex;
+use std::{collections::BTreeMap, sync::Arc};
+use tokio::{
+    sync::broadcast::{self, error::RecvError},
+    task::JoinHandle,
+};
+
+/// The maximum number of gossip messages buffered for a validator that falls behind.
+const GOSSIP_CAPACITY: usize = 4096;
+
+/// A message gossiped between the validators of a devnet.
+#[derive(Clone, Debug)]
+pub enum GossipMessage<N: Network> {
+    /// An unconfirmed transaction, from the memory pool of the given validator.
+    Transaction { sender: usize, transaction: Transaction<N> },
+    /// A block, proposed by the given validator.
+    Block { sender: usize, block: Block<N> },
+}
+
+impl<N: Network> GossipMessage<N> {
+    /// Returns the index of the validator that sent the message.
+    pub const fn sender(&self) -> usize {
+        match self {
+            Self::Transaction { sender, .. } | Self::Block { sender, .. } => *sender,
+        }
+    }
+}
+
+/// The gossip channel shared by the validators of a devnet.
+/// Each message is delivered after the gossip latency and a random jitter, so messages may arrive out of order.
+#[derive(Clone)]
+pub struct Gossip<N: Network> {
+    /// The sender of the channel.
+    sender: broadcast::Sender<GossipMessage<N>>,
+    /// The minimum delay before a message is delivered.
+    latency: Duration,
+    /// The maximum random delay added to the latency of each message.
+    jitter: Duration,
+}
+
+impl<N: Network> Gossip<N> {
+    /// Initializes a new gossip channel with the given latency and jitter.
+    pub fn new(latency: Duration, jitter: Duration) -> Self {
+        let (sender, _) = broadcast::channel(GOSSIP_CAPACITY);
+        Self { sender, latency, jitter }
+    }
+
+    /// Sends the given message to every other validator.
+    pub fn publish(&self, message: GossipMessage<N>) {
+        // Note: Sending only fails if no validator is subscribed.
+        let _ = self.sender.send(message);
+    }
+
+    /// Delivers the messages of the other validators to the given validator, until the channel is closed.
+    pub fn subscribe(&self, validator: Validator<N>) -> JoinHandle<()> {
+        let mut receiver = self.sender.subscribe();
+        let (latency, jitter) = (self.latency, self.jitter);
+        tokio::spawn(async move {
+            loop {
+                let message = match receiver.recv().await {
+                    Ok(message) => message,
+                    Err(RecvError::Lagged(num_messages)) => {
+                        warn!("Validator {} missed {num_messages} gossip messages", validator.index());
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+                if message.sender() == validator.index() {
+                    continue;
+                }
+                // Deliver the message after the latency, without holding up the messages behind it.
+                let validator = validator.clone();
+                let delay = latency + jitter.mul_f64(rand::random::<f64>());
+                tokio::spawn(async move {
+                    tokio::time::sleep(delay).await;
+                    if let Err(error) = tokio::task::spawn_blocking(move || validator.receive(message)).await {
+                        error!("Failed to deliver a gossip message (JoinError): {error}");
+                    }
+                });
+            }
+        })
+    }
+}
+
+/// A validator of a devnet. The validators propose blocks in turn, from their own memory pools,
+/// and accept the blocks proposed by the other validators.
+#[derive(Clone)]
+pub struct Validator<N: Network> {
+    /// The index of the validator.
+    index: usize,
+    /// The private key that signs the blocks proposed by the validator.
+    private_key: PrivateKey<N>,
+    /// The consensus module of the validator.
+    consensus: SingleNodeConsensus<N, ConsensusMemory<N>>,
+    /// The ledger of the validator.
+    ledger: Ledger<N, ConsensusMemory<N>>,
+    /// The blocks that arrived before their parent block, by height.
+    pending_blocks: Arc<Mutex<BTreeMap<u32, (usize, Block<N>)>>>,
+}
+
+impl<N: Network> Validator<N> {
+    /// Initializes a validator with the given consensus module.
+    pub fn new(
+        index: usize,
+        private_key: PrivateKey<N>,
+        consensus: SingleNodeConsensus<N, ConsensusMemory<N>>,
+        ledger: Ledger<N, ConsensusMemory<N>>,
+    ) -> Self {
+        Self { index, private_key, consensus, ledger, pending_blocks: Default::default() }
+    }
+
+    /// Initializes a validator with a new ledger, which replays the blocks of the given ledger.
+    pub fn replicate(
+        index: usize,
+        private_key: PrivateKey<N>,
+        source: &Ledger<N, ConsensusMemory<N>>,
+        clock_skew_tolerance: u64,
+        pool_limits: PoolLimits,
+    ) -> Result<Self> {
+        let ledger = Ledger::load(Some(source.get_block(0)?), None)?;
+        let consensus = SingleNodeConsensus::new(ledger.clone(), clock_skew_tolerance, pool_limits)?;
+        for height in 1..=source.latest_height() {
+            consensus.add_next_block(&source.get_block(height)?)?;
+        }
+        Ok(Self::new(index, private_key, consensus, ledger))
+    }
+
+    /// Returns the index of the validator.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the address of the validator.
+    pub fn address(&self) -> Result<Address<N>> {
+        Address::try_from(&self.private_key)
+    }
+
+    /// Returns the consensus module of the validator.
+    pub const fn consensus(&self) -> &SingleNodeConsensus<N, ConsensusMemory<N>> {
+        &self.consensus
+    }
+
+    /// Returns the ledger of the validator.
+    pub const fn ledger(&self) -> &Ledger<N, ConsensusMemory<N>> {
+        &self.ledger
+    }
+
+    /// Proposes the next block from the memory pool of the validator, and advances to it.
+    pub fn propose(&self) -> Result<Block<N>> {
+        self.consensus.produce_next_block(&self.private_key, &mut rand::thread_rng())
+    }
+
+    /// Handles the given message from another validator.
+    pub fn receive(&self, message: GossipMessage<N>) {
+        match message {
+            GossipMessage::Transaction { sender, transaction } => {
+                let transaction_id = transaction.id();
+                if let Err(error) = self.consensus.add_unconfirmed_transaction(transaction) {
+                    trace!(
+                        "Validator {} ignored transaction '{transaction_id}' from validator {sender}: {error}",
+                        self.index
+                    );
+                }
+            }
+            GossipMessage::Block { sender, block } => self.receive_block(sender, block),
+        }
+    }
+
+    /// Accepts the given block, and any pending blocks that follow it.
+    /// A block that arrives before its parent block waits for it.
+    fn receive_block(&self, sender: usize, block: Block<N>) {
+        let mut pending_blocks = self.pending_blocks.lock();
+        if block.height() <= self.ledger.latest_height() {
+            return;
+        }
+        pending_blocks.insert(block.height(), (sender, block));
+
+        while let Some((sender, block)) = pending_blocks.remove(&(self.ledger.latest_height() + 1)) {
+            match self.consensus.add_next_block(&block) {
+                Ok(()) => debug!("Validator {} accepted block {} from validator {sender}", self.index, block.height()),
+                Err(error) => {
+                    warn!(
+                        "Validator {} rejected block {} from validator {sender}: {error}",
+                        self.index,
+                        block.height()
+                    );
+                    break;
+                }
+            }
+        }
+    }
+}
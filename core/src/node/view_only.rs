@@ -0,0 +1,113 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::node::{Ledger, NodeConfig, Peer, ReloadableConfig, Rest, SingleNodeConsensus};
+
+use snarkvm::prelude::{Block, ConsensusMemory, Network};
+
+use anyhow::Result;
+use parking_lot::RwLock;
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use tokio::task::JoinHandle;
+
+/// A view-only node serves the ledger of a genesis block, or the chain of the peer it follows,
+/// without holding a private key. It accepts broadcast transactions, but never produces blocks itself.
+#[derive(Clone)]
+pub struct ViewOnlyNode<N: Network> {
+    /// The ledger of the node.
+    ledger: Ledger<N, ConsensusMemory<N>>,
+    /// The REST server of the node.
+    rest: Arc<Rest<N, ConsensusMemory<N>>>,
+    /// The spawned handles.
+    handles: Arc<RwLock<Vec<JoinHandle<()>>>>,
+    /// The shutdown signal.
+    shutdown: Arc<AtomicBool>,
+}
+
+impl<N: Network> ViewOnlyNode<N> {
+    /// Initializes a new view-only node from the given genesis block, which follows the configured peer, if any.
+    pub async fn new(rest_ip: SocketAddr, genesis: Block<N>, config: NodeConfig) -> Result<Self> {
+        // Initialize the ledger.
+        let ledger = Ledger::load(Some(genesis), None)?;
+        // Persist the verifying keys of program functions, if requested.
+        if let Some(cache_dir) = &config.cache_dir {
+            ledger.key_cache().set_directory(cache_dir)?;
+        }
+        // Initialize the consensus, which checks the blocks of the peer and the broadcast transactions.
+        let consensus = SingleNodeConsensus::new(ledger.clone(), config.clock_skew_tolerance, config.pool_limits)?;
+        // Retrieve the peer the node follows, if any.
+        let peer = config.peer.as_deref().map(Peer::new);
+        // Initialize the REST server, without the node and faucet accounts.
+        let rest = Arc::new(Rest::start(rest_ip, None, None, Some(consensus.clone()), ledger.clone(), config)?);
+        // Initialize the node, which shares the shutdown signal of the REST server.
+        let shutdown = rest.shutdown.flag();
+        let node = Self { ledger, rest, handles: Default::default(), shutdown };
+        // Follow the peer, if any.
+        if let Some(peer) = peer {
+            let handle = peer.follow(consensus, node.ledger.clone(), node.shutdown.clone()).await?;
+            node.handles.write().push(handle);
+        }
+        // Initialize the signal handler.
+        node.handle_signals();
+        // Return the node.
+        Ok(node)
+    }
+
+    /// Returns the ledger.
+    pub fn ledger(&self) -> &Ledger<N, ConsensusMemory<N>> {
+        &self.ledger
+    }
+
+    /// Returns the REST server.
+    pub fn rest(&self) -> &Arc<Rest<N, ConsensusMemory<N>>> {
+        &self.rest
+    }
+
+    /// Handles OS signals for the node to intercept and perform a clean shutdown, and the shutdown requests of
+    /// `POST /testnet3/node/shutdown`. As the node produces no blocks, it has no block to finish before it exits.
+    fn handle_signals(&self) {
+        let node = self.clone();
+        tokio::task::spawn(async move {
+            match tokio::signal::ctrl_c().await {
+                Ok(()) => node.shut_down(),
+                Err(error) => error!("tokio::signal::ctrl_c encountered an error: {}", error),
+            }
+        });
+
+        let node = self.clone();
+        tokio::task::spawn(async move {
+            node.rest.shutdown.requested().await;
+            node.shut_down();
+        });
+
+        // Reload the configuration file, if any, on `SIGHUP`.
+        ReloadableConfig::reload_on_hangup(self.rest.config.clone());
+    }
+
+    /// Shuts down the node, and exits the process.
+    fn shut_down(&self) {
+        info!("Shutting down...");
+        self.shutdown.store(true, Ordering::SeqCst);
+        self.handles.read().iter().for_each(|handle| handle.abort());
+        std::process::exit(0);
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm::prelude::{Block, Field, GraphKey, Network, Plaintext, Record, ViewKey};
+
+use anyhow::Result;
+use parking_lot::RwLock;
+use rand::Rng;
+use serde::Serialize;
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::broadcast;
+
+/// The number of notifications buffered for each WebSocket subscriber of a watch.
+const WATCH_CHANNEL_CAPACITY: usize = 64;
+
+/// A notification that a watched record was spent.
+#[derive(Clone, Debug, Serialize)]
+#[serde(bound = "")]
+pub struct RecordSpent<N: Network> {
+    /// The ID of the watch.
+    pub watch_id: String,
+    /// The commitment of the spent record.
+    pub commitment: Field<N>,
+    /// The ID of the transaction that spent the record.
+    pub transaction_id: N::TransactionID,
+    /// The height of the block that includes the transaction.
+    pub height: u32,
+}
+
+/// The commitments watched by a client, until they are spent.
+#[derive(Debug)]
+struct Watch<N: Network> {
+    /// The map of the tags of the watched records to their commitments.
+    tags: HashMap<Field<N>, Field<N>>,
+    /// The URL the notifications are posted to, if any.
+    webhook: Option<String>,
+    /// The channel of the notifications, for the WebSocket subscribers.
+    sender: broadcast::Sender<RecordSpent<N>>,
+}
+
+/// The watch lists of record commitments, which notify clients when a watched record is spent.
+#[derive(Clone, Debug)]
+pub struct WatchRegistry<N: Network> {
+    /// The map of watch IDs to watches.
+    watches: Arc<RwLock<HashMap<String, Watch<N>>>>,
+}
+
+impl<N: Network> Default for WatchRegistry<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> WatchRegistry<N> {
+    /// Initializes a new instance of the watch registry.
+    pub fn new() -> Self {
+        Self { watches: Default::default() }
+    }
+
+    /// Returns the tags of the records with the given commitments, which are revealed when the records are spent.
+    pub fn tags(view_key: &ViewKey<N>, commitments: &[Field<N>]) -> Result<Vec<(Field<N>, Field<N>)>> {
+        let sk_tag = GraphKey::try_from(view_key)?.sk_tag();
+        commitments
+            .iter()
+            .map(|commitment| Ok((Record::<N, Plaintext<N>>::tag(sk_tag, *commitment)?, *commitment)))
+            .collect()
+    }
+
+    /// Watches the records with the given tags and commitments, and returns the ID of the watch.
+    pub fn register(&self, tags: Vec<(Field<N>, Field<N>)>, webhook: Option<String>) -> String {
+        let id = rand::thread_rng().gen::<[u8; 16]>().iter().map(|byte| format!("{byte:02x}")).collect::<String>();
+        let (sender, _) = broadcast::channel(WATCH_CHANNEL_CAPACITY);
+        self.watches.write().insert(id.clone(), Watch { tags: tags.into_iter().collect(), webhook, sender });
+        id
+    }
+
+    /// Removes the given watch, and closes its WebSocket subscriptions. Returns `true` if the watch existed.
+    pub fn remove(&self, id: &str) -> bool {
+        self.watches.write().remove(id).is_some()
+    }
+
+    /// Returns a receiver of the notifications of the given watch, if it exists.
+    pub fn subscribe(&self, id: &str) -> Option<broadcast::Receiver<RecordSpent<N>>> {
+        self.watches.read().get(id).map(|watch| watch.sender.subscribe())
+    }
+
+    /// Returns `true` if there are no watches.
+    pub fn is_empty(&self) -> bool {
+        self.watches.read().is_empty()
+    }
+
+    /// Notifies the WebSocket subscribers of the watched records spent in the given block, and stops watching them.
+    /// Returns the notifications to post to the webhooks, with their URLs.
+    pub fn notify(&self, block: &Block<N>) -> Vec<(String, RecordSpent<N>)> {
+        let mut deliveries = Vec::new();
+        let mut watches = self.watches.write();
+        for (_, transaction) in block.transactions().iter() {
+            for tag in transaction.tags() {
+                for (id, watch) in watches.iter_mut() {
+                    if let Some(commitment) = watch.tags.remove(tag) {
+                        let notification = RecordSpent {
+                            watch_id: id.clone(),
+                            commitment,
+                            transaction_id: transaction.id(),
+                            height: block.height(),
+                        };
+                        // A send only fails if there are no subscribers, in which case the notification is not needed.
+                        let _ = watch.sender.send(notification.clone());
+                        if let Some(webhook) = &watch.webhook {
+                            deliveries.push((webhook.clone(), notification));
+                        }
+                    }
+                }
+            }
+        }
+        deliveries
+    }
+}
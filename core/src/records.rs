@@ -0,0 +1,289 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Helpers to decrypt, commit to, and serialize records, shared by the node, the CLI, and library users.
+
+use snarkvm::{
+    prelude::{
+        Balance,
+        Ciphertext,
+        Entry,
+        Field,
+        Identifier,
+        Network,
+        Owner,
+        Plaintext,
+        ProgramID,
+        Record,
+        Transaction,
+        ViewKey,
+    },
+    synthesizer::Output,
+};
+
+use anyhow::{anyhow, bail, ensure, Result};
+use core::str::FromStr;
+use serde_json::{json, Map, Value};
+
+/// Returns `true` if the given record is owned by the account of the given view key.
+pub fn is_owner<N: Network>(record: &Record<N, Ciphertext<N>>, view_key: &ViewKey<N>) -> bool {
+    record.is_owner(view_key)
+}
+
+/// Decrypts the given record with the given view key.
+/// Returns an error if the record is not owned by the account of the view key.
+pub fn decrypt<N: Network>(
+    record: &Record<N, Ciphertext<N>>,
+    view_key: &ViewKey<N>,
+) -> Result<Record<N, Plaintext<N>>> {
+    ensure!(record.is_owner(view_key), "The record is not owned by the account of the given view key");
+    record.decrypt(view_key)
+}
+
+/// Returns the commitment of the given record, as output by the given record type of the given program.
+pub fn commitment<N: Network>(
+    record: &Record<N, Plaintext<N>>,
+    program_id: &ProgramID<N>,
+    record_name: &Identifier<N>,
+) -> Result<Field<N>> {
+    record.to_commitment(program_id, record_name)
+}
+
+/// Returns the commitment and the ciphertext of each record output by the given transaction.
+pub fn record_outputs<N: Network>(
+    transaction: &Transaction<N>,
+) -> impl '_ + Iterator<Item = (Field<N>, Option<&Record<N, Ciphertext<N>>>)> {
+    transaction.transitions().flat_map(|transition| transition.outputs()).filter_map(|output| match output {
+        Output::Record(commitment, _, record) => Some((*commitment, record.as_ref())),
+        _ => None,
+    })
+}
+
+/// Returns the record outputs of the `credits.aleo/transfer` transition of the given transaction: the commitment and
+/// the ciphertext of the record of the recipient, and the commitment of the change record of the sender.
+/// The function outputs the record of the recipient first, whatever the other transitions of the transaction are,
+/// e.g. a transition that pays the fee.
+pub fn transfer_outputs<N: Network>(
+    transaction: &Transaction<N>,
+) -> Result<(Field<N>, Record<N, Ciphertext<N>>, Field<N>)> {
+    let (program_id, function_name) = (ProgramID::from_str("credits.aleo")?, Identifier::from_str("transfer")?);
+    let transition = transaction
+        .transitions()
+        .find(|transition| *transition.program_id() == program_id && *transition.function_name() == function_name)
+        .ok_or_else(|| anyhow!("The transaction has no 'credits.aleo/transfer' transition"))?;
+
+    let mut outputs = transition.outputs().iter().filter_map(|output| match output {
+        Output::Record(commitment, _, record) => Some((*commitment, record.as_ref())),
+        _ => None,
+    });
+    match (outputs.next(), outputs.next()) {
+        (Some((recipient_commitment, Some(recipient_record))), Some((change_commitment, _))) => {
+            Ok((recipient_commitment, recipient_record.clone(), change_commitment))
+        }
+        _ => bail!("The 'credits.aleo/transfer' transition does not output a recipient record and a change record"),
+    }
+}
+
+/// Returns the commitment and the plaintext of each record output by the given transaction,
+/// that is owned by the account of the given view key.
+pub fn decrypt_outputs<N: Network>(
+    transaction: &Transaction<N>,
+    view_key: &ViewKey<N>,
+) -> Vec<(Field<N>, Record<N, Plaintext<N>>)> {
+    record_outputs(transaction)
+        .filter_map(|(commitment, record)| match record {
+            Some(record) if record.is_owner(view_key) => match record.decrypt(view_key) {
+                Ok(record) => Some((commitment, record)),
+                Err(error) => {
+                    warn!("Failed to decrypt the record '{commitment}': {error}");
+                    None
+                }
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// The number of gates below which an unspent record is counted as dust, as it cannot cover a typical fee.
+pub const DUST_THRESHOLD: u64 = 10_000;
+
+/// Returns a warning if the given balance is spread across records, such that a single transaction
+/// can spend much less than the whole balance. Note that a transaction spends one record for its fee.
+pub fn fragmentation_warning(
+    balance: u64,
+    spendable: u64,
+    num_records: usize,
+    num_dust_records: usize,
+) -> Option<String> {
+    // Warn if a single transaction can spend less than a quarter of the balance.
+    match num_records > 1 && spendable < balance / 4 {
+        true => Some(format!(
+            "Only {spendable} of the {balance} gates can be spent in a single transaction, as they are spread \
+             across {num_records} records ({num_dust_records} of which hold less than {DUST_THRESHOLD} gates). \
+             Consolidate the records with 'slingshot sweep'."
+        )),
+        false => None,
+    }
+}
+
+/// Returns the given record as structured JSON. For example:
+/// ```json
+/// {
+///   "owner": { "visibility": "private", "value": "aleo1..." },
+///   "gates": { "visibility": "private", "value": 100 },
+///   "data": { "amount": { "visibility": "public", "value": "5u64" } },
+///   "nonce": "...group"
+/// }
+/// ```
+/// Literals are strings, and interfaces are objects of their members.
+pub fn to_json<N: Network>(record: &Record<N, Plaintext<N>>) -> Value {
+    let owner = match record.owner() {
+        Owner::Public(_) => "public",
+        Owner::Private(_) => "private",
+    };
+    let gates = match record.gates() {
+        Balance::Public(_) => "public",
+        Balance::Private(_) => "private",
+    };
+    let data = record
+        .data()
+        .iter()
+        .map(|(name, entry)| {
+            let (visibility, plaintext) = match entry {
+                Entry::Constant(plaintext) => ("constant", plaintext),
+                Entry::Public(plaintext) => ("public", plaintext),
+                Entry::Private(plaintext) => ("private", plaintext),
+            };
+            (name.to_string(), json!({ "visibility": visibility, "value": plaintext_to_json(plaintext) }))
+        })
+        .collect::<Map<_, _>>();
+
+    json!({
+        "owner": { "visibility": owner, "value": (**record.owner()).to_string() },
+        "gates": { "visibility": gates, "value": ***record.gates() },
+        "data": data,
+        "nonce": record.nonce().to_string(),
+    })
+}
+
+/// Returns the given plaintext as structured JSON.
+fn plaintext_to_json<N: Network>(plaintext: &Plaintext<N>) -> Value {
+    match plaintext {
+        Plaintext::Literal(literal, _) => Value::String(literal.to_string()),
+        Plaintext::Interface(members, _) => {
+            Value::Object(members.iter().map(|(name, member)| (name.to_string(), plaintext_to_json(member))).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Network as CurrentNetwork;
+
+    use snarkvm::prelude::{Address, PrivateKey, Scalar, Uniform};
+
+    /// Returns a record owned by the given private key, and its ciphertext.
+    fn sample_record(
+        private_key: &PrivateKey<CurrentNetwork>,
+    ) -> (Record<CurrentNetwork, Plaintext<CurrentNetwork>>, Record<CurrentNetwork, Ciphertext<CurrentNetwork>>) {
+        let rng = &mut rand::thread_rng();
+        let address = Address::try_from(private_key).unwrap();
+
+        // Sample the randomizer, and derive the nonce.
+        let randomizer = Scalar::rand(rng);
+        let nonce = <CurrentNetwork as Network>::g_scalar_multiply(&randomizer);
+
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, gates: 100u64.private, amount: 5u64.public, \
+             point: {{ x: 1field.private, y: 2field.private }}, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let ciphertext = record.encrypt(randomizer).unwrap();
+        (record, ciphertext)
+    }
+
+    #[test]
+    fn test_decrypt() {
+        let rng = &mut rand::thread_rng();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let (record, ciphertext) = sample_record(&private_key);
+
+        assert!(is_owner(&ciphertext, &view_key));
+        assert_eq!(decrypt(&ciphertext, &view_key).unwrap(), record);
+    }
+
+    #[test]
+    fn test_decrypt_with_another_view_key() {
+        let rng = &mut rand::thread_rng();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let (_, ciphertext) = sample_record(&private_key);
+
+        let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        assert!(!is_owner(&ciphertext, &other_view_key));
+        assert!(decrypt(&ciphertext, &other_view_key).is_err());
+    }
+
+    #[test]
+    fn test_commitment() {
+        let rng = &mut rand::thread_rng();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let (record, ciphertext) = sample_record(&private_key);
+
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let record_name = Identifier::from_str("token").unwrap();
+        let expected = commitment(&record, &program_id, &record_name).unwrap();
+
+        // The commitment is deterministic, and is preserved by encryption.
+        assert_eq!(commitment(&record, &program_id, &record_name).unwrap(), expected);
+        let decrypted = decrypt(&ciphertext, &view_key).unwrap();
+        assert_eq!(commitment(&decrypted, &program_id, &record_name).unwrap(), expected);
+
+        // The commitment binds the program ID and the record name.
+        let other_program_id = ProgramID::from_str("other.aleo").unwrap();
+        assert_ne!(commitment(&record, &other_program_id, &record_name).unwrap(), expected);
+        let other_record_name = Identifier::from_str("other").unwrap();
+        assert_ne!(commitment(&record, &program_id, &other_record_name).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_to_json() {
+        let rng = &mut rand::thread_rng();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let (record, _) = sample_record(&private_key);
+
+        let json = to_json(&record);
+        assert_eq!(json["owner"], json!({ "visibility": "private", "value": address.to_string() }));
+        assert_eq!(json["gates"], json!({ "visibility": "private", "value": 100 }));
+        assert_eq!(json["data"]["amount"], json!({ "visibility": "public", "value": "5u64" }));
+        assert_eq!(
+            json["data"]["point"],
+            json!({ "visibility": "private", "value": { "x": "1field", "y": "2field" } })
+        );
+        assert_eq!(json["nonce"], json!(record.nonce().to_string()));
+    }
+
+    #[test]
+    fn test_fragmentation_warning() {
+        assert!(fragmentation_warning(1_000_000, 1_000_000, 1, 0).is_none());
+        assert!(fragmentation_warning(1_000_000, 600_000, 2, 0).is_none());
+        assert!(fragmentation_warning(1_000_000, 10_000, 100, 90).is_some());
+        assert!(fragmentation_warning(0, 0, 0, 0).is_none());
+    }
+}
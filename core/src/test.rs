@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the Aleo library.
+
+// The Aleo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    node::{serve, DevelopmentBeacon, NodeScan},
+    Network,
+};
+
+use snarkos::node::ledger::RecordMap;
+use snarkvm::prelude::{Address, Block, Identifier, PrivateKey, Program, ProgramID, Transaction, Value, ViewKey};
+
+use anyhow::{anyhow, Result};
+use std::net::{SocketAddr, TcpListener};
+use tokio::task::JoinHandle;
+
+/// A development node for the integration tests of Aleo programs, which serves its REST routes on a random port
+/// of the loopback interface. Blocks are only produced by `advance_block`, so that a test decides when the
+/// transactions it sends are confirmed. The node account is a new account, and the genesis block funds it as the
+/// faucet. The node is torn down by `shut_down`, or when it is dropped.
+pub struct TestNode {
+    /// The node.
+    node: DevelopmentBeacon<Network>,
+    /// The address at which the REST routes are served.
+    rest_ip: SocketAddr,
+    /// The REST server.
+    server: JoinHandle<()>,
+}
+
+impl TestNode {
+    /// Starts a node, and serves its REST routes on a random port.
+    pub async fn start() -> Result<Self> {
+        // Start the node, without block production, as the test advances the chain.
+        let node = DevelopmentBeacon::<Network>::builder().start().await?;
+        node.consensus().pause();
+
+        // Reserve a random port, and serve the REST routes at it.
+        let rest_ip = TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+        let (limits, cors_policy) = (node.rest().config.rest_limits, node.rest().config.cors_policy.clone());
+        let server = tokio::spawn(serve(node.rest().routes(), rest_ip, limits, cors_policy));
+
+        Ok(Self { node, rest_ip, server })
+    }
+
+    /// Returns the base endpoint of the REST routes, e.g. `http://127.0.0.1:54321`.
+    pub fn endpoint(&self) -> String {
+        format!("http://{}", self.rest_ip)
+    }
+
+    /// Returns the node.
+    pub const fn node(&self) -> &DevelopmentBeacon<Network> {
+        &self.node
+    }
+
+    /// Returns the private key of the node account, which is also the faucet account.
+    pub fn private_key(&self) -> &PrivateKey<Network> {
+        self.node.private_key()
+    }
+
+    /// Transfers the given number of gates from the faucet to the given address.
+    /// Returns the transaction, which is confirmed by the next block.
+    pub async fn pour(&self, address: Address<Network>, amount: u64) -> Result<Transaction<Network>> {
+        let (ledger, private_key) = (self.node.ledger().clone(), *self.node.faucet().private_key());
+        self.submit(move || ledger.create_transfer(&private_key, &NodeScan, address, amount)).await
+    }
+
+    /// Deploys the given program, signed by the account of the given private key.
+    /// Returns the transaction, which is confirmed by the next block.
+    pub async fn deploy(
+        &self,
+        private_key: &PrivateKey<Network>,
+        program: &Program<Network>,
+    ) -> Result<Transaction<Network>> {
+        let (ledger, private_key, program) = (self.node.ledger().clone(), *private_key, program.clone());
+        self.submit(move || ledger.create_deploy(&private_key, &NodeScan, &program, 0)).await
+    }
+
+    /// Executes the given function with the given inputs, signed by the account of the given private key.
+    /// Returns the transaction, which is confirmed by the next block.
+    pub async fn execute(
+        &self,
+        private_key: &PrivateKey<Network>,
+        program_id: ProgramID<Network>,
+        function_name: Identifier<Network>,
+        inputs: Vec<Value<Network>>,
+    ) -> Result<Transaction<Network>> {
+        let (ledger, private_key) = (self.node.ledger().clone(), *private_key);
+        self.submit(move || ledger.create_execute(&private_key, &NodeScan, &program_id, &function_name, &inputs, None))
+            .await
+    }
+
+    /// Produces the next block, which confirms the transactions in the memory pool.
+    /// If the memory pool is empty, the block contains a transfer of zero gates by the faucet.
+    pub async fn advance_block(&self) -> Result<Block<Network>> {
+        let (consensus, private_key, faucet_key) =
+            (self.node.consensus().clone(), *self.node.private_key(), *self.node.faucet().private_key());
+        tokio::task::spawn_blocking(move || {
+            if consensus.memory_pool().num_unconfirmed_transactions() == 0 {
+                consensus.add_empty_block_transaction(&faucet_key)?;
+            }
+            consensus.produce_next_block(&private_key, &mut rand::thread_rng())
+        })
+        .await
+        .map_err(|error| anyhow!("Failed to produce the next block (JoinError): {error}"))?
+    }
+
+    /// Returns the unspent records of the account of the given view key, keyed by commitment.
+    pub fn records(&self, view_key: &ViewKey<Network>) -> Result<RecordMap<Network>> {
+        self.node.ledger().find_unspent_records(view_key)
+    }
+
+    /// Shuts down the node, and stops serving its REST routes.
+    pub async fn shut_down(self) {
+        self.node.shut_down().await;
+        self.server.abort();
+    }
+
+    /// Constructs a transaction off the async runtime, and adds it to the memory pool.
+    async fn submit(
+        &self,
+        construct: impl FnOnce() -> Result<Transaction<Network>> + Send + 'static,
+    ) -> Result<Transaction<Network>> {
+        let transaction = tokio::task::spawn_blocking(construct)
+            .await
+            .map_err(|error| anyhow!("Failed to construct the transaction (JoinError): {error}"))??;
+        self.node.consensus().add_unconfirmed_transaction(transaction.clone())?;
+        Ok(transaction)
+    }
+}
+
+impl Drop for TestNode {
+    /// Stops the background tasks of the node once their current iteration is complete, and the REST server.
+    fn drop(&mut self) {
+        self.node.rest().shutdown.start();
+        self.server.abort();
+    }
+}